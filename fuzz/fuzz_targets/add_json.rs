@@ -0,0 +1,31 @@
+#![no_main]
+
+use json_gettext::JSONGetText;
+use libfuzzer_sys::fuzz_target;
+
+// The input is split at the first 0xFF byte (which cannot appear in UTF-8) into the catalogs of the default key and of another key.
+fuzz_target!(|data: &[u8]| {
+    let (default, other) = match data.iter().position(|&b| b == 0xFF) {
+        Some(i) => (&data[..i], &data[i + 1..]),
+        None => (data, &[][..]),
+    };
+
+    let (Ok(default), Ok(other)) = (std::str::from_utf8(default), std::str::from_utf8(other))
+    else {
+        return;
+    };
+
+    let mut builder = JSONGetText::build("en");
+
+    if builder.add_json("en", default).is_err() || builder.add_json("fr", other).is_err() {
+        return;
+    }
+
+    let Ok(ctx) = builder.build() else {
+        return;
+    };
+
+    for text in ctx.get("en").keys() {
+        let _ = ctx.get_text_with_key("fr", text);
+    }
+});
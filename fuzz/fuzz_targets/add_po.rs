@@ -0,0 +1,21 @@
+#![no_main]
+
+use json_gettext::JSONGetText;
+use libfuzzer_sys::fuzz_target;
+
+// The input is a PO catalog of the default key.
+fuzz_target!(|data: &str| {
+    let mut builder = JSONGetText::build("en");
+
+    if builder.add_po("en", data).is_err() {
+        return;
+    }
+
+    let Ok(ctx) = builder.build() else {
+        return;
+    };
+
+    for text in ctx.get("en").keys() {
+        let _ = ctx.get_text(text);
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use std::collections::HashMap;
+
+use json_gettext::{interpolate, placeholders_of, JSONGetTextValue};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|template: &str| {
+    let names = placeholders_of(template);
+
+    let args: HashMap<&str, JSONGetTextValue> =
+        names.iter().map(|&name| (name, JSONGetTextValue::Str(name))).collect();
+
+    interpolate(template, &args);
+    interpolate(template, &HashMap::new());
+});
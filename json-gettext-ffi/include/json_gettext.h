@@ -0,0 +1,77 @@
+#ifndef JSON_GETTEXT_H
+#define JSON_GETTEXT_H
+
+/* This file is generated by the build script of json-gettext-ffi. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ A `JSONGetText` instance.
+ */
+typedef struct JSONGetTextContext JSONGetTextContext;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Create a context from every JSON file in a directory, whose file names are used as keys (e.g. `en_US.json`). Returns `NULL` on failure.
+
+ # Safety
+
+ `default_key` and `path` must be valid NUL-terminated strings.
+ */
+struct JSONGetTextContext *json_gettext_context_from_directory(const char *default_key,
+                                                               const char *path);
+
+/*
+ Release a context. `ctx` can be `NULL`.
+
+ # Safety
+
+ `ctx` must be `NULL` or a context which has not been released.
+ */
+void json_gettext_context_free(struct JSONGetTextContext *ctx);
+
+/*
+ Get the default key of a context.
+
+ # Safety
+
+ `ctx` must be a valid context.
+ */
+char *json_gettext_default_key(const struct JSONGetTextContext *ctx);
+
+/*
+ Get a text with a key. If `key` is `NULL` or not in the context, the default key is used. Strings are returned as they are and other values as JSON. Returns `NULL` if the text is not found.
+
+ # Safety
+
+ `ctx` must be a valid context. `key` must be `NULL` or a valid NUL-terminated string, and `text` must be a valid NUL-terminated string.
+ */
+char *json_gettext_get_text(const struct JSONGetTextContext *ctx,
+                            const char *key,
+                            const char *text);
+
+/*
+ Release a string returned by this library. `s` can be `NULL`.
+
+ # Safety
+
+ `s` must be `NULL` or a string returned by this library which has not been released.
+ */
+void json_gettext_string_free(char *s);
+
+/*
+ Get the message of the last error in the current thread, or `NULL` if there has been no error. The string is owned by this library and is valid until the next error in the same thread.
+ */
+const char *json_gettext_last_error(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* JSON_GETTEXT_H */
@@ -0,0 +1,174 @@
+/*!
+# JSON Get Text FFI
+
+A C ABI for the `json-gettext` crate, so that programs in other languages can use the same catalogs. The C header is `include/json_gettext.h`. It is generated into `OUT_DIR` when this crate is built, and a test checks that the checked-in one is up to date. Run `JSON_GETTEXT_FFI_UPDATE_HEADER=1 cargo test -p json-gettext-ffi` to update it.
+
+Strings passed to the functions must be NUL-terminated UTF-8. Strings returned by the functions must be released with `json_gettext_string_free`, and contexts with `json_gettext_context_free`. When a function fails, it returns `NULL` and the reason can be retrieved with `json_gettext_last_error`.
+*/
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::fmt::Display;
+use std::ptr;
+
+use json_gettext::JSONGetText;
+
+/// A `JSONGetText` instance.
+pub struct JSONGetTextContext(JSONGetText<'static>);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error<E: Display>(err: E) {
+    // interior NUL bytes cannot be represented in a C string
+    let message = CString::new(err.to_string().replace('\0', " ")).unwrap();
+
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// # Safety
+///
+/// `s` must be `NULL` or a valid NUL-terminated string.
+unsafe fn to_str<'s>(s: *const c_char, name: &str) -> Option<&'s str> {
+    if s.is_null() {
+        set_last_error(format_args!("`{}` is NULL.", name));
+
+        return None;
+    }
+
+    match CStr::from_ptr(s).to_str() {
+        Ok(s) => Some(s),
+        Err(err) => {
+            set_last_error(format_args!("`{}` is not UTF-8: {}", name, err));
+
+            None
+        }
+    }
+}
+
+fn into_raw_string(s: String) -> *mut c_char {
+    match CString::new(s) {
+        Ok(s) => s.into_raw(),
+        Err(err) => {
+            set_last_error(err);
+
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Create a context from every JSON file in a directory, whose file names are used as keys (e.g. `en_US.json`). Returns `NULL` on failure.
+///
+/// # Safety
+///
+/// `default_key` and `path` must be valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn json_gettext_context_from_directory(
+    default_key: *const c_char,
+    path: *const c_char,
+) -> *mut JSONGetTextContext {
+    let (Some(default_key), Some(path)) =
+        (to_str(default_key, "default_key"), to_str(path, "path"))
+    else {
+        return ptr::null_mut();
+    };
+
+    let mut builder = JSONGetText::build(default_key);
+
+    let ctx = match builder.add_directory(path) {
+        Ok(_) => builder.build(),
+        Err(err) => Err(err),
+    };
+
+    match ctx {
+        Ok(ctx) => Box::into_raw(Box::new(JSONGetTextContext(ctx))),
+        Err(err) => {
+            set_last_error(err);
+
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release a context. `ctx` can be `NULL`.
+///
+/// # Safety
+///
+/// `ctx` must be `NULL` or a context which has not been released.
+#[no_mangle]
+pub unsafe extern "C" fn json_gettext_context_free(ctx: *mut JSONGetTextContext) {
+    if !ctx.is_null() {
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// Get the default key of a context.
+///
+/// # Safety
+///
+/// `ctx` must be a valid context.
+#[no_mangle]
+pub unsafe extern "C" fn json_gettext_default_key(ctx: *const JSONGetTextContext) -> *mut c_char {
+    into_raw_string((*ctx).0.get_default_key().to_string())
+}
+
+/// Get a text with a key. If `key` is `NULL` or not in the context, the default key is used. Strings are returned as they are and other values as JSON. Returns `NULL` if the text is not found.
+///
+/// # Safety
+///
+/// `ctx` must be a valid context. `key` must be `NULL` or a valid NUL-terminated string, and `text` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn json_gettext_get_text(
+    ctx: *const JSONGetTextContext,
+    key: *const c_char,
+    text: *const c_char,
+) -> *mut c_char {
+    let ctx = &(*ctx).0;
+
+    let Some(text) = to_str(text, "text") else {
+        return ptr::null_mut();
+    };
+
+    let value = if key.is_null() {
+        ctx.get_text(text)
+    } else {
+        let Some(key) = to_str(key, "key") else {
+            return ptr::null_mut();
+        };
+
+        ctx.get_text_with_key(key, text)
+    };
+
+    match value {
+        Some(value) => into_raw_string(value.to_string()),
+        None => {
+            set_last_error(format_args!("The text `{}` is not found.", text));
+
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release a string returned by this library. `s` can be `NULL`.
+///
+/// # Safety
+///
+/// `s` must be `NULL` or a string returned by this library which has not been released.
+#[no_mangle]
+pub unsafe extern "C" fn json_gettext_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Get the message of the last error in the current thread, or `NULL` if there has been no error. The string is owned by this library and is valid until the next error in the same thread.
+#[no_mangle]
+pub extern "C" fn json_gettext_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        match last_error.borrow().as_ref() {
+            Some(message) => message.as_ptr(),
+            None => ptr::null(),
+        }
+    })
+}
@@ -0,0 +1,90 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use json_gettext_ffi::*;
+
+unsafe fn take_string(s: *mut std::ffi::c_char) -> String {
+    assert!(!s.is_null());
+
+    let string = CStr::from_ptr(s).to_str().unwrap().to_string();
+
+    json_gettext_string_free(s);
+
+    string
+}
+
+#[test]
+fn context_from_directory() {
+    let default_key = CString::new("en_US").unwrap();
+    let path = CString::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../langs")).unwrap();
+
+    unsafe {
+        let ctx = json_gettext_context_from_directory(default_key.as_ptr(), path.as_ptr());
+
+        assert!(!ctx.is_null());
+
+        assert_eq!("en_US", take_string(json_gettext_default_key(ctx)));
+
+        let hello = CString::new("hello").unwrap();
+        let zh_tw = CString::new("zh_TW").unwrap();
+        let de = CString::new("de").unwrap();
+
+        assert_eq!(
+            "Hello, world!",
+            take_string(json_gettext_get_text(ctx, ptr::null(), hello.as_ptr()))
+        );
+        assert_eq!(
+            "哈囉，世界！",
+            take_string(json_gettext_get_text(ctx, zh_tw.as_ptr(), hello.as_ptr()))
+        );
+        assert_eq!(
+            "Hello, world!",
+            take_string(json_gettext_get_text(ctx, de.as_ptr(), hello.as_ptr()))
+        );
+
+        let missing = CString::new("missing").unwrap();
+
+        assert!(json_gettext_get_text(ctx, ptr::null(), missing.as_ptr()).is_null());
+        assert_eq!(
+            "The text `missing` is not found.",
+            CStr::from_ptr(json_gettext_last_error()).to_str().unwrap()
+        );
+
+        json_gettext_context_free(ctx);
+        json_gettext_context_free(ptr::null_mut());
+        json_gettext_string_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn context_from_directory_error() {
+    let default_key = CString::new("en_US").unwrap();
+    let path = CString::new("/nonexistent/json-gettext").unwrap();
+
+    unsafe {
+        assert!(json_gettext_context_from_directory(default_key.as_ptr(), path.as_ptr()).is_null());
+        assert!(!json_gettext_last_error().is_null());
+
+        assert!(json_gettext_context_from_directory(ptr::null(), path.as_ptr()).is_null());
+        assert_eq!(
+            "`default_key` is NULL.",
+            CStr::from_ptr(json_gettext_last_error()).to_str().unwrap()
+        );
+    }
+}
+
+#[test]
+fn header_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/json_gettext.h"));
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/json_gettext.h");
+
+    if std::env::var_os("JSON_GETTEXT_FFI_UPDATE_HEADER").is_some() {
+        std::fs::write(path, generated).unwrap();
+    }
+
+    assert_eq!(
+        generated,
+        std::fs::read_to_string(path).unwrap(),
+        "`include/json_gettext.h` is out of date, run `JSON_GETTEXT_FFI_UPDATE_HEADER=1 cargo test -p json-gettext-ffi` to update it"
+    );
+}
@@ -0,0 +1,64 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Expand to an array of the file stems and the `include_str!`ed contents of the JSON files in a directory relative to the manifest directory, sorted by their file names.
+pub(crate) fn expand(path: LitStr) -> syn::Result<TokenStream> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new(path.span(), "`CARGO_MANIFEST_DIR` is not set"))?;
+
+    let dir = PathBuf::from(manifest_dir).join(path.value());
+
+    let entries = fs::read_dir(&dir).map_err(|err| {
+        syn::Error::new(path.span(), format!("cannot read `{}`: {}", dir.display(), err))
+    })?;
+
+    let mut files = Vec::new();
+
+    for entry in entries {
+        let file = entry
+            .map_err(|err| {
+                syn::Error::new(path.span(), format!("cannot read `{}`: {}", dir.display(), err))
+            })?
+            .path();
+
+        if !file.is_file() || file.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+
+        let stem = match file.file_stem().and_then(|stem| stem.to_str()) {
+            Some(stem) => stem.to_string(),
+            None => continue,
+        };
+
+        let file = match file.to_str() {
+            Some(file) => file.to_string(),
+            None => {
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!("the path `{}` is not valid UTF-8", file.display()),
+                ))
+            }
+        };
+
+        files.push((stem, file));
+    }
+
+    files.sort();
+
+    let len = files.len();
+    let stems = files.iter().map(|(stem, _)| stem);
+    let files = files.iter().map(|(_, file)| file);
+
+    Ok(quote! {
+        {
+            let files: [(&'static str, &'static str); #len] = [#((#stems, include_str!(#files))),*];
+
+            files
+        }
+    })
+}
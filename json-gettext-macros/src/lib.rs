@@ -0,0 +1,62 @@
+/*!
+# JSON Get Text Macros
+
+Procedural macros for the [json-gettext](https://crates.io/crates/json-gettext) crate. Enable the `derive` feature of `json-gettext` instead of depending on this crate directly.
+*/
+
+mod json_files;
+mod localized_display;
+mod text_id;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+/// Implement `json_gettext::LocalizedDisplay` for an enum by mapping its variants to text ids.
+///
+/// Every variant needs a `#[loc("id")]` attribute, unless the enum has a `#[loc(prefix = "prefix")]` attribute, in which case the id of a variant without the attribute is `prefix.variant_name` in snake case.
+///
+/// ```ignore
+/// #[derive(LocalizedDisplay)]
+/// #[loc(prefix = "status")]
+/// enum Status {
+///     Active,                 // status.active
+///     #[loc("status.away")]
+///     Idle { since: u64 },    // status.away
+/// }
+/// ```
+#[proc_macro_derive(LocalizedDisplay, attributes(loc))]
+pub fn localized_display_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match localized_display::derive(ast) {
+        Ok(token_stream) => token_stream.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Embed the JSON files in a directory, which is relative to the manifest directory of the crate. Expand to an array of `(file_stem, content)` pairs sorted by file names, e.g. `[("en_US", "{...}"), ("zh_TW", "{...}")]`. Use `static_json_gettext_build_dir!` of `json-gettext` instead of calling this directly.
+#[proc_macro]
+pub fn json_files(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+
+    match json_files::expand(path) {
+        Ok(token_stream) => token_stream.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Check at compile time that a text id is in the catalog of the default key, and expand to the id, so a misspelled id fails the build instead of getting `None` at runtime. The catalog is a JSON file whose path, relative to the manifest directory, is given by `catalog = "path"` or the `JSON_GETTEXT_CATALOG` environment variable, e.g. set in the `[env]` table of `.cargo/config.toml`. Ids of nested objects, like `menu.file.open`, are checked too.
+///
+/// ```ignore
+/// get_text!(ctx, t!("hello"));
+/// get_text!(ctx, t!(catalog = "langs/en_US.json", "hello"));
+/// ```
+#[proc_macro]
+pub fn t(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as text_id::Input);
+
+    match text_id::expand(input) {
+        Ok(token_stream) => token_stream.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,121 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Parse `#[loc("id")]` of a variant.
+fn variant_id(attrs: &[Attribute]) -> Result<Option<LitStr>, Error> {
+    let mut id = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("loc")) {
+        if id.is_some() {
+            return Err(Error::new_spanned(attr, "duplicate `loc` attribute"));
+        }
+
+        id = Some(attr.parse_args::<LitStr>()?);
+    }
+
+    Ok(id)
+}
+
+/// Parse `#[loc(prefix = "prefix")]` of an enum.
+fn enum_prefix(attrs: &[Attribute]) -> Result<Option<String>, Error> {
+    let mut prefix = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("loc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                if prefix.is_some() {
+                    return Err(meta.error("duplicate `prefix`"));
+                }
+
+                prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+
+                Ok(())
+            } else {
+                Err(meta.error("expected `prefix = \"...\"`"))
+            }
+        })?;
+    }
+
+    Ok(prefix)
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut output = String::with_capacity(s.len() + 4);
+
+    for (i, c) in s.char_indices() {
+        if c.is_uppercase() {
+            if i > 0 {
+                output.push('_');
+            }
+
+            output.extend(c.to_lowercase());
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+pub(crate) fn derive(ast: DeriveInput) -> Result<TokenStream, Error> {
+    let Data::Enum(data) = &ast.data else {
+        return Err(Error::new_spanned(
+            &ast.ident,
+            "`LocalizedDisplay` can only be derived for enums",
+        ));
+    };
+
+    let prefix = enum_prefix(&ast.attrs)?;
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+
+    for variant in data.variants.iter() {
+        let variant_ident = &variant.ident;
+
+        let id =
+            match variant_id(&variant.attrs)? {
+                Some(id) => id.value(),
+                None => match prefix.as_ref() {
+                    Some(prefix) => {
+                        format!("{}.{}", prefix, to_snake_case(&variant_ident.to_string()))
+                    }
+                    None => return Err(Error::new_spanned(
+                        variant_ident,
+                        "missing `#[loc(\"...\")]`, or add `#[loc(prefix = \"...\")]` to the enum",
+                    )),
+                },
+            };
+
+        let pattern = match &variant.fields {
+            Fields::Unit => quote! { Self::#variant_ident },
+            Fields::Unnamed(_) => quote! { Self::#variant_ident(..) },
+            Fields::Named(_) => quote! { Self::#variant_ident { .. } },
+        };
+
+        arms.push(quote! { #pattern => #id, });
+    }
+
+    let ident = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let body = if arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::json_gettext::LocalizedDisplay for #ident #ty_generics #where_clause {
+            #[inline]
+            fn text_id(&self) -> &'static str {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,122 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Map, Value};
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token};
+
+/// The environment variable holding the path of the catalog of the default key, relative to the manifest directory.
+const CATALOG_VAR: &str = "JSON_GETTEXT_CATALOG";
+
+/// The input of `t!`, which is a text id, optionally preceded by `catalog = "path",`.
+pub(crate) struct Input {
+    catalog: Option<LitStr>,
+    text: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let catalog = if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+
+            if ident != "catalog" {
+                return Err(syn::Error::new(ident.span(), "expected `catalog = \"path\"`"));
+            }
+
+            input.parse::<Token![=]>()?;
+
+            let catalog = input.parse()?;
+
+            input.parse::<Token![,]>()?;
+
+            Some(catalog)
+        } else {
+            None
+        };
+
+        let text = input.parse()?;
+
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Input {
+            catalog,
+            text,
+        })
+    }
+}
+
+/// Find a value in a nested object by a dot path, trying every split of the path as `json-gettext` does.
+fn find_in_object<'v>(object: &'v Map<String, Value>, path: &str) -> Option<&'v Value> {
+    if let Some(value) = object.get(path) {
+        return Some(value);
+    }
+
+    path.match_indices('.')
+        .find_map(|(i, _)| find_in_object(object.get(&path[..i])?.as_object()?, &path[i + 1..]))
+}
+
+/// Expand to the text id if it is in the catalog, which is also included so that the crate is recompiled when the catalog changes.
+pub(crate) fn expand(input: Input) -> syn::Result<TokenStream> {
+    let Input {
+        catalog,
+        text,
+    } = input;
+
+    let catalog = match catalog {
+        Some(catalog) => catalog.value(),
+        None => {
+            env::var(CATALOG_VAR).map_err(|_| {
+                syn::Error::new(
+                    text.span(),
+                    format!(
+                        "set `catalog = \"path\"` or the `{}` environment variable",
+                        CATALOG_VAR
+                    ),
+                )
+            })?
+        }
+    };
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new(text.span(), "`CARGO_MANIFEST_DIR` is not set"))?;
+
+    let path = PathBuf::from(manifest_dir).join(catalog);
+
+    let json = fs::read_to_string(&path).map_err(|err| {
+        syn::Error::new(text.span(), format!("cannot read `{}`: {}", path.display(), err))
+    })?;
+
+    let map: Map<String, Value> = serde_json::from_str(&json).map_err(|err| {
+        syn::Error::new(text.span(), format!("cannot parse `{}`: {}", path.display(), err))
+    })?;
+
+    let id = text.value();
+
+    if find_in_object(&map, &id).is_none() {
+        return Err(syn::Error::new(
+            text.span(),
+            format!("the text `{}` is not in `{}`", id, path.display()),
+        ));
+    }
+
+    let path = match path.to_str() {
+        Some(path) => path.to_string(),
+        None => {
+            return Err(syn::Error::new(
+                text.span(),
+                format!("the path `{}` is not valid UTF-8", path.display()),
+            ))
+        }
+    };
+
+    Ok(quote! {
+        {
+            const _: &[u8] = include_bytes!(#path);
+
+            #text
+        }
+    })
+}
@@ -0,0 +1,209 @@
+/*!
+# JSON Get Text Node
+
+Node.js bindings for the `json-gettext` crate, so that Node.js services can use the same catalogs, with the same fallback to the default key, as Rust programs. Build the addon with [`@napi-rs/cli`](https://napi.rs/), e.g. `npm run build` in the directory of this crate.
+
+```javascript
+const { Catalog } = require("json-gettext");
+
+const catalog = Catalog.fromDirectory("en_US", "langs");
+
+console.log(catalog.getText("hello", "zh_TW"));
+console.log(catalog.format("greeting", { name: "Alice" }, "en_US"));
+console.log(catalog.formatPlural("apple", 3, { name: "Alice" }, "en_US"));
+```
+*/
+
+use std::collections::HashMap;
+
+use json_gettext::{placeholders_of, JSONGetText, JSONGetTextBuildError, JSONGetTextValue};
+use napi::Error;
+use napi_derive::napi;
+use serde_json::Value;
+
+#[inline]
+fn build_error(err: JSONGetTextBuildError) -> Error {
+    Error::from_reason(err.to_string())
+}
+
+/// A catalog of texts, i.e. a `JSONGetText` instance.
+#[napi]
+pub struct Catalog {
+    ctx: JSONGetText<'static>,
+}
+
+/// Convert a JSON Get Text value to a JSON value for JavaScript.
+#[inline]
+fn to_json(value: &JSONGetTextValue) -> napi::Result<Value> {
+    serde_json::to_value(value).map_err(|err| Error::from_reason(err.to_string()))
+}
+
+/// Convert a count from JavaScript, which cannot be negative.
+#[inline]
+fn count_of(count: i64) -> napi::Result<u64> {
+    u64::try_from(count).map_err(|_| Error::from_reason("The count cannot be negative."))
+}
+
+/// Borrow the arguments of `format` and `formatPlural`.
+#[inline]
+fn args_of(args: &Option<HashMap<String, Value>>) -> HashMap<&str, JSONGetTextValue<'_>> {
+    args.iter()
+        .flatten()
+        .map(|(name, value)| (name.as_str(), JSONGetTextValue::from_json_value_ref(value)))
+        .collect()
+}
+
+impl Catalog {
+    #[inline]
+    fn lookup(&self, text: &str, key: Option<&str>) -> Option<JSONGetTextValue<'_>> {
+        match key {
+            Some(key) => self.ctx.get_text_with_key(key, text),
+            None => self.ctx.get_text(text),
+        }
+    }
+
+    #[inline]
+    fn key_or_default<'k>(&'k self, key: Option<&'k str>) -> &'k str {
+        key.unwrap_or_else(|| self.ctx.get_default_key())
+    }
+}
+
+#[napi]
+impl Catalog {
+    /// Load every JSON file in a directory, using its file name as the key (e.g. `en_US.json`).
+    #[napi(factory)]
+    pub fn from_directory(default_key: String, path: String) -> napi::Result<Self> {
+        let mut builder = JSONGetText::build(default_key);
+
+        builder.add_directory(path).map_err(build_error)?;
+
+        Ok(Catalog {
+            ctx: builder.build().map_err(build_error)?,
+        })
+    }
+
+    /// Load JSON files given as an object of keys and paths.
+    #[napi(factory)]
+    pub fn from_files(default_key: String, files: HashMap<String, String>) -> napi::Result<Self> {
+        let mut files: Vec<_> = files.into_iter().collect();
+
+        // load in a stable order, so that the same error is reported every time
+        files.sort_unstable();
+
+        let mut builder = JSONGetText::build(default_key);
+
+        for (key, path) in files {
+            builder.add_json_file(key, path).map_err(build_error)?;
+        }
+
+        Ok(Catalog {
+            ctx: builder.build().map_err(build_error)?,
+        })
+    }
+
+    /// Load JSON strings given as an object of keys and JSON strings.
+    #[napi(factory)]
+    pub fn from_jsons(default_key: String, jsons: HashMap<String, String>) -> napi::Result<Self> {
+        let mut jsons: Vec<_> = jsons.into_iter().collect();
+
+        jsons.sort_unstable();
+
+        let mut builder = JSONGetText::build(default_key);
+
+        for (key, json) in jsons {
+            builder.add_json_owned(key, json).map_err(build_error)?;
+        }
+
+        Ok(Catalog {
+            ctx: builder.build().map_err(build_error)?,
+        })
+    }
+
+    /// The default key.
+    #[napi(getter)]
+    pub fn default_key(&self) -> String {
+        self.ctx.get_default_key().to_string()
+    }
+
+    /// Get all keys, sorted.
+    #[napi]
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.ctx.get_keys().into_iter().map(String::from).collect();
+
+        keys.sort_unstable();
+
+        keys
+    }
+
+    /// Returns `true` if the catalog contains the key.
+    #[napi]
+    pub fn contains_key(&self, key: String) -> bool {
+        self.ctx.contains_key(key)
+    }
+
+    /// Get a text with a key, or with the default key if `key` is not given or not in the catalog. Returns `null` if the text is not found.
+    #[napi]
+    pub fn get_text(&self, text: String, key: Option<String>) -> napi::Result<Option<Value>> {
+        self.lookup(&text, key.as_deref()).map(|value| to_json(&value)).transpose()
+    }
+
+    /// Get the plural form of a text for a count, e.g. `{"one": "{count} apple", "other": "{count} apples"}`. Texts which are not plural are returned as they are. Returns `null` if the text is not found.
+    #[napi]
+    pub fn get_plural_text(
+        &self,
+        text: String,
+        count: i64,
+        key: Option<String>,
+    ) -> napi::Result<Option<Value>> {
+        self.ctx
+            .get_plural_text(self.key_or_default(key.as_deref()), text, count_of(count)?)
+            .map(|value| to_json(&value))
+            .transpose()
+    }
+
+    /// Get the CLDR plural category of a count in the language of a key, e.g. `"few"`.
+    #[napi]
+    pub fn plural_category(&self, count: i64, key: Option<String>) -> napi::Result<String> {
+        Ok(self
+            .ctx
+            .plural_category(self.key_or_default(key.as_deref()), count_of(count)?)
+            .as_str()
+            .to_string())
+    }
+
+    /// Get a text and replace its placeholders with the values of `args`, like `format_text_with_key` of Rust, so numbers are written with the number symbols of the key and placeholder defaults are resolved. Returns `null` if the text is not found.
+    #[napi]
+    pub fn format(
+        &self,
+        text: String,
+        args: Option<HashMap<String, Value>>,
+        key: Option<String>,
+    ) -> Option<String> {
+        self.ctx.format_text_with_key(self.key_or_default(key.as_deref()), text, &args_of(&args))
+    }
+
+    /// Get the plural form of a text for a count and format it like `format`. The count is the `{count}` argument unless `args` has one. Returns `null` if the text is not found.
+    #[napi]
+    pub fn format_plural(
+        &self,
+        text: String,
+        count: i64,
+        args: Option<HashMap<String, Value>>,
+        key: Option<String>,
+    ) -> napi::Result<Option<String>> {
+        Ok(self.ctx.format_plural_text_with_key(
+            self.key_or_default(key.as_deref()),
+            text,
+            count_of(count)?,
+            &args_of(&args),
+        ))
+    }
+
+    /// Get the names of the placeholders in a text. Returns `null` if the text is not found.
+    #[napi]
+    pub fn placeholders(&self, text: String, key: Option<String>) -> Option<Vec<String>> {
+        self.lookup(&text, key.as_deref()).map(|template| {
+            placeholders_of(&template.to_string()).into_iter().map(String::from).collect()
+        })
+    }
+}
@@ -0,0 +1,124 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::OnceLock;
+
+fn addon() -> &'static PathBuf {
+    static ADDON: OnceLock<PathBuf> = OnceLock::new();
+
+    ADDON.get_or_init(|| {
+        // the library is built next to the test executable, in `target/<profile>/deps`
+        let dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+
+        let library = if cfg!(windows) {
+            "json_gettext_node.dll"
+        } else if cfg!(target_os = "macos") {
+            "libjson_gettext_node.dylib"
+        } else {
+            "libjson_gettext_node.so"
+        };
+
+        // Node.js only loads addons with the `.node` extension
+        let addon = dir.join("json_gettext_node_test.node");
+
+        fs::copy(dir.join(library), &addon).unwrap();
+
+        addon
+    })
+}
+
+fn run(code: &str) {
+    let script = format!(
+        "const {{ Catalog }} = require({:?});\nconst assert = require(\"assert\");\nconst langs = \
+         {:?};\n{}",
+        addon(),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../langs"),
+        code
+    );
+
+    let output = match Command::new("node").arg("-e").arg(script).output() {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("Skipped because Node.js cannot be run: {}", err);
+
+            return;
+        }
+    };
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn catalog_from_directory() {
+    run(r#"
+const catalog = Catalog.fromDirectory("en_US", langs);
+
+assert.strictEqual(catalog.defaultKey, "en_US");
+assert.deepStrictEqual(catalog.keys(), ["en_US", "zh_TW"]);
+assert.ok(catalog.containsKey("zh_TW"));
+assert.ok(!catalog.containsKey("de"));
+
+assert.strictEqual(catalog.getText("hello"), "Hello, world!");
+assert.strictEqual(catalog.getText("hello", "zh_TW"), "哈囉，世界！");
+assert.strictEqual(catalog.getText("hello", "de"), "Hello, world!");
+assert.strictEqual(catalog.getText("missing"), null);
+"#);
+}
+
+#[test]
+fn catalog_values_and_format() {
+    run(r#"
+const catalog = Catalog.fromJsons("en", {
+    en: '{"greeting": "Hi, {name}! You have {count} messages.", "limits": {"max": 3, "tags": ["a", null, true]}}',
+    fr: '{"greeting": "Salut, {name} !"}',
+});
+
+assert.deepStrictEqual(catalog.getText("limits"), { max: 3, tags: ["a", null, true] });
+
+assert.strictEqual(catalog.format("greeting", { name: "Alice", count: 2 }), "Hi, Alice! You have 2 messages.");
+assert.strictEqual(catalog.format("greeting", { name: "Alice" }, "fr"), "Salut, Alice !");
+assert.strictEqual(catalog.format("greeting"), "Hi, {name}! You have {count} messages.");
+assert.strictEqual(catalog.format("missing", {}), null);
+
+assert.deepStrictEqual(catalog.placeholders("greeting"), ["name", "count"]);
+assert.deepStrictEqual(catalog.placeholders("greeting", "fr"), ["name"]);
+"#);
+}
+
+#[test]
+fn catalog_plural_and_number_format() {
+    run(r##"
+const catalog = Catalog.fromJsons("en", {
+    en: '{"apple": {"one": "{count} apple for {name|guest}", "other": "{count} apples for {name|guest}"}, "guest": "a guest", "total": "Total: {amount}", "id": "#{amount:raw}"}',
+    de: '{"apple": {"one": "{count} Apfel", "other": "{count} Äpfel"}, "guest": "einen Gast", "total": "Summe: {amount}"}',
+});
+
+assert.strictEqual(catalog.pluralCategory(1), "one");
+assert.strictEqual(catalog.pluralCategory(2, "de"), "other");
+assert.throws(() => catalog.pluralCategory(-1), /negative/);
+
+assert.strictEqual(catalog.getPluralText("apple", 1), "{count} apple for {name|guest}");
+assert.strictEqual(catalog.getPluralText("apple", 5, "de"), "{count} Äpfel");
+assert.strictEqual(catalog.getPluralText("guest", 5), "a guest");
+assert.strictEqual(catalog.getPluralText("missing", 5), null);
+
+assert.strictEqual(catalog.formatPlural("apple", 1), "1 apple for a guest");
+assert.strictEqual(catalog.formatPlural("apple", 1000, { name: "Alice" }), "1,000 apples for Alice");
+assert.strictEqual(catalog.formatPlural("apple", 1000, null, "de"), "1.000 Äpfel");
+assert.strictEqual(catalog.formatPlural("apple", 2, { count: "many" }), "many apples for a guest");
+assert.strictEqual(catalog.formatPlural("missing", 2), null);
+
+assert.strictEqual(catalog.format("total", { amount: 1234567 }), "Total: 1,234,567");
+assert.strictEqual(catalog.format("total", { amount: 1234567 }, "de"), "Summe: 1.234.567");
+assert.strictEqual(catalog.format("id", { amount: 1234567 }, "de"), "#1234567");
+"##);
+}
+
+#[test]
+fn catalog_build_error() {
+    run(r#"
+assert.throws(() => Catalog.fromJsons("en", { en: "{}", fr: '{"bye": "Au revoir"}' }), /bye/);
+assert.throws(() => Catalog.fromJsons("en", { en: "[]" }));
+"#);
+}
@@ -0,0 +1,278 @@
+/*!
+# JSON Get Text Python
+
+Python bindings for the `json-gettext` crate, so that Python scripts can load, validate and query the same catalogs as Rust programs. Build the `json_gettext` module with [maturin](https://www.maturin.rs/), e.g. `maturin develop` in the directory of this crate.
+
+```python
+import json_gettext
+
+catalog = json_gettext.Catalog.from_directory("en_US", "langs")
+
+print(catalog.get_text("hello", "zh_TW"))
+print(catalog.format("greeting", {"name": "Alice"}, "en_US"))
+print(catalog.format_plural("apple", 3, {"name": "Alice"}, "en_US"))
+```
+
+Catalogs which cannot be built raise `json_gettext.BuildError`.
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use json_gettext::{placeholders_of, JSONGetText, JSONGetTextBuildError, JSONGetTextValue};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString};
+use serde_json::Value;
+
+create_exception!(json_gettext, BuildError, PyValueError, "Raised when a catalog cannot be built.");
+
+#[inline]
+fn build_error(err: JSONGetTextBuildError) -> PyErr {
+    BuildError::new_err(err.to_string())
+}
+
+/// Convert a JSON value to a Python object. Objects become `dict`s and arrays become `list`s.
+fn to_python<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(b) => PyBool::new(py, *b).to_owned().into_any(),
+        Value::Number(n) => {
+            if let Some(n) = n.as_i64() {
+                n.into_pyobject(py)?.into_any()
+            } else if let Some(n) = n.as_u64() {
+                n.into_pyobject(py)?.into_any()
+            } else {
+                n.as_f64().unwrap_or(f64::NAN).into_pyobject(py)?.into_any()
+            }
+        }
+        Value::String(s) => PyString::new(py, s).into_any(),
+        Value::Array(array) => {
+            let items =
+                array.iter().map(|value| to_python(py, value)).collect::<PyResult<Vec<_>>>()?;
+
+            PyList::new(py, items)?.into_any()
+        }
+        Value::Object(object) => {
+            let dict = PyDict::new(py);
+
+            for (k, v) in object {
+                dict.set_item(k, to_python(py, v)?)?;
+            }
+
+            dict.into_any()
+        }
+    })
+}
+
+/// Convert a Python object to a value for interpolation. Objects other than `None`, `bool`, `int`, `float` and `str` are converted with `str()`.
+fn from_python(object: &Bound<'_, PyAny>) -> PyResult<JSONGetTextValue<'static>> {
+    if object.is_none() {
+        Ok(JSONGetTextValue::null())
+    } else if object.is_instance_of::<PyBool>() {
+        Ok(JSONGetTextValue::from_bool(object.extract()?))
+    } else if object.is_instance_of::<PyInt>() {
+        if let Ok(n) = object.extract::<i64>() {
+            Ok(JSONGetTextValue::from_i64(n))
+        } else if let Ok(n) = object.extract::<u64>() {
+            Ok(JSONGetTextValue::from_u64(n))
+        } else {
+            Ok(JSONGetTextValue::from_string(object.str()?.to_string()))
+        }
+    } else if object.is_instance_of::<PyFloat>() {
+        Ok(JSONGetTextValue::from_f64(object.extract()?))
+    } else {
+        Ok(JSONGetTextValue::from_string(object.str()?.to_string()))
+    }
+}
+
+/// A catalog of texts, i.e. a `JSONGetText` instance.
+#[pyclass(frozen, module = "json_gettext")]
+pub struct Catalog {
+    ctx: JSONGetText<'static>,
+}
+
+/// Convert a JSON Get Text value to a Python object.
+#[inline]
+fn value_to_python<'py>(py: Python<'py>, value: &JSONGetTextValue) -> PyResult<Bound<'py, PyAny>> {
+    let value =
+        serde_json::to_value(value).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    to_python(py, &value)
+}
+
+/// Convert the Python arguments of `format` and `format_plural`.
+#[inline]
+fn args_from_python(
+    args: Option<HashMap<String, Bound<'_, PyAny>>>,
+) -> PyResult<Vec<(String, JSONGetTextValue<'static>)>> {
+    args.unwrap_or_default()
+        .into_iter()
+        .map(|(name, value)| Ok((name, from_python(&value)?)))
+        .collect()
+}
+
+impl Catalog {
+    #[inline]
+    fn lookup(&self, text: &str, key: Option<&str>) -> Option<JSONGetTextValue<'_>> {
+        match key {
+            Some(key) => self.ctx.get_text_with_key(key, text),
+            None => self.ctx.get_text(text),
+        }
+    }
+
+    #[inline]
+    fn key_or_default<'k>(&'k self, key: Option<&'k str>) -> &'k str {
+        key.unwrap_or_else(|| self.ctx.get_default_key())
+    }
+}
+
+#[pymethods]
+impl Catalog {
+    /// Load every JSON file in a directory, using its file name as the key (e.g. `en_US.json`).
+    #[staticmethod]
+    fn from_directory(default_key: &str, path: PathBuf) -> PyResult<Self> {
+        let mut builder = JSONGetText::build(default_key);
+
+        builder.add_directory(path).map_err(build_error)?;
+
+        Ok(Catalog {
+            ctx: builder.build().map_err(build_error)?,
+        })
+    }
+
+    /// Load JSON files given as a `dict` of keys and paths.
+    #[staticmethod]
+    fn from_files(default_key: &str, files: BTreeMap<String, PathBuf>) -> PyResult<Self> {
+        let mut builder = JSONGetText::build(default_key);
+
+        for (key, path) in files {
+            builder.add_json_file(key, path).map_err(build_error)?;
+        }
+
+        Ok(Catalog {
+            ctx: builder.build().map_err(build_error)?,
+        })
+    }
+
+    /// Load JSON strings given as a `dict` of keys and JSON strings.
+    #[staticmethod]
+    fn from_jsons(default_key: &str, jsons: BTreeMap<String, String>) -> PyResult<Self> {
+        let mut builder = JSONGetText::build(default_key);
+
+        for (key, json) in jsons {
+            builder.add_json_owned(key, json).map_err(build_error)?;
+        }
+
+        Ok(Catalog {
+            ctx: builder.build().map_err(build_error)?,
+        })
+    }
+
+    /// The default key.
+    #[getter]
+    fn default_key(&self) -> &str {
+        self.ctx.get_default_key()
+    }
+
+    /// Get all keys, sorted.
+    fn keys(&self) -> Vec<&str> {
+        let mut keys = self.ctx.get_keys();
+
+        keys.sort_unstable();
+
+        keys
+    }
+
+    fn __contains__(&self, key: &str) -> bool {
+        self.ctx.contains_key(key)
+    }
+
+    /// Get a text with a key, or with the default key if `key` is `None` or not in the catalog. Returns `None` if the text is not found.
+    #[pyo3(signature = (text, key = None))]
+    fn get_text<'py>(
+        &self,
+        py: Python<'py>,
+        text: &str,
+        key: Option<&str>,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        self.lookup(text, key).map(|value| value_to_python(py, &value)).transpose()
+    }
+
+    /// Get the plural form of a text for a count, e.g. `{"one": "{count} apple", "other": "{count} apples"}`. Texts which are not plural are returned as they are. Returns `None` if the text is not found.
+    #[pyo3(signature = (text, count, key = None))]
+    fn get_plural_text<'py>(
+        &self,
+        py: Python<'py>,
+        text: &str,
+        count: u64,
+        key: Option<&str>,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        self.ctx
+            .get_plural_text(self.key_or_default(key), text, count)
+            .map(|value| value_to_python(py, &value))
+            .transpose()
+    }
+
+    /// Get the CLDR plural category of a count in the language of a key, e.g. `"few"`.
+    #[pyo3(signature = (count, key = None))]
+    fn plural_category(&self, count: u64, key: Option<&str>) -> &'static str {
+        self.ctx.plural_category(self.key_or_default(key), count).as_str()
+    }
+
+    /// Get a text and replace its placeholders with the values of `args`, like `format_text_with_key` of Rust, so numbers are written with the number symbols of the key and placeholder defaults are resolved. Returns `None` if the text is not found.
+    #[pyo3(signature = (text, args = None, key = None))]
+    fn format(
+        &self,
+        text: &str,
+        args: Option<HashMap<String, Bound<'_, PyAny>>>,
+        key: Option<&str>,
+    ) -> PyResult<Option<String>> {
+        let args = args_from_python(args)?;
+
+        let args: HashMap<&str, JSONGetTextValue> =
+            args.iter().map(|(name, value)| (name.as_str(), value.clone_borrowed())).collect();
+
+        Ok(self.ctx.format_text_with_key(self.key_or_default(key), text, &args))
+    }
+
+    /// Get the plural form of a text for a count and format it like `format`. The count is the `{count}` argument unless `args` has one. Returns `None` if the text is not found.
+    #[pyo3(signature = (text, count, args = None, key = None))]
+    fn format_plural(
+        &self,
+        text: &str,
+        count: u64,
+        args: Option<HashMap<String, Bound<'_, PyAny>>>,
+        key: Option<&str>,
+    ) -> PyResult<Option<String>> {
+        let args = args_from_python(args)?;
+
+        let args: HashMap<&str, JSONGetTextValue> =
+            args.iter().map(|(name, value)| (name.as_str(), value.clone_borrowed())).collect();
+
+        Ok(self.ctx.format_plural_text_with_key(self.key_or_default(key), text, count, &args))
+    }
+
+    /// Get the names of the placeholders in a text. Returns `None` if the text is not found.
+    #[pyo3(signature = (text, key = None))]
+    fn placeholders(&self, text: &str, key: Option<&str>) -> Option<Vec<String>> {
+        self.lookup(text, key).map(|template| {
+            placeholders_of(&template.to_string()).into_iter().map(String::from).collect()
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Catalog(default_key={:?}, keys={:?})", self.default_key(), self.keys())
+    }
+}
+
+/// The `json_gettext` Python module.
+#[pymodule]
+#[pyo3(name = "json_gettext")]
+pub fn json_gettext_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Catalog>()?;
+    m.add("BuildError", m.py().get_type::<BuildError>())?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::ffi::CString;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+
+fn run(code: &str) {
+    Python::attach(|py| {
+        let module = PyModule::new(py, "json_gettext").unwrap();
+
+        json_gettext_python::json_gettext_python(&module).unwrap();
+
+        let locals = PyDict::new(py);
+
+        locals.set_item("json_gettext", module).unwrap();
+        locals.set_item("langs", concat!(env!("CARGO_MANIFEST_DIR"), "/../langs")).unwrap();
+
+        let code = CString::new(code).unwrap();
+
+        if let Err(err) = py.run(&code, None, Some(&locals)) {
+            panic!("{}", err);
+        }
+    })
+}
+
+#[test]
+fn catalog_from_directory() {
+    run(r#"
+catalog = json_gettext.Catalog.from_directory("en_US", langs)
+
+assert catalog.default_key == "en_US"
+assert catalog.keys() == ["en_US", "zh_TW"]
+assert "zh_TW" in catalog
+assert "de" not in catalog
+
+assert catalog.get_text("hello") == "Hello, world!"
+assert catalog.get_text("hello", "zh_TW") == "哈囉，世界！"
+assert catalog.get_text("hello", "de") == "Hello, world!"
+assert catalog.get_text("missing") is None
+"#);
+}
+
+#[test]
+fn catalog_values_and_format() {
+    run(r#"
+catalog = json_gettext.Catalog.from_jsons("en", {
+    "en": '{"greeting": "Hi, {name}! You have {count} messages.", "limits": {"max": 3, "ratio": 0.5, "tags": ["a", null, true]}}',
+    "fr": '{"greeting": "Salut, {name} !"}',
+})
+
+assert catalog.get_text("limits") == {"max": 3, "ratio": 0.5, "tags": ["a", None, True]}
+
+assert catalog.format("greeting", {"name": "Alice", "count": 2}) == "Hi, Alice! You have 2 messages."
+assert catalog.format("greeting", {"name": "Alice"}, "fr") == "Salut, Alice !"
+assert catalog.format("greeting", {"name": True, "count": 1.5}) == "Hi, true! You have 1.5 messages."
+assert catalog.format("greeting") == "Hi, {name}! You have {count} messages."
+assert catalog.format("missing", {}) is None
+
+assert catalog.placeholders("greeting") == ["name", "count"]
+assert catalog.placeholders("greeting", "fr") == ["name"]
+"#);
+}
+
+#[test]
+fn catalog_plural_and_number_format() {
+    run(r##"
+catalog = json_gettext.Catalog.from_jsons("en", {
+    "en": '{"apple": {"one": "{count} apple for {name|guest}", "other": "{count} apples for {name|guest}"}, "guest": "a guest", "total": "Total: {amount}", "id": "#{amount:raw}"}',
+    "de": '{"apple": {"one": "{count} Apfel", "other": "{count} Äpfel"}, "guest": "einen Gast", "total": "Summe: {amount}"}',
+})
+
+assert catalog.plural_category(1) == "one"
+assert catalog.plural_category(2, "de") == "other"
+
+assert catalog.get_plural_text("apple", 1) == "{count} apple for {name|guest}"
+assert catalog.get_plural_text("apple", 5, "de") == "{count} Äpfel"
+assert catalog.get_plural_text("guest", 5) == "a guest"
+assert catalog.get_plural_text("missing", 5) is None
+
+assert catalog.format_plural("apple", 1) == "1 apple for a guest"
+assert catalog.format_plural("apple", 1000, {"name": "Alice"}) == "1,000 apples for Alice"
+assert catalog.format_plural("apple", 1000, None, "de") == "1.000 Äpfel"
+assert catalog.format_plural("apple", 2, {"count": "many"}) == "many apples for a guest"
+assert catalog.format_plural("missing", 2) is None
+
+assert catalog.format("total", {"amount": 1234567}) == "Total: 1,234,567"
+assert catalog.format("total", {"amount": 1234567}, "de") == "Summe: 1.234.567"
+assert catalog.format("id", {"amount": 1234567}, "de") == "#1234567"
+"##);
+}
+
+#[test]
+fn catalog_build_error() {
+    run(r#"
+try:
+    json_gettext.Catalog.from_jsons("en", {"en": '{}', "fr": '{"bye": "Au revoir"}'})
+except json_gettext.BuildError as err:
+    assert "bye" in str(err)
+else:
+    raise AssertionError("the catalog should not be built")
+
+try:
+    json_gettext.Catalog.from_jsons("en", {"en": '[]'})
+except ValueError:
+    pass
+else:
+    raise AssertionError("the catalog should not be built")
+"#);
+}
@@ -0,0 +1,141 @@
+/*!
+# JSON Get Text UniFFI
+
+Kotlin and Swift bindings for the `json-gettext` crate, generated by [UniFFI](https://mozilla.github.io/uniffi-rs/), so that mobile apps can use the catalogs exported by the backend (e.g. with `JSONGetText::export_minified`) with the same lookup and formatting as Rust programs.
+
+Build this crate as a library for the target platform, and generate the bindings from it with the `uniffi-bindgen` binary of this crate.
+
+```bash
+cargo build --release -p json-gettext-uniffi
+cargo run --features cli --bin uniffi-bindgen -- generate --library target/release/libjson_gettext_uniffi.so --language kotlin --out-dir out
+```
+*/
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+
+use json_gettext::{
+    interpolate, placeholders_of, JSONGetText, JSONGetTextBuildError, JSONGetTextValue,
+};
+
+uniffi::setup_scaffolding!();
+
+/// Errors occurring when a catalog is created.
+#[derive(Debug, Clone, uniffi::Error)]
+pub enum CatalogError {
+    Build {
+        reason: String,
+    },
+}
+
+impl Display for CatalogError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CatalogError::Build {
+                reason,
+            } => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+impl From<JSONGetTextBuildError> for CatalogError {
+    #[inline]
+    fn from(err: JSONGetTextBuildError) -> Self {
+        CatalogError::Build {
+            reason: err.to_string(),
+        }
+    }
+}
+
+/// A catalog of texts, i.e. a `JSONGetText` instance.
+#[derive(Debug, uniffi::Object)]
+pub struct Catalog {
+    ctx: JSONGetText<'static>,
+}
+
+impl Catalog {
+    #[inline]
+    fn lookup(&self, text: &str, key: Option<&str>) -> Option<JSONGetTextValue<'_>> {
+        match key {
+            Some(key) => self.ctx.get_text_with_key(key, text),
+            None => self.ctx.get_text(text),
+        }
+    }
+}
+
+#[uniffi::export]
+impl Catalog {
+    /// Create a catalog from JSON strings given as a map of keys and JSON strings.
+    #[uniffi::constructor]
+    pub fn from_jsons(
+        default_key: String,
+        jsons: HashMap<String, String>,
+    ) -> Result<Arc<Self>, CatalogError> {
+        let mut jsons: Vec<_> = jsons.into_iter().collect();
+
+        // load in a stable order, so that the same error is reported every time
+        jsons.sort_unstable();
+
+        let mut builder = JSONGetText::build(default_key);
+
+        for (key, json) in jsons {
+            builder.add_json_owned(key, json)?;
+        }
+
+        Ok(Arc::new(Catalog {
+            ctx: builder.build()?,
+        }))
+    }
+
+    /// Get the default key.
+    pub fn default_key(&self) -> String {
+        self.ctx.get_default_key().to_string()
+    }
+
+    /// Get all keys, sorted.
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.ctx.get_keys().into_iter().map(String::from).collect();
+
+        keys.sort_unstable();
+
+        keys
+    }
+
+    /// Returns `true` if the catalog contains the key.
+    pub fn contains_key(&self, key: String) -> bool {
+        self.ctx.contains_key(key)
+    }
+
+    /// Get a text with a key, or with the default key if `key` is not given or not in the catalog. Strings are returned as they are and other values as JSON.
+    pub fn get_text(&self, text: String, key: Option<String>) -> Option<String> {
+        self.lookup(&text, key.as_deref()).map(|value| value.to_string())
+    }
+
+    /// Get a text and replace its `{name}` placeholders with the values of `args`.
+    pub fn format(
+        &self,
+        text: String,
+        args: HashMap<String, String>,
+        key: Option<String>,
+    ) -> Option<String> {
+        let template = self.lookup(&text, key.as_deref())?;
+
+        let args: HashMap<&str, JSONGetTextValue> = args
+            .iter()
+            .map(|(name, value)| (name.as_str(), JSONGetTextValue::from_str(value)))
+            .collect();
+
+        Some(interpolate(&template.to_string(), &args))
+    }
+
+    /// Get the names of the placeholders in a text.
+    pub fn placeholders(&self, text: String, key: Option<String>) -> Option<Vec<String>> {
+        self.lookup(&text, key.as_deref()).map(|template| {
+            placeholders_of(&template.to_string()).into_iter().map(String::from).collect()
+        })
+    }
+}
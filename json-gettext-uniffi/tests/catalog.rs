@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use json_gettext::JSONGetText;
+use json_gettext_uniffi::*;
+
+fn jsons(jsons: &[(&str, &str)]) -> HashMap<String, String> {
+    jsons.iter().map(|(key, json)| (key.to_string(), json.to_string())).collect()
+}
+
+#[test]
+fn catalog_from_exported_jsons() {
+    let mut builder = JSONGetText::build("en");
+
+    builder
+        .add_json("en", r#"{"greeting": "Hi, {name}!", "limits": {"max": 3}}"#)
+        .unwrap()
+        .add_json("fr", r#"{"greeting": "Salut, {name} !"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let catalog = Catalog::from_jsons(
+        "en".to_string(),
+        ["en", "fr"]
+            .into_iter()
+            .map(|key| (key.to_string(), ctx.export_minified(key).unwrap()))
+            .collect(),
+    )
+    .unwrap();
+
+    assert_eq!("en", catalog.default_key());
+    assert_eq!(vec!["en", "fr"], catalog.keys());
+    assert!(catalog.contains_key("fr".to_string()));
+    assert!(!catalog.contains_key("de".to_string()));
+
+    assert_eq!(Some("Hi, {name}!".to_string()), catalog.get_text("greeting".to_string(), None));
+    assert_eq!(
+        Some("Salut, {name} !".to_string()),
+        catalog.get_text("greeting".to_string(), Some("fr".to_string()))
+    );
+    assert_eq!(
+        Some("Hi, {name}!".to_string()),
+        catalog.get_text("greeting".to_string(), Some("de".to_string()))
+    );
+    assert_eq!(
+        Some(r#"{"max":3}"#.to_string()),
+        catalog.get_text("limits".to_string(), Some("fr".to_string()))
+    );
+    assert_eq!(None, catalog.get_text("missing".to_string(), None));
+
+    let args: HashMap<String, String> = [("name".to_string(), "Alice".to_string())].into();
+
+    assert_eq!(
+        Some("Salut, Alice !".to_string()),
+        catalog.format("greeting".to_string(), args, Some("fr".to_string()))
+    );
+
+    assert_eq!(Some(vec!["name".to_string()]), catalog.placeholders("greeting".to_string(), None));
+}
+
+#[test]
+fn catalog_error() {
+    let err = Catalog::from_jsons(
+        "en".to_string(),
+        jsons(&[("en", "{}"), ("fr", r#"{"bye": "Au revoir"}"#)]),
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("bye"));
+
+    assert!(Catalog::from_jsons("en".to_string(), jsons(&[("en", "[]")])).is_err());
+}
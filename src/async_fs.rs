@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use tokio::fs::{self, File};
+use tokio::io::AsyncReadExt;
+
+use crate::modified::record_modified;
+use crate::{
+    CatalogId, IngestLimit, IngestLimits, JSONGetTextBuildError, JSONGetTextBuilder,
+    JSONGetTextValue, Key,
+};
+
+/// Read a catalog file of a key without blocking the runtime, like `IngestLimits::read_file`.
+async fn read_file(
+    limits: IngestLimits,
+    key: &Key,
+    path: &Path,
+) -> Result<Vec<u8>, JSONGetTextBuildError> {
+    let file = File::open(path).await?;
+
+    if file.metadata().await?.len() > limits.max_bytes as u64 {
+        return Err(IngestLimit::Bytes.exceeded_by(key.to_owned()));
+    }
+
+    let mut json = Vec::new();
+
+    // the file may grow after its metadata is read
+    file.take((limits.max_bytes as u64).saturating_add(1)).read_to_end(&mut json).await?;
+
+    if json.len() > limits.max_bytes {
+        return Err(IngestLimit::Bytes.exceeded_by(key.to_owned()));
+    }
+
+    Ok(json)
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Read a JSON catalog file of a key without blocking the runtime, and record its modification time.
+    async fn read_catalog_file_async(
+        &mut self,
+        id: CatalogId,
+        path: &Path,
+    ) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextBuildError> {
+        let limits = self.options.ingest_limits;
+
+        let json = read_file(limits, &id.key, path).await?;
+
+        let map = limits.parse_catalog(&id.key, &json)?;
+
+        if let Ok(time) = fs::metadata(path).await.and_then(|metadata| metadata.modified()) {
+            record_modified(&mut self.options.last_modified, id, time);
+        }
+
+        Ok(map)
+    }
+
+    /// Add a JSON file to the context for a specify key like `add_json_file`, reading it with `tokio::fs` so that building the context during an async startup does not block the runtime.
+    pub async fn add_json_file_async<K: Into<Key>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+        let path = path.as_ref();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key));
+        }
+
+        let map = self.read_catalog_file_async(CatalogId::new(key.to_owned(), None), path).await?;
+
+        #[cfg(feature = "watch")]
+        self.options.json_files.push((key.to_owned(), path.to_path_buf()));
+
+        self.context.insert(key, map);
+
+        Ok(self)
+    }
+
+    /// Add a JSON file to a namespace of a key like `add_json_file_ns`, reading it with `tokio::fs`. See `add_json_file_async`.
+    pub async fn add_json_file_ns_async<K: Into<Key>, N: Into<String>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        namespace: N,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+        let namespace = namespace.into();
+
+        let map = self
+            .read_catalog_file_async(
+                CatalogId::new(key.to_owned(), Some(namespace.clone())),
+                path.as_ref(),
+            )
+            .await?;
+
+        self.options.namespaces.push((key, namespace, map));
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,165 @@
+/*!
+Watch a directory of JSON catalogs and validate them every time a file is saved, printing the build errors, or the texts which changed and the texts which are missing, so translators get feedback while editing.
+
+```bash
+json-gettext-watch en_US langs
+json-gettext-watch --once en_US langs
+```
+
+With `--once`, the catalogs are validated once, and the exit code is `1` if they cannot be built.
+*/
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use json_gettext::{JSONGetText, Key};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+/// How long to wait after a file event for no other event to come, because editors often write a file in several steps.
+const QUIET_PERIOD: Duration = Duration::from_millis(100);
+
+/// Every text of every key, by `(key, text)`, in their display forms.
+type Texts = BTreeMap<(String, String), String>;
+
+#[cfg(not(feature = "langid"))]
+#[inline]
+fn parse_key(key: &str) -> Result<Key, String> {
+    Ok(Key::from(key))
+}
+
+#[cfg(feature = "langid")]
+#[inline]
+fn parse_key(key: &str) -> Result<Key, String> {
+    key.replace('_', "-").parse().map_err(|_| format!("The key `{}` is invalid.", key))
+}
+
+fn build(default_key: &str, directory: &Path) -> Result<JSONGetText<'static>, String> {
+    let mut builder = JSONGetText::build(parse_key(default_key)?);
+
+    builder.add_directory(directory).map_err(|err| err.to_string())?;
+
+    builder.build().map_err(|err| err.to_string())
+}
+
+fn texts_of(ctx: &JSONGetText) -> Texts {
+    ctx.iter()
+        .map(|(key, text, value)| ((key.to_string(), text.to_string()), value.to_string()))
+        .collect()
+}
+
+/// Build the catalogs and print the problems, and the changes since the last valid build if there is one. Returns the texts if the build succeeds.
+fn validate(default_key: &str, directory: &Path, last: Option<&Texts>) -> Option<Texts> {
+    let ctx = match build(default_key, directory) {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            println!("error: {}", err);
+
+            return None;
+        }
+    };
+
+    let texts = texts_of(&ctx);
+
+    if let Some(last) = last {
+        for ((key, text), value) in texts.iter() {
+            match last.get(&(key.clone(), text.clone())) {
+                Some(last_value) if last_value == value => (),
+                Some(last_value) => println!("~ {} {}: {:?} -> {:?}", key, text, last_value, value),
+                None => println!("+ {} {}: {:?}", key, text, value),
+            }
+        }
+
+        for (key, text) in last.keys().filter(|id| !texts.contains_key(*id)) {
+            println!("- {} {}", key, text);
+        }
+    }
+
+    for (key, coverage) in ctx.coverage() {
+        if !coverage.missing.is_empty() {
+            println!(
+                "warning: {} is {:.0}% translated, missing {}",
+                key,
+                coverage.percentage,
+                coverage.missing.join(", ")
+            );
+        }
+    }
+
+    println!("ok: {} key(s), {} text(s)", ctx.get_keys().len(), texts.len());
+
+    Some(texts)
+}
+
+fn watch(default_key: &str, directory: &Path) -> notify::Result<()> {
+    let mut last = validate(default_key, directory, None);
+
+    let (sender, receiver) = mpsc::channel();
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            if !matches!(event.kind, EventKind::Access(_)) {
+                let _ = sender.send(());
+            }
+        }
+    })?;
+
+    watcher.watch(directory, RecursiveMode::Recursive)?;
+
+    while receiver.recv().is_ok() {
+        loop {
+            match receiver.recv_timeout(QUIET_PERIOD) {
+                Ok(()) => (),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+
+        println!();
+
+        // keep the last valid texts, so that the changes are shown once the catalogs are fixed
+        if let Some(texts) = validate(default_key, directory, last.as_ref()) {
+            last = Some(texts);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let once = args.first().is_some_and(|arg| arg == "--once");
+
+    if once {
+        args.remove(0);
+    }
+
+    let (default_key, directory) = match args.as_slice() {
+        [default_key, directory] => (default_key, PathBuf::from(directory)),
+        _ => {
+            eprintln!("usage: json-gettext-watch [--once] <default key> <directory>");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    if once {
+        return match validate(default_key, &directory, None) {
+            Some(_) => ExitCode::SUCCESS,
+            None => ExitCode::FAILURE,
+        };
+    }
+
+    match watch(default_key, &directory) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+
+            ExitCode::FAILURE
+        }
+    }
+}
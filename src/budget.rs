@@ -0,0 +1,118 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// The suffix of the id of a short variant, e.g. `body@short` is the short variant of `body`.
+pub const SHORT_VARIANT_SUFFIX: &str = "@short";
+
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ \
+                          !\"#¤%&'()*+,-./0123456789:;<=>?\
+                          ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§\
+                          ¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+const GSM7_EXTENSION: &str = "\u{c}^{}\\[~]|€";
+
+/// The encoding which an SMS message is sent with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmsEncoding {
+    /// The GSM 03.38 7-bit default alphabet.
+    Gsm7,
+    /// UCS-2 (UTF-16), used when a character is not in the GSM 7-bit alphabet.
+    Ucs2,
+}
+
+/// The length of a text sent as SMS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SmsLength {
+    pub encoding: SmsEncoding,
+    /// The number of septets (GSM-7) or UTF-16 code units (UCS-2).
+    pub units: usize,
+    /// The number of messages needed. Concatenated messages have smaller payloads because of the user data header.
+    pub segments: usize,
+}
+
+/// Measure a text as SMS.
+pub fn sms_length(text: &str) -> SmsLength {
+    let mut septets = 0;
+
+    for c in text.chars() {
+        if GSM7_BASIC.contains(c) {
+            septets += 1;
+        } else if GSM7_EXTENSION.contains(c) {
+            septets += 2;
+        } else {
+            let units = text.encode_utf16().count();
+
+            return SmsLength {
+                encoding: SmsEncoding::Ucs2,
+                units,
+                segments: segments(units, 70, 67),
+            };
+        }
+    }
+
+    SmsLength {
+        encoding: SmsEncoding::Gsm7,
+        units: septets,
+        segments: segments(septets, 160, 153),
+    }
+}
+
+#[inline]
+fn segments(units: usize, single: usize, concatenated: usize) -> usize {
+    if units == 0 {
+        0
+    } else if units <= single {
+        1
+    } else {
+        units.div_ceil(concatenated)
+    }
+}
+
+/// A limit on the length of a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextBudget {
+    /// The maximum length in bytes of UTF-8.
+    Bytes(usize),
+    /// The maximum number of SMS segments.
+    SmsSegments(usize),
+}
+
+impl TextBudget {
+    /// Returns `true` if the text is within this budget.
+    #[inline]
+    pub fn fits(&self, text: &str) -> bool {
+        match self {
+            TextBudget::Bytes(max) => text.len() <= *max,
+            TextBudget::SmsSegments(max) => sms_length(text).segments <= *max,
+        }
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get text from context with a specific key, preferring the variant which fits the budget. The text itself is used if it fits, otherwise its short variant (`{text}@short`) is used if there is one. If no variant fits, the short variant (or the text itself if there is no short variant) is returned anyway. If the key is not in context, the default key is used.
+    pub fn get_text_within_budget<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        text: T,
+        budget: TextBudget,
+    ) -> Option<JSONGetTextValue<'_>>
+    where
+        Key: Borrow<Q>, {
+        let text = text.as_ref();
+
+        let map = self.find_map_or_default(key);
+
+        let long = self.lookup_text(map, text);
+
+        if long.as_ref().is_some_and(|long| budget.fits(&long.to_string())) {
+            return long;
+        }
+
+        match self.lookup_text(map, &format!("{}{}", text, SHORT_VARIANT_SUFFIX)) {
+            Some(short) => Some(short),
+            None => long,
+        }
+    }
+}
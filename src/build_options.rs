@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+#[cfg(feature = "watch")]
+use std::path::PathBuf;
+#[cfg(feature = "fs")]
+use std::sync::Arc;
+#[cfg(feature = "watch")]
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::collect::ExtendError;
+#[cfg(feature = "http")]
+use crate::http::RemoteCatalogs;
+use crate::ingest::IngestLimits;
+use crate::missing::MissingHandler;
+use crate::modified::CatalogId;
+use crate::namespace::Namespaces;
+use crate::observer::Observer;
+use crate::pipeline::Pipeline;
+use crate::read_through::ReadThrough;
+#[cfg(feature = "fs")]
+use crate::write_back::WriteBack;
+use crate::{Key, NumberSymbols};
+
+/// Options of a `JSONGetTextBuilder` which are shared by every type of keys.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BuildOptions {
+    pub(crate) observer: Option<Observer>,
+    pub(crate) missing_handler: Option<MissingHandler>,
+    pub(crate) read_through: Option<ReadThrough>,
+    /// The write-back file set by `set_write_back_file`, to be attached to the secondary context.
+    #[cfg(feature = "fs")]
+    pub(crate) write_back: Option<Arc<WriteBack>>,
+    /// The catalogs added by `add_json_url`.
+    #[cfg(feature = "http")]
+    pub(crate) remote_catalogs: RemoteCatalogs,
+    pub(crate) migrations: HashMap<String, String>,
+    pub(crate) pipeline: Pipeline,
+    pub(crate) ingest_limits: IngestLimits,
+    pub(crate) spell_out_key: Option<Key>,
+    pub(crate) number_symbols: HashMap<Key, NumberSymbols>,
+    pub(crate) locale_groups: HashMap<String, Vec<Key>>,
+    pub(crate) namespaces: Namespaces,
+    pub(crate) last_modified: HashMap<CatalogId, SystemTime>,
+    /// The namespaces registered by `add_crate_namespace`, with the names of their crates.
+    #[cfg(feature = "embed_dir")]
+    pub(crate) crate_namespaces: HashMap<&'static str, &'static str>,
+    /// The first error found by `Extend`, to be returned by `build`.
+    pub(crate) extend_error: Option<ExtendError>,
+    /// The JSON files added by `add_json_file`, to be watched by `JSONGetTextReloadable`.
+    #[cfg(feature = "watch")]
+    pub(crate) json_files: Vec<(Key, PathBuf)>,
+    #[cfg(feature = "watch")]
+    pub(crate) reload_quiet_period: Option<Duration>,
+}
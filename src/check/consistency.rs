@@ -0,0 +1,164 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, Key};
+
+/// A problem found by `ConsistencyCheck`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ConsistencyIssue {
+    /// The translation is exactly the same as the text of the default key.
+    Untranslated,
+    /// The text contains two or more consecutive spaces.
+    DoubleSpace,
+    /// The terminal punctuation of the translation differs from the text of the default key.
+    TerminalPunctuationMismatch {
+        expected: Option<char>,
+        found: Option<char>,
+    },
+}
+
+/// A problem of a text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConsistencyFinding {
+    pub text: String,
+    pub issue: ConsistencyIssue,
+}
+
+/// An analysis pass which checks translations for untranslated fragments, double spaces and mismatched terminal punctuation.
+///
+/// Words in the dictionary (e.g. brand names) are allowed to be the same in every key, so a translation only consisting of them is not reported as untranslated.
+#[derive(Debug, Clone, Default)]
+pub struct ConsistencyCheck {
+    dictionary: HashSet<String>,
+}
+
+impl ConsistencyCheck {
+    #[inline]
+    pub fn new() -> ConsistencyCheck {
+        ConsistencyCheck::default()
+    }
+
+    /// Add a word which does not need to be translated. Words are compared case-insensitively.
+    #[inline]
+    pub fn add_word<S: AsRef<str>>(&mut self, word: S) -> &mut Self {
+        self.dictionary.insert(word.as_ref().to_lowercase());
+
+        self
+    }
+
+    /// Add multiple words which do not need to be translated.
+    #[inline]
+    pub fn add_words<I: IntoIterator<Item = S>, S: AsRef<str>>(&mut self, words: I) -> &mut Self {
+        for word in words {
+            self.add_word(word);
+        }
+
+        self
+    }
+
+    /// Run the check over every key of the context. Keys without findings are omitted.
+    pub fn check(&self, ctx: &JSONGetText) -> BTreeMap<Key, Vec<ConsistencyFinding>> {
+        let default_map = ctx.context.get(&ctx.default_key).unwrap();
+
+        let mut report = BTreeMap::new();
+
+        for (key, map) in ctx.context.iter() {
+            let is_default_key = *key == ctx.default_key;
+            let filled = ctx.filled.get(key);
+
+            let mut findings = Vec::new();
+
+            let mut texts: Vec<&String> = map.keys().filter(|t| !is_metadata_text(t)).collect();
+            texts.sort_unstable();
+
+            for text in texts {
+                if filled.map(|filled| filled.contains(text)).unwrap_or(false) {
+                    continue;
+                }
+
+                let value = match map.get(text).unwrap().as_str() {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                if value.contains("  ") {
+                    findings.push(ConsistencyFinding {
+                        text: text.clone(),
+                        issue: ConsistencyIssue::DoubleSpace,
+                    });
+                }
+
+                if is_default_key {
+                    continue;
+                }
+
+                let source = match default_map.get(text).and_then(|v| v.as_str()) {
+                    Some(source) => source,
+                    None => continue,
+                };
+
+                if source == value && !self.is_exempt(value) {
+                    findings.push(ConsistencyFinding {
+                        text: text.clone(),
+                        issue: ConsistencyIssue::Untranslated,
+                    });
+                }
+
+                let expected = terminal_punctuation(source);
+                let found = terminal_punctuation(value);
+
+                if expected.map(normalize_punctuation) != found.map(normalize_punctuation) {
+                    findings.push(ConsistencyFinding {
+                        text: text.clone(),
+                        issue: ConsistencyIssue::TerminalPunctuationMismatch {
+                            expected,
+                            found,
+                        },
+                    });
+                }
+            }
+
+            if !findings.is_empty() {
+                report.insert(key.to_owned(), findings);
+            }
+        }
+
+        report
+    }
+
+    fn is_exempt(&self, value: &str) -> bool {
+        if !value.chars().any(|c| c.is_alphabetic()) {
+            return true;
+        }
+
+        if self.dictionary.contains(&value.trim().to_lowercase()) {
+            return true;
+        }
+
+        value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().any(|c| c.is_alphabetic()))
+            .all(|word| self.dictionary.contains(&word.to_lowercase()))
+    }
+}
+
+#[inline]
+fn terminal_punctuation(s: &str) -> Option<char> {
+    s.trim_end().chars().next_back().filter(|c| {
+        matches!(c, '.' | '!' | '?' | ':' | ';' | '…' | '。' | '！' | '？' | '：' | '；')
+    })
+}
+
+#[inline]
+fn normalize_punctuation(c: char) -> char {
+    match c {
+        '。' => '.',
+        '！' => '!',
+        '？' => '?',
+        '：' => ':',
+        '；' => ';',
+        _ => c,
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, Key};
+
+/// An approved translation of a term for a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryEntry {
+    pub term: String,
+    pub approved: String,
+    pub unapproved: Vec<String>,
+}
+
+/// A problem found by `Glossary::check`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum GlossaryIssue {
+    /// The text of the default key contains the term, but the translation does not contain the approved translation.
+    MissingApprovedTerm {
+        term: String,
+        approved: String,
+    },
+    /// The translation contains a variant of the term which is not approved.
+    UnapprovedVariant {
+        term: String,
+        variant: String,
+        approved: String,
+    },
+}
+
+/// A glossary problem of a text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GlossaryFinding {
+    pub text: String,
+    pub issue: GlossaryIssue,
+}
+
+/// Per-key terminology. Terms are in the language of the default key and are compared case-insensitively.
+#[derive(Debug, Clone, Default)]
+pub struct Glossary {
+    entries: HashMap<Key, Vec<GlossaryEntry>>,
+}
+
+impl Glossary {
+    #[inline]
+    pub fn new() -> Glossary {
+        Glossary::default()
+    }
+
+    /// Add an approved translation of a term for a key.
+    pub fn add_term<K: Into<Key>, T: Into<String>, A: Into<String>>(
+        &mut self,
+        key: K,
+        term: T,
+        approved: A,
+    ) -> &mut Self {
+        let term = term.into();
+        let approved = approved.into();
+
+        let entries = self.entries.entry(key.into()).or_default();
+
+        match entries.iter_mut().find(|entry| entry.term == term) {
+            Some(entry) => entry.approved = approved,
+            None => {
+                entries.push(GlossaryEntry {
+                    term,
+                    approved,
+                    unapproved: Vec::new(),
+                })
+            }
+        }
+
+        self
+    }
+
+    /// Add a variant of a term which must not be used for a key. The term needs to be added by `add_term` first, or the variant is ignored.
+    pub fn add_unapproved_variant<K: Into<Key>, T: AsRef<str>, V: Into<String>>(
+        &mut self,
+        key: K,
+        term: T,
+        variant: V,
+    ) -> &mut Self {
+        if let Some(entries) = self.entries.get_mut(&key.into()) {
+            if let Some(entry) = entries.iter_mut().find(|entry| entry.term == term.as_ref()) {
+                entry.unapproved.push(variant.into());
+            }
+        }
+
+        self
+    }
+
+    /// Get the entries of a key.
+    #[inline]
+    pub fn get_entries(&self, key: &Key) -> &[GlossaryEntry] {
+        self.entries.get(key).map(|entries| entries.as_slice()).unwrap_or(&[])
+    }
+
+    /// Check the translations in the context against the glossary. Keys without findings are omitted.
+    pub fn check(&self, ctx: &JSONGetText) -> BTreeMap<Key, Vec<GlossaryFinding>> {
+        let default_map = ctx.context.get(&ctx.default_key).unwrap();
+
+        let mut report = BTreeMap::new();
+
+        for (key, entries) in self.entries.iter() {
+            let map = match ctx.context.get(key) {
+                Some(map) => map,
+                None => continue,
+            };
+
+            let filled = ctx.filled.get(key);
+
+            let mut texts: Vec<&String> = map.keys().filter(|t| !is_metadata_text(t)).collect();
+            texts.sort_unstable();
+
+            let mut findings = Vec::new();
+
+            for text in texts {
+                if filled.map(|filled| filled.contains(text)).unwrap_or(false) {
+                    continue;
+                }
+
+                let value = match map.get(text).unwrap().as_str() {
+                    Some(value) => value.to_lowercase(),
+                    None => continue,
+                };
+
+                let source = default_map
+                    .get(text)
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_lowercase())
+                    .unwrap_or_default();
+
+                for entry in entries {
+                    if !source.contains(&entry.term.to_lowercase()) {
+                        continue;
+                    }
+
+                    if let Some(variant) =
+                        entry.unapproved.iter().find(|v| value.contains(&v.to_lowercase()))
+                    {
+                        findings.push(GlossaryFinding {
+                            text: text.clone(),
+                            issue: GlossaryIssue::UnapprovedVariant {
+                                term: entry.term.clone(),
+                                variant: variant.clone(),
+                                approved: entry.approved.clone(),
+                            },
+                        });
+                    } else if !value.contains(&entry.approved.to_lowercase()) {
+                        findings.push(GlossaryFinding {
+                            text: text.clone(),
+                            issue: GlossaryIssue::MissingApprovedTerm {
+                                term: entry.term.clone(),
+                                approved: entry.approved.clone(),
+                            },
+                        });
+                    }
+                }
+            }
+
+            if !findings.is_empty() {
+                report.insert(key.to_owned(), findings);
+            }
+        }
+
+        report
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(feature = "regex")]
+use regex::Regex;
+use serde::Serialize;
+
+use crate::metadata::is_metadata_text;
+use crate::JSONGetText;
+
+/// A problem found by `IdLint`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum IdLintIssue {
+    /// The id does not match the naming convention.
+    NamingConvention {
+        pattern: String,
+    },
+    /// The id has more segments than allowed.
+    TooDeep {
+        depth: usize,
+        max_depth: usize,
+    },
+    /// The id contains a forbidden character.
+    ForbiddenCharacter {
+        character: char,
+    },
+    /// The id is not in any of the required namespaces.
+    MissingNamespace {
+        namespaces: Vec<String>,
+    },
+}
+
+/// A problem of a text id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IdLintFinding {
+    pub text: String,
+    pub issue: IdLintIssue,
+}
+
+/// A configurable lint pass over text ids. Every rule is disabled until it is configured.
+///
+/// Ids are split into segments by a separator (`.` by default), e.g. `menu.file.open` has a depth of 3.
+#[derive(Debug, Clone)]
+pub struct IdLint {
+    #[cfg(feature = "regex")]
+    naming_convention: Option<(String, Regex)>,
+    separator: char,
+    max_depth: Option<usize>,
+    forbidden_characters: Vec<char>,
+    namespaces: Vec<String>,
+}
+
+impl Default for IdLint {
+    #[inline]
+    fn default() -> Self {
+        IdLint {
+            #[cfg(feature = "regex")]
+            naming_convention: None,
+            separator: '.',
+            max_depth: None,
+            forbidden_characters: Vec::new(),
+            namespaces: Vec::new(),
+        }
+    }
+}
+
+impl IdLint {
+    #[inline]
+    pub fn new() -> IdLint {
+        IdLint::default()
+    }
+
+    /// Require ids to match a regular expression. The whole id has to match, e.g. `[a-z_]+(\.[a-z_]+)*` for dotted snake case.
+    #[cfg(feature = "regex")]
+    pub fn set_naming_convention<S: Into<String>>(
+        &mut self,
+        pattern: S,
+    ) -> Result<&mut Self, regex::Error> {
+        let pattern = pattern.into();
+        let regex = Regex::new(&format!("^(?:{})$", pattern))?;
+
+        self.naming_convention = Some((pattern, regex));
+
+        Ok(self)
+    }
+
+    /// Set the separator of segments.
+    #[inline]
+    pub fn set_separator(&mut self, separator: char) -> &mut Self {
+        self.separator = separator;
+
+        self
+    }
+
+    /// Limit the number of segments of ids.
+    #[inline]
+    pub fn set_max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.max_depth = Some(max_depth);
+
+        self
+    }
+
+    /// Forbid characters in ids.
+    #[inline]
+    pub fn add_forbidden_characters<I: IntoIterator<Item = char>>(
+        &mut self,
+        characters: I,
+    ) -> &mut Self {
+        self.forbidden_characters.extend(characters);
+
+        self
+    }
+
+    /// Add a namespace (e.g. `checkout`). Once a namespace is added, every id must be in one of the namespaces, i.e. its first segment must be one of them.
+    #[inline]
+    pub fn add_namespace<S: Into<String>>(&mut self, namespace: S) -> &mut Self {
+        self.namespaces.push(namespace.into());
+
+        self
+    }
+
+    /// Lint an id. Forbidden characters are reported once each, in the order of their first occurrence.
+    pub fn lint_id<S: AsRef<str>>(&self, id: S) -> Vec<IdLintIssue> {
+        let id = id.as_ref();
+
+        let mut issues = Vec::new();
+
+        #[cfg(feature = "regex")]
+        if let Some((pattern, regex)) = self.naming_convention.as_ref() {
+            if !regex.is_match(id) {
+                issues.push(IdLintIssue::NamingConvention {
+                    pattern: pattern.clone(),
+                });
+            }
+        }
+
+        if let Some(max_depth) = self.max_depth {
+            let depth = id.split(self.separator).count();
+
+            if depth > max_depth {
+                issues.push(IdLintIssue::TooDeep {
+                    depth,
+                    max_depth,
+                });
+            }
+        }
+
+        let mut found: Vec<char> = Vec::new();
+
+        for c in id.chars() {
+            if self.forbidden_characters.contains(&c) && !found.contains(&c) {
+                found.push(c);
+
+                issues.push(IdLintIssue::ForbiddenCharacter {
+                    character: c,
+                });
+            }
+        }
+
+        if !self.namespaces.is_empty() {
+            let namespace = id.split(self.separator).next().unwrap_or_default();
+
+            if !self.namespaces.iter().any(|n| n == namespace) {
+                issues.push(IdLintIssue::MissingNamespace {
+                    namespaces: self.namespaces.clone(),
+                });
+            }
+        }
+
+        issues
+    }
+
+    /// Lint the text ids of the context, sorted. Since every key has the same ids as the default key, only the default key is linted. Metadata (texts starting with `@`) is skipped.
+    pub fn check(&self, ctx: &JSONGetText) -> Vec<IdLintFinding> {
+        let mut texts: Vec<&String> =
+            ctx.default_map().keys().filter(|t| !is_metadata_text(t)).collect();
+        texts.sort_unstable();
+
+        texts
+            .into_iter()
+            .flat_map(|text| {
+                self.lint_id(text).into_iter().map(move |issue| {
+                    IdLintFinding {
+                        text: text.clone(),
+                        issue,
+                    }
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,7 @@
+mod consistency;
+mod glossary;
+mod lint;
+
+pub use consistency::*;
+pub use glossary::*;
+pub use lint::*;
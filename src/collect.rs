@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::{
+    IngestLimit, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+/// An error found by `Extend`, which cannot return it, so that `build` returns it instead.
+#[derive(Debug, Clone)]
+pub(crate) enum ExtendError {
+    DuplicatedKey(Key),
+    IngestLimitExceeded(Key, IngestLimit),
+}
+
+impl From<ExtendError> for JSONGetTextBuildError {
+    #[inline]
+    fn from(error: ExtendError) -> JSONGetTextBuildError {
+        match error {
+            ExtendError::DuplicatedKey(key) => JSONGetTextBuildError::DuplicatedKey(key),
+            ExtendError::IngestLimitExceeded(key, limit) => limit.exceeded_by(key),
+        }
+    }
+}
+
+/// The default key of a builder collected from no pairs. Its context is empty, so `build` returns `DefaultKeyNotFound`.
+#[inline]
+fn undefined_key() -> Key {
+    #[cfg(not(feature = "langid"))]
+    let key = Key::from("");
+
+    #[cfg(feature = "language_region_pair")]
+    let key = Key(Default::default(), None);
+
+    #[cfg(feature = "language")]
+    let key = Key(Default::default());
+
+    #[cfg(feature = "region")]
+    let key = Key(crate::unic_langid::subtags::Region::from_bytes(b"ZZ").unwrap());
+
+    key
+}
+
+impl<'a> Extend<(Key, HashMap<String, JSONGetTextValue<'a>>)> for JSONGetTextBuilder<'a> {
+    /// Add maps to the context like `add_map`. Since errors cannot be returned here, the first one (e.g. a duplicated key) is returned by `build`, and the pairs after it are ignored.
+    fn extend<I: IntoIterator<Item = (Key, HashMap<String, JSONGetTextValue<'a>>)>>(
+        &mut self,
+        iter: I,
+    ) {
+        for (key, map) in iter {
+            if self.options.extend_error.is_some() {
+                return;
+            }
+
+            if self.context.contains_key(&key) {
+                self.options.extend_error = Some(ExtendError::DuplicatedKey(key));
+            } else if let Err(limit) = self.options.ingest_limits.check_map(&map) {
+                self.options.extend_error = Some(ExtendError::IngestLimitExceeded(key, limit));
+            } else {
+                self.context.insert(key, map);
+            }
+        }
+    }
+}
+
+impl<'a> FromIterator<(Key, HashMap<String, JSONGetTextValue<'a>>)> for JSONGetTextBuilder<'a> {
+    /// Create a `JSONGetTextBuilder` instance from maps. The key of the first map is the default key.
+    fn from_iter<I: IntoIterator<Item = (Key, HashMap<String, JSONGetTextValue<'a>>)>>(
+        iter: I,
+    ) -> JSONGetTextBuilder<'a> {
+        let mut iter = iter.into_iter().peekable();
+
+        let default_key = match iter.peek() {
+            Some((key, _)) => key.to_owned(),
+            None => undefined_key(),
+        };
+
+        let mut builder = JSONGetTextBuilder::new(default_key);
+
+        builder.extend(iter);
+
+        builder
+    }
+}
+
+impl<'a> TryFrom<HashMap<Key, HashMap<String, JSONGetTextValue<'a>>>> for JSONGetText<'a> {
+    type Error = JSONGetTextBuildError;
+
+    /// Create a `JSONGetText` instance from maps. The default key is the one with the most texts, since the other keys cannot have texts which it does not have. Among keys with as many texts, the smallest one is chosen.
+    fn try_from(
+        context: HashMap<Key, HashMap<String, JSONGetTextValue<'a>>>,
+    ) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
+        let default_key = context
+            .iter()
+            .max_by(|(a, a_map), (b, b_map)| a_map.len().cmp(&b_map.len()).then_with(|| b.cmp(a)))
+            .map(|(key, _)| key.to_owned())
+            .unwrap_or_else(undefined_key);
+
+        let mut builder = JSONGetTextBuilder::new(default_key);
+
+        builder.extend(context);
+
+        builder.build()
+    }
+}
+
+#[cfg(not(feature = "langid"))]
+impl<'a> TryFrom<HashMap<String, HashMap<String, JSONGetTextValue<'a>>>> for JSONGetText<'a> {
+    type Error = JSONGetTextBuildError;
+
+    /// Create a `JSONGetText` instance from maps. The default key is chosen in the same way as from maps of `Key`s.
+    #[inline]
+    fn try_from(
+        context: HashMap<String, HashMap<String, JSONGetTextValue<'a>>>,
+    ) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
+        context.into_iter().map(|(key, map)| (Key(key), map)).collect::<HashMap<_, _>>().try_into()
+    }
+}
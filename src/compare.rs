@@ -0,0 +1,111 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+
+use serde_json::Value;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+#[inline]
+fn json_value_of<'v>(value: &'v JSONGetTextValue) -> Option<&'v Value> {
+    match value {
+        JSONGetTextValue::Str(_) => None,
+        JSONGetTextValue::JSONValue(v) => Some(v),
+        JSONGetTextValue::JSONValueRef(v) => Some(v),
+    }
+}
+
+/// Whether two values represent the same JSON value, whether they are borrowed or owned.
+#[inline]
+fn same_value(a: &JSONGetTextValue, b: &JSONGetTextValue) -> bool {
+    match (json_value_of(a), json_value_of(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.as_str().is_some() && a.as_str() == b.as_str(),
+    }
+}
+
+fn same_map(a: &HashMap<String, JSONGetTextValue>, b: &HashMap<String, JSONGetTextValue>) -> bool {
+    a.len() == b.len()
+        && a.iter().all(|(text, value)| b.get(text).is_some_and(|v| same_value(value, v)))
+}
+
+/// A value formatted as JSON, so that borrowed and owned values look the same.
+struct DebugValue<'b, 'a>(&'b JSONGetTextValue<'a>);
+
+impl Debug for DebugValue<'_, '_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0.as_str() {
+            Some(s) => Debug::fmt(s, f),
+            None => fmt::Display::fmt(json_value_of(self.0).unwrap(), f),
+        }
+    }
+}
+
+#[inline]
+fn sorted_sets(sets: &HashMap<Key, HashSet<String>>) -> BTreeMap<&Key, BTreeSet<&String>> {
+    sets.iter()
+        .filter(|(_, set)| !set.is_empty())
+        .map(|(key, set)| (key, set.iter().collect()))
+        .collect()
+}
+
+impl<'a, 'b> PartialEq<JSONGetText<'b>> for JSONGetText<'a> {
+    /// Compare the catalogs of two instances, i.e. their default keys, texts, which texts are filled, stale, deprecated, quarantined, extra or empty, and their migrations, namespaces, number symbols and locale groups, regardless of the order of maps and of whether values are borrowed. Modification times, observers, missing handlers, build steps and secondary contexts are not compared.
+    fn eq(&self, other: &JSONGetText<'b>) -> bool {
+        let mut quarantined: Vec<_> =
+            self.quarantined.iter().map(|q| (&q.key, &q.text, &q.reason)).collect();
+        let mut other_quarantined: Vec<_> =
+            other.quarantined.iter().map(|q| (&q.key, &q.text, &q.reason)).collect();
+
+        quarantined.sort_unstable();
+        other_quarantined.sort_unstable();
+
+        self.default_key == other.default_key
+            && self.context.len() == other.context.len()
+            && self.context.iter().all(|(key, map)| {
+                other.context.get(key).is_some_and(|other_map| same_map(map, other_map))
+            })
+            && sorted_sets(&self.filled) == sorted_sets(&other.filled)
+            && sorted_sets(&self.stale) == sorted_sets(&other.stale)
+            && self.deprecated == other.deprecated
+            && self.migrations == other.migrations
+            && quarantined == other_quarantined
+            && self.extra_texts == other.extra_texts
+            && self.empty_texts == other.empty_texts
+            && self.namespaces == other.namespaces
+            && self.number_symbols == other.number_symbols
+            && self.locale_groups == other.locale_groups
+    }
+}
+
+impl<'a> Debug for JSONGetText<'a> {
+    /// Format the catalogs compared by `PartialEq` with sorted maps, so that the output is stable and `assert_eq!` shows readable differences. Use `{:#?}` to pretty-print them.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let context: BTreeMap<&Key, BTreeMap<&String, DebugValue>> = self
+            .context
+            .iter()
+            .map(|(key, map)| {
+                (key, map.iter().map(|(text, value)| (text, DebugValue(value))).collect())
+            })
+            .collect();
+
+        let mut quarantined: Vec<_> = self.quarantined.iter().collect();
+
+        quarantined.sort_unstable_by(|a, b| (&a.key, &a.text).cmp(&(&b.key, &b.text)));
+
+        f.debug_struct("JSONGetText")
+            .field("default_key", &self.default_key)
+            .field("context", &context)
+            .field("filled", &sorted_sets(&self.filled))
+            .field("stale", &sorted_sets(&self.stale))
+            .field("deprecated", &self.deprecated.iter().collect::<BTreeMap<_, _>>())
+            .field("migrations", &self.migrations.iter().collect::<BTreeMap<_, _>>())
+            .field("quarantined", &quarantined)
+            .field("extra_texts", &self.extra_texts)
+            .field("empty_texts", &self.empty_texts)
+            .field("namespaces", &self.namespaces.iter().collect::<BTreeSet<_>>())
+            .field("number_symbols", &self.number_symbols.iter().collect::<BTreeMap<_, _>>())
+            .field("locale_groups", &self.locale_groups.iter().collect::<BTreeMap<_, _>>())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[cfg(feature = "fs")]
+use crate::CatalogId;
+use crate::{
+    IngestLimits, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+/// A `JSONGetTextBuilder` which catalogs can be added to from multiple threads, e.g. one task per delivery of a translation vendor. Catalogs are read and parsed by the calling threads in parallel, and only adding the parsed catalogs to the builder is serialized.
+#[derive(Debug)]
+pub struct SharedJSONGetTextBuilder<'a> {
+    builder: Mutex<JSONGetTextBuilder<'a>>,
+}
+
+impl<'a> SharedJSONGetTextBuilder<'a> {
+    #[inline]
+    pub fn new(builder: JSONGetTextBuilder<'a>) -> SharedJSONGetTextBuilder<'a> {
+        SharedJSONGetTextBuilder {
+            builder: Mutex::new(builder),
+        }
+    }
+
+    /// Lock the builder. A thread which panicked while holding the lock cannot have left a catalog half-added, so the poison is ignored.
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, JSONGetTextBuilder<'a>> {
+        self.builder.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[inline]
+    fn ingest_limits(&self) -> IngestLimits {
+        self.lock().options.ingest_limits
+    }
+
+    /// Add a parsed catalog to the builder, and let `f` record anything else about it while the builder is locked.
+    fn insert<F: FnOnce(&mut JSONGetTextBuilder<'a>, &Key)>(
+        &self,
+        key: Key,
+        map: HashMap<String, JSONGetTextValue<'a>>,
+        f: F,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let mut builder = self.lock();
+
+        if builder.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key));
+        }
+
+        f(&mut builder, &key);
+
+        builder.context.insert(key, map);
+
+        Ok(())
+    }
+
+    /// Add a JSON string to the context for a specify key, like `JSONGetTextBuilder::add_json_owned`.
+    #[inline]
+    pub fn add_json_owned<K: Into<Key>, J: AsRef<str>>(
+        &self,
+        key: K,
+        json: J,
+    ) -> Result<&Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let map = self.ingest_limits().parse_catalog(&key, json.as_ref().as_bytes())?;
+
+        self.insert(key, map, |_, _| ())?;
+
+        Ok(self)
+    }
+
+    /// Add a JSON file to the context for a specify key, like `JSONGetTextBuilder::add_json_file`.
+    #[cfg(feature = "fs")]
+    pub fn add_json_file<K: Into<Key>, P: AsRef<Path>>(
+        &self,
+        key: K,
+        path: P,
+    ) -> Result<&Self, JSONGetTextBuildError> {
+        let key = key.into();
+        let path = path.as_ref();
+
+        let limits = self.ingest_limits();
+
+        let json = limits.read_file(&key, path)?;
+
+        let map = limits.parse_catalog(&key, &json)?;
+
+        self.insert(key, map, |builder, key| {
+            #[cfg(feature = "watch")]
+            builder.options.json_files.push((key.to_owned(), path.to_path_buf()));
+
+            builder.record_file_modified(CatalogId::new(key.to_owned(), None), path);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Add a map to the context, like `JSONGetTextBuilder::add_map`.
+    #[inline]
+    pub fn add_map<K: Into<Key>>(
+        &self,
+        key: K,
+        map: HashMap<String, JSONGetTextValue<'a>>,
+    ) -> Result<&Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        self.ingest_limits().check_map(&map).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        self.insert(key, map, |_, _| ())?;
+
+        Ok(self)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> JSONGetTextBuilder<'a> {
+        self.builder.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Build a `JSONGetText` instance, like `JSONGetTextBuilder::build`.
+    #[inline]
+    pub fn build(self) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
+        self.into_inner().build()
+    }
+}
+
+impl<'a> From<JSONGetTextBuilder<'a>> for SharedJSONGetTextBuilder<'a> {
+    #[inline]
+    fn from(builder: JSONGetTextBuilder<'a>) -> SharedJSONGetTextBuilder<'a> {
+        SharedJSONGetTextBuilder::new(builder)
+    }
+}
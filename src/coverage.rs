@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, Key};
+
+/// How much of the texts of the default key a key has translated, in `JSONGetText::coverage`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Coverage {
+    pub translated_count: usize,
+    pub missing_count: usize,
+    /// The texts which the key has translated, sorted.
+    pub translated: Vec<String>,
+    /// The texts which were filled from another key because the key does not have them, sorted.
+    pub missing: Vec<String>,
+    /// The share of the texts of the default key which are translated, from `0` to `100`.
+    pub percentage: f64,
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the translation coverage of every key relative to the default key, e.g. for a dashboard showing that German is 83% translated. Metadata texts are not counted.
+    #[inline]
+    pub fn coverage(&self) -> BTreeMap<String, Coverage> {
+        self.coverage_of(self.context.keys())
+    }
+
+    /// Get the translation coverage of the given keys which are in context.
+    pub(crate) fn coverage_of<'k, I: IntoIterator<Item = &'k Key>>(
+        &self,
+        keys: I,
+    ) -> BTreeMap<String, Coverage> {
+        let (_, default_map) = self.default_entry();
+
+        let mut texts: Vec<&String> =
+            default_map.keys().filter(|text| !is_metadata_text(text)).collect();
+
+        texts.sort_unstable();
+
+        keys.into_iter()
+            .filter(|key| self.context.contains_key(*key))
+            .map(|key| {
+                let (missing, translated): (Vec<String>, Vec<String>) = texts
+                    .iter()
+                    .map(|text| text.to_string())
+                    .partition(|text| self.is_filled_text(key, text));
+
+                let percentage = if texts.is_empty() {
+                    100.0
+                } else {
+                    translated.len() as f64 * 100.0 / texts.len() as f64
+                };
+
+                let coverage = Coverage {
+                    translated_count: translated.len(),
+                    missing_count: missing.len(),
+                    translated,
+                    missing,
+                    percentage,
+                };
+
+                (key.to_string(), coverage)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::metadata::is_metadata_text;
+use crate::observer::Observer;
+use crate::{JSONGetText, JSONGetTextBuilder, JSONGetTextValue, LookupObserver};
+
+/// A deprecated text and the text which should be used instead of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeprecatedText {
+    pub text: String,
+    pub replacement: Option<String>,
+}
+
+/// Collect deprecated texts from the metadata of a map. A text is deprecated if its metadata looks like `"@text": {"deprecated": true}` or `"@text": {"deprecated": "replacement_text"}`.
+pub(crate) fn collect_deprecated_texts(
+    map: &HashMap<String, JSONGetTextValue>,
+) -> HashMap<String, Option<String>> {
+    let mut deprecated = HashMap::new();
+
+    for (text, value) in map.iter() {
+        if !is_metadata_text(text) {
+            continue;
+        }
+
+        let value = match value {
+            JSONGetTextValue::JSONValue(v) => v,
+            JSONGetTextValue::JSONValueRef(v) => v,
+            JSONGetTextValue::Str(_) => continue,
+        };
+
+        match value.get("deprecated") {
+            Some(serde_json::Value::Bool(true)) => {
+                deprecated.insert(text[1..].to_string(), None);
+            }
+            Some(serde_json::Value::String(replacement)) => {
+                deprecated.insert(text[1..].to_string(), Some(replacement.clone()));
+            }
+            _ => (),
+        }
+    }
+
+    deprecated
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set an observer which gets notified about lookups, e.g. when a deprecated text is used.
+    #[inline]
+    pub fn set_observer<O: LookupObserver + 'static>(&mut self, observer: O) -> &mut Self {
+        self.options.observer = Some(Observer(Arc::new(observer)));
+
+        self
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Returns `true` if the text is marked as deprecated in the metadata of the default key.
+    #[inline]
+    pub fn is_deprecated<T: AsRef<str>>(&self, text: T) -> bool {
+        self.deprecated.contains_key(text.as_ref())
+    }
+
+    /// Get all deprecated texts with their replacements, sorted by texts.
+    pub fn get_deprecated_texts(&self) -> Vec<DeprecatedText> {
+        let mut deprecated: Vec<DeprecatedText> = self
+            .deprecated
+            .iter()
+            .map(|(text, replacement)| {
+                DeprecatedText {
+                    text: text.clone(),
+                    replacement: replacement.clone(),
+                }
+            })
+            .collect();
+
+        deprecated.sort_unstable_by(|a, b| a.text.cmp(&b.text));
+
+        deprecated
+    }
+
+    #[inline]
+    pub(crate) fn report_deprecated_text(&self, text: &str) {
+        if let Some(observer) = self.observer.as_ref() {
+            if let Some(replacement) = self.deprecated.get(text) {
+                observer.0.deprecated_text_used(text, replacement.as_deref());
+            }
+        }
+    }
+}
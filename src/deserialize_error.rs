@@ -0,0 +1,157 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::{JSONGetText, JSONGetTextValue, Key, LocalizedError};
+
+/// The prefix of the error codes of deserialization errors, e.g. the message of `deserialize.missing_field` is the `errors.deserialize.missing_field` text.
+pub const DESERIALIZE_ERROR_CODE_PREFIX: &str = "deserialize.";
+
+/// The kind of a deserialization error, recognized from the messages which `serde::de::Error` produces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeserializeErrorKind {
+    MissingField,
+    UnknownField,
+    DuplicateField,
+    UnknownVariant,
+    InvalidType,
+    InvalidValue,
+    InvalidLength,
+    /// Any other error, e.g. a syntax error of the data format.
+    Other,
+}
+
+impl DeserializeErrorKind {
+    /// The error code, without `DESERIALIZE_ERROR_CODE_PREFIX`.
+    #[inline]
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            DeserializeErrorKind::MissingField => "missing_field",
+            DeserializeErrorKind::UnknownField => "unknown_field",
+            DeserializeErrorKind::DuplicateField => "duplicate_field",
+            DeserializeErrorKind::UnknownVariant => "unknown_variant",
+            DeserializeErrorKind::InvalidType => "invalid_type",
+            DeserializeErrorKind::InvalidValue => "invalid_value",
+            DeserializeErrorKind::InvalidLength => "invalid_length",
+            DeserializeErrorKind::Other => "invalid",
+        }
+    }
+}
+
+/// The information which can be recognized from the message of a deserialization error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeserializeErrorInfo {
+    pub kind: DeserializeErrorKind,
+    /// The field, for missing, unknown and duplicate fields.
+    pub field: Option<String>,
+    /// The unknown variant, or what was found for invalid types, values and lengths.
+    pub found: Option<String>,
+    pub expected: Option<String>,
+}
+
+impl DeserializeErrorInfo {
+    /// Recognize the message of a deserialization error. The position appended by `serde_json` (`at line 1 column 2`) is ignored.
+    pub fn parse<S: AsRef<str>>(message: S) -> DeserializeErrorInfo {
+        static POSITION: OnceLock<Regex> = OnceLock::new();
+        static PATTERNS: OnceLock<Vec<(DeserializeErrorKind, Regex)>> = OnceLock::new();
+
+        let message = message.as_ref();
+
+        let position = POSITION.get_or_init(|| Regex::new(r" at line \d+ column \d+$").unwrap());
+
+        let message = match position.find(message) {
+            Some(m) => &message[..m.start()],
+            None => message,
+        };
+
+        let patterns = PATTERNS.get_or_init(|| {
+            [
+                (DeserializeErrorKind::MissingField, r"^missing field `(?P<field>.*)`$"),
+                (DeserializeErrorKind::UnknownField, r"^unknown field `(?P<field>.*)`, (?:expected (?P<expected>.*)|there are no fields)$"),
+                (DeserializeErrorKind::DuplicateField, r"^duplicate field `(?P<field>.*)`$"),
+                (DeserializeErrorKind::UnknownVariant, r"^unknown variant `(?P<found>.*)`, (?:expected (?P<expected>.*)|there are no variants)$"),
+                (DeserializeErrorKind::InvalidType, r"^invalid type: (?P<found>.*), expected (?P<expected>.*)$"),
+                (DeserializeErrorKind::InvalidValue, r"^invalid value: (?P<found>.*), expected (?P<expected>.*)$"),
+                (DeserializeErrorKind::InvalidLength, r"^invalid length (?P<found>.*), expected (?P<expected>.*)$"),
+            ]
+            .into_iter()
+            .map(|(kind, pattern)| (kind, Regex::new(pattern).unwrap()))
+            .collect()
+        });
+
+        for (kind, regex) in patterns {
+            if let Some(captures) = regex.captures(message) {
+                let get = |name: &str| captures.name(name).map(|m| m.as_str().to_string());
+
+                return DeserializeErrorInfo {
+                    kind: kind.clone(),
+                    field: get("field"),
+                    found: get("found"),
+                    expected: get("expected"),
+                };
+            }
+        }
+
+        DeserializeErrorInfo {
+            kind: DeserializeErrorKind::Other,
+            field: None,
+            found: None,
+            expected: None,
+        }
+    }
+}
+
+/// A localized deserialization error, created by `JSONGetText::localize_deserialize_error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedDeserializeError {
+    /// The field which the error is about, if known.
+    pub field: Option<String>,
+    pub error: LocalizedError,
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Localize a deserialization error (e.g. `serde_json::Error`) with a specific key, so the English message of serde is not shown to users. The message comes from `localize_error` with the code `deserialize.{kind}` (e.g. `errors.deserialize.missing_field`), and `{field}` (the localized field name), `{found}` and `{expected}` placeholders are replaced. If the key is not in context, the default key is used.
+    pub fn localize_deserialize_error<Q: ?Sized + Hash + Eq, E: Display>(
+        &self,
+        key: &Q,
+        error: &E,
+    ) -> LocalizedDeserializeError
+    where
+        Key: Borrow<Q>, {
+        let key = self.find_entry_or_default(key).0;
+
+        let info = DeserializeErrorInfo::parse(error.to_string());
+
+        let field_name =
+            info.field.as_ref().map(|field| self.localize_field_name::<Key, _>(key, field));
+
+        let mut args = HashMap::new();
+
+        if let Some(field_name) = field_name.as_ref() {
+            args.insert("field", JSONGetTextValue::from_str(field_name));
+        }
+
+        if let Some(found) = info.found.as_ref() {
+            args.insert("found", JSONGetTextValue::from_str(found));
+        }
+
+        if let Some(expected) = info.expected.as_ref() {
+            args.insert("expected", JSONGetTextValue::from_str(expected));
+        }
+
+        let error = self.localize_error::<Key, _>(
+            key,
+            format!("{}{}", DESERIALIZE_ERROR_CODE_PREFIX, info.kind.as_code()),
+            &args,
+        );
+
+        LocalizedDeserializeError {
+            field: info.field,
+            error,
+        }
+    }
+}
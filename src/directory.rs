@@ -0,0 +1,185 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::locale::key_from_tag;
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, Key};
+
+/// Decides which files of a directory are catalogs and which keys they belong to, so that `JSONGetTextBuilder::add_directory_with_scheme` can load existing project layouts.
+pub trait NamingScheme {
+    /// Get the key of a file from its path relative to the directory, or `None` to skip the file.
+    fn key_of(&self, relative_path: &Path) -> Option<Key>;
+
+    /// Whether subdirectories are searched. The default is `false`.
+    #[inline]
+    fn searches_subdirectories(&self) -> bool {
+        false
+    }
+}
+
+/// Files named by their language tags, e.g. `en_US.json`, `zh-TW.json`. This is the scheme of `add_directory`.
+#[derive(Debug, Clone)]
+pub struct FileStemScheme {
+    extension: String,
+}
+
+impl FileStemScheme {
+    /// Create a scheme for files with an extension, e.g. `json` or `i18n`.
+    #[inline]
+    pub fn new<S: Into<String>>(extension: S) -> FileStemScheme {
+        FileStemScheme {
+            extension: extension.into(),
+        }
+    }
+}
+
+impl Default for FileStemScheme {
+    #[inline]
+    fn default() -> Self {
+        FileStemScheme::new("json")
+    }
+}
+
+impl NamingScheme for FileStemScheme {
+    fn key_of(&self, relative_path: &Path) -> Option<Key> {
+        if relative_path.extension()? != self.extension.as_str() {
+            return None;
+        }
+
+        key_from_tag(relative_path.file_stem()?.to_str()?)
+    }
+}
+
+/// Files named by a prefix, a language tag and an extension, e.g. `messages.en.json`, `messages.zh-TW.json`.
+#[derive(Debug, Clone)]
+pub struct SuffixScheme {
+    prefix: String,
+    extension: String,
+}
+
+impl SuffixScheme {
+    /// Create a scheme for files like `<prefix>.<tag>.<extension>`.
+    #[inline]
+    pub fn new<P: Into<String>, E: Into<String>>(prefix: P, extension: E) -> SuffixScheme {
+        SuffixScheme {
+            prefix: prefix.into(),
+            extension: extension.into(),
+        }
+    }
+}
+
+impl NamingScheme for SuffixScheme {
+    fn key_of(&self, relative_path: &Path) -> Option<Key> {
+        let tag = relative_path
+            .to_str()?
+            .strip_prefix(self.prefix.as_str())?
+            .strip_prefix('.')?
+            .strip_suffix(self.extension.as_str())?
+            .strip_suffix('.')?;
+
+        key_from_tag(tag)
+    }
+}
+
+/// A directory for each language tag, each of which contains a file with the same name, e.g. `en-US/translation.json` as used by i18next.
+#[derive(Debug, Clone)]
+pub struct DirectoryScheme {
+    file_name: String,
+}
+
+impl DirectoryScheme {
+    /// Create a scheme for files like `<tag>/<file_name>`.
+    #[inline]
+    pub fn new<S: Into<String>>(file_name: S) -> DirectoryScheme {
+        DirectoryScheme {
+            file_name: file_name.into(),
+        }
+    }
+}
+
+impl NamingScheme for DirectoryScheme {
+    fn key_of(&self, relative_path: &Path) -> Option<Key> {
+        let mut components = relative_path.iter();
+
+        let tag = components.next()?.to_str()?;
+
+        if components.next()? != self.file_name.as_str() || components.next().is_some() {
+            return None;
+        }
+
+        key_from_tag(tag)
+    }
+
+    #[inline]
+    fn searches_subdirectories(&self) -> bool {
+        true
+    }
+}
+
+/// Collect the files of a directory as paths relative to `root`.
+fn collect_files(
+    root: &Path,
+    dir: &Path,
+    recursive: bool,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), JSONGetTextBuildError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_file() {
+            files.push(path.strip_prefix(root).unwrap().to_path_buf());
+        } else if recursive && path.is_dir() {
+            collect_files(root, &path, recursive, files)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add every JSON file in a directory, using its file name as the key (e.g. `en_US.json`, `zh-TW.json`). Files whose names are not language tags are skipped, and subdirectories are not searched. The errors of the files are returned as in `add_directory_with_scheme`.
+    #[inline]
+    pub fn add_directory<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        self.add_directory_with_scheme(path, &FileStemScheme::default())
+    }
+
+    /// Add every file in a directory which a naming scheme gives a key to. Every file is tried even if some of them fail, and the failures are returned together with their paths as `JSONGetTextBuildError::FilesFailed`. The files which are loaded are kept added.
+    pub fn add_directory_with_scheme<P: AsRef<Path>, N: NamingScheme + ?Sized>(
+        &mut self,
+        path: P,
+        scheme: &N,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let path = path.as_ref();
+
+        let mut relative_paths = Vec::new();
+
+        collect_files(path, path, scheme.searches_subdirectories(), &mut relative_paths)?;
+
+        let mut files = Vec::new();
+
+        for relative_path in relative_paths {
+            if let Some(key) = scheme.key_of(&relative_path) {
+                files.push((key, path.join(relative_path)));
+            }
+        }
+
+        // load in a stable order, so that errors are reported in the same order every time
+        files.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+        let mut errors = Vec::new();
+
+        for (key, path) in files {
+            if let Err(err) = self.add_json_file(key, &path) {
+                errors.push((path, err));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(self)
+        } else {
+            Err(JSONGetTextBuildError::FilesFailed(errors))
+        }
+    }
+}
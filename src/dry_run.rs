@@ -0,0 +1,172 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::metadata::{is_in_default_map, is_metadata_text};
+use crate::{placeholders_of, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// A problem which would be found by importing a catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ImportProblem {
+    /// The key has already been added, so the catalog cannot be added to this builder.
+    DuplicatedKey,
+    /// The text is not in the default key, so building would fail.
+    TextNotInDefaultKey {
+        text: String,
+    },
+    /// The placeholders of the text are different from the text of the default key.
+    PlaceholderMismatch {
+        text: String,
+        missing: Vec<String>,
+        unknown: Vec<String>,
+    },
+}
+
+/// A text whose value would be changed by importing a catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TextChange {
+    pub text: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// What importing a catalog for a key would change, compared with the catalog of the key already added to the builder (if any). Texts are sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ImportPreview {
+    pub new_texts: Vec<String>,
+    pub changed_texts: Vec<TextChange>,
+    pub removed_texts: Vec<String>,
+    pub problems: Vec<ImportProblem>,
+}
+
+impl ImportPreview {
+    /// Returns `true` if there are no problems.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+#[inline]
+fn to_json_value(value: &JSONGetTextValue) -> Value {
+    match value {
+        JSONGetTextValue::Str(s) => Value::String(s.to_string()),
+        JSONGetTextValue::JSONValue(v) => v.clone(),
+        JSONGetTextValue::JSONValueRef(v) => (*v).clone(),
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Preview what adding a JSON file for a key would change without adding it: new, changed and removed texts, and problems such as texts which are not in the default key or whose placeholders are different from the default key. Errors of reading, parsing and ingest limits are returned like `add_json_file`.
+    pub fn dry_run_add_json_file<K: Into<Key>, P: AsRef<Path>>(
+        &self,
+        key: K,
+        path: P,
+    ) -> Result<ImportPreview, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let limits = self.options.ingest_limits;
+
+        let json = limits.read_file(&key, path.as_ref())?;
+
+        limits.check_json(&json).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let new_map: Map<String, Value> = serde_json::from_slice(&json)?;
+
+        limits.check_texts(new_map.len()).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let empty = HashMap::new();
+
+        let old_map = self.context.get::<Key>(&key).unwrap_or(&empty);
+
+        let is_default_key = key == self.default_key;
+
+        let default_map = if is_default_key {
+            None
+        } else {
+            self.context.get(&self.default_key)
+        };
+
+        let mut preview = ImportPreview::default();
+
+        if self.context.contains_key::<Key>(&key) {
+            preview.problems.push(ImportProblem::DuplicatedKey);
+        }
+
+        let mut texts: Vec<&String> = new_map.keys().collect();
+
+        texts.sort_unstable();
+
+        for text in texts {
+            let new_value = &new_map[text];
+
+            match old_map.get(text) {
+                Some(old_value) => {
+                    let old_value = to_json_value(old_value);
+
+                    if &old_value != new_value {
+                        preview.changed_texts.push(TextChange {
+                            text: text.clone(),
+                            old: old_value,
+                            new: new_value.clone(),
+                        });
+                    }
+                }
+                None => preview.new_texts.push(text.clone()),
+            }
+
+            let default_map = match default_map {
+                Some(default_map) => default_map,
+                None => continue,
+            };
+
+            if is_metadata_text(text) {
+                if !is_in_default_map(text, default_map) {
+                    preview.problems.push(ImportProblem::TextNotInDefaultKey {
+                        text: text.clone(),
+                    });
+                }
+
+                continue;
+            }
+
+            let default_value = match default_map.get(text) {
+                Some(default_value) => default_value,
+                None => {
+                    preview.problems.push(ImportProblem::TextNotInDefaultKey {
+                        text: text.clone(),
+                    });
+
+                    continue;
+                }
+            };
+
+            if let (Some(default_template), Value::String(template)) =
+                (default_value.as_str(), new_value)
+            {
+                let expected: BTreeSet<&str> =
+                    placeholders_of(default_template).into_iter().collect();
+                let actual: BTreeSet<&str> = placeholders_of(template).into_iter().collect();
+
+                if expected != actual {
+                    preview.problems.push(ImportProblem::PlaceholderMismatch {
+                        text: text.clone(),
+                        missing: expected.difference(&actual).map(|s| s.to_string()).collect(),
+                        unknown: actual.difference(&expected).map(|s| s.to_string()).collect(),
+                    });
+                }
+            }
+        }
+
+        let mut removed_texts: Vec<String> =
+            old_map.keys().filter(|text| !new_map.contains_key(*text)).cloned().collect();
+
+        removed_texts.sort_unstable();
+
+        preview.removed_texts = removed_texts;
+
+        Ok(preview)
+    }
+}
@@ -0,0 +1,84 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::format::interpolate_with;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// A localized email, rendered by `JSONGetText::render_email`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedEmail {
+    pub subject: String,
+    /// The body as plain text (or as Markdown source).
+    pub body: String,
+    /// The body rendered from Markdown to HTML. It is only available with the `markdown` feature.
+    pub html: Option<String>,
+}
+
+#[cfg(feature = "markdown")]
+fn escape_html(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+#[cfg(feature = "markdown")]
+fn render_html(body: &str, args: &HashMap<&str, JSONGetTextValue>) -> String {
+    let parser = pulldown_cmark::Parser::new(body);
+
+    let mut html = String::new();
+
+    pulldown_cmark::html::push_html(&mut html, parser);
+
+    // placeholders are replaced after rendering, so the values are escaped instead of being parsed as Markdown
+    interpolate_with(&html, |name| args.get(name).map(|value| escape_html(&value.to_string())))
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Render the email of a namespace for a recipient whose locale is `key`. The texts `{namespace}.subject` and `{namespace}.body` are used, and `{name}` placeholders in them are replaced with `args`. If the key is not in context, the default key is used.
+    pub fn render_email<Q: ?Sized + Hash + Eq, N: AsRef<str>>(
+        &self,
+        key: &Q,
+        namespace: N,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> Option<LocalizedEmail>
+    where
+        Key: Borrow<Q>, {
+        let namespace = namespace.as_ref();
+
+        let map = self.find_map_or_default(key);
+
+        let subject = self.lookup_text(map, &format!("{}.subject", namespace))?;
+        let body = self.lookup_text(map, &format!("{}.body", namespace))?;
+
+        let subject = subject.to_string();
+        let body = body.to_string();
+
+        let interpolate = |template: &str| {
+            interpolate_with(template, |name| args.get(name).map(|value| value.to_string()))
+        };
+
+        #[cfg(feature = "markdown")]
+        let html = Some(render_html(&body, args));
+
+        #[cfg(not(feature = "markdown"))]
+        let html = None;
+
+        Some(LocalizedEmail {
+            subject: interpolate(&subject),
+            body: interpolate(&body),
+            html,
+        })
+    }
+}
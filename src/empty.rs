@@ -0,0 +1,135 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::metadata::is_metadata_text;
+use crate::{Context, JSONGetText, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// The kind of an empty value, which translation vendors use to mean "not translated yet".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmptyKind {
+    /// `null`
+    Null,
+    /// `""`
+    EmptyString,
+}
+
+impl EmptyKind {
+    /// Get the kind of a value if it is empty.
+    #[inline]
+    pub(crate) fn of(value: &JSONGetTextValue) -> Option<EmptyKind> {
+        match value.as_str() {
+            Some("") => Some(EmptyKind::EmptyString),
+            Some(_) => None,
+            None => {
+                match value {
+                    JSONGetTextValue::JSONValue(Value::Null)
+                    | JSONGetTextValue::JSONValueRef(Value::Null) => Some(EmptyKind::Null),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// How building handles a text whose value is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EmptyTextPolicy {
+    /// Keep the value as it is, e.g. an empty string is rendered as one.
+    #[default]
+    Literal,
+    /// Treat the text as missing in its key, so that it is filled from the fallback keys or the default key like a text which is not there. Empty texts of the default key are kept, since there is nothing to fill them from.
+    Missing,
+}
+
+/// The policies of both kinds of empty values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) struct EmptyTextPolicies {
+    null: EmptyTextPolicy,
+    empty_string: EmptyTextPolicy,
+}
+
+impl EmptyTextPolicies {
+    #[inline]
+    fn get(&self, kind: EmptyKind) -> EmptyTextPolicy {
+        match kind {
+            EmptyKind::Null => self.null,
+            EmptyKind::EmptyString => self.empty_string,
+        }
+    }
+}
+
+/// A text whose value is empty, found when building.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct EmptyText {
+    pub key: String,
+    pub text: String,
+    pub kind: EmptyKind,
+    /// Whether the text was treated as missing by `EmptyTextPolicy::Missing` and filled from another key.
+    pub filled: bool,
+}
+
+/// Find the texts with empty values in the context, sorted by their keys and texts, and drop the ones of the keys other than the default key which are treated as missing.
+pub(crate) fn collect_empty_texts<Q: ?Sized + Hash + Eq>(
+    default_key: &Q,
+    context: &mut Context,
+    policies: EmptyTextPolicies,
+) -> Vec<EmptyText>
+where
+    Key: Borrow<Q>, {
+    let mut empty_texts = Vec::new();
+
+    for (key, map) in context.iter_mut() {
+        let is_default_key = key.borrow() == default_key;
+
+        map.retain(|text, value| {
+            if is_metadata_text(text) {
+                return true;
+            }
+
+            let kind = match EmptyKind::of(value) {
+                Some(kind) => kind,
+                None => return true,
+            };
+
+            let filled = !is_default_key && policies.get(kind) == EmptyTextPolicy::Missing;
+
+            empty_texts.push(EmptyText {
+                key: key.to_string(),
+                text: text.clone(),
+                kind,
+                filled,
+            });
+
+            !filled
+        });
+    }
+
+    empty_texts.sort_unstable();
+
+    empty_texts
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set how texts whose values are empty of a kind are handled when building. `EmptyTextPolicy::Literal` is used for both kinds if this is not called. Empty texts are reported by `JSONGetText::get_empty_texts` either way.
+    #[inline]
+    pub fn set_empty_text_policy(&mut self, kind: EmptyKind, policy: EmptyTextPolicy) -> &mut Self {
+        match kind {
+            EmptyKind::Null => self.options.pipeline.empty_texts.null = policy,
+            EmptyKind::EmptyString => self.options.pipeline.empty_texts.empty_string = policy,
+        }
+
+        self
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the texts whose values were empty (`null` or `""`) when building, sorted by their keys and texts, e.g. to report the texts which are not translated yet.
+    #[inline]
+    pub fn get_empty_texts(&self) -> &[EmptyText] {
+        &self.empty_texts
+    }
+}
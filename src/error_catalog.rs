@@ -0,0 +1,66 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::format::interpolate_with;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// The prefix of the ids of error messages, e.g. `errors.not_found`.
+pub const ERROR_TEXT_PREFIX: &str = "errors.";
+
+/// The code of the message used for errors which have no message of their own.
+pub const GENERIC_ERROR_CODE: &str = "generic";
+
+/// The message used if the context has no `errors.generic` text.
+pub const FALLBACK_ERROR_MESSAGE: &str = "An error occurred.";
+
+/// A localized error message, created by `JSONGetText::localize_error`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LocalizedError {
+    /// The requested error code.
+    pub code: String,
+    pub message: String,
+    /// Whether the generic message was used because there is no message for the code.
+    #[serde(skip)]
+    pub generic: bool,
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the message of an error code from `errors.{code}` with a specific key, replacing `{name}` placeholders with `args`. If there is no such text, `errors.generic` is used, or `FALLBACK_ERROR_MESSAGE` if even that is missing, so a message is always returned. If the key is not in context, the default key is used.
+    pub fn localize_error<Q: ?Sized + Hash + Eq, C: AsRef<str>>(
+        &self,
+        key: &Q,
+        code: C,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> LocalizedError
+    where
+        Key: Borrow<Q>, {
+        let code = code.as_ref();
+
+        let map = self.find_map_or_default(key);
+
+        let (template, generic) =
+            match self.lookup_text(map, &format!("{}{}", ERROR_TEXT_PREFIX, code)) {
+                Some(value) => (value.to_string(), false),
+                None => {
+                    let template = self
+                        .lookup_text(map, &format!("{}{}", ERROR_TEXT_PREFIX, GENERIC_ERROR_CODE))
+                        .map(|value| value.to_string())
+                        .unwrap_or_else(|| String::from(FALLBACK_ERROR_MESSAGE));
+
+                    (template, true)
+                }
+            };
+
+        let message =
+            interpolate_with(&template, |name| args.get(name).map(|value| value.to_string()));
+
+        LocalizedError {
+            code: code.to_string(),
+            message,
+            generic,
+        }
+    }
+}
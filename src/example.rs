@@ -0,0 +1,114 @@
+#[cfg(feature = "fs")]
+use std::fs::{self, OpenOptions};
+#[cfg(feature = "fs")]
+use std::io::{self, ErrorKind, Write};
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+
+use crate::locale::key_from_tag;
+#[cfg(feature = "fs")]
+use crate::Key;
+use crate::{JSONGetText, JSONGetTextBuilder};
+
+const EXAMPLE_DEFAULT_TAG: &str = "en-US";
+
+/// The example catalogs, as language tags and JSON. The first one is the default.
+const EXAMPLE_CATALOGS: [(&str, &str); 3] = [
+    (
+        "en-US",
+        r#"{
+    "hello": "Hello, world!",
+    "greeting": "Hello, {name}!",
+    "@greeting": {"description": "Greets a user by their name."},
+    "items": "{count} items in your cart"
+}
+"#,
+    ),
+    (
+        "fr-FR",
+        r#"{
+    "hello": "Bonjour, le monde !",
+    "greeting": "Bonjour, {name} !",
+    "items": "{count} articles dans votre panier"
+}
+"#,
+    ),
+    (
+        "zh-TW",
+        r#"{
+    "hello": "哈囉，世界！",
+    "greeting": "哈囉，{name}！",
+    "items": "購物車中有 {count} 件商品"
+}
+"#,
+    ),
+];
+
+/// Get the starter JSON of a language tag. Languages without an example translation get an empty catalog, whose texts are filled from the default key when building.
+#[cfg(feature = "fs")]
+fn starter_json(tag: &str, default: bool) -> &'static str {
+    if default {
+        return EXAMPLE_CATALOGS[0].1;
+    }
+
+    let tag_key = key_from_tag(tag);
+
+    EXAMPLE_CATALOGS
+        .iter()
+        .find(|(example_tag, _)| key_from_tag(example_tag) == tag_key)
+        .map(|(_, json)| *json)
+        .unwrap_or("{}\n")
+}
+
+impl JSONGetText<'static> {
+    /// Create an instance from the embedded example catalogs (`en-US` as the default, `fr-FR` and `zh-TW`), to try the API or to test code without writing JSON.
+    pub fn example() -> JSONGetText<'static> {
+        let mut builder = JSONGetTextBuilder::new(key_from_tag(EXAMPLE_DEFAULT_TAG).unwrap());
+
+        for (tag, json) in EXAMPLE_CATALOGS {
+            builder.add_json(key_from_tag(tag).unwrap(), json).unwrap();
+        }
+
+        builder.build().unwrap()
+    }
+}
+
+/// Create a directory (e.g. `locales/`) with a starter JSON file for each language tag, which can be loaded with `JSONGetTextBuilder::add_directory`. The first language is the default and gets example texts. Others get example translations if there are any, or empty catalogs. Existing files are kept. Return the paths of the created files.
+#[cfg(feature = "fs")]
+pub fn scaffold_locales<P: AsRef<Path>, S: AsRef<str>>(
+    path: P,
+    languages: &[S],
+) -> io::Result<Vec<PathBuf>> {
+    let path = path.as_ref();
+
+    let keys = languages
+        .iter()
+        .map(|tag| {
+            let tag = tag.as_ref();
+
+            key_from_tag(tag).map(|key| (tag, key)).ok_or_else(|| {
+                io::Error::new(ErrorKind::InvalidInput, format!("{:?} is not a language tag", tag))
+            })
+        })
+        .collect::<io::Result<Vec<(&str, Key)>>>()?;
+
+    fs::create_dir_all(path)?;
+
+    let mut created = Vec::new();
+
+    for (i, (tag, key)) in keys.into_iter().enumerate() {
+        let file_path = path.join(format!("{}.json", key));
+
+        let mut file = match OpenOptions::new().write(true).create_new(true).open(&file_path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err),
+        };
+
+        file.write_all(starter_json(tag, i == 0).as_bytes())?;
+
+        created.push(file_path);
+    }
+
+    Ok(created)
+}
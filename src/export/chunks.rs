@@ -0,0 +1,149 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+#[cfg(feature = "content_hash")]
+use crate::ContentHash;
+use crate::{DeprecatedText, JSONGetText, Key};
+
+/// A size-bounded piece of an exported key. The `json` field is a JSON object which can be served as a standalone file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportChunk {
+    pub file_name: String,
+    pub json: String,
+}
+
+/// Describes a chunk in an `ExportManifest`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportManifestEntry {
+    pub file_name: String,
+    pub size: usize,
+    pub texts: Vec<String>,
+}
+
+/// Tells clients which chunk file contains which text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportManifest {
+    pub key: String,
+    /// The content hash of the key, for verifying that the chunks are produced from the expected texts.
+    #[cfg(feature = "content_hash")]
+    pub content_hash: ContentHash,
+    pub chunks: Vec<ExportManifestEntry>,
+    pub deprecated: Vec<DeprecatedText>,
+}
+
+impl ExportManifest {
+    /// Get the file name of the chunk which contains the specified text.
+    pub fn chunk_for<T: AsRef<str>>(&self, text: T) -> Option<&str> {
+        let text = text.as_ref();
+
+        self.chunks
+            .iter()
+            .find(|entry| entry.texts.iter().any(|t| t == text))
+            .map(|entry| entry.file_name.as_str())
+    }
+
+    /// Convert to a string for JSON format.
+    #[inline]
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+/// The result of `JSONGetText::export_chunks`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportChunks {
+    pub manifest: ExportManifest,
+    pub chunks: Vec<ExportChunk>,
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Export the text of a key as a JSON object.
+    pub fn export_json<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map(key)?;
+
+        Some(serde_json::to_string(map).unwrap())
+    }
+
+    /// Split the exported JSON of a key into chunks whose sizes do not exceed `max_bytes` (unless a single text is larger than that), along with a manifest. Texts are sorted, so the result is stable.
+    pub fn export_chunks<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+        max_bytes: usize,
+    ) -> Option<ExportChunks>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry(key)?;
+
+        let mut texts: Vec<&String> = map.keys().collect();
+        texts.sort_unstable();
+
+        let mut manifest = ExportManifest {
+            key: key.to_string(),
+            #[cfg(feature = "content_hash")]
+            content_hash: ContentHash::of_map(map),
+            chunks: Vec::new(),
+            deprecated: self.get_deprecated_texts(),
+        };
+        let mut chunks = Vec::new();
+
+        let mut json = String::from("{");
+        let mut chunk_texts = Vec::new();
+
+        for text in texts {
+            let entry = format!(
+                "{}:{}",
+                serde_json::to_string(text).unwrap(),
+                serde_json::to_string(map.get(text).unwrap()).unwrap()
+            );
+
+            // the length of `entry`, a separator (if needed) and the closing brace
+            let needed = entry.len() + usize::from(!chunk_texts.is_empty()) + 1;
+
+            if !chunk_texts.is_empty() && json.len() + needed > max_bytes {
+                push_chunk(&mut manifest, &mut chunks, &mut json, &mut chunk_texts);
+            }
+
+            if !chunk_texts.is_empty() {
+                json.push(',');
+            }
+
+            json.push_str(&entry);
+            chunk_texts.push(text.clone());
+        }
+
+        if !chunk_texts.is_empty() || chunks.is_empty() {
+            push_chunk(&mut manifest, &mut chunks, &mut json, &mut chunk_texts);
+        }
+
+        Some(ExportChunks {
+            manifest,
+            chunks,
+        })
+    }
+}
+
+fn push_chunk(
+    manifest: &mut ExportManifest,
+    chunks: &mut Vec<ExportChunk>,
+    json: &mut String,
+    texts: &mut Vec<String>,
+) {
+    json.push('}');
+
+    let file_name = format!("{}.{}.json", manifest.key, chunks.len());
+
+    manifest.chunks.push(ExportManifestEntry {
+        file_name: file_name.clone(),
+        size: json.len(),
+        texts: std::mem::take(texts),
+    });
+
+    chunks.push(ExportChunk {
+        file_name,
+        json: std::mem::replace(json, String::from("{")),
+    });
+}
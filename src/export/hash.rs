@@ -0,0 +1,84 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter, Write};
+use std::hash::Hash;
+
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use super::minified::write_minified;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// A SHA-256 digest of the canonical form of catalog content. It does not depend on the whitespace or the order of the original JSON, nor on metadata (texts starting with `@`), so reproducible builds can compare it across machines. It is displayed and serialized as a lowercase hexadecimal string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentHash(pub [u8; 32]);
+
+impl ContentHash {
+    #[inline]
+    fn of(canonical: &str) -> ContentHash {
+        ContentHash(Sha256::digest(canonical.as_bytes()).into())
+    }
+
+    /// Get the content hash of the texts of a key.
+    #[inline]
+    pub(crate) fn of_map(map: &HashMap<String, JSONGetTextValue>) -> ContentHash {
+        let mut canonical = String::new();
+
+        write_minified(map, &mut canonical);
+
+        ContentHash::of(&canonical)
+    }
+}
+
+impl Display for ContentHash {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for ContentHash {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the content hash of a key, which is the SHA-256 digest of `export_minified(key)`.
+    pub fn key_content_hash<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<ContentHash>
+    where
+        Key: Borrow<Q>, {
+        self.find_map(key).map(ContentHash::of_map)
+    }
+
+    /// Get the content hash of this instance, covering the default key and the texts of every key. Texts filled from the default key are included, so it only changes when the texts served for some key change.
+    pub fn content_hash(&self) -> ContentHash {
+        let mut entries: Vec<_> =
+            self.context.iter().map(|(key, map)| (key.to_string(), map)).collect();
+        entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        // `[default_key,{key:texts,...}]`
+        let mut canonical = String::from("[");
+
+        canonical.push_str(&serde_json::to_string(&self.default_entry().0.to_string()).unwrap());
+        canonical.push_str(",{");
+
+        for (i, (key, map)) in entries.into_iter().enumerate() {
+            if i > 0 {
+                canonical.push(',');
+            }
+
+            write!(canonical, "{}:", serde_json::to_string(&key).unwrap()).unwrap();
+
+            write_minified(map, &mut canonical);
+        }
+
+        canonical.push_str("}]");
+
+        ContentHash::of(&canonical)
+    }
+}
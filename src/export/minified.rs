@@ -0,0 +1,86 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use serde_json::Value;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// Write a JSON value with the entries of objects sorted by their names and without whitespace, so the output does not depend on how the value was written or on the iteration order of maps.
+pub(crate) fn write_canonical_json(value: &Value, output: &mut String) {
+    match value {
+        Value::Array(array) => {
+            output.push('[');
+
+            for (i, value) in array.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+
+                write_canonical_json(value, output);
+            }
+
+            output.push(']');
+        }
+        Value::Object(object) => {
+            let mut entries: Vec<(&String, &Value)> = object.iter().collect();
+            entries.sort_unstable_by_key(|(name, _)| *name);
+
+            output.push('{');
+
+            for (i, (name, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+
+                output.push_str(&serde_json::to_string(name).unwrap());
+                output.push(':');
+
+                write_canonical_json(value, output);
+            }
+
+            output.push('}');
+        }
+        _ => output.push_str(&serde_json::to_string(value).unwrap()),
+    }
+}
+
+/// Write the texts of a map, without metadata, as a canonical JSON object.
+pub(crate) fn write_minified(map: &HashMap<String, JSONGetTextValue>, output: &mut String) {
+    let sorted: BTreeMap<&str, Value> = map
+        .iter()
+        .filter(|(text, _)| !is_metadata_text(text))
+        .map(|(text, value)| (text.as_str(), serde_json::to_value(value).unwrap()))
+        .collect();
+
+    output.push('{');
+
+    for (i, (text, value)) in sorted.into_iter().enumerate() {
+        if i > 0 {
+            output.push(',');
+        }
+
+        output.push_str(&serde_json::to_string(text).unwrap());
+        output.push(':');
+
+        write_canonical_json(&value, output);
+    }
+
+    output.push('}');
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Export the text of a key as a minified JSON object. Texts and the entries of object values are sorted, and metadata (texts starting with `@`) is stripped, so the output only changes when the text changes. It is suitable for serving to clients and for content hashing.
+    pub fn export_minified<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map(key)?;
+
+        let mut output = String::new();
+
+        write_minified(map, &mut output);
+
+        Some(output)
+    }
+}
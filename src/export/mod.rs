@@ -0,0 +1,28 @@
+mod chunks;
+#[cfg(feature = "content_hash")]
+mod hash;
+mod minified;
+#[cfg(feature = "content_hash")]
+mod search;
+#[cfg(feature = "content_hash")]
+mod source_hash;
+
+pub use chunks::*;
+#[cfg(feature = "content_hash")]
+pub use hash::*;
+#[cfg(feature = "content_hash")]
+pub use search::SearchDocument;
+#[cfg(feature = "content_hash")]
+pub(crate) use source_hash::collect_stale_texts;
+#[cfg(feature = "content_hash")]
+pub use source_hash::source_hash;
+
+/// Without the `content_hash` feature, source hashes cannot be checked, so no text is stale.
+#[cfg(not(feature = "content_hash"))]
+#[inline]
+pub(crate) fn collect_stale_texts(
+    _default_map: &std::collections::HashMap<String, crate::JSONGetTextValue>,
+    _map: &std::collections::HashMap<String, crate::JSONGetTextValue>,
+) -> std::collections::HashSet<String> {
+    std::collections::HashSet::new()
+}
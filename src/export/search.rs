@@ -0,0 +1,111 @@
+use std::borrow::Borrow;
+use std::fmt::Write;
+use std::hash::Hash;
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// A document of a search index over the texts of a key, e.g. for the in-app help search, created by `JSONGetText::export_search_documents`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SearchDocument {
+    /// The first 16 hexadecimal digits of the SHA-256 digest of the text id, which only has characters that search engines (e.g. Meilisearch) allow in primary keys. Set it as the primary key explicitly, since `id` ends with `id` too.
+    pub document_id: String,
+    /// The text id, which is a dot path for the texts in nested objects.
+    pub id: String,
+    pub text: String,
+    /// The namespace of the text, if it was added to one.
+    pub namespace: Option<String>,
+}
+
+impl SearchDocument {
+    fn new(id: String, text: String, namespace: Option<String>) -> SearchDocument {
+        let digest = Sha256::digest(id.as_bytes());
+
+        let mut document_id = String::with_capacity(16);
+
+        for b in &digest[..8] {
+            write!(document_id, "{:02x}", b).unwrap();
+        }
+
+        SearchDocument {
+            document_id,
+            id,
+            text,
+            namespace,
+        }
+    }
+}
+
+/// Collect the strings of a value with their dot paths, flattening nested objects and skipping other values.
+fn collect_strings(id: String, value: &Value, output: &mut Vec<(String, String)>) {
+    match value {
+        Value::String(s) => output.push((id, s.clone())),
+        Value::Object(object) => {
+            for (name, value) in object {
+                if !is_metadata_text(name) {
+                    collect_strings(format!("{}.{}", id, name), value, output);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Export the texts of a key as documents of a search index, sorted by their ids, so that one index per key can be built over the catalogs, e.g. with Tantivy or Meilisearch. Texts in nested objects get their dot paths as ids, other values than strings and metadata are skipped, and texts filled from the default key are included, since they are what the key shows.
+    pub fn export_search_documents<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+    ) -> Option<Vec<SearchDocument>>
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map(key)?;
+
+        let mut strings = Vec::with_capacity(map.len());
+
+        for (text, value) in map.iter() {
+            if is_metadata_text(text) {
+                continue;
+            }
+
+            match value {
+                JSONGetTextValue::Str(s) => strings.push((text.clone(), s.to_string())),
+                JSONGetTextValue::JSONValue(v) => collect_strings(text.clone(), v, &mut strings),
+                JSONGetTextValue::JSONValueRef(v) => collect_strings(text.clone(), v, &mut strings),
+            }
+        }
+
+        strings.sort_unstable();
+
+        Some(
+            strings
+                .into_iter()
+                .map(|(id, text)| {
+                    let namespace = self.namespace_of(&id).map(String::from);
+
+                    SearchDocument::new(id, text, namespace)
+                })
+                .collect(),
+        )
+    }
+
+    /// Export the texts of a key as documents of a search index like `export_search_documents`, in the JSON Lines format (one JSON object per line), which Tantivy and Meilisearch can ingest directly.
+    pub fn export_search_index<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let documents = self.export_search_documents(key)?;
+
+        let mut output = String::new();
+
+        for document in documents {
+            output.push_str(&serde_json::to_string(&document).unwrap());
+            output.push('\n');
+        }
+
+        Some(output)
+    }
+}
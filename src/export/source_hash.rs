@@ -0,0 +1,141 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::hash::Hash;
+
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use super::minified::write_canonical_json;
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// The name of the metadata field which stores the source hash of a translation.
+const SOURCE_HASH_FIELD: &str = "source_hash";
+
+/// Get the source hash of a text, which is the first 16 hexadecimal digits of the SHA-256 digest of its canonical JSON form.
+pub fn source_hash(value: &JSONGetTextValue) -> String {
+    let mut canonical = String::new();
+
+    write_canonical_json(&serde_json::to_value(value).unwrap(), &mut canonical);
+
+    let digest = Sha256::digest(canonical.as_bytes());
+
+    let mut output = String::with_capacity(16);
+
+    for b in &digest[..8] {
+        write!(output, "{:02x}", b).unwrap();
+    }
+
+    output
+}
+
+#[inline]
+fn metadata_object<'v>(value: &'v JSONGetTextValue) -> Option<&'v Map<String, Value>> {
+    match value {
+        JSONGetTextValue::JSONValue(v) => v.as_object(),
+        JSONGetTextValue::JSONValueRef(v) => v.as_object(),
+        JSONGetTextValue::Str(_) => None,
+    }
+}
+
+/// Collect the texts of a map whose source hashes (`"@text": {"source_hash": "..."}`) are different from the current texts of the default key, i.e. the source text has been changed since the text was translated.
+pub(crate) fn collect_stale_texts(
+    default_map: &HashMap<String, JSONGetTextValue>,
+    map: &HashMap<String, JSONGetTextValue>,
+) -> HashSet<String> {
+    let mut stale = HashSet::new();
+
+    for (metadata_text, value) in map.iter() {
+        if !is_metadata_text(metadata_text) {
+            continue;
+        }
+
+        let hash = match metadata_object(value).and_then(|m| m.get(SOURCE_HASH_FIELD)) {
+            Some(Value::String(hash)) => hash,
+            _ => continue,
+        };
+
+        let text = &metadata_text[1..];
+
+        if !map.contains_key(text) {
+            continue;
+        }
+
+        if let Some(default_value) = default_map.get(text) {
+            if source_hash(default_value) != *hash {
+                stale.insert(text.to_string());
+            }
+        }
+    }
+
+    stale
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Export the texts of a key for translators as a JSON object. Only texts translated for the key are included (not the ones filled from the default key), and the metadata of each text gets the source hash of the text of the default key, e.g. `"@hello": {"source_hash": "..."}`. When the exported texts are added back, the texts whose source text has been changed since then are marked as stale (see `is_stale_text`).
+    pub fn export_for_translation<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry(key)?;
+        let default_map = self.default_map();
+
+        let mut output: BTreeMap<String, Value> = BTreeMap::new();
+
+        for (text, value) in map.iter() {
+            if is_metadata_text(text) || self.is_filled_text(key, text) {
+                continue;
+            }
+
+            let default_value = match default_map.get(text) {
+                Some(default_value) => default_value,
+                None => continue,
+            };
+
+            let metadata_text = format!("@{}", text);
+
+            let mut metadata = match map.get(&metadata_text) {
+                Some(_) if self.is_filled_text(key, &metadata_text) => Map::new(),
+                Some(JSONGetTextValue::Str(description)) => {
+                    let mut metadata = Map::new();
+
+                    metadata.insert(String::from("description"), Value::from(*description));
+
+                    metadata
+                }
+                Some(metadata) => metadata_object(metadata).cloned().unwrap_or_default(),
+                None => Map::new(),
+            };
+
+            metadata
+                .insert(String::from(SOURCE_HASH_FIELD), Value::String(source_hash(default_value)));
+
+            output.insert(text.clone(), serde_json::to_value(value).unwrap());
+            output.insert(metadata_text, Value::Object(metadata));
+        }
+
+        Some(serde_json::to_string_pretty(&output).unwrap())
+    }
+
+    /// Returns `true` if a text of a key was translated from a source text which has been changed since then, according to the source hash in its metadata (see `export_for_translation`).
+    #[inline]
+    pub fn is_stale_text<Q: ?Sized + Hash + Eq, T: AsRef<str>>(&self, key: &Q, text: T) -> bool
+    where
+        Key: Borrow<Q>, {
+        self.stale.get(key).is_some_and(|texts| texts.contains(text.as_ref()))
+    }
+
+    /// Get the stale texts of a key (see `is_stale_text`), sorted.
+    pub fn stale_texts<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Vec<&str>
+    where
+        Key: Borrow<Q>, {
+        let mut texts: Vec<&str> = match self.stale.get(key) {
+            Some(texts) => texts.iter().map(|text| text.as_str()).collect(),
+            None => Vec::new(),
+        };
+
+        texts.sort_unstable();
+
+        texts
+    }
+}
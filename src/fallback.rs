@@ -0,0 +1,76 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{Context, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// Collect the texts which each key with a fallback chain can take from its fallback keys, in the order of the chain. The chain ends at the default key, since texts are filled from the default key anyway.
+pub(crate) fn collect_fallback_texts<'a, Q: ?Sized + Hash + Eq>(
+    context: &Context<'a>,
+    default_key: &Q,
+    fallbacks: &HashMap<Key, Vec<Key>>,
+) -> Result<HashMap<Key, Vec<(String, JSONGetTextValue<'a>)>>, JSONGetTextBuildError>
+where
+    Key: Borrow<Q>, {
+    let mut fallback_texts = HashMap::new();
+
+    for (key, chain) in fallbacks {
+        let map = match context.get::<Key>(key) {
+            Some(map) => map,
+            None => {
+                return Err(JSONGetTextBuildError::FallbackKeyNotFound {
+                    key: key.to_owned(),
+                    fallback: key.to_owned(),
+                })
+            }
+        };
+
+        if Borrow::<Q>::borrow(key) == default_key {
+            continue;
+        }
+
+        let mut texts: Vec<(String, JSONGetTextValue<'a>)> = Vec::new();
+
+        for fallback in chain {
+            if Borrow::<Q>::borrow(fallback) == default_key {
+                break;
+            }
+
+            let fallback_map = match context.get::<Key>(fallback) {
+                Some(fallback_map) => fallback_map,
+                None => {
+                    return Err(JSONGetTextBuildError::FallbackKeyNotFound {
+                        key: key.to_owned(),
+                        fallback: fallback.to_owned(),
+                    })
+                }
+            };
+
+            for (text, value) in fallback_map {
+                if !map.contains_key(text) && texts.iter().all(|(t, _)| t != text) {
+                    texts.push((text.clone(), value.clone()));
+                }
+            }
+        }
+
+        fallback_texts.insert(key.to_owned(), texts);
+    }
+
+    Ok(fallback_texts)
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set the fallback chain of a key, e.g. `zh` for `zh_TW`. When building, the texts missing in the key are filled from its fallback keys in order, and then from the default key. Both the key and its fallback keys must be added.
+    pub fn set_fallbacks<K: Into<Key>, F: Into<Key>, I: IntoIterator<Item = F>>(
+        &mut self,
+        key: K,
+        fallbacks: I,
+    ) -> &mut Self {
+        self.options
+            .pipeline
+            .fallbacks
+            .insert(key.into(), fallbacks.into_iter().map(Into::into).collect());
+
+        self
+    }
+}
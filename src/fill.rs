@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetTextBuilder, JSONGetTextValue};
+
+/// Returns `true` if a value of a key has every member of the nested objects of the value of the default key.
+fn has_members_of(value: &Value, default_value: &Value) -> bool {
+    match (value, default_value) {
+        (Value::Object(object), Value::Object(default_object)) => {
+            default_object.iter().all(|(name, default_value)| {
+                is_metadata_text(name)
+                    || object.get(name).is_some_and(|value| has_members_of(value, default_value))
+            })
+        }
+        _ => true,
+    }
+}
+
+/// Copy the members of the nested objects of the value of the default key which a value of a key lacks.
+fn fill_members(value: &mut Value, default_value: &Value) {
+    if let (Value::Object(object), Value::Object(default_object)) = (value, default_value) {
+        for (name, default_value) in default_object {
+            if is_metadata_text(name) {
+                continue;
+            }
+
+            match object.get_mut(name) {
+                Some(value) => fill_members(value, default_value),
+                None => {
+                    object.insert(name.clone(), default_value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Fill the members which the nested objects of the texts of a key lack from the texts of the default key. Texts which were filled from another key are skipped, since they are complete already.
+pub(crate) fn fill_nested_texts(
+    map: &mut HashMap<String, JSONGetTextValue>,
+    default_map: &HashMap<String, JSONGetTextValue>,
+    filled_texts: &HashSet<String>,
+) {
+    for (text, value) in map.iter_mut() {
+        if is_metadata_text(text) || filled_texts.contains(text) {
+            continue;
+        }
+
+        let default_value = match default_map.get(text) {
+            Some(JSONGetTextValue::JSONValue(v)) => v,
+            Some(JSONGetTextValue::JSONValueRef(v)) => *v,
+            _ => continue,
+        };
+
+        let mut json_value = match value {
+            JSONGetTextValue::Str(_) => continue,
+            JSONGetTextValue::JSONValue(v) => {
+                fill_members(v, default_value);
+
+                continue;
+            }
+            JSONGetTextValue::JSONValueRef(v) => {
+                if has_members_of(v, default_value) {
+                    continue;
+                }
+
+                (*v).clone()
+            }
+        };
+
+        fill_members(&mut json_value, default_value);
+
+        *value = JSONGetTextValue::JSONValue(json_value);
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Texts missing in a key are always filled from the default key when building, so looking them up returns `Some`. Enable this to fill the members missing in the nested objects of the texts of a key as well (e.g. `menu.file.open` when the key only has `menu.file.close`), trading memory for lookups of nested texts which never need to fall back to the default key at runtime. It is disabled by default.
+    #[inline]
+    pub fn fill_missing_from_default(&mut self, enable: bool) -> &mut Self {
+        self.options.pipeline.fill_missing_from_default = enable;
+
+        self
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::io::{self, ErrorKind};
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use fluent_syntax::ast::{
+    Entry, Expression, InlineExpression, Pattern, PatternElement, Resource, VariantKey,
+};
+use fluent_syntax::parser;
+use fluent_syntax::unicode::unescape_unicode_to_string;
+use serde_json::{Map, Value};
+
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// The maximum depth of inlined message and term references, to stop reference cycles.
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Escape braces so that interpolation writes them as they are.
+#[inline]
+fn escape_braces(s: &str) -> String {
+    s.replace('{', "{{").replace('}', "}}")
+}
+
+/// Messages and terms of a resource, for inlining references.
+struct References<'r, 's> {
+    messages: HashMap<&'s str, &'r fluent_syntax::ast::Message<&'s str>>,
+    terms: HashMap<&'s str, &'r fluent_syntax::ast::Term<&'s str>>,
+}
+
+impl<'r, 's> References<'r, 's> {
+    fn new(resource: &'r Resource<&'s str>) -> Self {
+        let mut messages = HashMap::new();
+        let mut terms = HashMap::new();
+
+        for entry in resource.body.iter() {
+            match entry {
+                Entry::Message(message) => {
+                    messages.insert(message.id.name, message);
+                }
+                Entry::Term(term) => {
+                    terms.insert(term.id.name, term);
+                }
+                _ => (),
+            }
+        }
+
+        References {
+            messages,
+            terms,
+        }
+    }
+
+    /// Get the pattern of a referenced message or term, or of one of its attributes.
+    fn pattern_of(
+        &self,
+        term: bool,
+        id: &str,
+        attribute: Option<&str>,
+    ) -> Option<&'r Pattern<&'s str>> {
+        let (value, attributes) = if term {
+            let term = self.terms.get(id)?;
+
+            (Some(&term.value), &term.attributes)
+        } else {
+            let message = self.messages.get(id)?;
+
+            (message.value.as_ref(), &message.attributes)
+        };
+
+        match attribute {
+            Some(attribute) => attributes.iter().find(|a| a.id.name == attribute).map(|a| &a.value),
+            None => value,
+        }
+    }
+}
+
+/// Get the name of the variable which a selector is, e.g. `$count` or `NUMBER($count)`.
+fn variable_of<'s>(expression: &InlineExpression<&'s str>) -> Option<&'s str> {
+    match expression {
+        InlineExpression::VariableReference {
+            id,
+        } => Some(id.name),
+        InlineExpression::FunctionReference {
+            id,
+            arguments,
+        } if id.name == "NUMBER" && arguments.named.is_empty() => {
+            match arguments.positional.as_slice() {
+                [argument] => variable_of(argument),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Convert an inline expression into a template, or `None` if it is not supported.
+fn convert_inline(
+    references: &References,
+    expression: &InlineExpression<&str>,
+    depth: usize,
+) -> Option<String> {
+    match expression {
+        InlineExpression::StringLiteral {
+            value,
+        } => Some(escape_braces(&unescape_unicode_to_string(value))),
+        InlineExpression::NumberLiteral {
+            value,
+        } => Some(value.to_string()),
+        InlineExpression::MessageReference {
+            id,
+            attribute,
+        } => {
+            let pattern =
+                references.pattern_of(false, id.name, attribute.as_ref().map(|a| a.name))?;
+
+            convert_simple_pattern(references, pattern, depth + 1)
+        }
+        InlineExpression::TermReference {
+            id,
+            attribute,
+            arguments: None,
+        } => {
+            let pattern =
+                references.pattern_of(true, id.name, attribute.as_ref().map(|a| a.name))?;
+
+            convert_simple_pattern(references, pattern, depth + 1)
+        }
+        InlineExpression::Placeable {
+            expression,
+        } => {
+            match expression.as_ref() {
+                Expression::Inline(expression) => convert_inline(references, expression, depth),
+                Expression::Select {
+                    ..
+                } => None,
+            }
+        }
+        _ => variable_of(expression).map(|name| format!("{{{}}}", name)),
+    }
+}
+
+/// Convert a pattern without select expressions into a template.
+fn convert_simple_pattern(
+    references: &References,
+    pattern: &Pattern<&str>,
+    depth: usize,
+) -> Option<String> {
+    if depth > MAX_REFERENCE_DEPTH {
+        return None;
+    }
+
+    let mut output = String::new();
+
+    for element in pattern.elements.iter() {
+        match element {
+            PatternElement::TextElement {
+                value,
+            } => output.push_str(&escape_braces(value)),
+            PatternElement::Placeable {
+                expression: Expression::Inline(expression),
+            } => output.push_str(&convert_inline(references, expression, depth)?),
+            PatternElement::Placeable {
+                expression: Expression::Select {
+                    ..
+                },
+            } => return None,
+        }
+    }
+
+    Some(output)
+}
+
+/// Convert a pattern into a value. A pattern with a select expression on plural categories becomes a plural text, whose forms include the text around the select expression.
+fn convert_pattern(references: &References, pattern: &Pattern<&str>) -> Option<Value> {
+    let select_index = pattern.elements.iter().position(|element| {
+        matches!(element, PatternElement::Placeable {
+            expression: Expression::Select { .. }
+        })
+    });
+
+    let select_index = match select_index {
+        Some(select_index) => select_index,
+        None => return convert_simple_pattern(references, pattern, 0).map(Value::String),
+    };
+
+    let convert_elements = |elements: &[PatternElement<&str>]| {
+        convert_simple_pattern(
+            references,
+            &Pattern {
+                elements: elements.to_vec(),
+            },
+            0,
+        )
+    };
+
+    let prefix = convert_elements(&pattern.elements[..select_index])?;
+    let suffix = convert_elements(&pattern.elements[select_index + 1..])?;
+
+    let (selector, variants) = match &pattern.elements[select_index] {
+        PatternElement::Placeable {
+            expression:
+                Expression::Select {
+                    selector,
+                    variants,
+                },
+        } => (selector, variants),
+        _ => unreachable!(),
+    };
+
+    variable_of(selector)?;
+
+    let mut forms = Map::new();
+    let mut default_form = None;
+
+    for variant in variants {
+        let category = match variant.key {
+            VariantKey::Identifier {
+                name,
+            } if PLURAL_CATEGORIES.contains(&name) => name,
+            _ => return None,
+        };
+
+        let form = format!(
+            "{}{}{}",
+            prefix,
+            convert_simple_pattern(references, &variant.value, 0)?,
+            suffix
+        );
+
+        if variant.default {
+            default_form = Some(form.clone());
+        }
+
+        forms.insert(category.to_string(), Value::String(form));
+    }
+
+    // the default variant is used for the categories which are not listed
+    if let Some(default_form) = default_form {
+        forms.entry("other").or_insert(Value::String(default_form));
+    }
+
+    Some(Value::Object(forms))
+}
+
+/// Get the line number of a byte offset.
+#[inline]
+fn line_of(ftl: &str, offset: usize) -> usize {
+    ftl.as_bytes()[..offset.min(ftl.len())].iter().filter(|b| **b == b'\n').count() + 1
+}
+
+/// Convert a Fluent resource into a string map. Messages which cannot be converted are skipped. Syntax errors are reported at the line where the invalid entry starts.
+fn ftl_to_map(
+    ftl: &str,
+) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextBuildError> {
+    let resource = parser::parse(ftl).map_err(|(_, errors)| {
+        let error = &errors[0];
+
+        JSONGetTextBuildError::InvalidFluent {
+            line: line_of(ftl, error.slice.as_ref().unwrap_or(&error.pos).start),
+            reason: error.kind.to_string(),
+        }
+    })?;
+
+    let references = References::new(&resource);
+
+    let mut map = HashMap::new();
+
+    for entry in resource.body.iter() {
+        let message = match entry {
+            Entry::Message(message) => message,
+            _ => continue,
+        };
+
+        if let Some(value) =
+            message.value.as_ref().and_then(|pattern| convert_pattern(&references, pattern))
+        {
+            map.insert(message.id.name.to_string(), JSONGetTextValue::from_json_value(value));
+        }
+
+        for attribute in message.attributes.iter() {
+            if let Some(value) = convert_pattern(&references, &attribute.value) {
+                map.insert(
+                    format!("{}.{}", message.id.name, attribute.id.name),
+                    JSONGetTextValue::from_json_value(value),
+                );
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add a Fluent resource to the context for a specify key. Messages become texts, and their attributes become texts like `message.attribute`. Variables (`{ $name }`) become placeholders (`{name}`), references to messages and terms are inlined, and a select expression on plural categories becomes a plural text (see `PluralCategory`). Messages with other syntax, e.g. functions, are skipped, so they are filled from the default key.
+    pub fn add_fluent<K: Into<Key>, S: AsRef<str>>(
+        &mut self,
+        key: K,
+        ftl: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let map = ftl_to_map(ftl.as_ref())?;
+
+        self.add_map(key.into(), map)
+    }
+
+    /// Add a Fluent file to the context for a specify key. See `add_fluent`.
+    #[cfg(feature = "fs")]
+    pub fn add_fluent_file<K: Into<Key>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let ftl = self.options.ingest_limits.read_file(&key, path.as_ref())?;
+
+        let ftl =
+            String::from_utf8(ftl).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        self.add_fluent(key, ftl)
+    }
+}
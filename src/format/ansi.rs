@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::find_byte;
+
+/// A piece of a text which may contain ANSI escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AnsiSegment<'t> {
+    Text(&'t str),
+    Escape(&'t str),
+}
+
+/// Splits a text into visible text and ANSI escape sequences: CSI sequences (e.g. `\x1b[31m`), OSC sequences terminated by BEL or ST (e.g. hyperlinks), and other two-byte sequences.
+#[derive(Debug, Clone)]
+struct AnsiSegments<'t> {
+    rest: &'t str,
+}
+
+/// Get the length of the escape sequence at the start of `s`, which starts with ESC.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    match bytes.get(1) {
+        Some(b'[') => {
+            // parameter and intermediate bytes, then a final byte
+            match bytes[2..].iter().position(|b| (0x40..=0x7E).contains(b)) {
+                Some(i) => i + 3,
+                None => bytes.len(),
+            }
+        }
+        Some(b']') => {
+            let mut i = 2;
+
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1B if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+
+            bytes.len()
+        }
+        Some(b) if b.is_ascii() => 2,
+        _ => 1,
+    }
+}
+
+impl<'t> Iterator for AnsiSegments<'t> {
+    type Item = AnsiSegment<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest;
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        match find_byte(0x1B, rest.as_bytes()) {
+            Some(0) => {
+                let len = escape_len(rest);
+
+                self.rest = &rest[len..];
+
+                Some(AnsiSegment::Escape(&rest[..len]))
+            }
+            Some(i) => {
+                self.rest = &rest[i..];
+
+                Some(AnsiSegment::Text(&rest[..i]))
+            }
+            None => {
+                self.rest = "";
+
+                Some(AnsiSegment::Text(rest))
+            }
+        }
+    }
+}
+
+#[inline]
+fn ansi_segments(text: &str) -> AnsiSegments<'_> {
+    AnsiSegments {
+        rest: text,
+    }
+}
+
+/// Remove ANSI escape sequences from a text.
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+
+    Cow::Owned(
+        ansi_segments(text)
+            .filter_map(|segment| {
+                match segment {
+                    AnsiSegment::Text(s) => Some(s),
+                    AnsiSegment::Escape(_) => None,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Get the width of a text in terminal columns, ignoring ANSI escape sequences. Wide characters such as CJK ideographs count as two columns and combining marks as none.
+pub fn ansi_width(text: &str) -> usize {
+    ansi_segments(text)
+        .map(|segment| {
+            match segment {
+                AnsiSegment::Text(s) => s.width(),
+                AnsiSegment::Escape(_) => 0,
+            }
+        })
+        .sum()
+}
+
+/// Truncate a text to a width in terminal columns, ending it with `ellipsis` if it is truncated. ANSI escape sequences are never split, and the ones after the cut are kept, so colors are still reset at the end.
+pub fn truncate_ansi<'t>(text: &'t str, width: usize, ellipsis: &str) -> Cow<'t, str> {
+    if ansi_width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let ellipsis_width = ellipsis.width();
+    let available = width.saturating_sub(ellipsis_width);
+
+    let mut output = String::with_capacity(text.len());
+    let mut used = 0;
+    let mut truncated = false;
+
+    for segment in ansi_segments(text) {
+        match segment {
+            AnsiSegment::Escape(s) => output.push_str(s),
+            AnsiSegment::Text(s) => {
+                for c in s.chars() {
+                    if truncated {
+                        break;
+                    }
+
+                    let c_width = c.width().unwrap_or(0);
+
+                    if used + c_width > available {
+                        if ellipsis_width <= width {
+                            output.push_str(ellipsis);
+                        }
+
+                        truncated = true;
+
+                        break;
+                    }
+
+                    used += c_width;
+                    output.push(c);
+                }
+            }
+        }
+    }
+
+    Cow::Owned(output)
+}
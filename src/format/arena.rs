@@ -0,0 +1,62 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use bumpalo::collections::String as BumpString;
+use bumpalo::Bump;
+
+use super::{interpolate_to, write_arg};
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// Like `interpolate`, but the result is allocated in a bump arena, so formatting many texts per request does not put pressure on the global allocator. The arena can be reset after the request.
+pub fn interpolate_in<'bump>(
+    bump: &'bump Bump,
+    template: &str,
+    args: &HashMap<&str, JSONGetTextValue>,
+) -> &'bump str {
+    let mut output = BumpString::with_capacity_in(template.len(), bump);
+
+    interpolate_to(template, &mut output, |placeholder, output| {
+        write_arg(args, placeholder.name, None, output)
+    });
+
+    output.into_bump_str()
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get text from context with a specific key and interpolate it into a bump arena by `interpolate_in`, with numeric arguments written with the number symbols of the key except in `{name:raw}` placeholders, and the defaults of placeholders resolved with the texts of the key. Non-string values are interpolated in their display form. If the key is not in context, the default key is used.
+    pub fn get_text_fmt_in<'bump, Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        bump: &'bump Bump,
+        key: &Q,
+        text: T,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> Option<&'bump str>
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map_or_default(key);
+
+        let value = self.lookup_text(map, text.as_ref())?;
+
+        Some(match value.as_str() {
+            Some(template) => {
+                let symbols = self.number_symbols(key);
+
+                let mut output = BumpString::with_capacity_in(template.len(), bump);
+
+                interpolate_to(template, &mut output, |placeholder, output| {
+                    self.write_placeholder(map, args, &symbols, placeholder, output)
+                });
+
+                output.into_bump_str()
+            }
+            None => {
+                let mut output = BumpString::new_in(bump);
+
+                std::fmt::Write::write_fmt(&mut output, format_args!("{}", value)).unwrap();
+
+                output.into_bump_str()
+            }
+        })
+    }
+}
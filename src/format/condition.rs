@@ -0,0 +1,416 @@
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use super::interpolate::{is_placeholder_char, Segment, Segments};
+use crate::metadata::is_metadata_text;
+use crate::{Context, JSONGetTextBuildError, JSONGetTextValue};
+
+/// A comparison operator of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Operator {
+    #[inline]
+    fn matches(self, ordering: Option<Ordering>) -> bool {
+        match self {
+            Operator::Eq => ordering == Some(Ordering::Equal),
+            Operator::Ne => ordering != Some(Ordering::Equal),
+            Operator::Lt => ordering == Some(Ordering::Less),
+            Operator::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            Operator::Gt => ordering == Some(Ordering::Greater),
+            Operator::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        }
+    }
+}
+
+/// The literal which an argument is compared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Literal<'t> {
+    Bool(bool),
+    /// A number, which has been checked to be parsable as `f64`.
+    Number(&'t str),
+    /// A quoted string, without the quotes and with its escapes kept.
+    Str(&'t str),
+}
+
+/// A `{name ? "then" : "else"}` or `{name op literal ? "then" : "else"}` conditional, e.g. `{isAdmin ? "Manage" : "View"}` or `{count > 1 ? "Delete {count} files" : "Delete the file"}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Conditional<'t> {
+    pub(crate) name: &'t str,
+    pub(crate) comparison: Option<(Operator, Literal<'t>)>,
+    /// The branches, without the quotes and with their escapes kept.
+    pub(crate) then: &'t str,
+    pub(crate) otherwise: &'t str,
+}
+
+/// A cursor over the inside of a conditional.
+struct Parser<'t> {
+    s: &'t str,
+    i: usize,
+}
+
+impl<'t> Parser<'t> {
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        self.i += self.s[self.i..].len() - self.s[self.i..].trim_start().len();
+    }
+
+    #[inline]
+    fn eat(&mut self, token: &str) -> bool {
+        if self.s[self.i..].starts_with(token) {
+            self.i += token.len();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn expect(&mut self, token: &str, reason: &'static str) -> Result<(), &'static str> {
+        self.skip_whitespace();
+
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(reason)
+        }
+    }
+
+    /// Take a run of characters which match a predicate.
+    #[inline]
+    fn take_while<P: Fn(char) -> bool>(&mut self, p: P) -> &'t str {
+        let rest = &self.s[self.i..];
+
+        let len = rest.find(|c| !p(c)).unwrap_or(rest.len());
+
+        self.i += len;
+
+        &rest[..len]
+    }
+
+    fn operator(&mut self) -> Option<Operator> {
+        self.skip_whitespace();
+
+        [
+            ("==", Operator::Eq),
+            ("!=", Operator::Ne),
+            ("<=", Operator::Le),
+            (">=", Operator::Ge),
+            ("<", Operator::Lt),
+            (">", Operator::Gt),
+        ]
+        .into_iter()
+        .find(|(token, _)| self.eat(token))
+        .map(|(_, operator)| operator)
+    }
+
+    /// Take a quoted string, in which `\"` and `\\` are escapes.
+    fn quoted(&mut self) -> Result<&'t str, &'static str> {
+        self.expect("\"", "expected a quoted string")?;
+
+        let start = self.i;
+
+        let mut escaped = false;
+
+        for (i, c) in self.s[start..].char_indices() {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => {
+                    self.i = start + i + 1;
+
+                    return Ok(&self.s[start..start + i]);
+                }
+                _ => escaped = false,
+            }
+        }
+
+        Err("unterminated quoted string")
+    }
+
+    fn literal(&mut self) -> Result<Literal<'t>, &'static str> {
+        self.skip_whitespace();
+
+        if self.s[self.i..].starts_with('"') {
+            return self.quoted().map(Literal::Str);
+        }
+
+        let word = self.take_while(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'));
+
+        match word {
+            "true" => Ok(Literal::Bool(true)),
+            "false" => Ok(Literal::Bool(false)),
+            _ if word.parse::<f64>().is_ok() => Ok(Literal::Number(word)),
+            _ => Err("expected a number, a boolean or a quoted string to compare with"),
+        }
+    }
+}
+
+impl<'t> Conditional<'t> {
+    /// Parse a conditional at the start of a text which starts with `{`. Return the conditional and its length including the braces, or the reason why it is invalid.
+    pub(crate) fn parse(s: &'t str) -> Result<(Conditional<'t>, usize), &'static str> {
+        let mut parser = Parser {
+            s,
+            i: 0,
+        };
+
+        parser.expect("{", "expected `{`")?;
+        parser.skip_whitespace();
+
+        let name = parser.take_while(is_placeholder_char);
+
+        if name.is_empty() {
+            return Err("expected the name of an argument");
+        }
+
+        let comparison = match parser.operator() {
+            Some(operator) => {
+                let literal = parser.literal()?;
+
+                if !matches!(literal, Literal::Number(_))
+                    && !matches!(operator, Operator::Eq | Operator::Ne)
+                {
+                    return Err("only numbers can be compared by `<`, `<=`, `>` or `>=`");
+                }
+
+                Some((operator, literal))
+            }
+            None => None,
+        };
+
+        parser.expect("?", "expected `?`")?;
+        parser.skip_whitespace();
+
+        let then = parser.quoted()?;
+
+        parser.expect(":", "expected `:`")?;
+        parser.skip_whitespace();
+
+        let otherwise = parser.quoted()?;
+
+        parser.expect("}", "expected `}`")?;
+
+        Ok((
+            Conditional {
+                name,
+                comparison,
+                then,
+                otherwise,
+            },
+            parser.i,
+        ))
+    }
+
+    /// Evaluate the condition with the display form of the argument.
+    pub(crate) fn test(&self, value: &str) -> bool {
+        match self.comparison {
+            None => {
+                !(value.is_empty()
+                    || value == "false"
+                    || value == "null"
+                    || value.parse::<f64>() == Ok(0.0))
+            }
+            Some((operator, Literal::Bool(b))) => {
+                operator.matches(value.parse::<bool>().ok().map(|value| value.cmp(&b)))
+            }
+            Some((operator, Literal::Number(n))) => {
+                let n: f64 = n.parse().unwrap();
+
+                operator.matches(value.parse::<f64>().ok().and_then(|value| value.partial_cmp(&n)))
+            }
+            Some((operator, Literal::Str(s))) => {
+                operator.matches(Some(value.cmp(unescape(s).as_str())))
+            }
+        }
+    }
+
+    /// Get the chosen branch, unescaped.
+    #[inline]
+    pub(crate) fn branch(&self, value: &str) -> String {
+        unescape(if self.test(value) {
+            self.then
+        } else {
+            self.otherwise
+        })
+    }
+}
+
+/// Unescape `\"` and `\\` in a quoted string.
+fn unescape(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    output.push(c);
+                }
+            }
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// Escape `"` and `\` for a quoted string.
+#[inline]
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Convert the text of a template outside conditionals and the branches of conditionals, keeping the syntax of conditionals as it is.
+pub(crate) fn map_outside_conditionals(
+    template: &str,
+    f: &mut dyn FnMut(&str) -> String,
+) -> String {
+    let mut output = String::with_capacity(template.len());
+
+    let mut segments = Segments::new(template);
+
+    let mut start = 0;
+
+    loop {
+        let offset = template.len() - segments.rest().len();
+
+        match segments.next() {
+            None => break,
+            Some(Segment::Conditional {
+                conditional,
+                raw,
+            }) => {
+                output.push_str(&f(&template[start..offset]));
+
+                let then_start = conditional.then.as_ptr() as usize - raw.as_ptr() as usize;
+                let then_end = then_start + conditional.then.len();
+                let otherwise_start =
+                    conditional.otherwise.as_ptr() as usize - raw.as_ptr() as usize;
+                let otherwise_end = otherwise_start + conditional.otherwise.len();
+
+                output.push_str(&raw[..then_start]);
+                output.push_str(&escape(&map_outside_conditionals(&unescape(conditional.then), f)));
+                output.push_str(&raw[then_end..otherwise_start]);
+                output.push_str(&escape(&map_outside_conditionals(
+                    &unescape(conditional.otherwise),
+                    f,
+                )));
+                output.push_str(&raw[otherwise_end..]);
+
+                start = offset + raw.len();
+            }
+            Some(_) => (),
+        }
+    }
+
+    output.push_str(&f(&template[start..]));
+
+    output
+}
+
+/// Returns `true` if a text starting with `{` is meant to be a conditional, i.e. an argument name, optionally followed by a comparison, is followed by `?` and a quoted string. Other texts in braces, e.g. `{i<n}` or `{a?b}`, are literals.
+fn looks_conditional(s: &str) -> bool {
+    let mut parser = Parser {
+        s,
+        i: 1,
+    };
+
+    parser.skip_whitespace();
+
+    if parser.take_while(is_placeholder_char).is_empty() {
+        return false;
+    }
+
+    if parser.operator().is_some() {
+        parser.skip_whitespace();
+
+        if parser.s[parser.i..].starts_with('"') {
+            if parser.quoted().is_err() {
+                return false;
+            }
+        } else {
+            parser.take_while(|c| !c.is_whitespace() && !matches!(c, '?' | '}'));
+        }
+    }
+
+    parser.skip_whitespace();
+
+    if !parser.eat("?") {
+        return false;
+    }
+
+    parser.skip_whitespace();
+
+    parser.eat("\"")
+}
+
+/// Check that the conditionals in a text are valid, including the ones in their branches.
+pub(crate) fn check_conditionals(template: &str) -> Result<(), &'static str> {
+    let mut segments = Segments::new(template);
+
+    loop {
+        let rest = segments.rest();
+
+        match segments.next() {
+            None => return Ok(()),
+            Some(Segment::Literal("{")) if looks_conditional(rest) => {
+                return Conditional::parse(rest).map(|_| ());
+            }
+            Some(Segment::Conditional {
+                conditional,
+                ..
+            }) => {
+                check_conditionals(&unescape(conditional.then))?;
+                check_conditionals(&unescape(conditional.otherwise))?;
+            }
+            _ => (),
+        }
+    }
+}
+
+fn check_value(value: &Value) -> Result<(), &'static str> {
+    match value {
+        Value::String(s) => check_conditionals(s),
+        Value::Array(array) => array.iter().try_for_each(check_value),
+        Value::Object(object) => object.values().try_for_each(check_value),
+        _ => Ok(()),
+    }
+}
+
+/// Check the conditionals in every text of the context.
+pub(crate) fn validate_context_conditionals(
+    context: &Context,
+) -> Result<(), JSONGetTextBuildError> {
+    for (key, map) in context.iter() {
+        for (text, value) in map.iter() {
+            if is_metadata_text(text) {
+                continue;
+            }
+
+            let result = match value {
+                JSONGetTextValue::Str(s) => check_conditionals(s),
+                JSONGetTextValue::JSONValue(v) => check_value(v),
+                JSONGetTextValue::JSONValueRef(v) => check_value(v),
+            };
+
+            if let Err(reason) = result {
+                return Err(JSONGetTextBuildError::InvalidConditional {
+                    key: key.to_owned(),
+                    text: text.clone(),
+                    reason: reason.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
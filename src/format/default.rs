@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::interpolate::{Segment, Segments};
+use crate::lookup::find_nested;
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetTextBuildError, JSONGetTextValue, Key};
+
+/// Get the default text ids of the placeholders in a text, including the ones in the branches of conditionals.
+fn collect_defaults<'t>(template: &'t str, defaults: &mut Vec<&'t str>) {
+    for segment in Segments::new(template) {
+        match segment {
+            Segment::Placeholder {
+                placeholder,
+                ..
+            } => {
+                if let Some(default) = placeholder.default {
+                    defaults.push(default);
+                }
+            }
+            Segment::Conditional {
+                conditional,
+                ..
+            } => {
+                collect_defaults(conditional.then, defaults);
+                collect_defaults(conditional.otherwise, defaults);
+            }
+            Segment::Literal(_) => (),
+        }
+    }
+}
+
+fn collect_value_defaults<'t>(value: &'t Value, defaults: &mut Vec<&'t str>) {
+    match value {
+        Value::String(s) => collect_defaults(s, defaults),
+        Value::Array(array) => array.iter().for_each(|v| collect_value_defaults(v, defaults)),
+        Value::Object(object) => object.values().for_each(|v| collect_value_defaults(v, defaults)),
+        _ => (),
+    }
+}
+
+/// Check that the default text ids of the placeholders in the texts of a string map are in the map.
+pub(crate) fn validate_placeholder_defaults(
+    key: &Key,
+    map: &HashMap<String, JSONGetTextValue>,
+) -> Result<(), JSONGetTextBuildError> {
+    for (text, value) in map.iter() {
+        if is_metadata_text(text) {
+            continue;
+        }
+
+        let mut defaults = Vec::new();
+
+        match value {
+            JSONGetTextValue::Str(s) => collect_defaults(s, &mut defaults),
+            JSONGetTextValue::JSONValue(v) => collect_value_defaults(v, &mut defaults),
+            JSONGetTextValue::JSONValueRef(v) => collect_value_defaults(v, &mut defaults),
+        }
+
+        if let Some(default) =
+            defaults.into_iter().find(|id| !map.contains_key(*id) && find_nested(map, id).is_none())
+        {
+            return Err(JSONGetTextBuildError::PlaceholderDefaultNotFound {
+                key: key.to_owned(),
+                text: text.clone(),
+                default: default.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,480 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hash::Hash;
+
+use serde_json::Value;
+
+#[cfg(feature = "ansi")]
+use super::ansi_width;
+use super::condition::Conditional;
+use super::{find_byte, find_byte2};
+use crate::{JSONGetText, JSONGetTextValue, Key, NumberSymbols};
+
+#[inline]
+pub(crate) fn is_placeholder_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+#[inline]
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(is_placeholder_char)
+}
+
+/// Replace `{name}` placeholders in a text with the values of `args`. `{{` and `}}` are written as `{` and `}`. Placeholders without a value are kept as they are.
+///
+/// * `{isAdmin ? "Manage" : "View"}` and `{count > 1 ? "{count} files" : "a file"}` are conditionals, taking the first branch if the argument is truthy or the comparison (`==`, `!=`, `<`, `<=`, `>` or `>=` with a number, `true`, `false` or a quoted string) holds. They are checked when building.
+/// * `{name:>10}`, `{name:<10}`, `{name:^10}` and `{name:*>10}` pad the value to a width like Rust's format specs, measured in terminal columns with the `ansi` feature (or else in characters).
+/// * `{name:raw}` writes a number as it is in `format_text`, which otherwise uses the number symbols of the key.
+/// * `{name|guest}` writes the text of the `guest` text id if the argument is missing, also after a spec, e.g. `{name:>10|guest}`. Defaults are resolved by `format_text` and checked when building, so `interpolate` keeps them as they are.
+pub fn interpolate(template: &str, args: &HashMap<&str, JSONGetTextValue>) -> String {
+    let mut output = String::with_capacity(template.len());
+
+    interpolate_to(template, &mut output, |placeholder, output| {
+        write_arg(args, placeholder.name, None, output)
+    });
+
+    output
+}
+
+/// Write the value of an argument, returning `false` if there is no such argument. Numbers are written with the number symbols if there are some.
+#[inline]
+pub(crate) fn write_arg<W: Write + ?Sized>(
+    args: &HashMap<&str, JSONGetTextValue>,
+    name: &str,
+    symbols: Option<&NumberSymbols>,
+    output: &mut W,
+) -> bool {
+    match args.get(name) {
+        Some(value) => {
+            match (symbols, number_of(value)) {
+                (Some(symbols), Some(number)) => symbols.write_number(&number, output),
+                _ => write!(output, "{}", value).unwrap(),
+            }
+
+            true
+        }
+        None => false,
+    }
+}
+
+/// Get the JSON form of a value if it is a number.
+#[inline]
+fn number_of(value: &JSONGetTextValue) -> Option<String> {
+    match value {
+        JSONGetTextValue::JSONValue(Value::Number(n)) => Some(n.to_string()),
+        JSONGetTextValue::JSONValueRef(Value::Number(n)) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Like `interpolate`, but the value of a placeholder is given by a closure.
+pub(crate) fn interpolate_with<F: FnMut(&str) -> Option<String>>(
+    template: &str,
+    mut f: F,
+) -> String {
+    let mut output = String::with_capacity(template.len());
+
+    interpolate_to(template, &mut output, |placeholder, output| {
+        match f(placeholder.name) {
+            Some(value) => {
+                output.write_str(&value).unwrap();
+
+                true
+            }
+            None => false,
+        }
+    });
+
+    output
+}
+
+/// Interpolate a text into a writer. The closure writes the value of a placeholder and returns `true`, or returns `false` to keep the placeholder as it is.
+#[inline]
+pub(crate) fn interpolate_to<W: Write, F: FnMut(&Placeholder, &mut dyn Write) -> bool>(
+    template: &str,
+    output: &mut W,
+    mut f: F,
+) {
+    write_segments(template, output, &mut f)
+}
+
+/// Interpolate a text into a writer, recursing into the branches of conditionals.
+fn write_segments(
+    template: &str,
+    output: &mut dyn Write,
+    f: &mut dyn FnMut(&Placeholder, &mut dyn Write) -> bool,
+) {
+    for segment in Segments::new(template) {
+        match segment {
+            Segment::Literal(s) => output.write_str(s).unwrap(),
+            Segment::Placeholder {
+                placeholder,
+                raw,
+                spec: None,
+            } => {
+                if !f(&placeholder, output) {
+                    output.write_str(raw).unwrap();
+                }
+            }
+            Segment::Placeholder {
+                placeholder,
+                raw,
+                spec: Some(spec),
+            } => {
+                let mut value = String::new();
+
+                if f(&placeholder, &mut value) {
+                    spec.pad(&value, output);
+                } else {
+                    output.write_str(raw).unwrap();
+                }
+            }
+            Segment::Conditional {
+                conditional,
+                raw,
+            } => {
+                let mut value = String::new();
+
+                let placeholder = Placeholder {
+                    name: conditional.name,
+                    raw_value: true,
+                    default: None,
+                };
+
+                if f(&placeholder, &mut value) {
+                    write_segments(&conditional.branch(&value), output, f);
+                } else {
+                    output.write_str(raw).unwrap();
+                }
+            }
+        }
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Write the value of a placeholder: its argument, with numbers written with the number symbols unless the raw value is asked for, or else the text of its default text id in the string map.
+    pub(crate) fn write_placeholder<W: Write + ?Sized>(
+        &self,
+        map: &HashMap<String, JSONGetTextValue<'a>>,
+        args: &HashMap<&str, JSONGetTextValue>,
+        symbols: &NumberSymbols,
+        placeholder: &Placeholder,
+        output: &mut W,
+    ) -> bool {
+        let symbols = Some(symbols).filter(|_| !placeholder.raw_value);
+
+        if write_arg(args, placeholder.name, symbols, output) {
+            return true;
+        }
+
+        match placeholder.default.and_then(|id| self.lookup_text(map, id)) {
+            Some(value) => {
+                write!(output, "{}", value).unwrap();
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Interpolate a value of a string map if it is a string, or get its display form.
+    pub(crate) fn format_value(
+        &self,
+        map: &HashMap<String, JSONGetTextValue<'a>>,
+        value: JSONGetTextValue,
+        args: &HashMap<&str, JSONGetTextValue>,
+        symbols: &NumberSymbols,
+    ) -> String {
+        match value.as_str() {
+            Some(template) => {
+                let mut output = String::with_capacity(template.len());
+
+                interpolate_to(template, &mut output, |placeholder, output| {
+                    self.write_placeholder(map, args, symbols, placeholder, output)
+                });
+
+                output
+            }
+            None => value.to_string(),
+        }
+    }
+
+    /// Get text from context and interpolate it by `interpolate`, with numeric arguments written with the number symbols of the default key (see `number_symbols`) except in `{name:raw}` placeholders, and the defaults of placeholders resolved with the texts of the default key. Non-string values are formatted in their display form.
+    #[inline]
+    pub fn format_text<T: AsRef<str>>(
+        &self,
+        text: T,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> Option<String> {
+        let map = self.default_map();
+
+        let value = self.lookup_text(map, text.as_ref())?;
+
+        Some(self.format_value(map, value, args, &self.number_symbols(&self.default_key)))
+    }
+
+    /// Get text from context with a specific key and interpolate it by `interpolate`, with numeric arguments written with the number symbols of the key (see `number_symbols`) except in `{name:raw}` placeholders, and the defaults of placeholders resolved with the texts of the key. Non-string values are formatted in their display form. If the key is not in context, the default key is used.
+    #[inline]
+    pub fn format_text_with_key<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        text: T,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map_or_default(key);
+
+        let value = self.lookup_text(map, text.as_ref())?;
+
+        Some(self.format_value(map, value, args, &self.number_symbols(key)))
+    }
+}
+
+/// Get the names of the placeholders in a text, in order of appearance, without duplicates. The arguments of conditionals and the placeholders in their branches are included.
+pub fn placeholders_of(template: &str) -> Vec<&str> {
+    let mut names: Vec<&str> = Vec::new();
+
+    collect_placeholders(template, &mut names);
+
+    names
+}
+
+fn collect_placeholders<'t>(template: &'t str, names: &mut Vec<&'t str>) {
+    for segment in Segments::new(template) {
+        match segment {
+            Segment::Literal(_) => (),
+            Segment::Placeholder {
+                placeholder,
+                ..
+            } => {
+                if !names.contains(&placeholder.name) {
+                    names.push(placeholder.name);
+                }
+            }
+            Segment::Conditional {
+                conditional,
+                ..
+            } => {
+                if !names.contains(&conditional.name) {
+                    names.push(conditional.name);
+                }
+
+                collect_placeholders(conditional.then, names);
+                collect_placeholders(conditional.otherwise, names);
+            }
+        }
+    }
+}
+
+/// A piece of a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'t> {
+    /// Literal text, with `{{` and `}}` already unescaped.
+    Literal(&'t str),
+    /// A `{name}`, `{name:spec}` or `{name:raw}` placeholder, optionally with a `|default`. `raw` includes the braces.
+    Placeholder {
+        placeholder: Placeholder<'t>,
+        raw: &'t str,
+        spec: Option<FormatSpec>,
+    },
+    /// A `{name ? "then" : "else"}` conditional. `raw` includes the braces.
+    Conditional {
+        conditional: Conditional<'t>,
+        raw: &'t str,
+    },
+}
+
+/// A placeholder to be interpolated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Placeholder<'t> {
+    pub(crate) name: &'t str,
+    /// `true` if the raw value of the argument is asked for, by `{name:raw}` or by a conditional.
+    pub(crate) raw_value: bool,
+    /// The text id whose text is written if the argument is missing, by `{name|default}`.
+    pub(crate) default: Option<&'t str>,
+}
+
+/// Measure a padded value, in terminal columns if the `ansi` feature is enabled, or else in characters.
+#[inline]
+fn width_of(value: &str) -> usize {
+    #[cfg(feature = "ansi")]
+    {
+        ansi_width(value)
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    {
+        value.chars().count()
+    }
+}
+
+/// The alignment of a padded value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+/// The padding of a placeholder, e.g. `*>10` in `{name:*>10}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FormatSpec {
+    fill: char,
+    align: Align,
+    width: usize,
+}
+
+impl FormatSpec {
+    /// Parse `[[fill]align]width`, where the width has at most 3 digits.
+    fn parse(spec: &str) -> Option<FormatSpec> {
+        let align_of = |c: char| {
+            match c {
+                '<' => Some(Align::Left),
+                '>' => Some(Align::Right),
+                '^' => Some(Align::Center),
+                _ => None,
+            }
+        };
+
+        let mut chars = spec.chars();
+
+        let (fill, align, width) = match (chars.next(), chars.next()) {
+            (Some(fill), Some(c)) if align_of(c).is_some() => (fill, align_of(c)?, chars.as_str()),
+            (Some(c), _) if align_of(c).is_some() => (' ', align_of(c)?, &spec[1..]),
+            _ => (' ', Align::Left, spec),
+        };
+
+        if width.is_empty() || width.len() > 3 || !width.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(FormatSpec {
+            fill,
+            align,
+            width: width.parse().ok()?,
+        })
+    }
+
+    /// Write a value padded to the width.
+    fn pad<W: Write + ?Sized>(&self, value: &str, output: &mut W) {
+        let padding = self.width.saturating_sub(width_of(value));
+
+        let (left, right) = match self.align {
+            Align::Left => (0, padding),
+            Align::Right => (padding, 0),
+            Align::Center => (padding / 2, padding - padding / 2),
+        };
+
+        for _ in 0..left {
+            output.write_char(self.fill).unwrap();
+        }
+
+        output.write_str(value).unwrap();
+
+        for _ in 0..right {
+            output.write_char(self.fill).unwrap();
+        }
+    }
+}
+
+/// Splits a text into literals and placeholders. Braces are searched with SIMD if the `memchr` feature is enabled, so long texts with few placeholders are scanned quickly.
+#[derive(Debug, Clone)]
+pub(crate) struct Segments<'t> {
+    rest: &'t str,
+}
+
+impl<'t> Segments<'t> {
+    #[inline]
+    pub(crate) fn new(template: &'t str) -> Segments<'t> {
+        Segments {
+            rest: template,
+        }
+    }
+
+    /// Get the text which has not been split yet.
+    #[inline]
+    pub(crate) fn rest(&self) -> &'t str {
+        self.rest
+    }
+}
+
+impl<'t> Iterator for Segments<'t> {
+    type Item = Segment<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest;
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let bytes = rest.as_bytes();
+
+        match find_byte2(b'{', b'}', bytes) {
+            Some(0) => (),
+            Some(i) => {
+                self.rest = &rest[i..];
+
+                return Some(Segment::Literal(&rest[..i]));
+            }
+            None => {
+                self.rest = "";
+
+                return Some(Segment::Literal(rest));
+            }
+        };
+
+        // `rest` starts with a brace here
+        if bytes.get(1) == Some(&bytes[0]) {
+            self.rest = &rest[2..];
+
+            return Some(Segment::Literal(&rest[..1]));
+        }
+
+        if bytes[0] == b'{' {
+            if let Some(end) = find_byte(b'}', bytes) {
+                let (inner, default) = match rest[1..end].split_once('|') {
+                    Some((inner, default)) => (inner, Some(default)),
+                    None => (&rest[1..end], None),
+                };
+
+                let (name, spec, raw_value) = match inner.split_once(':') {
+                    Some((name, "raw")) => (name, Some(None), true),
+                    Some((name, spec)) => (name, FormatSpec::parse(spec).map(Some), false),
+                    None => (inner, Some(None), false),
+                };
+
+                let spec = spec.filter(|_| default.is_none_or(is_placeholder_name));
+
+                if let (true, Some(spec)) = (is_placeholder_name(name), spec) {
+                    self.rest = &rest[end + 1..];
+
+                    return Some(Segment::Placeholder {
+                        placeholder: Placeholder {
+                            name,
+                            raw_value,
+                            default,
+                        },
+                        raw: &rest[..=end],
+                        spec,
+                    });
+                }
+            }
+
+            if let Ok((conditional, len)) = Conditional::parse(rest) {
+                self.rest = &rest[len..];
+
+                return Some(Segment::Conditional {
+                    conditional,
+                    raw: &rest[..len],
+                });
+            }
+        }
+
+        self.rest = &rest[1..];
+
+        Some(Segment::Literal(&rest[..1]))
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "ansi")]
+mod ansi;
+#[cfg(feature = "bumpalo")]
+mod arena;
+mod condition;
+mod default;
+mod interpolate;
+
+#[cfg(feature = "ansi")]
+pub use ansi::*;
+#[cfg(feature = "bumpalo")]
+pub use arena::*;
+pub use interpolate::*;
+
+pub(crate) use condition::{map_outside_conditionals, validate_context_conditionals};
+pub(crate) use default::validate_placeholder_defaults;
+
+/// Find the first occurrence of a byte, with SIMD if the `memchr` feature is enabled.
+#[inline]
+pub(crate) fn find_byte(needle: u8, haystack: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memchr(needle, haystack)
+    }
+
+    #[cfg(not(feature = "memchr"))]
+    {
+        haystack.iter().position(|&b| b == needle)
+    }
+}
+
+/// Find the first occurrence of either of two bytes, with SIMD if the `memchr` feature is enabled.
+#[inline]
+pub(crate) fn find_byte2(needle1: u8, needle2: u8, haystack: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memchr2(needle1, needle2, haystack)
+    }
+
+    #[cfg(not(feature = "memchr"))]
+    {
+        haystack.iter().position(|&b| b == needle1 || b == needle2)
+    }
+}
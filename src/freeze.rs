@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::{JSONGetText, JSONGetTextValue};
+
+/// A value whose string, if any, has been moved into the packed buffer.
+enum PackedValue {
+    Str(Range<usize>),
+    Value(Value),
+}
+
+/// Get the string of a value if it is a string.
+#[inline]
+fn string_of<'v>(value: &'v JSONGetTextValue) -> Option<&'v str> {
+    match value {
+        JSONGetTextValue::Str(s) => Some(s),
+        JSONGetTextValue::JSONValue(Value::String(s)) => Some(s),
+        JSONGetTextValue::JSONValueRef(Value::String(s)) => Some(s),
+        _ => None,
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Freeze this instance so that it can be shared read-only, between threads and between the worker processes forked from a pre-fork server.
+    ///
+    /// The string texts of every key are packed into one buffer, the maps are shrunk to fit, and the instance is leaked, so it is never dropped and looking texts up never needs to clone an `Arc`. Looking texts up does not write to its memory, which lets forked processes keep sharing its copy-on-write pages instead of duplicating them. This does not hold for lookups which reach an observer (`set_observer`), a missing handler (`set_missing_handler`) or a secondary context (`set_secondary_context`), which are kept as they are: they record or cache on every call, so leave them unset on an instance to be shared by forked processes. The memory is never released, so freeze an instance once, after it is built.
+    pub fn freeze(self) -> &'static JSONGetText<'static> {
+        let JSONGetText {
+            default_key,
+            context,
+            mut filled,
+            mut stale,
+            mut deprecated,
+            mut migrations,
+            mut quarantined,
+            mut extra_texts,
+            mut empty_texts,
+            observer,
+            missing_handler,
+            read_through,
+            pipeline,
+            #[cfg(feature = "http")]
+            remote_catalogs,
+            mut last_modified,
+            mut namespaces,
+            mut number_symbols,
+            #[cfg(feature = "plural_rules")]
+            mut plural_rules,
+            mut locale_groups,
+        } = self;
+
+        let total_len: usize = context
+            .values()
+            .flat_map(|map| map.values())
+            .filter_map(string_of)
+            .map(|s| s.len())
+            .sum();
+
+        let mut buffer = String::with_capacity(total_len);
+
+        let packed: Vec<_> = context
+            .into_iter()
+            .map(|(key, map)| {
+                let map: HashMap<String, PackedValue> = map
+                    .iter()
+                    .map(|(text, value)| {
+                        let packed = match string_of(value) {
+                            Some(s) => {
+                                let start = buffer.len();
+
+                                buffer.push_str(s);
+
+                                PackedValue::Str(start..buffer.len())
+                            }
+                            None => {
+                                PackedValue::Value(match value {
+                                    JSONGetTextValue::JSONValue(v) => v.clone(),
+                                    JSONGetTextValue::JSONValueRef(v) => (*v).clone(),
+                                    JSONGetTextValue::Str(_) => unreachable!(),
+                                })
+                            }
+                        };
+
+                        (text.clone(), packed)
+                    })
+                    .collect();
+
+                (key, map)
+            })
+            .collect();
+
+        let buffer: &'static str = Box::leak(buffer.into_boxed_str());
+
+        let context = packed
+            .into_iter()
+            .map(|(key, map)| {
+                let mut map: HashMap<String, JSONGetTextValue<'static>> = map
+                    .into_iter()
+                    .map(|(text, packed)| {
+                        let value = match packed {
+                            PackedValue::Str(range) => JSONGetTextValue::Str(&buffer[range]),
+                            PackedValue::Value(value) => JSONGetTextValue::JSONValue(value),
+                        };
+
+                        (text, value)
+                    })
+                    .collect();
+
+                map.shrink_to_fit();
+
+                (key, Arc::new(map))
+            })
+            .collect();
+
+        filled.shrink_to_fit();
+        stale.shrink_to_fit();
+        deprecated.shrink_to_fit();
+        migrations.shrink_to_fit();
+        quarantined.shrink_to_fit();
+        extra_texts.shrink_to_fit();
+        empty_texts.shrink_to_fit();
+        number_symbols.shrink_to_fit();
+        #[cfg(feature = "plural_rules")]
+        plural_rules.shrink_to_fit();
+        last_modified.shrink_to_fit();
+        namespaces.shrink_to_fit();
+        locale_groups.shrink_to_fit();
+
+        Box::leak(Box::new(JSONGetText {
+            default_key,
+            context,
+            filled,
+            stale,
+            deprecated,
+            migrations,
+            quarantined,
+            extra_texts,
+            empty_texts,
+            observer,
+            missing_handler,
+            read_through,
+            pipeline,
+            #[cfg(feature = "http")]
+            remote_catalogs,
+            last_modified,
+            namespaces,
+            number_symbols,
+            #[cfg(feature = "plural_rules")]
+            plural_rules,
+            locale_groups,
+        }))
+    }
+}
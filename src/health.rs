@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::metadata::is_metadata_text;
+#[cfg(feature = "content_hash")]
+use crate::ContentHash;
+use crate::JSONGetText;
+
+/// The outcome of the last rebuilding of a reloadable instance, in a `HealthSummary`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReloadHealth {
+    /// When the rebuilding finished, in seconds since the Unix epoch.
+    pub finished_at: u64,
+    pub ok: bool,
+    /// The error of the rebuilding if it failed, in which case the previous instance is still served.
+    pub error: Option<String>,
+}
+
+/// A summary of a `JSONGetText` instance which can be serialized into the responses of `/healthz` or `/readyz` endpoints.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthSummary {
+    pub default_key: String,
+    pub locale_count: usize,
+    /// The share of the texts of the default key which each key has translated rather than filled from another key, from `0` to `1`.
+    pub coverage: BTreeMap<String, f64>,
+    /// The content hash of the instance, which tells whether two replicas serve the same texts.
+    #[cfg(feature = "content_hash")]
+    pub fingerprint: ContentHash,
+    /// The outcome of the last rebuilding, for a `JSONGetTextReloadable` instance which has been rebuilt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reload: Option<ReloadHealth>,
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get a health summary of this instance.
+    pub fn health_summary(&self) -> HealthSummary {
+        let (default_key, default_map) = self.default_entry();
+
+        let total = default_map.keys().filter(|text| !is_metadata_text(text)).count();
+
+        let coverage = self
+            .context
+            .keys()
+            .map(|key| {
+                let filled = self.filled.get(key).map_or(0, |filled| {
+                    filled.iter().filter(|text| !is_metadata_text(text)).count()
+                });
+
+                let coverage = if total == 0 {
+                    1.0
+                } else {
+                    (total - filled) as f64 / total as f64
+                };
+
+                (key.to_string(), coverage)
+            })
+            .collect();
+
+        HealthSummary {
+            default_key: default_key.to_string(),
+            locale_count: self.context.len(),
+            coverage,
+            #[cfg(feature = "content_hash")]
+            fingerprint: self.content_hash(),
+            last_reload: None,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::mem;
+
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    ConflictPolicy, IngestLimit, IngestLimits, JSONGetText, JSONGetTextBuildError,
+    JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+/// A catalog added by `add_json_url`, with the ETag it was downloaded with.
+#[derive(Debug, Clone)]
+struct RemoteCatalog {
+    key: Key,
+    url: String,
+    etag: Option<String>,
+    limits: IngestLimits,
+}
+
+/// The catalogs added by `add_json_url`, to be refreshed by `JSONGetText::refresh`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RemoteCatalogs {
+    client: Option<Client>,
+    catalogs: Vec<RemoteCatalog>,
+}
+
+impl RemoteCatalogs {
+    #[inline]
+    fn client(&self) -> Client {
+        self.client.clone().unwrap_or_default()
+    }
+}
+
+/// Download a JSON catalog. With an ETag, the request is conditional and `None` is returned if the catalog has not changed.
+async fn fetch(
+    client: &Client,
+    key: &Key,
+    url: &str,
+    etag: Option<&str>,
+    limits: IngestLimits,
+) -> Result<
+    Option<(HashMap<String, JSONGetTextValue<'static>>, Option<String>)>,
+    JSONGetTextBuildError,
+> {
+    let mut request = client.get(url);
+
+    if let Some(etag) = etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+
+    let response = request.send().await?;
+
+    if etag.is_some() && response.status() == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+
+    let mut response = response.error_for_status()?;
+
+    let etag = response.headers().get(ETAG).and_then(|v| v.to_str().ok()).map(String::from);
+
+    let mut json = Vec::new();
+
+    // stop downloading as soon as the catalog is too large
+    while let Some(chunk) = response.chunk().await? {
+        json.extend_from_slice(&chunk);
+
+        if json.len() > limits.max_bytes {
+            return Err(IngestLimit::Bytes.exceeded_by(key.to_owned()));
+        }
+    }
+
+    Ok(Some((limits.parse_catalog(key, &json)?, etag)))
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set the HTTP client used by `add_json_url` and `JSONGetText::refresh`, e.g. with default headers which authenticate to a translation platform. A default client is used if this is not called.
+    #[inline]
+    pub fn set_http_client(&mut self, client: Client) -> &mut Self {
+        self.options.remote_catalogs.client = Some(client);
+
+        self
+    }
+
+    /// Download a JSON catalog from a URL and add it to the context for a specify key, like `add_json_owned`. Its ETag is kept, so that `JSONGetText::refresh` only downloads it again if it has changed. This crate does not enable TLS in `reqwest`, so enable one of its TLS features (e.g. `rustls-tls`) in your own dependencies to download from `https` URLs.
+    pub async fn add_json_url<K: Into<Key>, U: Into<String>>(
+        &mut self,
+        key: K,
+        url: U,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key));
+        }
+
+        let url = url.into();
+        let limits = self.options.ingest_limits;
+        let client = self.options.remote_catalogs.client();
+
+        // only conditional requests return `None`
+        let (map, etag) = fetch(&client, &key, &url, None, limits).await?.unwrap();
+
+        self.add_map(key.to_owned(), map)?;
+
+        self.options.remote_catalogs.catalogs.push(RemoteCatalog {
+            key,
+            url,
+            etag,
+            limits,
+        });
+
+        Ok(self)
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Download the catalogs added by `JSONGetTextBuilder::add_json_url` again if their ETags have changed, and return the keys whose catalogs have changed. The catalog of a key other than the default key is replaced and its missing texts are filled from the default key, while the catalog of the default key is merged like `insert_text`, so texts removed from it are kept until the next build. If refreshing fails, this instance is left unchanged.
+    pub async fn refresh(&mut self) -> Result<Vec<Key>, JSONGetTextBuildError> {
+        let client = self.remote_catalogs.client();
+
+        let mut ctx = self.clone();
+
+        let mut catalogs = mem::take(&mut ctx.remote_catalogs.catalogs);
+
+        let mut changed = Vec::new();
+
+        for catalog in catalogs.iter_mut() {
+            let fetched =
+                fetch(&client, &catalog.key, &catalog.url, catalog.etag.as_deref(), catalog.limits)
+                    .await?;
+
+            let (map, etag) = match fetched {
+                Some(fetched) => fetched,
+                None => continue,
+            };
+
+            let key = catalog.key.to_owned();
+
+            if key == *ctx.default_entry().0 {
+                ctx.merge_map(key.to_owned(), map, ConflictPolicy::Overwrite)?;
+            } else {
+                ctx.context.remove(&key);
+                ctx.filled.remove(&key);
+                ctx.stale.remove(&key);
+
+                ctx.merge_map(key.to_owned(), map, ConflictPolicy::Error)?;
+            }
+
+            catalog.etag = etag;
+
+            changed.push(key);
+        }
+
+        ctx.remote_catalogs.catalogs = catalogs;
+
+        *self = ctx;
+
+        Ok(changed)
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+#[cfg(feature = "fs")]
+use std::fs::File;
+#[cfg(feature = "fs")]
+use std::io::Read;
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// Limits checked for every catalog added to a `JSONGetTextBuilder`, so that catalogs uploaded by users cannot exhaust memory or the stack. There are no limits by default; use `IngestLimits::UNTRUSTED` or your own limits for catalogs which are not yours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IngestLimits {
+    /// The maximum size of a catalog in bytes, checked before parsing.
+    pub max_bytes: usize,
+    /// The maximum nesting depth of objects and arrays, checked before parsing. `serde_json` itself stops at a depth of 128.
+    pub max_depth: usize,
+    /// The maximum number of texts in a catalog.
+    pub max_texts: usize,
+}
+
+impl IngestLimits {
+    /// No limits except for the nesting depth of `serde_json`.
+    pub const UNLIMITED: IngestLimits = IngestLimits {
+        max_bytes: usize::MAX,
+        max_depth: usize::MAX,
+        max_texts: usize::MAX,
+    };
+    /// Limits for catalogs uploaded by users, far above what a real catalog needs: 16 MiB, a nesting depth of 32 and 100,000 texts.
+    pub const UNTRUSTED: IngestLimits = IngestLimits {
+        max_bytes: 16 * 1024 * 1024,
+        max_depth: 32,
+        max_texts: 100_000,
+    };
+}
+
+impl Default for IngestLimits {
+    /// `IngestLimits::UNLIMITED`, so that large catalogs of your own load as they are.
+    #[inline]
+    fn default() -> Self {
+        IngestLimits::UNLIMITED
+    }
+}
+
+/// The limit of `IngestLimits` which has been exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IngestLimit {
+    Bytes,
+    Depth,
+    Texts,
+}
+
+impl Display for IngestLimit {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IngestLimit::Bytes => "size",
+            IngestLimit::Depth => "nesting depth",
+            IngestLimit::Texts => "number of texts",
+        })
+    }
+}
+
+impl IngestLimit {
+    #[inline]
+    pub(crate) fn exceeded_by(self, key: Key) -> JSONGetTextBuildError {
+        JSONGetTextBuildError::IngestLimitExceeded {
+            key,
+            limit: self,
+        }
+    }
+}
+
+impl IngestLimits {
+    /// Check the size and the nesting depth of a JSON text without parsing it.
+    pub(crate) fn check_json(&self, json: &[u8]) -> Result<(), IngestLimit> {
+        if json.len() > self.max_bytes {
+            return Err(IngestLimit::Bytes);
+        }
+
+        if self.max_depth == usize::MAX {
+            return Ok(());
+        }
+
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        for &b in json {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if b == b'\\' {
+                    escaped = true;
+                } else if b == b'"' {
+                    in_string = false;
+                }
+
+                continue;
+            }
+
+            match b {
+                b'"' => in_string = true,
+                b'{' | b'[' => {
+                    depth += 1;
+
+                    if depth > self.max_depth {
+                        return Err(IngestLimit::Depth);
+                    }
+                }
+                b'}' | b']' => depth = depth.saturating_sub(1),
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the nesting depth of a JSON value in a container at `depth`, counted in the same way as `check_json`, i.e. the catalog object itself has a depth of 1.
+    pub(crate) fn check_value(&self, value: &Value, depth: usize) -> Result<(), IngestLimit> {
+        // iterative, so that a deeply nested value cannot overflow the stack here
+        let mut stack = vec![(value, depth)];
+
+        while let Some((value, depth)) = stack.pop() {
+            let depth = depth + 1;
+
+            match value {
+                Value::Array(array) => {
+                    if depth > self.max_depth {
+                        return Err(IngestLimit::Depth);
+                    }
+
+                    stack.extend(array.iter().map(|value| (value, depth)));
+                }
+                Value::Object(object) => {
+                    if depth > self.max_depth {
+                        return Err(IngestLimit::Depth);
+                    }
+
+                    stack.extend(object.values().map(|value| (value, depth)));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the number of texts and the nesting depth of a parsed catalog.
+    pub(crate) fn check_map(
+        &self,
+        map: &HashMap<String, JSONGetTextValue>,
+    ) -> Result<(), IngestLimit> {
+        self.check_texts(map.len())?;
+
+        for value in map.values() {
+            match value {
+                JSONGetTextValue::Str(_) => (),
+                JSONGetTextValue::JSONValue(value) => self.check_value(value, 1)?,
+                JSONGetTextValue::JSONValueRef(value) => self.check_value(value, 1)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check and parse a JSON catalog of a key, which must represent a map object (key-value).
+    pub(crate) fn parse_catalog(
+        &self,
+        key: &Key,
+        json: &[u8],
+    ) -> Result<HashMap<String, JSONGetTextValue<'static>>, JSONGetTextBuildError> {
+        self.check_json(json).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let value: Map<String, Value> = serde_json::from_slice(json)?;
+
+        self.check_texts(value.len()).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        Ok(value.into_iter().map(|(k, v)| (k, JSONGetTextValue::from_json_value(v))).collect())
+    }
+
+    #[inline]
+    pub(crate) fn check_texts(&self, count: usize) -> Result<(), IngestLimit> {
+        if count > self.max_texts {
+            Err(IngestLimit::Texts)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read a catalog file of a key, failing with `IngestLimitExceeded` as soon as it is known to be larger than `max_bytes`.
+    #[cfg(feature = "fs")]
+    pub(crate) fn read_file(
+        &self,
+        key: &Key,
+        path: &Path,
+    ) -> Result<Vec<u8>, JSONGetTextBuildError> {
+        let file = File::open(path)?;
+
+        if file.metadata()?.len() > self.max_bytes as u64 {
+            return Err(IngestLimit::Bytes.exceeded_by(key.to_owned()));
+        }
+
+        let mut json = Vec::new();
+
+        // the file may grow after its metadata is read
+        file.take((self.max_bytes as u64).saturating_add(1)).read_to_end(&mut json)?;
+
+        if json.len() > self.max_bytes {
+            return Err(IngestLimit::Bytes.exceeded_by(key.to_owned()));
+        }
+
+        Ok(json)
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set the limits checked for every catalog added afterwards, e.g. `IngestLimits::UNTRUSTED` for catalogs uploaded by users. There are no limits if this is not called.
+    #[inline]
+    pub fn set_ingest_limits(&mut self, limits: IngestLimits) -> &mut Self {
+        self.options.ingest_limits = limits;
+
+        self
+    }
+}
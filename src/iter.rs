@@ -0,0 +1,33 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+impl<'a> JSONGetText<'a> {
+    /// Iterate over the keys in context, in arbitrary order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &Key> + '_ {
+        self.context.keys()
+    }
+
+    /// Iterate over every text of every key as `(key, text, value)`, without cloning, e.g. to export or audit a large catalog. Texts filled from another key are included. The order is arbitrary.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &str, &JSONGetTextValue<'a>)> + '_ {
+        self.context
+            .iter()
+            .flat_map(|(key, map)| map.iter().map(move |(text, value)| (key, text.as_str(), value)))
+    }
+
+    /// Iterate over every text of a key as `(key, text, value)`, like `iter`. Return `None` if the key is not in context.
+    #[inline]
+    pub fn iter_language<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+    ) -> Option<impl Iterator<Item = (&Key, &str, &JSONGetTextValue<'a>)> + '_>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry(key)?;
+
+        Some(map.iter().map(move |(text, value)| (key, text.as_str(), value)))
+    }
+}
@@ -0,0 +1,233 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use serde_json::Value;
+
+use crate::locale::language_of;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// Joins a word (e.g. an article or a preposition) and a translated fragment according to the grammar of a language, instead of concatenating them with a space.
+///
+/// Built-in joiners are chosen by `joiner_for`. Implement this trait for other languages and call `join` directly:
+///
+/// ```
+/// use json_gettext::FragmentJoiner;
+///
+/// /// Joins Catalan articles with elision (e.g. `l'escola`).
+/// struct CatalanJoiner;
+///
+/// impl FragmentJoiner for CatalanJoiner {
+///     fn join(&self, word: &str, fragment: &str) -> String {
+///         let elided = matches!(word, "el" | "la")
+///             && fragment.starts_with(|c: char| "aeiouàèéíòóúh".contains(c.to_ascii_lowercase()));
+///
+///         if elided {
+///             format!("l'{}", fragment)
+///         } else {
+///             format!("{} {}", word, fragment)
+///         }
+///     }
+/// }
+///
+/// assert_eq!("l'escola", CatalanJoiner.join("la", "escola"));
+/// assert_eq!("la casa", CatalanJoiner.join("la", "casa"));
+/// ```
+pub trait FragmentJoiner {
+    /// Join a word and the fragment following it.
+    fn join(&self, word: &str, fragment: &str) -> String;
+}
+
+/// Joins fragments with a space. It is used for languages without a specific joiner.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpaceJoiner;
+
+impl FragmentJoiner for SpaceJoiner {
+    #[inline]
+    fn join(&self, word: &str, fragment: &str) -> String {
+        format!("{} {}", word, fragment)
+    }
+}
+
+/// Joins fragments without a space, for languages which do not separate words with spaces (e.g. Chinese, Japanese and Thai).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoSpaceJoiner;
+
+impl FragmentJoiner for NoSpaceJoiner {
+    #[inline]
+    fn join(&self, word: &str, fragment: &str) -> String {
+        format!("{}{}", word, fragment)
+    }
+}
+
+/// Joins French fragments with elision (e.g. `le` + `arbre` → `l'arbre`, `si` + `il` → `s'il`) and contraction (e.g. `de` + `le livre` → `du livre`, `à` + `les amis` → `aux amis`). The case of the first letter of the word is kept.
+///
+/// A leading `h` is treated as mute, so fragments starting with an aspirated `h` (e.g. `héros`) should not be joined with this joiner.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrenchJoiner;
+
+const FRENCH_ELIDED_WORDS: [&str; 13] =
+    ["le", "la", "de", "je", "me", "te", "se", "ne", "que", "ce", "jusque", "lorsque", "puisque"];
+
+const FRENCH_CONTRACTIONS: [(&str, &str, &str); 4] =
+    [("de", "le", "du"), ("de", "les", "des"), ("à", "le", "au"), ("à", "les", "aux")];
+
+#[inline]
+fn starts_with_french_vowel(s: &str) -> bool {
+    s.chars()
+        .next()
+        .is_some_and(|c| "aeiouhâàäéèêëîïôöûùü".contains(c.to_lowercase().next().unwrap()))
+}
+
+/// Give `s` the case of the first letter of `like`.
+fn with_case_of(s: &str, like: &str) -> String {
+    if like.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = s.chars();
+
+        match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    } else {
+        s.to_string()
+    }
+}
+
+impl FragmentJoiner for FrenchJoiner {
+    fn join(&self, word: &str, fragment: &str) -> String {
+        let lowercase_word = word.to_lowercase();
+
+        for (preposition, article, contraction) in FRENCH_CONTRACTIONS {
+            if lowercase_word != preposition {
+                continue;
+            }
+
+            if let Some(rest) =
+                fragment.strip_prefix(article).and_then(|rest| rest.strip_prefix(' '))
+            {
+                return format!("{} {}", with_case_of(contraction, word), rest);
+            }
+        }
+
+        let elided = if lowercase_word == "si" {
+            fragment == "il"
+                || fragment == "ils"
+                || fragment.starts_with("il ")
+                || fragment.starts_with("ils ")
+        } else {
+            FRENCH_ELIDED_WORDS.contains(&lowercase_word.as_str())
+                && starts_with_french_vowel(fragment)
+        };
+
+        if elided {
+            let mut chars = word.chars();
+            chars.next_back();
+
+            format!("{}'{}", chars.as_str(), fragment)
+        } else {
+            format!("{} {}", word, fragment)
+        }
+    }
+}
+
+/// Get the built-in joiner of a key, by its language.
+pub fn joiner_for(key: &Key) -> &'static dyn FragmentJoiner {
+    match language_of(key).as_deref() {
+        Some("fr") => &FrenchJoiner,
+        Some("zh" | "ja" | "th" | "lo" | "km" | "my" | "yue") => &NoSpaceJoiner,
+        _ => &SpaceJoiner,
+    }
+}
+
+/// A grammatical gender, given in metadata like `"@lamp": {"gender": "f"}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+impl Gender {
+    /// Parse `m`/`masculine`, `f`/`feminine` or `n`/`neuter`.
+    #[inline]
+    pub fn parse<S: AsRef<str>>(s: S) -> Option<Gender> {
+        match s.as_ref() {
+            "m" | "masculine" => Some(Gender::Masculine),
+            "f" | "feminine" => Some(Gender::Feminine),
+            "n" | "neuter" => Some(Gender::Neuter),
+            _ => None,
+        }
+    }
+}
+
+/// A grammatical case of German.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GermanCase {
+    Nominative,
+    Accusative,
+    Dative,
+    Genitive,
+}
+
+/// Get the German article (singular) of a gender in a case, e.g. `der`, `dem`, `einer`.
+pub fn german_article(gender: Gender, case: GermanCase, definite: bool) -> &'static str {
+    match (definite, case, gender) {
+        (true, GermanCase::Nominative, Gender::Masculine) => "der",
+        (true, GermanCase::Nominative | GermanCase::Accusative, Gender::Feminine) => "die",
+        (true, GermanCase::Nominative | GermanCase::Accusative, Gender::Neuter) => "das",
+        (true, GermanCase::Accusative, Gender::Masculine) => "den",
+        (true, GermanCase::Dative, Gender::Masculine | Gender::Neuter) => "dem",
+        (true, GermanCase::Dative | GermanCase::Genitive, Gender::Feminine) => "der",
+        (true, GermanCase::Genitive, Gender::Masculine | Gender::Neuter) => "des",
+        (false, GermanCase::Nominative, Gender::Masculine | Gender::Neuter) => "ein",
+        (false, GermanCase::Nominative | GermanCase::Accusative, Gender::Feminine) => "eine",
+        (false, GermanCase::Accusative, Gender::Masculine) => "einen",
+        (false, GermanCase::Accusative, Gender::Neuter) => "ein",
+        (false, GermanCase::Dative, Gender::Masculine | Gender::Neuter) => "einem",
+        (false, GermanCase::Dative | GermanCase::Genitive, Gender::Feminine) => "einer",
+        (false, GermanCase::Genitive, Gender::Masculine | Gender::Neuter) => "eines",
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Join a word and a fragment with the built-in joiner of a key (see `joiner_for`). If the key is not in context, the default key is used.
+    pub fn join_fragments<Q: ?Sized + Hash + Eq, W: AsRef<str>, F: AsRef<str>>(
+        &self,
+        key: &Q,
+        word: W,
+        fragment: F,
+    ) -> String
+    where
+        Key: Borrow<Q>, {
+        let (key, _) = self.find_entry_or_default(key);
+
+        joiner_for(key).join(word.as_ref(), fragment.as_ref())
+    }
+
+    /// Get the grammatical gender of a text from the metadata of a key, e.g. `"@lamp": {"gender": "f"}` in the German catalog. Metadata filled from the default key is ignored, since genders differ between languages. If the key is not in context, the default key is used.
+    pub fn gender_of<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        text: T,
+    ) -> Option<Gender>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry_or_default(key);
+
+        let metadata_text = format!("@{}", text.as_ref());
+
+        if self.is_filled_text(key, &metadata_text) {
+            return None;
+        }
+
+        let metadata = match map.get(&metadata_text)? {
+            JSONGetTextValue::JSONValue(v) => v,
+            JSONGetTextValue::JSONValueRef(v) => v,
+            JSONGetTextValue::Str(_) => return None,
+        };
+
+        match metadata.get("gender") {
+            Some(Value::String(gender)) => Gender::parse(gender),
+            _ => None,
+        }
+    }
+}
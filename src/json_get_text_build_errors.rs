@@ -1,10 +1,12 @@
 use std::error::Error;
 use std::fmt::{Display, Error as FmtError, Formatter};
 use std::io;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
 
 use crate::serde_json::Error as JSONError;
 
-use crate::Key;
+use crate::{IngestLimit, Key};
 
 #[derive(Debug)]
 pub enum JSONGetTextBuildError {
@@ -14,8 +16,75 @@ pub enum JSONGetTextBuildError {
         text: String,
     },
     DuplicatedKey(Key),
+    DuplicatedText {
+        key: Key,
+        text: String,
+    },
+    MigrationTargetNotFound {
+        from: String,
+        to: String,
+    },
+    ContentRejected {
+        key: Key,
+        text: String,
+        reason: String,
+    },
+    IngestLimitExceeded {
+        key: Key,
+        limit: IngestLimit,
+    },
+    FallbackKeyNotFound {
+        key: Key,
+        fallback: Key,
+    },
+    InvalidConditional {
+        key: Key,
+        text: String,
+        reason: String,
+    },
+    MessageReferenceNotFound {
+        key: Key,
+        text: String,
+        reference: String,
+    },
+    MessageReferenceCycle {
+        key: Key,
+        texts: Vec<String>,
+    },
+    PlaceholderDefaultNotFound {
+        key: Key,
+        text: String,
+        default: String,
+    },
+    /// The files of a directory which failed to load, with their errors, in the order of their paths.
+    #[cfg(feature = "fs")]
+    FilesFailed(Vec<(PathBuf, JSONGetTextBuildError)>),
+    /// A namespace registered by `add_crate_namespace` which was already registered, with the names of both crates.
+    #[cfg(feature = "embed_dir")]
+    NamespaceCollision {
+        namespace: String,
+        crates: [String; 2],
+    },
+    #[cfg(feature = "po")]
+    InvalidPO {
+        line: usize,
+        reason: String,
+    },
+    #[cfg(feature = "fluent")]
+    InvalidFluent {
+        line: usize,
+        reason: String,
+    },
     IOError(io::Error),
     SerdeJSONError(JSONError),
+    #[cfg(feature = "yaml")]
+    SerdeYAMLError(serde_yaml::Error),
+    #[cfg(feature = "toml")]
+    TOMLError(toml::de::Error),
+    #[cfg(feature = "sqlite")]
+    SQLiteError(rusqlite::Error),
+    #[cfg(feature = "http")]
+    HTTPError(reqwest::Error),
 }
 
 impl Display for JSONGetTextBuildError {
@@ -28,15 +97,119 @@ impl Display for JSONGetTextBuildError {
             JSONGetTextBuildError::TextInKeyNotInDefaultKey {
                 key,
                 text,
+            } => f.write_fmt(format_args!(
+                "The text `{}` in the key `{}` is not found in the default key.",
+                text, key
+            )),
+            JSONGetTextBuildError::DuplicatedKey(key) => Display::fmt(key, f),
+            JSONGetTextBuildError::DuplicatedText {
+                key,
+                text,
+            } => f.write_fmt(format_args!("The text `{}` is duplicated in the key `{}`.", text, key)),
+            JSONGetTextBuildError::MigrationTargetNotFound {
+                from,
+                to,
+            } => f.write_fmt(format_args!(
+                "The text `{}` is migrated to `{}`, which is not found in the default key.",
+                from, to
+            )),
+            JSONGetTextBuildError::ContentRejected {
+                key,
+                text,
+                reason,
+            } => f.write_fmt(format_args!(
+                "The text `{}` in the key `{}` is rejected: {}",
+                text, key, reason
+            )),
+            JSONGetTextBuildError::IngestLimitExceeded {
+                key,
+                limit,
+            } => f.write_fmt(format_args!(
+                "The catalog of the key `{}` exceeds the maximum {}.",
+                key, limit
+            )),
+            JSONGetTextBuildError::FallbackKeyNotFound {
+                key,
+                fallback,
+            } => f.write_fmt(format_args!(
+                "The fallback key `{}` of the key `{}` is not found.",
+                fallback, key
+            )),
+            JSONGetTextBuildError::InvalidConditional {
+                key,
+                text,
+                reason,
+            } => f.write_fmt(format_args!(
+                "The text `{}` in the key `{}` has an invalid conditional: {}",
+                text, key, reason
+            )),
+            JSONGetTextBuildError::MessageReferenceNotFound {
+                key,
+                text,
+                reference,
+            } => f.write_fmt(format_args!(
+                "The text `{}` in the key `{}` refers to `{}`, which is not found.",
+                text, key, reference
+            )),
+            JSONGetTextBuildError::MessageReferenceCycle {
+                key,
+                texts,
+            } => f.write_fmt(format_args!(
+                "The texts in the key `{}` refer to each other in a cycle: {}",
+                key,
+                texts.join(" -> ")
+            )),
+            JSONGetTextBuildError::PlaceholderDefaultNotFound {
+                key,
+                text,
+                default,
+            } => f.write_fmt(format_args!(
+                "The text `{}` in the key `{}` has a placeholder whose default is `{}`, which is not found.",
+                text, key, default
+            )),
+            #[cfg(feature = "fs")]
+            JSONGetTextBuildError::FilesFailed(errors) => {
+                f.write_fmt(format_args!("{} file(s) failed to load:", errors.len()))?;
+
+                for (path, err) in errors {
+                    f.write_fmt(format_args!("\n{}: {}", path.display(), err))?;
+                }
+
+                Ok(())
+            }
+            #[cfg(feature = "embed_dir")]
+            JSONGetTextBuildError::NamespaceCollision {
+                namespace,
+                crates,
+            } => f.write_fmt(format_args!(
+                "The namespace `{}` is registered by both `{}` and `{}`.",
+                namespace, crates[0], crates[1]
+            )),
+            #[cfg(feature = "po")]
+            JSONGetTextBuildError::InvalidPO {
+                line,
+                reason,
             } => {
-                f.write_fmt(format_args!(
-                    "The text `{}` in the key `{}` is not found in the default key.",
-                    text, key
-                ))
+                f.write_fmt(format_args!("The PO catalog is invalid at line {}: {}", line, reason))
             }
-            JSONGetTextBuildError::DuplicatedKey(key) => Display::fmt(key, f),
+            #[cfg(feature = "fluent")]
+            JSONGetTextBuildError::InvalidFluent {
+                line,
+                reason,
+            } => f.write_fmt(format_args!(
+                "The Fluent resource is invalid at line {}: {}",
+                line, reason
+            )),
             JSONGetTextBuildError::IOError(err) => Display::fmt(err, f),
             JSONGetTextBuildError::SerdeJSONError(err) => Display::fmt(err, f),
+            #[cfg(feature = "yaml")]
+            JSONGetTextBuildError::SerdeYAMLError(err) => Display::fmt(err, f),
+            #[cfg(feature = "toml")]
+            JSONGetTextBuildError::TOMLError(err) => Display::fmt(err, f),
+            #[cfg(feature = "sqlite")]
+            JSONGetTextBuildError::SQLiteError(err) => Display::fmt(err, f),
+            #[cfg(feature = "http")]
+            JSONGetTextBuildError::HTTPError(err) => Display::fmt(err, f),
         }
     }
 }
@@ -56,3 +229,35 @@ impl From<JSONError> for JSONGetTextBuildError {
         JSONGetTextBuildError::SerdeJSONError(v)
     }
 }
+
+#[cfg(feature = "yaml")]
+impl From<serde_yaml::Error> for JSONGetTextBuildError {
+    #[inline]
+    fn from(v: serde_yaml::Error) -> JSONGetTextBuildError {
+        JSONGetTextBuildError::SerdeYAMLError(v)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for JSONGetTextBuildError {
+    #[inline]
+    fn from(v: toml::de::Error) -> JSONGetTextBuildError {
+        JSONGetTextBuildError::TOMLError(v)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for JSONGetTextBuildError {
+    #[inline]
+    fn from(v: rusqlite::Error) -> JSONGetTextBuildError {
+        JSONGetTextBuildError::SQLiteError(v)
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<reqwest::Error> for JSONGetTextBuildError {
+    #[inline]
+    fn from(v: reqwest::Error) -> JSONGetTextBuildError {
+        JSONGetTextBuildError::HTTPError(v)
+    }
+}
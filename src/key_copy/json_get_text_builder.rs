@@ -1,10 +1,13 @@
 use std::collections::HashMap;
-use std::fs::File;
+#[cfg(feature = "fs")]
 use std::path::Path;
 
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::build_options::BuildOptions;
+#[cfg(feature = "fs")]
+use crate::CatalogId;
 use crate::JSONGetTextBuildError;
 
 use super::{Context, JSONGetText, JSONGetTextValue, Key};
@@ -12,8 +15,9 @@ use super::{Context, JSONGetText, JSONGetTextValue, Key};
 /// To build a JSONGetText instance, this struct can help you do that step by step.
 #[derive(Debug, Clone)]
 pub struct JSONGetTextBuilder<'a> {
-    default_key: Key,
-    context: Context<'a>,
+    pub(crate) default_key: Key,
+    pub(crate) context: Context<'a>,
+    pub(crate) options: BuildOptions,
 }
 
 impl<'a> JSONGetTextBuilder<'a> {
@@ -23,6 +27,7 @@ impl<'a> JSONGetTextBuilder<'a> {
         JSONGetTextBuilder {
             default_key,
             context: HashMap::new(),
+            options: BuildOptions::default(),
         }
     }
 
@@ -36,7 +41,19 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key));
         }
 
-        let map: HashMap<String, JSONGetTextValue<'a>> = serde_json::from_str(json.as_ref())?;
+        let json = json.as_ref();
+
+        self.options
+            .ingest_limits
+            .check_json(json.as_bytes())
+            .map_err(|limit| limit.exceeded_by(key))?;
+
+        let map: HashMap<String, JSONGetTextValue<'a>> = serde_json::from_str(json)?;
+
+        self.options
+            .ingest_limits
+            .check_texts(map.len())
+            .map_err(|limit| limit.exceeded_by(key))?;
 
         self.context.insert(key, map);
 
@@ -53,7 +70,19 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key));
         }
 
-        let value: Map<String, Value> = serde_json::from_str(json.as_ref())?;
+        let json = json.as_ref();
+
+        self.options
+            .ingest_limits
+            .check_json(json.as_bytes())
+            .map_err(|limit| limit.exceeded_by(key))?;
+
+        let value: Map<String, Value> = serde_json::from_str(json)?;
+
+        self.options
+            .ingest_limits
+            .check_texts(value.len())
+            .map_err(|limit| limit.exceeded_by(key))?;
 
         let mut map: HashMap<String, JSONGetTextValue<'static>> =
             HashMap::with_capacity(value.len());
@@ -68,6 +97,7 @@ impl<'a> JSONGetTextBuilder<'a> {
     }
 
     /// Add a JSON file to the context for a specify key. The JSON file must represent a map object (key-value).
+    #[cfg(feature = "fs")]
     pub fn add_json_file<P: AsRef<Path>>(
         &mut self,
         key: Key,
@@ -77,9 +107,15 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key));
         }
 
-        let path = path.as_ref();
+        let limits = self.options.ingest_limits;
+
+        let json = limits.read_file(&key, path.as_ref())?;
+
+        limits.check_json(&json).map_err(|limit| limit.exceeded_by(key))?;
 
-        let value: Map<String, Value> = serde_json::from_reader(File::open(path)?)?;
+        let value: Map<String, Value> = serde_json::from_slice(&json)?;
+
+        limits.check_texts(value.len()).map_err(|limit| limit.exceeded_by(key))?;
 
         let mut map: HashMap<String, JSONGetTextValue<'static>> =
             HashMap::with_capacity(value.len());
@@ -88,6 +124,11 @@ impl<'a> JSONGetTextBuilder<'a> {
             map.insert(k, JSONGetTextValue::from_json_value(v));
         }
 
+        #[cfg(feature = "watch")]
+        self.options.json_files.push((key, path.as_ref().to_path_buf()));
+
+        self.record_file_modified(CatalogId::new(key, None), path.as_ref());
+
         self.context.insert(key, map);
 
         Ok(self)
@@ -105,8 +146,18 @@ impl<'a> JSONGetTextBuilder<'a> {
 
         let value: Value = serde_json::to_value(value)?;
 
+        self.options
+            .ingest_limits
+            .check_value(&value, 0)
+            .map_err(|limit| limit.exceeded_by(key))?;
+
         match value {
             Value::Object(value) => {
+                self.options
+                    .ingest_limits
+                    .check_texts(value.len())
+                    .map_err(|limit| limit.exceeded_by(key))?;
+
                 let mut map: HashMap<String, JSONGetTextValue<'static>> =
                     HashMap::with_capacity(value.len());
 
@@ -136,14 +187,20 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key));
         }
 
+        self.options.ingest_limits.check_map(&map).map_err(|limit| limit.exceeded_by(key))?;
+
         self.context.insert(key, map);
 
         Ok(self)
     }
 
-    /// Build a `JSONGetText` instance.
-    pub fn build(self) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
-        JSONGetText::from_context_with_default_key(self.default_key, self.context)
+    /// Build a `JSONGetText` instance. A text can embed another text of the same key with `{#text}` (use `{{#text}}` for the literal), which is inlined here, after the missing texts of the key are filled from the default key. A reference to a missing text or a cycle of references is an error, and so is a placeholder whose default text id (`{name|text_id}`) is not in the key.
+    pub fn build(mut self) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
+        if let Some(error) = self.options.extend_error.take() {
+            return Err(error.into());
+        }
+
+        JSONGetText::from_context_with_default_key(self.default_key, self.context, self.options)
     }
 }
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::JSONGetTextValue;
+use crate::{JSONGetTextValue, LocaleShard};
 
 mod json_get_text_builder;
 mod json_gettext;
@@ -18,3 +18,5 @@ pub use keys::*;
 pub use rocket_feature::*;
 
 pub type Context<'a> = HashMap<Key, HashMap<String, JSONGetTextValue<'a>>>;
+
+pub(crate) type ShardedContext<'a> = HashMap<Key, LocaleShard<'a>>;
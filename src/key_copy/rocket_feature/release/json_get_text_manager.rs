@@ -29,6 +29,14 @@ impl JSONGetTextManager {
     }
 }
 
+impl JSONGetTextManager {
+    /// Get the `JSONGetText` instance. The JSON files are not reloaded in the `release` profile, so this is just a reference.
+    #[inline]
+    pub fn read(&self) -> &JSONGetText<'static> {
+        &self.json_gettext
+    }
+}
+
 impl JSONGetTextManager {
     /// Create the fairing of `JSONGetTextManager`.
     pub fn fairing<F>(f: F) -> impl Fairing
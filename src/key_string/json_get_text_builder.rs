@@ -1,19 +1,23 @@
 use std::collections::HashMap;
-use std::fs::File;
+#[cfg(feature = "fs")]
 use std::path::Path;
 
 use serde::Serialize;
 use serde_json::{Map, Value};
 
+use crate::build_options::BuildOptions;
+#[cfg(feature = "fs")]
+use crate::CatalogId;
 use crate::JSONGetTextBuildError;
 
-use super::{Context, JSONGetText, JSONGetTextValue};
+use super::{Context, JSONGetText, JSONGetTextValue, Key};
 
 /// To build a JSONGetText instance, this struct can help you do that step by step.
 #[derive(Debug, Clone)]
 pub struct JSONGetTextBuilder<'a> {
-    default_key: String,
-    context: Context<'a>,
+    pub(crate) default_key: String,
+    pub(crate) context: Context<'a>,
+    pub(crate) options: BuildOptions,
 }
 
 impl<'a> JSONGetTextBuilder<'a> {
@@ -23,6 +27,7 @@ impl<'a> JSONGetTextBuilder<'a> {
         JSONGetTextBuilder {
             default_key: default_key.into(),
             context: HashMap::new(),
+            options: BuildOptions::default(),
         }
     }
 
@@ -36,7 +41,19 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key.into().into()));
         }
 
-        let map: HashMap<String, JSONGetTextValue<'a>> = serde_json::from_str(json.as_ref())?;
+        let json = json.as_ref();
+
+        self.options
+            .ingest_limits
+            .check_json(json.as_bytes())
+            .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
+
+        let map: HashMap<String, JSONGetTextValue<'a>> = serde_json::from_str(json)?;
+
+        self.options
+            .ingest_limits
+            .check_texts(map.len())
+            .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
 
         self.context.insert(key.into().into(), map);
 
@@ -53,7 +70,19 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key.into().into()));
         }
 
-        let value: Map<String, Value> = serde_json::from_str(json.as_ref())?;
+        let json = json.as_ref();
+
+        self.options
+            .ingest_limits
+            .check_json(json.as_bytes())
+            .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
+
+        let value: Map<String, Value> = serde_json::from_str(json)?;
+
+        self.options
+            .ingest_limits
+            .check_texts(value.len())
+            .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
 
         let mut map: HashMap<String, JSONGetTextValue<'static>> =
             HashMap::with_capacity(value.len());
@@ -68,6 +97,7 @@ impl<'a> JSONGetTextBuilder<'a> {
     }
 
     /// Add a JSON file to the context for a specify key. The JSON file must represent a map object (key-value).
+    #[cfg(feature = "fs")]
     pub fn add_json_file<K: AsRef<str> + Into<String>, P: AsRef<Path>>(
         &mut self,
         key: K,
@@ -77,9 +107,15 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key.into().into()));
         }
 
-        let path = path.as_ref();
+        let limits = self.options.ingest_limits;
 
-        let value: Map<String, Value> = serde_json::from_reader(File::open(path)?)?;
+        let json = limits.read_file(&Key::from(key.as_ref()), path.as_ref())?;
+
+        limits.check_json(&json).map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
+
+        let value: Map<String, Value> = serde_json::from_slice(&json)?;
+
+        limits.check_texts(value.len()).map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
 
         let mut map: HashMap<String, JSONGetTextValue<'static>> =
             HashMap::with_capacity(value.len());
@@ -88,7 +124,14 @@ impl<'a> JSONGetTextBuilder<'a> {
             map.insert(k, JSONGetTextValue::from_json_value(v));
         }
 
-        self.context.insert(key.into().into(), map);
+        let key: String = key.into();
+
+        #[cfg(feature = "watch")]
+        self.options.json_files.push((key.as_str().into(), path.as_ref().to_path_buf()));
+
+        self.record_file_modified(CatalogId::new(key.as_str().into(), None), path.as_ref());
+
+        self.context.insert(key.into(), map);
 
         Ok(self)
     }
@@ -105,8 +148,18 @@ impl<'a> JSONGetTextBuilder<'a> {
 
         let value: Value = serde_json::to_value(value)?;
 
+        self.options
+            .ingest_limits
+            .check_value(&value, 0)
+            .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
+
         match value {
             Value::Object(value) => {
+                self.options
+                    .ingest_limits
+                    .check_texts(value.len())
+                    .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
+
                 let mut map: HashMap<String, JSONGetTextValue<'static>> =
                     HashMap::with_capacity(value.len());
 
@@ -136,14 +189,23 @@ impl<'a> JSONGetTextBuilder<'a> {
             return Err(JSONGetTextBuildError::DuplicatedKey(key.into().into()));
         }
 
+        self.options
+            .ingest_limits
+            .check_map(&map)
+            .map_err(|limit| limit.exceeded_by(key.as_ref().into()))?;
+
         self.context.insert(key.into().into(), map);
 
         Ok(self)
     }
 
-    /// Build a `JSONGetText` instance.
-    pub fn build(self) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
-        JSONGetText::from_context_with_default_key(self.default_key, self.context)
+    /// Build a `JSONGetText` instance. A text can embed another text of the same key with `{#text}` (use `{{#text}}` for the literal), which is inlined here, after the missing texts of the key are filled from the default key. A reference to a missing text or a cycle of references is an error, and so is a placeholder whose default text id (`{name|text_id}`) is not in the key.
+    pub fn build(mut self) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
+        if let Some(error) = self.options.extend_error.take() {
+            return Err(error.into());
+        }
+
+        JSONGetText::from_context_with_default_key(self.default_key, self.context, self.options)
     }
 }
 
@@ -153,3 +215,10 @@ impl<'a> From<String> for JSONGetTextBuilder<'a> {
         JSONGetTextBuilder::new(v)
     }
 }
+
+impl<'a> From<Key> for JSONGetTextBuilder<'a> {
+    #[inline]
+    fn from(v: Key) -> JSONGetTextBuilder<'a> {
+        JSONGetTextBuilder::new(v)
+    }
+}
@@ -1,16 +1,58 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::SystemTime;
 
+#[cfg(feature = "regex")]
 use regex::Regex;
 
-use crate::{JSONGetTextBuildError, JSONGetTextValue};
-
-use super::{Context, JSONGetTextBuilder};
+use crate::build_options::BuildOptions;
+use crate::deprecation::collect_deprecated_texts;
+use crate::empty::EmptyText;
+use crate::fallback::collect_fallback_texts;
+use crate::format::validate_placeholder_defaults;
+#[cfg(feature = "http")]
+use crate::http::RemoteCatalogs;
+use crate::migration::validate_migrations;
+use crate::missing::MissingHandler;
+use crate::modified::CatalogId;
+use crate::namespace::merge_namespaces;
+use crate::observer::Observer;
+use crate::pipeline::{Findings, Pipeline};
+#[cfg(feature = "plural_rules")]
+use crate::plural::PluralRulesCache;
+use crate::read_through::ReadThrough;
+use crate::reference::inline_references;
+use crate::scan::QuarantinedText;
+use crate::spell_out::add_spell_out_key;
+use crate::strictness::ExtraText;
+use crate::{JSONGetTextBuildError, JSONGetTextValue, NumberSymbols};
+
+use super::{Context, JSONGetTextBuilder, Key, ShardedContext};
 
 /// A wrapper for context and a default key. **Keys** are usually considered as locales.
-#[derive(Debug)]
+#[derive(Clone)]
 pub struct JSONGetText<'a> {
-    default_key: String,
-    context: Context<'a>,
+    pub(crate) default_key: String,
+    pub(crate) context: ShardedContext<'a>,
+    pub(crate) filled: HashMap<Key, HashSet<String>>,
+    pub(crate) stale: HashMap<Key, HashSet<String>>,
+    pub(crate) deprecated: HashMap<String, Option<String>>,
+    pub(crate) migrations: HashMap<String, String>,
+    pub(crate) quarantined: Vec<QuarantinedText>,
+    pub(crate) extra_texts: Vec<ExtraText>,
+    pub(crate) empty_texts: Vec<EmptyText>,
+    pub(crate) observer: Option<Observer>,
+    pub(crate) missing_handler: Option<MissingHandler>,
+    pub(crate) read_through: Option<ReadThrough>,
+    pub(crate) pipeline: Pipeline,
+    #[cfg(feature = "http")]
+    pub(crate) remote_catalogs: RemoteCatalogs,
+    pub(crate) last_modified: HashMap<CatalogId, SystemTime>,
+    pub(crate) namespaces: HashSet<String>,
+    pub(crate) number_symbols: HashMap<Key, NumberSymbols>,
+    #[cfg(feature = "plural_rules")]
+    pub(crate) plural_rules: PluralRulesCache,
+    pub(crate) locale_groups: HashMap<String, Vec<Key>>,
 }
 
 impl<'a> JSONGetText<'a> {
@@ -24,47 +66,90 @@ impl<'a> JSONGetText<'a> {
     pub(crate) fn from_context_with_default_key<S: AsRef<str> + Into<String>>(
         default_key: S,
         mut context: Context<'a>,
+        mut options: BuildOptions,
     ) -> Result<JSONGetText<'a>, JSONGetTextBuildError> {
+        let namespaces = merge_namespaces(&mut context, std::mem::take(&mut options.namespaces))?;
+
         if !context.contains_key(default_key.as_ref()) {
             return Err(JSONGetTextBuildError::DefaultKeyNotFound);
         }
 
         let default_key = default_key.into();
 
-        let default_map = context.remove(&default_key).unwrap();
+        let mut findings = Findings::default();
+
+        options.pipeline.prepare(&default_key, &mut context, &mut findings)?;
+
+        if let Some(key) = options.spell_out_key.as_ref() {
+            add_spell_out_key(&mut context, &default_key, key)?;
+        }
+
+        let mut fallback_texts =
+            collect_fallback_texts(&context, &default_key, &options.pipeline.fallbacks)?;
+
+        let mut default_map = context.remove(&default_key).unwrap();
+
+        validate_migrations(&options.migrations, &default_map)?;
+
+        let deprecated = collect_deprecated_texts(&default_map);
 
         let mut inner_context = HashMap::new();
+        let mut filled = HashMap::new();
+        let mut stale = HashMap::new();
 
         {
             for (key, mut map) in context {
-                {
-                    for map_key in map.keys() {
-                        if !default_map.contains_key(map_key) {
-                            return Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey {
-                                key,
-                                text: map_key.clone(),
-                            });
-                        }
-                    }
-                }
-
-                {
-                    for map_key in default_map.keys() {
-                        if !map.contains_key(map_key) {
-                            map.insert(map_key.clone(), default_map.get(map_key).unwrap().clone());
-                        }
-                    }
-                }
-
-                inner_context.insert(key, map);
+                let (filled_texts, stale_texts) = options.pipeline.complete(
+                    &key,
+                    &mut map,
+                    &default_map,
+                    fallback_texts.remove(&key),
+                    &mut findings.extra_texts,
+                )?;
+
+                filled.insert(key.clone(), filled_texts);
+                stale.insert(key.clone(), stale_texts);
+                inner_context.insert(key, Arc::new(map));
             }
 
-            inner_context.insert(default_key.clone().into(), default_map);
+            inline_references(&Key(default_key.clone()), &mut default_map)?;
+            validate_placeholder_defaults(&Key(default_key.clone()), &default_map)?;
+
+            inner_context.insert(default_key.clone().into(), Arc::new(default_map));
+        }
+
+        findings.extra_texts.sort_unstable();
+
+        #[cfg(feature = "plural_rules")]
+        let mut plural_rules = PluralRulesCache::default();
+
+        #[cfg(feature = "plural_rules")]
+        for key in inner_context.keys() {
+            plural_rules.cache(key);
         }
 
         Ok(JSONGetText {
             default_key,
             context: inner_context,
+            filled,
+            stale,
+            deprecated,
+            migrations: options.migrations,
+            quarantined: findings.quarantined,
+            extra_texts: findings.extra_texts,
+            empty_texts: findings.empty_texts,
+            observer: options.observer,
+            missing_handler: options.missing_handler,
+            read_through: options.read_through,
+            pipeline: options.pipeline,
+            #[cfg(feature = "http")]
+            remote_catalogs: options.remote_catalogs,
+            last_modified: options.last_modified,
+            namespaces,
+            number_symbols: options.number_symbols,
+            #[cfg(feature = "plural_rules")]
+            plural_rules,
+            locale_groups: options.locale_groups,
         })
     }
 
@@ -94,42 +179,38 @@ impl<'a> JSONGetText<'a> {
         }
     }
 
-    /// Get text from context.
+    /// Get text from context. A dot path such as `menu.file.open` finds a text in nested objects.
     #[inline]
     pub fn get_text<T: AsRef<str>>(&'a self, text: T) -> Option<JSONGetTextValue<'a>> {
-        let map = self.context.get(&self.default_key).unwrap();
+        let (key, map) = self.default_entry();
 
-        map.get(text.as_ref()).map(|v| v.clone_borrowed())
+        self.lookup_text_or_missing(key, map, text.as_ref())
     }
 
-    /// Get text from context with a specific key.
+    /// Get text from context with a specific key. A dot path such as `menu.file.open` finds a text in nested objects.
     #[inline]
     pub fn get_text_with_key<K: AsRef<str>, T: AsRef<str>>(
         &'a self,
         key: K,
         text: T,
     ) -> Option<JSONGetTextValue<'a>> {
-        let map = self
-            .context
-            .get(key.as_ref())
-            .unwrap_or_else(|| self.context.get(&self.default_key).unwrap());
+        let (key, map) = self.find_entry_or_default(key.as_ref());
 
-        map.get(text.as_ref()).map(|v| v.clone_borrowed())
+        self.lookup_text_or_missing(key, map, text.as_ref())
     }
 
     /// Get multiple text from context. The output map is usually used for serialization.
     pub fn get_multiple_text<'b, T: AsRef<str> + ?Sized>(
         &self,
         text_array: &[&'b T],
-    ) -> Option<HashMap<&'b str, JSONGetTextValue>> {
-        let map = self.context.get(&self.default_key).unwrap();
+    ) -> Option<HashMap<&'b str, JSONGetTextValue<'_>>> {
+        let (key, map) = self.default_entry();
 
         let mut new_map = HashMap::new();
 
         for &text in text_array.iter() {
             let text = text.as_ref();
-            let value = map.get(text)?;
-            new_map.insert(text, value.clone_borrowed());
+            new_map.insert(text, self.lookup_text_or_missing(key, map, text)?);
         }
 
         Some(new_map)
@@ -141,23 +222,20 @@ impl<'a> JSONGetText<'a> {
         key: K,
         text_array: &[&'b T],
     ) -> Option<HashMap<&'b str, JSONGetTextValue<'a>>> {
-        let map = self
-            .context
-            .get(key.as_ref())
-            .unwrap_or_else(|| self.context.get(&self.default_key).unwrap());
+        let (key, map) = self.find_entry_or_default(key.as_ref());
 
         let mut new_map = HashMap::new();
 
         for &text in text_array.iter() {
             let text = text.as_ref();
-            let value = map.get(text)?;
-            new_map.insert(text, value.clone_borrowed());
+            new_map.insert(text, self.lookup_text_or_missing(key, map, text)?);
         }
 
         Some(new_map)
     }
 
     /// Get filtered text from context by a Regex instance. The output map is usually used for serialization.
+    #[cfg(feature = "regex")]
     pub fn get_filtered_text(
         &'a self,
         regex: &Regex,
@@ -177,6 +255,7 @@ impl<'a> JSONGetText<'a> {
     }
 
     /// Get filtered text from context with a specific key by a Regex instance. The output map is usually used for serialization.
+    #[cfg(feature = "regex")]
     pub fn get_filtered_text_with_key<K: AsRef<str>>(
         &'a self,
         key: K,
@@ -9,7 +9,7 @@ use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
 use std::ops::Deref;
 
-use crate::JSONGetTextValue;
+use crate::{JSONGetTextValue, LocaleShard};
 
 pub use self::json_gettext::*;
 
@@ -49,6 +49,27 @@ impl From<String> for Key {
     }
 }
 
+impl From<&str> for Key {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Key(String::from(s))
+    }
+}
+
+impl From<Key> for String {
+    #[inline]
+    fn from(key: Key) -> Self {
+        key.0
+    }
+}
+
+impl AsRef<str> for Key {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
 impl Borrow<str> for Key {
     #[inline]
     fn borrow(&self) -> &str {
@@ -74,6 +95,8 @@ impl Deref for Key {
 
 pub type Context<'a> = HashMap<Key, HashMap<String, JSONGetTextValue<'a>>>;
 
+pub(crate) type ShardedContext<'a> = HashMap<Key, LocaleShard<'a>>;
+
 /**
 Create a literal key.
 
@@ -1,24 +1,24 @@
 extern crate rocket;
 
 use std::collections::HashMap;
-use std::mem;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, TryLockError};
 use std::time::SystemTime;
 
 use crate::{
-    DebuggableMutate, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextFairing,
+    JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextFairing, ReadGuard,
+    SharedJSONGetText,
 };
 
 use rocket::fairing::Fairing;
 
 #[derive(Debug)]
 pub struct JSONGetTextManager {
-    empty: JSONGetText<'static>,
-    json_gettext: DebuggableMutate<JSONGetText<'static>>,
-    files: DebuggableMutate<HashMap<&'static str, (PathBuf, Option<SystemTime>)>>,
-    reloading: AtomicBool,
+    json_gettext: SharedJSONGetText,
+    files: Mutex<HashMap<&'static str, (PathBuf, Option<SystemTime>)>>,
+    /// The instances replaced by reloads, kept until the manager is dropped so that the references handed out by `deref` stay valid.
+    replaced: Mutex<Vec<Arc<JSONGetText<'static>>>>,
 }
 
 impl JSONGetTextManager {
@@ -42,78 +42,64 @@ impl JSONGetTextManager {
             files.insert(key, (json_file_path, mtime));
         }
 
-        let mut empty_builder = JSONGetTextBuilder::new(default_key);
-
-        empty_builder.add_map(default_key, HashMap::new())?;
-
         Ok(JSONGetTextManager {
-            empty: empty_builder.build()?,
-            json_gettext: DebuggableMutate::new(builder.build()?),
-            files: DebuggableMutate::new(files),
-            reloading: AtomicBool::new(false),
+            json_gettext: SharedJSONGetText::new(builder.build()?),
+            files: Mutex::new(files),
+            replaced: Mutex::new(Vec::new()),
         })
     }
 
+    /// Get the current `JSONGetText` instance. This never waits for a reload.
+    #[inline]
+    pub fn read(&self) -> ReadGuard {
+        self.json_gettext.read()
+    }
+
+    /// Rebuild the `JSONGetText` instance if any of the JSON files has been modified. If another thread is already doing that, return immediately.
     pub fn reload_if_needed(&self) -> Result<(), JSONGetTextBuildError> {
-        if self
-            .reloading
-            .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
-            .is_ok()
-        {
-            let mut do_reload = false;
-
-            let files = self.files.get_mut();
-
-            for (_, (path, mtime)) in files.iter_mut() {
-                let metadata = path.metadata().map_err(|err| {
-                    self.reloading.store(false, Ordering::Relaxed);
-
-                    err
-                })?;
-
-                let (reload, new_mtime) = match mtime {
-                    Some(mtime) => {
-                        match metadata.modified() {
-                            Ok(new_mtime) => (new_mtime > *mtime, Some(new_mtime)),
-                            Err(_) => (true, None),
-                        }
+        let mut files = match self.files.try_lock() {
+            Ok(files) => files,
+            Err(TryLockError::WouldBlock) => return Ok(()),
+            Err(TryLockError::Poisoned(err)) => err.into_inner(),
+        };
+
+        let mut do_reload = false;
+
+        for (_, (path, mtime)) in files.iter_mut() {
+            let metadata = path.metadata()?;
+
+            let (reload, new_mtime) = match mtime {
+                Some(mtime) => {
+                    match metadata.modified() {
+                        Ok(new_mtime) => (new_mtime > *mtime, Some(new_mtime)),
+                        Err(_) => (true, None),
                     }
-                    None => {
-                        match metadata.modified() {
-                            Ok(new_mtime) => (true, Some(new_mtime)),
-                            Err(_) => (true, None),
-                        }
+                }
+                None => {
+                    match metadata.modified() {
+                        Ok(new_mtime) => (true, Some(new_mtime)),
+                        Err(_) => (true, None),
                     }
-                };
-
-                if reload {
-                    *mtime = new_mtime;
-
-                    do_reload = true;
                 }
-            }
+            };
 
-            if do_reload {
-                let mut builder = JSONGetTextBuilder::new(self.get_default_key());
+            if reload {
+                *mtime = new_mtime;
 
-                for (&key, (path, _)) in files {
-                    builder.add_json_file(key, path).map_err(|err| {
-                        self.reloading.store(false, Ordering::Relaxed);
-
-                        err
-                    })?;
-                }
-
-                let json_gettext = builder.build().map_err(|err| {
-                    self.reloading.store(false, Ordering::Relaxed);
+                do_reload = true;
+            }
+        }
 
-                    err
-                })?;
+        if do_reload {
+            let mut builder = JSONGetTextBuilder::new(self.read().get_default_key());
 
-                drop(mem::replace(self.json_gettext.get_mut(), json_gettext));
+            for (&key, (path, _)) in files.iter() {
+                builder.add_json_file(key, path)?;
             }
 
-            self.reloading.store(false, Ordering::Relaxed);
+            let replaced = self.json_gettext.replace(builder.build()?);
+
+            self.replaced.lock().unwrap_or_else(PoisonError::into_inner).push(replaced);
         }
 
         Ok(())
@@ -131,16 +117,15 @@ impl JSONGetTextManager {
     }
 }
 
-impl<'a> Deref for JSONGetTextManager {
+impl Deref for JSONGetTextManager {
     type Target = JSONGetText<'static>;
 
+    /// Get the current `JSONGetText` instance. Unlike `read`, the reference is not a snapshot which the caller owns, but it stays valid after a reload, because the replaced instances are kept until the manager is dropped.
     #[inline]
     fn deref(&self) -> &JSONGetText<'static> {
-        // NOTICE: Not always safe!
-        if self.reloading.load(Ordering::Relaxed) {
-            &self.empty
-        } else {
-            self.json_gettext.get()
-        }
+        let ctx = self.json_gettext.load_full();
+
+        // SAFETY: the instance is held by `json_gettext`, and after it is replaced, by `replaced`, which never drops it while `self` is alive
+        unsafe { &*Arc::as_ptr(&ctx) }
     }
 }
@@ -62,7 +62,79 @@ fn rocket() -> _ {
 }
 ```
 
-If you are not using the `release` profile, `JSONGetTextManager` can reload the json files automatically if needed.
+If you are not using the `release` profile, `JSONGetTextManager` can reload the json files automatically if needed. In that case, it keeps the `JSONGetText` instance in a `SharedJSONGetText`, whose read path is lock-free, so requests are never stalled by a reload. Its `read` method returns a `ReadGuard`, a snapshot which stays the same while it is held.
+
+## Axum Support
+
+Enable the `axum` feature to use the `Text` extractor, which negotiates the locale of a request from its query string, cookie and `Accept-Language` header. Share the `JSONGetText` instance with handlers by putting a `JSONGetTextState` in the state of the `Router`, either directly or as a field of your own state which implements `FromRef`.
+
+```rust,ignore
+#[macro_use] extern crate json_gettext;
+
+use axum::{routing::get, Router};
+
+use json_gettext::{JSONGetTextState, Text};
+
+async fn hello(text: Text) -> String {
+    format!("Ron: {}", get_text!(text, "hello").unwrap().as_str().unwrap())
+}
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+).unwrap();
+
+let app: Router = Router::new().route("/", get(hello)).with_state(JSONGetTextState::new(ctx));
+```
+
+## Actix Web Support
+
+Enable the `actix-web` feature to register `JSONGetTextMiddleware`, which negotiates the locale of each request, and use the `I18n` extractor to get texts in that locale.
+
+```rust,ignore
+#[macro_use] extern crate json_gettext;
+
+use actix_web::{web, App, HttpServer};
+
+use json_gettext::{I18n, JSONGetTextMiddleware};
+
+async fn hello(i18n: I18n) -> String {
+    format!("Ron: {}", i18n.t("hello").unwrap())
+}
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+).unwrap();
+
+let middleware = JSONGetTextMiddleware::new(ctx);
+
+HttpServer::new(move || App::new().wrap(middleware.clone()).route("/", web::get().to(hello)))
+    .bind(("127.0.0.1", 8080))?
+    .run()
+    .await
+```
+
+## Lookup-only Builds
+
+The methods which read catalogs from files and directories (e.g. `add_json_file` and `add_directory`) are behind the `fs` feature, which is enabled by default. If your catalogs are embedded in the program, e.g. with the `static_json_gettext_build` macro, you can disable the default features to build without file IO, for example for embedded or wasm targets.
+
+```toml
+[dependencies.json-gettext]
+version = "*"
+default-features = false
+```
+
+Values are JSON values, so `serde_json` is always a dependency. Each other optional dependency is behind the default feature that uses it:
+
+* `regex` for `get_filtered_text`, deserialization error details and the naming convention of `IdLint`.
+* `memchr` for SIMD searching of placeholders and references. Without it, the standard library search is used.
+* `plural_rules` for the CLDR plural rules. Without it, every language is pluralized like English.
+* `likely_subtags` for maximizing language tags in `negotiate`, e.g. matching `zh-TW` to `zh_Hant`. Without it, only explicit scripts and regions are compared.
+* `content_hash` for content hashes, source hashes of exported translations and search documents.
+* `ansi` for measuring the display width of padded placeholders. Without it, the width is the number of characters.
 
 ## `unic-langid` Support
 
@@ -128,24 +200,172 @@ pub extern crate unic_langid_macros;
 #[doc(hidden)]
 pub extern crate manifest_dir_macros;
 
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub extern crate log;
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub extern crate tracing;
+
+#[cfg(feature = "tokio")]
+mod async_fs;
+mod budget;
+mod build_options;
+mod check;
+mod collect;
+mod compare;
+mod concurrent;
+mod coverage;
+mod deprecation;
+#[cfg(feature = "regex")]
+mod deserialize_error;
+#[cfg(feature = "fs")]
+mod directory;
+#[cfg(feature = "fs")]
+mod dry_run;
+mod email;
+mod empty;
+mod error_catalog;
+mod example;
+mod export;
+mod fallback;
+mod fill;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod format;
+mod freeze;
+mod health;
+#[cfg(feature = "http")]
+mod http;
+mod ingest;
+mod iter;
+mod joining;
 mod json_get_text_build_errors;
+mod locale;
+mod locale_data;
+mod locale_group;
+mod localized_display;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging;
+mod lookup;
 mod macros;
+mod merge;
+mod metadata;
+mod migration;
+mod missing;
+mod model;
+mod modified;
+mod mutation;
+mod namespace;
+mod observer;
+mod openapi;
+mod pair;
+mod pipeline;
+mod plural;
+#[cfg(feature = "po")]
+mod po;
+mod preflight;
+mod read_through;
+mod reference;
+#[cfg(feature = "watch")]
+mod reloadable;
+mod scan;
+mod shard;
+mod shared;
+mod slug;
+mod spell_out;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod stats;
+mod strictness;
+mod suggest;
+#[cfg(feature = "toml")]
+mod toml;
+mod typography;
+mod validation;
 mod value;
+mod web;
+#[cfg(feature = "fs")]
+mod write_back;
+#[cfg(feature = "yaml")]
+mod yaml;
 
-#[cfg(all(debug_assertions, feature = "rocket"))]
-mod mutate;
+#[cfg(feature = "segmentation")]
+mod segmentation;
 
-#[cfg(all(feature = "langid"))]
+#[cfg(feature = "langid")]
 mod key_copy;
 
 #[cfg(not(feature = "langid"))]
 mod key_string;
 
+pub use budget::*;
+pub use check::*;
+pub use concurrent::SharedJSONGetTextBuilder;
+pub use coverage::Coverage;
+pub use deprecation::*;
+#[cfg(feature = "regex")]
+pub use deserialize_error::*;
+#[cfg(feature = "fs")]
+pub use directory::*;
+#[cfg(feature = "fs")]
+pub use dry_run::*;
+pub use email::*;
+pub use empty::{EmptyKind, EmptyText, EmptyTextPolicy};
+pub use error_catalog::*;
+#[cfg(feature = "fs")]
+pub use example::scaffold_locales;
+pub use export::*;
+pub use format::*;
+pub use health::*;
+pub use ingest::{IngestLimit, IngestLimits};
+pub use joining::*;
 pub use json_get_text_build_errors::*;
+pub use locale_data::*;
+pub use localized_display::LocalizedDisplay;
+pub use merge::ConflictPolicy;
+pub use model::CatalogModel;
+pub use modified::CatalogId;
+#[cfg(feature = "embed_dir")]
+pub use namespace::CrateNamespace;
+pub use observer::LookupObserver;
+pub use openapi::*;
+pub use pair::TextPair;
+pub use plural::PluralCategory;
+pub use preflight::PreflightFailure;
+pub use read_through::{ReadThroughOptions, SecondaryContext};
+#[cfg(feature = "watch")]
+pub use reloadable::{JSONGetTextReloadable, ReloadStats};
+pub use scan::{ContentScanner, QuarantinedText, ScanVerdict};
+pub use shard::*;
+pub use shared::*;
+pub use slug::*;
+pub use spell_out::spell_out;
+pub use stats::{CatalogStats, LengthBucket};
+pub use strictness::{ExtraText, Strictness};
+pub use suggest::TextNotFound;
+pub use validation::*;
 pub use value::*;
+pub use web::*;
+
+#[cfg(feature = "segmentation")]
+pub use segmentation::*;
+
+#[cfg(feature = "derive")]
+pub use json_gettext_macros::LocalizedDisplay;
+
+#[cfg(feature = "checked_text")]
+pub use json_gettext_macros::t;
+
+#[cfg(feature = "embed_dir")]
+#[doc(hidden)]
+pub use json_gettext_macros::json_files;
+#[doc(hidden)]
+pub use macros::key_from_file_stem;
 
-#[cfg(all(debug_assertions, feature = "rocket"))]
-use mutate::DebuggableMutate;
+#[cfg(feature = "proptest")]
+pub use model::strategy;
 
 #[cfg(feature = "langid")]
 pub use key_copy::*;
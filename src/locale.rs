@@ -0,0 +1,108 @@
+use crate::Key;
+
+/// Get the language subtag of a key in lowercase, if it has one.
+pub(crate) fn language_of(key: &Key) -> Option<String> {
+    #[cfg(feature = "region")]
+    {
+        let _ = key;
+
+        None
+    }
+
+    #[cfg(not(feature = "region"))]
+    {
+        let s = key.to_string();
+
+        let language = s.split(['_', '-']).next().unwrap_or_default();
+
+        if (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic()) {
+            Some(language.to_ascii_lowercase())
+        } else {
+            None
+        }
+    }
+}
+
+/// Get the region subtag of a key in uppercase, if it has one.
+pub(crate) fn region_of(key: &Key) -> Option<String> {
+    let s = key.to_string();
+
+    #[cfg(feature = "region")]
+    let region = Some(s.as_str());
+
+    #[cfg(not(feature = "region"))]
+    let region = s.split(['_', '-']).skip(1).find(|subtag| {
+        (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+            || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+    });
+
+    region.map(|region| region.to_ascii_uppercase())
+}
+
+/// Returns `true` if two string keys are the same language tag, i.e. they only differ in case and in `-` and `_` separators, e.g. `zh-TW` and `zh_TW`.
+#[cfg(not(feature = "langid"))]
+pub(crate) fn is_same_tag(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes().zip(b.bytes()).all(|(a, b)| {
+            a.eq_ignore_ascii_case(&b) || (matches!(a, b'-' | b'_') && matches!(b, b'-' | b'_'))
+        })
+}
+
+/// Convert a BCP 47 language tag (e.g. `en-US`) into a key. For string keys, subtags are joined by `_` and cased conventionally (e.g. `en_US`, `zh_Hant_TW`).
+pub(crate) fn key_from_tag(tag: &str) -> Option<Key> {
+    let tag = tag.trim();
+
+    if tag.is_empty() || tag == "*" {
+        return None;
+    }
+
+    #[cfg(not(feature = "langid"))]
+    {
+        let mut subtags = Vec::new();
+
+        for (i, subtag) in tag.split(['_', '-']).enumerate() {
+            if subtag.is_empty() || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+
+            let subtag = if i == 0 {
+                subtag.to_ascii_lowercase()
+            } else if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+                let mut s = subtag.to_ascii_lowercase();
+                s[..1].make_ascii_uppercase();
+                s
+            } else if subtag.len() == 2 {
+                subtag.to_ascii_uppercase()
+            } else {
+                subtag.to_ascii_lowercase()
+            };
+
+            subtags.push(subtag);
+        }
+
+        Some(Key(subtags.join("_")))
+    }
+
+    #[cfg(feature = "language_region_pair")]
+    {
+        use std::str::FromStr;
+
+        Key::from_str(tag).ok()
+    }
+
+    #[cfg(feature = "language")]
+    {
+        use std::str::FromStr;
+
+        Key::from_str(tag.split(['_', '-']).next().unwrap()).ok()
+    }
+
+    #[cfg(feature = "region")]
+    {
+        use std::str::FromStr;
+
+        let langid = crate::unic_langid::LanguageIdentifier::from_str(tag).ok()?;
+
+        langid.region.map(Key)
+    }
+}
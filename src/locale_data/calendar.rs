@@ -0,0 +1,459 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use super::data_language;
+use crate::{JSONGetText, Key};
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All days of the week, from Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// The number of days since Monday.
+    #[inline]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The name used in text ids, e.g. `monday`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"][self.index()]
+    }
+}
+
+/// A month of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// All months of the year.
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// The number of months since January.
+    #[inline]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The name used in text ids, e.g. `january`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        [
+            "january",
+            "february",
+            "march",
+            "april",
+            "may",
+            "june",
+            "july",
+            "august",
+            "september",
+            "october",
+            "november",
+            "december",
+        ][self.index()]
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Weekday> for Weekday {
+    #[inline]
+    fn from(weekday: chrono::Weekday) -> Self {
+        Weekday::ALL[weekday.num_days_from_monday() as usize]
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Month> for Month {
+    #[inline]
+    fn from(month: chrono::Month) -> Self {
+        Month::ALL[month.number_from_month() as usize - 1]
+    }
+}
+
+/// The width of a calendar name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NameStyle {
+    /// e.g. `Monday`, `January`
+    #[default]
+    Wide,
+    /// e.g. `Mon`, `Jan`
+    Abbreviated,
+}
+
+impl NameStyle {
+    /// The name used in text ids.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NameStyle::Wide => "wide",
+            NameStyle::Abbreviated => "abbreviated",
+        }
+    }
+}
+
+impl Display for NameStyle {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Stand-alone names from CLDR, as (language, wide, abbreviated).
+type Names<const N: usize> = (&'static str, [&'static str; N], [&'static str; N]);
+
+static WEEKDAY_NAMES: [Names<7>; 11] = [
+    ("en", ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"], [
+        "Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun",
+    ]),
+    ("de", ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"], [
+        "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So.",
+    ]),
+    ("fr", ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"], [
+        "lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim.",
+    ]),
+    ("es", ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"], [
+        "lun", "mar", "mié", "jue", "vie", "sáb", "dom",
+    ]),
+    ("it", ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"], [
+        "lun", "mar", "mer", "gio", "ven", "sab", "dom",
+    ]),
+    (
+        "pt",
+        [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+    ),
+    (
+        "zh",
+        ["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"],
+        ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+    ),
+    (
+        "zh_Hant",
+        ["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"],
+        ["週一", "週二", "週三", "週四", "週五", "週六", "週日"],
+    ),
+    (
+        "ja",
+        ["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"],
+        ["月", "火", "水", "木", "金", "土", "日"],
+    ),
+    (
+        "ko",
+        ["월요일", "화요일", "수요일", "목요일", "금요일", "토요일", "일요일"],
+        ["월", "화", "수", "목", "금", "토", "일"],
+    ),
+    (
+        "ru",
+        ["понедельник", "вторник", "среда", "четверг", "пятница", "суббота", "воскресенье"],
+        ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+    ),
+];
+
+static MONTH_NAMES: [Names<12>; 11] = [
+    (
+        "en",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    ),
+    (
+        "de",
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        ["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"],
+    ),
+    (
+        "fr",
+        [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+    ),
+    (
+        "es",
+        [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+    ),
+    (
+        "it",
+        [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+    ),
+    (
+        "pt",
+        [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+    ),
+    (
+        "zh",
+        [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    ),
+    (
+        "zh_Hant",
+        [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    ),
+    (
+        "ja",
+        ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    ),
+    (
+        "ko",
+        ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"],
+        ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"],
+    ),
+    (
+        "ru",
+        [
+            "январь",
+            "февраль",
+            "март",
+            "апрель",
+            "май",
+            "июнь",
+            "июль",
+            "август",
+            "сентябрь",
+            "октябрь",
+            "ноябрь",
+            "декабрь",
+        ],
+        [
+            "янв.",
+            "февр.",
+            "март",
+            "апр.",
+            "май",
+            "июнь",
+            "июль",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+    ),
+];
+
+/// Find the embedded names for a key, falling back to English.
+fn builtin_names<const N: usize>(table: &'static [Names<N>], key: &Key) -> &'static Names<N> {
+    let language = data_language(key);
+
+    table.iter().find(|names| names.0 == language).unwrap_or(&table[0])
+}
+
+#[inline]
+fn pick<const N: usize>(names: &'static Names<N>, index: usize, style: NameStyle) -> &'static str {
+    match style {
+        NameStyle::Wide => names.1[index],
+        NameStyle::Abbreviated => names.2[index],
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the name of a day of the week with a specific key. The `calendar.weekdays.{wide|abbreviated}.{monday..sunday}` text is used if there is one, otherwise (including when the text is only copied from the default key) the name comes from embedded CLDR data for common languages, or is in English. If the key is not in context, the default key is used.
+    pub fn weekday_name<Q: ?Sized + Hash + Eq, W: Into<Weekday>>(
+        &self,
+        key: &Q,
+        weekday: W,
+        style: NameStyle,
+    ) -> String
+    where
+        Key: Borrow<Q>, {
+        let weekday = weekday.into();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        let id = format!("calendar.weekdays.{}.{}", style, weekday.as_str());
+
+        match self.lookup_own_text(key, map, &id) {
+            Some(value) => value,
+            None => pick(builtin_names(&WEEKDAY_NAMES, key), weekday.index(), style).to_string(),
+        }
+    }
+
+    /// Get the name of a month with a specific key. The `calendar.months.{wide|abbreviated}.{january..december}` text is used if there is one, otherwise (including when the text is only copied from the default key) the name comes from embedded CLDR data for common languages, or is in English. If the key is not in context, the default key is used.
+    pub fn month_name<Q: ?Sized + Hash + Eq, M: Into<Month>>(
+        &self,
+        key: &Q,
+        month: M,
+        style: NameStyle,
+    ) -> String
+    where
+        Key: Borrow<Q>, {
+        let month = month.into();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        let id = format!("calendar.months.{}.{}", style, month.as_str());
+
+        match self.lookup_own_text(key, map, &id) {
+            Some(value) => value,
+            None => pick(builtin_names(&MONTH_NAMES, key), month.index(), style).to_string(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+#[cfg(feature = "display_names")]
+use super::data_language;
+use crate::{JSONGetText, Key};
+
+/// The languages of the embedded names, in the order of the columns of the tables.
+#[cfg(feature = "display_names")]
+const DISPLAY_LANGUAGES: [&str; 7] = ["en", "de", "fr", "es", "zh", "zh_Hant", "ja"];
+
+/// Names of countries from CLDR.
+#[cfg(feature = "display_names")]
+static COUNTRY_NAMES: [(&str, [&str; 7]); 16] = [
+    ("BR", ["Brazil", "Brasilien", "Brésil", "Brasil", "巴西", "巴西", "ブラジル"]),
+    ("CA", ["Canada", "Kanada", "Canada", "Canadá", "加拿大", "加拿大", "カナダ"]),
+    ("CN", ["China", "China", "Chine", "China", "中国", "中國", "中国"]),
+    ("DE", ["Germany", "Deutschland", "Allemagne", "Alemania", "德国", "德國", "ドイツ"]),
+    ("ES", ["Spain", "Spanien", "Espagne", "España", "西班牙", "西班牙", "スペイン"]),
+    ("FR", ["France", "Frankreich", "France", "Francia", "法国", "法國", "フランス"]),
+    ("GB", [
+        "United Kingdom",
+        "Vereinigtes Königreich",
+        "Royaume-Uni",
+        "Reino Unido",
+        "英国",
+        "英國",
+        "イギリス",
+    ]),
+    ("IN", ["India", "Indien", "Inde", "India", "印度", "印度", "インド"]),
+    ("IT", ["Italy", "Italien", "Italie", "Italia", "意大利", "義大利", "イタリア"]),
+    ("JP", ["Japan", "Japan", "Japon", "Japón", "日本", "日本", "日本"]),
+    ("KR", ["South Korea", "Südkorea", "Corée du Sud", "Corea del Sur", "韩国", "南韓", "韓国"]),
+    ("MX", ["Mexico", "Mexiko", "Mexique", "México", "墨西哥", "墨西哥", "メキシコ"]),
+    ("NL", ["Netherlands", "Niederlande", "Pays-Bas", "Países Bajos", "荷兰", "荷蘭", "オランダ"]),
+    ("RU", ["Russia", "Russland", "Russie", "Rusia", "俄罗斯", "俄羅斯", "ロシア"]),
+    ("TW", ["Taiwan", "Taiwan", "Taïwan", "Taiwán", "台湾", "台灣", "台湾"]),
+    ("US", [
+        "United States",
+        "Vereinigte Staaten",
+        "États-Unis",
+        "Estados Unidos",
+        "美国",
+        "美國",
+        "アメリカ合衆国",
+    ]),
+];
+
+/// Names of languages from CLDR.
+#[cfg(feature = "display_names")]
+static LANGUAGE_NAMES: [(&str, [&str; 7]); 11] = [
+    ("de", ["German", "Deutsch", "allemand", "alemán", "德语", "德文", "ドイツ語"]),
+    ("en", ["English", "Englisch", "anglais", "inglés", "英语", "英文", "英語"]),
+    ("es", ["Spanish", "Spanisch", "espagnol", "español", "西班牙语", "西班牙文", "スペイン語"]),
+    ("fr", ["French", "Französisch", "français", "francés", "法语", "法文", "フランス語"]),
+    ("it", ["Italian", "Italienisch", "italien", "italiano", "意大利语", "義大利文", "イタリア語"]),
+    ("ja", ["Japanese", "Japanisch", "japonais", "japonés", "日语", "日文", "日本語"]),
+    ("ko", ["Korean", "Koreanisch", "coréen", "coreano", "韩语", "韓文", "韓国語"]),
+    ("nl", [
+        "Dutch",
+        "Niederländisch",
+        "néerlandais",
+        "neerlandés",
+        "荷兰语",
+        "荷蘭文",
+        "オランダ語",
+    ]),
+    ("pt", [
+        "Portuguese",
+        "Portugiesisch",
+        "portugais",
+        "portugués",
+        "葡萄牙语",
+        "葡萄牙文",
+        "ポルトガル語",
+    ]),
+    ("ru", ["Russian", "Russisch", "russe", "ruso", "俄语", "俄文", "ロシア語"]),
+    ("zh", ["Chinese", "Chinesisch", "chinois", "chino", "中文", "中文", "中国語"]),
+];
+
+/// Find an embedded name for a key, falling back to English.
+#[cfg(feature = "display_names")]
+fn builtin_name(table: &'static [(&str, [&str; 7])], key: &Key, code: &str) -> Option<String> {
+    let names = &table.iter().find(|(c, _)| *c == code)?.1;
+
+    let language = data_language(key);
+
+    let column = DISPLAY_LANGUAGES.iter().position(|l| *l == language).unwrap_or(0);
+
+    Some(names[column].to_string())
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the name of a country by its ISO 3166-1 alpha-2 code (e.g. `DE`) with a specific key. The `names.countries.{code}` text is used if there is one, otherwise, with the `display_names` feature, the name comes from embedded CLDR data for common countries. If the key is not in context, the default key is used.
+    pub fn country_name<Q: ?Sized + Hash + Eq, C: AsRef<str>>(
+        &self,
+        key: &Q,
+        code: C,
+    ) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let code = code.as_ref().to_ascii_uppercase();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        let name = self.lookup_own_text(key, map, &format!("names.countries.{}", code));
+
+        #[cfg(feature = "display_names")]
+        let name = name.or_else(|| builtin_name(&COUNTRY_NAMES, key, &code));
+
+        name
+    }
+
+    /// Get the name of a language by its ISO 639 code (e.g. `de`) with a specific key. The `names.languages.{code}` text is used if there is one, otherwise, with the `display_names` feature, the name comes from embedded CLDR data for common languages. If the key is not in context, the default key is used.
+    pub fn language_name<Q: ?Sized + Hash + Eq, C: AsRef<str>>(
+        &self,
+        key: &Q,
+        code: C,
+    ) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let code = code.as_ref().to_ascii_lowercase();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        let name = self.lookup_own_text(key, map, &format!("names.languages.{}", code));
+
+        #[cfg(feature = "display_names")]
+        let name = name.or_else(|| builtin_name(&LANGUAGE_NAMES, key, &code));
+
+        name
+    }
+}
@@ -0,0 +1,55 @@
+mod calendar;
+mod display_names;
+mod numbers;
+mod order;
+mod phone;
+mod timezones;
+mod week;
+
+pub use calendar::*;
+pub use numbers::*;
+pub use order::*;
+pub use phone::*;
+pub use timezones::*;
+pub use week::*;
+
+#[cfg(feature = "likely_subtags")]
+use unic_langid::LanguageIdentifier;
+
+use crate::locale::{language_of, region_of};
+use crate::Key;
+
+/// Get the language which embedded locale data is looked up by. Traditional Chinese is `zh_Hant`.
+fn data_language(key: &Key) -> String {
+    let language = language_of(key).unwrap_or_default();
+
+    if language == "zh"
+        && (key.to_string().contains("Hant")
+            || matches!(region_of(key).as_deref(), Some("TW" | "HK" | "MO")))
+    {
+        String::from("zh_Hant")
+    } else {
+        language
+    }
+}
+
+/// Get the region of a key, or the likely region of its language if it has no region (e.g. `US` for `en` and `TW` for `zh_Hant`). Likely regions need the `likely_subtags` feature.
+fn likely_region_of(key: &Key) -> Option<String> {
+    region_of(key).or_else(|| likely_region_of_language(key))
+}
+
+#[cfg(feature = "likely_subtags")]
+#[inline]
+fn likely_region_of_language(key: &Key) -> Option<String> {
+    let mut langid: LanguageIdentifier = key.to_string().replace('_', "-").parse().ok()?;
+
+    langid.maximize();
+
+    langid.region.map(|region| region.as_str().to_string())
+}
+
+#[cfg(not(feature = "likely_subtags"))]
+#[inline]
+fn likely_region_of_language(_key: &Key) -> Option<String> {
+    None
+}
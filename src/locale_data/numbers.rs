@@ -0,0 +1,196 @@
+use std::borrow::Borrow;
+use std::fmt::Write;
+use std::hash::Hash;
+
+use super::data_language;
+use crate::locale::region_of;
+use crate::{JSONGetText, JSONGetTextBuilder, Key};
+
+/// The symbols and grouping of numbers of a locale, from the number data of CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberSymbols {
+    pub decimal: char,
+    pub group: char,
+    /// The size of the groups after the one nearest to the decimal separator, which is always 3, e.g. `2` in India (`12,34,567`).
+    pub secondary_grouping: usize,
+    /// The number of digits the integer part needs to have more than 3 of to be grouped, e.g. `2` in Spanish, where `1000` is not grouped but `10.000` is.
+    pub minimum_grouping_digits: usize,
+}
+
+impl Default for NumberSymbols {
+    #[inline]
+    fn default() -> Self {
+        NumberSymbols::new('.', ',')
+    }
+}
+
+/// The symbols of languages, as `(language, decimal, group)`. Languages not listed use `.` and `,`.
+const LANGUAGE_SYMBOLS: [(&str, char, char); 19] = [
+    ("cs", ',', '\u{a0}'),
+    ("da", ',', '.'),
+    ("de", ',', '.'),
+    ("el", ',', '.'),
+    ("es", ',', '.'),
+    ("fi", ',', '\u{a0}'),
+    ("fr", ',', '\u{202f}'),
+    ("id", ',', '.'),
+    ("it", ',', '.'),
+    ("nb", ',', '\u{a0}'),
+    ("nl", ',', '.'),
+    ("pl", ',', '\u{a0}'),
+    ("pt", ',', '.'),
+    ("ru", ',', '\u{a0}'),
+    ("sk", ',', '\u{a0}'),
+    ("sv", ',', '\u{a0}'),
+    ("tr", ',', '.'),
+    ("uk", ',', '\u{a0}'),
+    ("vi", ',', '.'),
+];
+
+/// The symbols of regions which differ from the ones of their languages, as `(language, region, decimal, group)`.
+const REGION_SYMBOLS: [(&str, &str, char, char); 5] = [
+    ("de", "CH", '.', '’'),
+    ("de", "LI", '.', '’'),
+    ("es", "MX", '.', ','),
+    ("es", "US", '.', ','),
+    ("pt", "PT", ',', '\u{a0}'),
+];
+
+/// Languages which group 4-digit integers only in some contexts, so they are not grouped.
+const MINIMUM_GROUPING_DIGITS_2: [&str; 2] = ["es", "pl"];
+
+impl NumberSymbols {
+    /// Create a `NumberSymbols` instance which groups digits by three.
+    #[inline]
+    pub const fn new(decimal: char, group: char) -> NumberSymbols {
+        NumberSymbols {
+            decimal,
+            group,
+            secondary_grouping: 3,
+            minimum_grouping_digits: 1,
+        }
+    }
+
+    /// Get the symbols of a key from embedded CLDR data for common languages. Other languages use `.` and `,`.
+    pub fn of(key: &Key) -> NumberSymbols {
+        let language = data_language(key);
+        let region = region_of(key);
+        let region = region.as_deref();
+
+        let mut symbols =
+            match REGION_SYMBOLS.iter().find(|(l, r, ..)| *l == language && Some(*r) == region) {
+                Some(&(_, _, decimal, group)) => NumberSymbols::new(decimal, group),
+                None => {
+                    match LANGUAGE_SYMBOLS.iter().find(|(l, ..)| *l == language) {
+                        Some(&(_, decimal, group)) => NumberSymbols::new(decimal, group),
+                        None => NumberSymbols::default(),
+                    }
+                }
+            };
+
+        if language == "hi" || (language == "en" && region == Some("IN")) {
+            symbols.secondary_grouping = 2;
+        }
+
+        if MINIMUM_GROUPING_DIGITS_2.contains(&language.as_str())
+            || (language == "pt" && region == Some("PT"))
+        {
+            symbols.minimum_grouping_digits = 2;
+        }
+
+        symbols
+    }
+
+    /// Write a number, given in its JSON form (e.g. `-1234567.5`), with these symbols. Numbers with an exponent are written as they are.
+    pub fn write_number<W: Write + ?Sized>(&self, number: &str, output: &mut W) {
+        if number.contains(['e', 'E']) {
+            output.write_str(number).unwrap();
+
+            return;
+        }
+
+        let (sign, unsigned) = match number.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", number),
+        };
+
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        output.write_str(sign).unwrap();
+
+        let len = integer.len();
+
+        if len < 3 + self.minimum_grouping_digits.max(1) {
+            output.write_str(integer).unwrap();
+        } else {
+            let secondary = self.secondary_grouping.max(1);
+
+            // the first group is the leftmost, which can be shorter
+            let head = (len - 3) % secondary;
+            let head = if head == 0 {
+                secondary.min(len - 3)
+            } else {
+                head
+            };
+
+            output.write_str(&integer[..head]).unwrap();
+
+            let mut i = head;
+
+            while i < len - 3 {
+                output.write_char(self.group).unwrap();
+                output.write_str(&integer[i..i + secondary]).unwrap();
+
+                i += secondary;
+            }
+
+            output.write_char(self.group).unwrap();
+            output.write_str(&integer[len - 3..]).unwrap();
+        }
+
+        if let Some(fraction) = fraction {
+            output.write_char(self.decimal).unwrap();
+            output.write_str(fraction).unwrap();
+        }
+    }
+
+    /// Format a number, given in its JSON form (e.g. `-1234567.5`), with these symbols.
+    #[inline]
+    pub fn format_number(&self, number: &str) -> String {
+        let mut output = String::with_capacity(number.len() + number.len() / 2);
+
+        self.write_number(number, &mut output);
+
+        output
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the number symbols of a key, which are set by `JSONGetTextBuilder::set_number_symbols` or come from embedded CLDR data. If the key is not in context, the default key is used.
+    pub fn number_symbols<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> NumberSymbols
+    where
+        Key: Borrow<Q>, {
+        let (key, _) = self.find_entry_or_default(key);
+
+        match self.number_symbols.get::<Key>(key) {
+            Some(symbols) => *symbols,
+            None => NumberSymbols::of(key),
+        }
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Override the number symbols of a key, which numeric arguments are formatted with by `format_text_with_key`, instead of the ones from embedded CLDR data.
+    pub fn set_number_symbols<K: Into<Key>>(
+        &mut self,
+        key: K,
+        symbols: NumberSymbols,
+    ) -> &mut Self {
+        self.options.number_symbols.insert(key.into(), symbols);
+
+        self
+    }
+}
@@ -0,0 +1,224 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use super::data_language;
+use crate::format::{interpolate_with, placeholders_of};
+use crate::locale::region_of;
+use crate::{JSONGetText, Key};
+
+/// The name of a person.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PersonName {
+    pub given: String,
+    pub family: String,
+}
+
+/// A postal address. Empty fields are left out when formatting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PostalAddress {
+    pub name: String,
+    pub organization: String,
+    pub street: String,
+    pub city: String,
+    /// The state, province or prefecture.
+    pub region: String,
+    pub postal_code: String,
+    /// The ISO 3166-1 alpha-2 code of the country, which decides the format.
+    pub country_code: String,
+}
+
+/// A field of `PostalAddress`. The names of fields are the placeholders of address formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressField {
+    Name,
+    Organization,
+    Street,
+    City,
+    Region,
+    PostalCode,
+    Country,
+}
+
+impl AddressField {
+    /// The placeholder name, e.g. `postal_code`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AddressField::Name => "name",
+            AddressField::Organization => "organization",
+            AddressField::Street => "street",
+            AddressField::City => "city",
+            AddressField::Region => "region",
+            AddressField::PostalCode => "postal_code",
+            AddressField::Country => "country",
+        }
+    }
+
+    #[inline]
+    fn from_str(s: &str) -> Option<AddressField> {
+        Some(match s {
+            "name" => AddressField::Name,
+            "organization" => AddressField::Organization,
+            "street" => AddressField::Street,
+            "city" => AddressField::City,
+            "region" => AddressField::Region,
+            "postal_code" => AddressField::PostalCode,
+            "country" => AddressField::Country,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for AddressField {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The id of the text which overrides the person name format, e.g. `{family} {given}`.
+pub const PERSON_NAME_FORMAT_TEXT: &str = "formats.person_name";
+
+/// The prefix of the ids of the texts which override address formats, e.g. `formats.postal_address.US`.
+pub const POSTAL_ADDRESS_FORMAT_TEXT_PREFIX: &str = "formats.postal_address.";
+
+/// Languages whose names are written family name first.
+const FAMILY_FIRST_LANGUAGES: [&str; 7] = ["hu", "ja", "ko", "mn", "vi", "zh", "zh_Hant"];
+
+/// Address formats of regions, in the notation of placeholders. Lines are separated by `\n`.
+const ADDRESS_FORMATS: [(&str, &str); 9] = [
+    ("AU", "{name}\n{organization}\n{street}\n{city} {region} {postal_code}\n{country}"),
+    ("CA", "{name}\n{organization}\n{street}\n{city} {region} {postal_code}\n{country}"),
+    ("CN", "{country}\n{postal_code}\n{region}{city}{street}\n{organization}\n{name}"),
+    ("GB", "{name}\n{organization}\n{street}\n{city}\n{postal_code}\n{country}"),
+    ("JP", "{country}\n〒{postal_code}\n{region}{city}{street}\n{organization}\n{name}"),
+    ("KR", "{country}\n{region} {city} {street}\n{organization}\n{name}\n{postal_code}"),
+    ("TW", "{country}\n{postal_code}\n{region}{city}{street}\n{organization}\n{name}"),
+    ("US", "{name}\n{organization}\n{street}\n{city}, {region} {postal_code}\n{country}"),
+    ("001", "{name}\n{organization}\n{street}\n{postal_code} {city}\n{country}"),
+];
+
+#[inline]
+fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{3040}'..='\u{30FF}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{AC00}'..='\u{D7AF}' | '\u{F900}'..='\u{FAFF}')
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Format the name of a person with a specific key. The format is the `formats.person_name` text if there is one. Otherwise, the family name comes first for languages such as Chinese, Japanese, Korean and Hungarian, without a space if both names are CJK. If the key is not in context, the default key is used.
+    pub fn format_person_name<Q: ?Sized + Hash + Eq>(&self, key: &Q, name: &PersonName) -> String
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry_or_default(key);
+
+        let format = self.lookup_own_text(key, map, PERSON_NAME_FORMAT_TEXT).unwrap_or_else(|| {
+            if FAMILY_FIRST_LANGUAGES.contains(&data_language(key).as_str()) {
+                if name.given.chars().chain(name.family.chars()).all(is_cjk) {
+                    String::from("{family}{given}")
+                } else {
+                    String::from("{family} {given}")
+                }
+            } else {
+                String::from("{given} {family}")
+            }
+        });
+
+        interpolate_with(&format, |placeholder| {
+            match placeholder {
+                "given" => Some(name.given.clone()),
+                "family" => Some(name.family.clone()),
+                _ => None,
+            }
+        })
+        .trim()
+        .to_string()
+    }
+
+    /// Get the format of addresses in a region, from the `formats.postal_address.{region}` text of a key or from embedded data.
+    fn postal_address_format(&self, key: &Key, region: &str) -> String {
+        let map = self.find_map_or_default(key);
+
+        self.lookup_own_text(key, map, &format!("{}{}", POSTAL_ADDRESS_FORMAT_TEXT_PREFIX, region))
+            .unwrap_or_else(|| {
+                let format = ADDRESS_FORMATS
+                    .iter()
+                    .find(|(r, _)| *r == region)
+                    .unwrap_or(&ADDRESS_FORMATS[ADDRESS_FORMATS.len() - 1])
+                    .1;
+
+                String::from(format)
+            })
+    }
+
+    /// Get the order of the fields of addresses in a region (e.g. `US`), for laying out address forms. If the region is not given, the one of the key is used. If the key is not in context, the default key is used.
+    pub fn address_field_order<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+        region: Option<&str>,
+    ) -> Vec<AddressField>
+    where
+        Key: Borrow<Q>, {
+        let key = self.find_entry_or_default(key).0;
+
+        let region = region.map(|region| region.to_ascii_uppercase()).or_else(|| region_of(key));
+
+        let format = self.postal_address_format(key, region.as_deref().unwrap_or("001"));
+
+        let mut fields = Vec::new();
+
+        for field in placeholders_of(&format).into_iter().filter_map(AddressField::from_str) {
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+
+        fields
+    }
+
+    /// Format a postal address with a specific key. The format is decided by the country of the address (or by the region of the key if the address has no country), and can be overridden by `formats.postal_address.{region}` texts. The country name is localized by `country_name`. Empty fields and the lines left empty are removed. If the key is not in context, the default key is used.
+    pub fn format_postal_address<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+        address: &PostalAddress,
+    ) -> String
+    where
+        Key: Borrow<Q>, {
+        let key = self.find_entry_or_default(key).0;
+
+        let region = if address.country_code.is_empty() {
+            region_of(key)
+        } else {
+            Some(address.country_code.to_ascii_uppercase())
+        };
+
+        let format = self.postal_address_format(key, region.as_deref().unwrap_or("001"));
+
+        let formatted = interpolate_with(&format, |placeholder| {
+            let value = match AddressField::from_str(placeholder)? {
+                AddressField::Name => address.name.clone(),
+                AddressField::Organization => address.organization.clone(),
+                AddressField::Street => address.street.clone(),
+                AddressField::City => address.city.clone(),
+                AddressField::Region => address.region.clone(),
+                AddressField::PostalCode => address.postal_code.clone(),
+                AddressField::Country => {
+                    if address.country_code.is_empty() {
+                        String::new()
+                    } else {
+                        self.country_name::<Key, _>(key, &address.country_code)
+                            .unwrap_or_else(|| address.country_code.to_ascii_uppercase())
+                    }
+                }
+            };
+
+            Some(value)
+        });
+
+        formatted
+            .lines()
+            .map(|line| line.trim_matches(|c: char| c == ',' || c.is_whitespace()))
+            .filter(|line| !line.is_empty() && *line != "〒")
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,111 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use super::likely_region_of;
+use crate::{JSONGetText, Key};
+
+/// An example phone number of a region, for placeholders of phone number fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneExample {
+    /// The ISO 3166-1 alpha-2 code of the region.
+    pub region: String,
+    /// The country calling code, e.g. `+1`.
+    pub calling_code: String,
+    /// e.g. `(201) 555-0123`
+    pub national: String,
+    /// e.g. `+1 201-555-0123`
+    pub international: String,
+}
+
+/// The prefix of the ids of the texts which override phone examples, e.g. `formats.phone_example.US`. The text is the national format.
+pub const PHONE_EXAMPLE_TEXT_PREFIX: &str = "formats.phone_example.";
+
+/// Example mobile numbers of libphonenumber, as (region, calling code, national, international).
+const PHONE_EXAMPLES: [(&str, &str, &str, &str); 15] = [
+    ("AU", "+61", "0412 345 678", "+61 412 345 678"),
+    ("BR", "+55", "(11) 96123-4567", "+55 11 96123-4567"),
+    ("CA", "+1", "(506) 234-5678", "+1 506-234-5678"),
+    ("CN", "+86", "131 2345 6789", "+86 131 2345 6789"),
+    ("DE", "+49", "01512 3456789", "+49 1512 3456789"),
+    ("ES", "+34", "612 34 56 78", "+34 612 34 56 78"),
+    ("FR", "+33", "06 12 34 56 78", "+33 6 12 34 56 78"),
+    ("GB", "+44", "07400 123456", "+44 7400 123456"),
+    ("IN", "+91", "081234 56789", "+91 81234 56789"),
+    ("IT", "+39", "312 345 6789", "+39 312 345 6789"),
+    ("JP", "+81", "090-1234-5678", "+81 90-1234-5678"),
+    ("KR", "+82", "010-2000-0000", "+82 10-2000-0000"),
+    ("NL", "+31", "06 12345678", "+31 6 12345678"),
+    ("TW", "+886", "0912 345 678", "+886 912 345 678"),
+    ("US", "+1", "(201) 555-0123", "+1 201-555-0123"),
+];
+
+impl<'a> JSONGetText<'a> {
+    /// Get an example phone number for a region (e.g. `DE`), to be shown in phone number fields. If the region is not given, the region of the key (or the likely region of its language, with the `likely_subtags` feature) is used. The national format can be overridden by the `formats.phone_example.{region}` text. If the key is not in context, the default key is used.
+    pub fn phone_example<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+        region: Option<&str>,
+    ) -> Option<PhoneExample>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry_or_default(key);
+
+        let region = match region {
+            Some(region) => region.to_ascii_uppercase(),
+            None => likely_region_of(key)?,
+        };
+
+        let (_, calling_code, national, international) =
+            PHONE_EXAMPLES.iter().find(|(r, ..)| *r == region)?;
+
+        let national = self
+            .lookup_own_text(key, map, &format!("{}{}", PHONE_EXAMPLE_TEXT_PREFIX, region))
+            .unwrap_or_else(|| national.to_string());
+
+        Some(PhoneExample {
+            region,
+            calling_code: calling_code.to_string(),
+            national,
+            international: international.to_string(),
+        })
+    }
+
+    /// Format a phone number entered by a user with the `phonenumber` crate. Numbers of the region (given, or the one of the key) are formatted in the national format, and other numbers in the international format. Returns `None` if the number is not valid. If the key is not in context, the default key is used.
+    #[cfg(feature = "phonenumber")]
+    pub fn format_phone_number<Q: ?Sized + Hash + Eq, S: AsRef<str>>(
+        &self,
+        key: &Q,
+        number: S,
+        region: Option<&str>,
+    ) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        use std::str::FromStr;
+
+        use phonenumber::country::Id;
+        use phonenumber::Mode;
+
+        let key = self.find_entry_or_default(key).0;
+
+        let region = match region {
+            Some(region) => Some(region.to_ascii_uppercase()),
+            None => likely_region_of(key),
+        };
+
+        let id = region.as_deref().and_then(|region| Id::from_str(region).ok());
+
+        let number = phonenumber::parse(id, number.as_ref()).ok()?;
+
+        if !number.is_valid() {
+            return None;
+        }
+
+        let mode = if id.is_some() && number.country().id() == id {
+            Mode::National
+        } else {
+            Mode::International
+        };
+
+        Some(number.format().mode(mode).to_string())
+    }
+}
@@ -0,0 +1,261 @@
+use std::borrow::Borrow;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+#[cfg(feature = "timezone_names")]
+use super::data_language;
+use crate::{JSONGetText, Key};
+
+/// The style of a time-zone name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TimeZoneNameStyle {
+    /// e.g. `Central European Time`
+    #[default]
+    Generic,
+    /// e.g. `Central European Standard Time`
+    Standard,
+    /// e.g. `Central European Summer Time`
+    Daylight,
+}
+
+impl TimeZoneNameStyle {
+    /// The name used in text ids.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeZoneNameStyle::Generic => "generic",
+            TimeZoneNameStyle::Standard => "standard",
+            TimeZoneNameStyle::Daylight => "daylight",
+        }
+    }
+
+    #[cfg(feature = "timezone_names")]
+    #[inline]
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for TimeZoneNameStyle {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An error of `timezone_display`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeZoneNameError {
+    /// The time zone has no `timezones.*` text and is not one of the time zones whose metazone is embedded.
+    UnknownTimeZone {
+        tz_id: String,
+    },
+    /// The metazone has no `metazones.*` text, and the language of the key is not covered by the embedded names (only English, French and German with the `timezone_names` feature).
+    UnsupportedLocale {
+        key: String,
+        metazone: &'static str,
+    },
+}
+
+impl Display for TimeZoneNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZoneNameError::UnknownTimeZone {
+                tz_id,
+            } => f.write_fmt(format_args!("The time zone `{}` is unknown.", tz_id)),
+            TimeZoneNameError::UnsupportedLocale {
+                key,
+                metazone,
+            } => {
+                f.write_fmt(format_args!(
+                    "There is no name of the metazone `{}` for the key `{}`.",
+                    metazone, key
+                ))
+            }
+        }
+    }
+}
+
+impl Error for TimeZoneNameError {}
+
+/// Time-zone IDs of the IANA database and the CLDR metazones they belong to. This is a subset of CLDR covering common time zones, not the whole database.
+static METAZONES: [(&str, &str); 17] = [
+    ("America/Chicago", "America_Central"),
+    ("America/Denver", "America_Mountain"),
+    ("America/Los_Angeles", "America_Pacific"),
+    ("America/New_York", "America_Eastern"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Taipei", "Taipei"),
+    ("Asia/Tokyo", "Japan"),
+    ("Etc/UTC", "UTC"),
+    ("Europe/Amsterdam", "Europe_Central"),
+    ("Europe/Berlin", "Europe_Central"),
+    ("Europe/Brussels", "Europe_Central"),
+    ("Europe/London", "GMT"),
+    ("Europe/Madrid", "Europe_Central"),
+    ("Europe/Paris", "Europe_Central"),
+    ("Europe/Rome", "Europe_Central"),
+    ("Europe/Vienna", "Europe_Central"),
+    ("UTC", "UTC"),
+];
+
+/// Get the CLDR metazone of a time-zone ID, e.g. `Europe_Central` for `Europe/Paris`. Only the time zones of US, China, Taiwan, Japan, UTC and the largest cities of western and central Europe are known.
+#[inline]
+pub fn metazone_of<S: AsRef<str>>(tz_id: S) -> Option<&'static str> {
+    let tz_id = tz_id.as_ref();
+
+    METAZONES.iter().find(|(id, _)| *id == tz_id).map(|(_, metazone)| *metazone)
+}
+
+/// Names of the metazones in `METAZONES` from CLDR, as (language, metazone, [generic, standard, daylight]), in English, French and German only.
+#[cfg(feature = "timezone_names")]
+static METAZONE_NAMES: [(&str, &str, [&str; 3]); 30] = [
+    ("en", "America_Central", ["Central Time", "Central Standard Time", "Central Daylight Time"]),
+    ("en", "America_Eastern", ["Eastern Time", "Eastern Standard Time", "Eastern Daylight Time"]),
+    ("en", "America_Mountain", [
+        "Mountain Time",
+        "Mountain Standard Time",
+        "Mountain Daylight Time",
+    ]),
+    ("en", "America_Pacific", ["Pacific Time", "Pacific Standard Time", "Pacific Daylight Time"]),
+    ("en", "China", ["China Time", "China Standard Time", "China Daylight Time"]),
+    ("en", "Europe_Central", [
+        "Central European Time",
+        "Central European Standard Time",
+        "Central European Summer Time",
+    ]),
+    ("en", "GMT", ["Greenwich Mean Time", "Greenwich Mean Time", "British Summer Time"]),
+    ("en", "Japan", ["Japan Time", "Japan Standard Time", "Japan Daylight Time"]),
+    ("en", "Taipei", ["Taipei Time", "Taipei Standard Time", "Taipei Daylight Time"]),
+    ("en", "UTC", [
+        "Coordinated Universal Time",
+        "Coordinated Universal Time",
+        "Coordinated Universal Time",
+    ]),
+    ("fr", "America_Central", [
+        "heure du centre nord-américain",
+        "heure normale du centre nord-américain",
+        "heure d’été du centre nord-américain",
+    ]),
+    ("fr", "America_Eastern", [
+        "heure de l’Est nord-américain",
+        "heure normale de l’Est nord-américain",
+        "heure d’été de l’Est nord-américain",
+    ]),
+    ("fr", "America_Mountain", [
+        "heure des Rocheuses",
+        "heure normale des Rocheuses",
+        "heure d’été des Rocheuses",
+    ]),
+    ("fr", "America_Pacific", [
+        "heure du Pacifique nord-américain",
+        "heure normale du Pacifique nord-américain",
+        "heure d’été du Pacifique nord-américain",
+    ]),
+    ("fr", "China", ["heure de la Chine", "heure normale de la Chine", "heure d’été de Chine"]),
+    ("fr", "Europe_Central", [
+        "heure d’Europe centrale",
+        "heure normale d’Europe centrale",
+        "heure d’été d’Europe centrale",
+    ]),
+    ("fr", "GMT", [
+        "heure moyenne de Greenwich",
+        "heure moyenne de Greenwich",
+        "heure d’été britannique",
+    ]),
+    ("fr", "Japan", ["heure du Japon", "heure normale du Japon", "heure d’été du Japon"]),
+    ("fr", "Taipei", ["heure de Taipei", "heure normale de Taipei", "heure d’été de Taipei"]),
+    ("fr", "UTC", [
+        "temps universel coordonné",
+        "temps universel coordonné",
+        "temps universel coordonné",
+    ]),
+    ("de", "America_Central", [
+        "Nordamerikanische Zentralzeit",
+        "Nordamerikanische Zentral-Normalzeit",
+        "Nordamerikanische Zentral-Sommerzeit",
+    ]),
+    ("de", "America_Eastern", [
+        "Nordamerikanische Ostküstenzeit",
+        "Nordamerikanische Ostküsten-Normalzeit",
+        "Nordamerikanische Ostküsten-Sommerzeit",
+    ]),
+    ("de", "America_Mountain", [
+        "Rocky-Mountain-Zeit",
+        "Rocky-Mountain-Normalzeit",
+        "Rocky-Mountain-Sommerzeit",
+    ]),
+    ("de", "America_Pacific", [
+        "Nordamerikanische Westküstenzeit",
+        "Nordamerikanische Westküsten-Normalzeit",
+        "Nordamerikanische Westküsten-Sommerzeit",
+    ]),
+    ("de", "China", ["Chinesische Zeit", "Chinesische Normalzeit", "Chinesische Sommerzeit"]),
+    ("de", "Europe_Central", [
+        "Mitteleuropäische Zeit",
+        "Mitteleuropäische Normalzeit",
+        "Mitteleuropäische Sommerzeit",
+    ]),
+    ("de", "GMT", ["Mittlere Greenwich-Zeit", "Mittlere Greenwich-Zeit", "Britische Sommerzeit"]),
+    ("de", "Japan", ["Japanische Zeit", "Japanische Normalzeit", "Japanische Sommerzeit"]),
+    ("de", "Taipei", ["Taipeh-Zeit", "Taipeh-Normalzeit", "Taipeh-Sommerzeit"]),
+    ("de", "UTC", ["Koordinierte Weltzeit", "Koordinierte Weltzeit", "Koordinierte Weltzeit"]),
+];
+
+/// Find an embedded name for a key.
+#[cfg(feature = "timezone_names")]
+fn builtin_name(key: &Key, metazone: &str, style: TimeZoneNameStyle) -> Option<String> {
+    let language = data_language(key);
+
+    METAZONE_NAMES
+        .iter()
+        .find(|(l, m, _)| *l == language && *m == metazone)
+        .map(|(_, _, names)| names[style.index()].to_string())
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the display name of a time zone by its IANA ID (e.g. `Europe/Paris`) with a specific key. The name comes from the first one found of
+    ///
+    /// 1. the `timezones.{tz_id}.{generic|standard|daylight}` text
+    /// 1. the `metazones.{metazone}.{generic|standard|daylight}` text, where the metazone is the one of CLDR, e.g. `Europe_Central` (see `metazone_of` for the known time zones)
+    /// 1. embedded CLDR data in English, French and German, with the `timezone_names` feature
+    ///
+    /// Other languages are not embedded, so their names have to be provided as texts, or `TimeZoneNameError::UnsupportedLocale` is returned. If the key is not in context, the default key is used.
+    pub fn timezone_display<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        tz_id: T,
+        style: TimeZoneNameStyle,
+    ) -> Result<String, TimeZoneNameError>
+    where
+        Key: Borrow<Q>, {
+        let tz_id = tz_id.as_ref();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        if let Some(name) =
+            self.lookup_own_text(key, map, &format!("timezones.{}.{}", tz_id, style))
+        {
+            return Ok(name);
+        }
+
+        let metazone = metazone_of(tz_id).ok_or_else(|| {
+            TimeZoneNameError::UnknownTimeZone {
+                tz_id: tz_id.to_string(),
+            }
+        })?;
+
+        let name = self.lookup_own_text(key, map, &format!("metazones.{}.{}", metazone, style));
+
+        #[cfg(feature = "timezone_names")]
+        let name = name.or_else(|| builtin_name(key, metazone, style));
+
+        name.ok_or_else(|| {
+            TimeZoneNameError::UnsupportedLocale {
+                key: key.to_string(),
+                metazone,
+            }
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use super::likely_region_of;
+use crate::{Key, Weekday};
+
+/// Whether times are usually written with a 12-hour or a 24-hour clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HourCycle {
+    /// 1 to 12, with AM and PM.
+    H12,
+    /// 0 to 23.
+    H23,
+}
+
+/// Calendar preferences of a locale, from the week and time data of CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarPreferences {
+    pub first_day_of_week: Weekday,
+    /// The first day of the weekend.
+    pub weekend_start: Weekday,
+    /// The last day of the weekend, which is the same as `weekend_start` for one-day weekends.
+    pub weekend_end: Weekday,
+    pub hour_cycle: HourCycle,
+}
+
+const FIRST_DAY_SUNDAY: &[&str] = &[
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CO", "DM", "DO", "ET", "GT", "GU", "HK",
+    "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ",
+    "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW", "UM",
+    "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+const FIRST_DAY_SATURDAY: &[&str] =
+    &["AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"];
+
+const WEEKEND_FRIDAY_SATURDAY: &[&str] =
+    &["BH", "DZ", "EG", "IL", "IQ", "JO", "KW", "LY", "OM", "QA", "SA", "SD", "SY", "YE"];
+
+const HOUR_CYCLE_12: &[&str] = &[
+    "AU", "BD", "CA", "CO", "EG", "HK", "IN", "JO", "KR", "MO", "MX", "MY", "NZ", "PH", "PK", "SA",
+    "SG", "TW", "US",
+];
+
+impl CalendarPreferences {
+    /// Get the preferences of the region of a key. For keys without a region, the likely region of the language is used (e.g. `US` for `en`) with the `likely_subtags` feature. Unknown regions get the preferences of CLDR's world region: weeks start on Monday, weekends are Saturday and Sunday, and a 24-hour clock is used.
+    pub fn of(key: &Key) -> CalendarPreferences {
+        let region = likely_region_of(key);
+
+        let region = region.as_deref().unwrap_or("001");
+
+        let first_day_of_week = if FIRST_DAY_SUNDAY.contains(&region) {
+            Weekday::Sunday
+        } else if FIRST_DAY_SATURDAY.contains(&region) {
+            Weekday::Saturday
+        } else if region == "MV" {
+            Weekday::Friday
+        } else {
+            Weekday::Monday
+        };
+
+        let (weekend_start, weekend_end) = if WEEKEND_FRIDAY_SATURDAY.contains(&region) {
+            (Weekday::Friday, Weekday::Saturday)
+        } else {
+            match region {
+                "AF" => (Weekday::Thursday, Weekday::Friday),
+                "IR" => (Weekday::Friday, Weekday::Friday),
+                "IN" | "UG" => (Weekday::Sunday, Weekday::Sunday),
+                _ => (Weekday::Saturday, Weekday::Sunday),
+            }
+        };
+
+        let hour_cycle = if HOUR_CYCLE_12.contains(&region) {
+            HourCycle::H12
+        } else {
+            HourCycle::H23
+        };
+
+        CalendarPreferences {
+            first_day_of_week,
+            weekend_start,
+            weekend_end,
+            hour_cycle,
+        }
+    }
+
+    /// Returns `true` if the day is in the weekend.
+    #[inline]
+    pub fn is_weekend(&self, day: Weekday) -> bool {
+        let start = self.weekend_start.index();
+        let length = (self.weekend_end.index() + 7 - start) % 7;
+
+        (day.index() + 7 - start) % 7 <= length
+    }
+
+    /// Get the days of a week in display order, starting from `first_day_of_week`.
+    #[inline]
+    pub fn week(&self) -> [Weekday; 7] {
+        let first = self.first_day_of_week.index();
+
+        std::array::from_fn(|i| Weekday::ALL[(first + i) % 7])
+    }
+}
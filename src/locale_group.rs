@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use crate::{Coverage, JSONGetText, JSONGetTextBuilder, Key, PreflightFailure};
+
+impl<'a> JSONGetText<'a> {
+    /// Get the keys of a locale group defined by `JSONGetTextBuilder::add_locale_group`, in the order they were added.
+    #[inline]
+    pub fn locale_group<N: AsRef<str>>(&self, name: N) -> Option<&[Key]> {
+        self.locale_groups.get(name.as_ref()).map(|keys| keys.as_slice())
+    }
+
+    /// Get the names of all locale groups, sorted.
+    pub fn get_locale_group_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.locale_groups.keys().map(|name| name.as_str()).collect();
+
+        names.sort_unstable();
+
+        names
+    }
+
+    /// Get the translation coverage of the keys of a locale group, like `coverage`. Keys of the group which are not in context are left out, and `None` is returned if the group is not defined.
+    #[inline]
+    pub fn group_coverage<N: AsRef<str>>(&self, name: N) -> Option<BTreeMap<String, Coverage>> {
+        let keys = self.locale_group(name)?;
+
+        Some(self.coverage_of(keys))
+    }
+
+    /// Check that every critical text is translated in every key of a locale group, e.g. a release gate requiring LATAM to be fully translated, like `preflight`.
+    pub fn preflight_group<N: AsRef<str>, T: AsRef<str>>(
+        &self,
+        name: N,
+        texts: &[T],
+    ) -> Result<(), Vec<PreflightFailure>> {
+        let name = name.as_ref();
+
+        let keys: Vec<&Key> = match self.locale_group(name) {
+            Some(keys) => keys.iter().collect(),
+            None => {
+                return Err(vec![PreflightFailure::UnknownLocaleGroup {
+                    group: name.to_string(),
+                }])
+            }
+        };
+
+        self.preflight(&keys, texts)
+    }
+
+    /// Export the texts of the keys of a locale group as one JSON object, keyed by the display forms of the keys, e.g. to ship a regional bundle. Keys of the group which are not in context are left out, and `None` is returned if the group is not defined.
+    pub fn export_group_json<N: AsRef<str>>(&self, name: N) -> Option<String> {
+        let keys = self.locale_group(name)?;
+
+        let bundle: BTreeMap<String, _> = keys
+            .iter()
+            .filter_map(|key| self.context.get(key).map(|map| (key.to_string(), map.as_ref())))
+            .collect();
+
+        Some(serde_json::to_string(&bundle).unwrap())
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Define a named group of keys, e.g. `"LATAM"` for the Latin American locales, which can be checked and exported together by `group_coverage`, `preflight_group` and `export_group_json`. Defining a group again replaces its keys. The keys do not need to be added to this builder.
+    pub fn add_locale_group<N: Into<String>, K: Into<Key>, I: IntoIterator<Item = K>>(
+        &mut self,
+        name: N,
+        keys: I,
+    ) -> &mut Self {
+        let mut group: Vec<Key> = Vec::new();
+
+        for key in keys {
+            let key = key.into();
+
+            if !group.contains(&key) {
+                group.push(key);
+            }
+        }
+
+        self.options.locale_groups.insert(name.into(), group);
+
+        self
+    }
+}
@@ -0,0 +1,22 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// Types whose values are displayed with texts in context, usually enums. With the `derive` feature, it can be derived by `#[derive(LocalizedDisplay)]`, which maps variants to text ids with `#[loc("id")]` attributes.
+pub trait LocalizedDisplay {
+    /// Get the text id of this value.
+    fn text_id(&self) -> &'static str;
+
+    /// Get the text of this value from context with a specific key. If the key is not in context, the default key is used.
+    #[inline]
+    fn localized<'b, Q: ?Sized + Hash + Eq>(
+        &self,
+        ctx: &'b JSONGetText,
+        key: &Q,
+    ) -> Option<JSONGetTextValue<'b>>
+    where
+        Key: Borrow<Q>, {
+        ctx.lookup_text(ctx.find_map_or_default(key), self.text_id())
+    }
+}
@@ -0,0 +1,44 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{interpolate, JSONGetText, JSONGetTextValue, Key};
+
+impl<'a> JSONGetText<'a> {
+    /// Render a text for `log_t!` and `tracing_t!`: the text of the default key, and the text of `key` if it is given. Texts are interpolated with `args` if they are strings. If the text is not found, its id is used.
+    #[doc(hidden)]
+    pub fn __log_texts<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: Option<&Q>,
+        text: &str,
+        args: Option<&HashMap<&str, JSONGetTextValue>>,
+    ) -> (String, Option<String>)
+    where
+        Key: Borrow<Q>, {
+        let render = |value: Option<JSONGetTextValue>| {
+            match value {
+                Some(value) => {
+                    match (value.as_str(), args) {
+                        (Some(template), Some(args)) => interpolate(template, args),
+                        _ => value.to_string(),
+                    }
+                }
+                None => text.to_string(),
+            }
+        };
+
+        match key {
+            Some(key) => {
+                match self.get_text_pair(key, text) {
+                    Some(pair) => (render(Some(pair.default)), Some(render(Some(pair.localized)))),
+                    None => {
+                        let localized = self.lookup_text(self.find_map_or_default(key), text);
+
+                        (text.to_string(), Some(render(localized)))
+                    }
+                }
+            }
+            None => (render(self.lookup_text(self.default_map(), text)), None),
+        }
+    }
+}
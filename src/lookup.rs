@@ -0,0 +1,172 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ptr;
+
+use serde_json::Value;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// Find a value in a nested object by a dot path. Names of members may contain dots themselves, so every split of the path is tried.
+fn find_in_value<'v>(value: &'v Value, path: &str) -> Option<&'v Value> {
+    let object = value.as_object()?;
+
+    if let Some(value) = object.get(path) {
+        return Some(value);
+    }
+
+    path.match_indices('.')
+        .find_map(|(i, _)| find_in_value(object.get(&path[..i])?, &path[i + 1..]))
+}
+
+/// Find a value in the nested objects of a string map by a dot path, e.g. `menu.file.open` for `{"menu": {"file": {"open": "Open"}}}`.
+pub(crate) fn find_nested<'b>(
+    map: &'b HashMap<String, JSONGetTextValue>,
+    path: &str,
+) -> Option<&'b Value> {
+    path.match_indices('.').find_map(|(i, _)| {
+        match map.get(&path[..i])? {
+            JSONGetTextValue::Str(_) => None,
+            JSONGetTextValue::JSONValue(v) => find_in_value(v, &path[i + 1..]),
+            JSONGetTextValue::JSONValueRef(v) => find_in_value(v, &path[i + 1..]),
+        }
+    })
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the string map of a key, if the key is in context.
+    #[inline]
+    pub(crate) fn find_map<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+    ) -> Option<&HashMap<String, JSONGetTextValue<'a>>>
+    where
+        Key: Borrow<Q>, {
+        self.context.get(key).map(|map| map.as_ref())
+    }
+
+    /// Get the key stored in context which is equal to the given one, along with its string map.
+    #[inline]
+    pub(crate) fn find_entry<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+    ) -> Option<(&Key, &HashMap<String, JSONGetTextValue<'a>>)>
+    where
+        Key: Borrow<Q>, {
+        self.context.get_key_value(key).map(|(key, map)| (key, map.as_ref()))
+    }
+
+    /// Get the string map of the default key.
+    #[inline]
+    pub(crate) fn default_map(&self) -> &HashMap<String, JSONGetTextValue<'a>> {
+        self.context.get(&self.default_key).unwrap()
+    }
+
+    /// Get the default key stored in context, along with its string map.
+    #[inline]
+    pub(crate) fn default_entry(&self) -> (&Key, &HashMap<String, JSONGetTextValue<'a>>) {
+        let (key, map) = self.context.get_key_value(&self.default_key).unwrap();
+
+        (key, map)
+    }
+
+    /// Get the key stored in context which is equal to the given one, or the default key if the key is not in context, along with its string map.
+    #[inline]
+    pub(crate) fn find_entry_or_default<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+    ) -> (&Key, &HashMap<String, JSONGetTextValue<'a>>)
+    where
+        Key: Borrow<Q>, {
+        match self.find_entry(key) {
+            Some(entry) => entry,
+            None => self.default_entry(),
+        }
+    }
+
+    /// Get the string map of a key, or the one of the default key if the key is not in context.
+    #[inline]
+    pub(crate) fn find_map_or_default<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+    ) -> &HashMap<String, JSONGetTextValue<'a>>
+    where
+        Key: Borrow<Q>, {
+        match self.find_map(key) {
+            Some(map) => map,
+            None => self.default_map(),
+        }
+    }
+
+    /// Returns `true` if the text of a key was copied from the default key when building.
+    #[inline]
+    pub(crate) fn is_filled_text(&self, key: &Key, text: &str) -> bool {
+        self.filled.get(key).is_some_and(|filled| filled.contains(text))
+    }
+
+    /// Get text from a string map of context, and report the usage. A dot path such as `menu.file.open` finds a text in nested objects.
+    #[inline]
+    pub(crate) fn lookup_text<'b>(
+        &'b self,
+        map: &'b HashMap<String, JSONGetTextValue<'a>>,
+        text: &str,
+    ) -> Option<JSONGetTextValue<'b>> {
+        let (text, value) = match map.get_key_value(text) {
+            Some(entry) => entry,
+            None => {
+                if let Some(value) = self.lookup_nested_text(map, text) {
+                    return Some(value);
+                }
+
+                let to = self.migrations.get(text)?;
+
+                if let Some(observer) = self.observer.as_ref() {
+                    observer.0.migrated_text_used(text, to);
+                }
+
+                map.get_key_value(to)?
+            }
+        };
+
+        self.report_deprecated_text(text);
+
+        Some(value.clone_borrowed())
+    }
+
+    /// Get a text in the nested objects of a string map by a dot path. Since only whole texts are filled from the default key when building, a path missing in the map is looked up in the default key.
+    #[inline]
+    fn lookup_nested_text<'b>(
+        &'b self,
+        map: &'b HashMap<String, JSONGetTextValue<'a>>,
+        path: &str,
+    ) -> Option<JSONGetTextValue<'b>> {
+        if !path.contains('.') {
+            return None;
+        }
+
+        let default_map = self.default_map();
+
+        let value = match find_nested(map, path) {
+            Some(value) => value,
+            None if !ptr::eq(map, default_map) => find_nested(default_map, path)?,
+            None => return None,
+        };
+
+        Some(JSONGetTextValue::JSONValueRef(value))
+    }
+
+    /// Get a text of a key, unless it was copied from the default key when building, since locale data should be preferred to texts in another language.
+    #[inline]
+    pub(crate) fn lookup_own_text(
+        &self,
+        key: &Key,
+        map: &HashMap<String, JSONGetTextValue<'a>>,
+        text: &str,
+    ) -> Option<String> {
+        if self.is_filled_text(key, text) {
+            None
+        } else {
+            self.lookup_text(map, text).map(|value| value.to_string())
+        }
+    }
+}
@@ -34,6 +34,76 @@ macro_rules! static_json_gettext_build {
     };
 }
 
+/**
+Used for including every JSON file in a directory into your executable binary file for building a `JSONGetText` instance. The file names are used as the keys (e.g. `en_US.json`, `zh-TW.json`), as by `JSONGetTextBuilder::add_directory`, and files whose names are not language tags are skipped. The directory is relative to the manifest directory of your crate. This needs the `embed_dir` feature.
+
+Cargo does not watch the directory itself, so after adding a file, make sure the crate is recompiled, e.g. by a build script which prints `cargo:rerun-if-changed=langs`.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let ctx = static_json_gettext_build_dir!("en_US"; "langs").unwrap();
+
+println!("{:?}", ctx);
+```
+**/
+#[macro_export]
+macro_rules! static_json_gettext_build_dir {
+    ($default_key:expr; $path:literal $(,)*) => {{
+        let mut builder = $crate::JSONGetText::build($default_key);
+
+        for (file_stem, json) in $crate::json_files!($path) {
+            if let Some(key) = $crate::key_from_file_stem(file_stem) {
+                builder.add_json(key, json).unwrap();
+            }
+        }
+
+        builder.build()
+    }};
+}
+
+/**
+Used for registering the catalogs of a crate as a namespace, by embedding the JSON files in a directory relative to the manifest directory of the crate, which is `locales` if not given. The files are named by language tags, e.g. `locales/en_US.json`. The `embed_dir` feature needs to be enabled.
+
+Each crate of a workspace can register its own namespace, and the application composes them with `add_crate_namespace`, so that no crate shares a file with another.
+
+```ignore
+// in the `billing` crate
+json_gettext_namespace!(pub LOCALES, "billing");
+
+// in the application
+let mut builder = json_gettext::JSONGetText::build("en_US");
+
+builder.add_json_file("en_US", "langs/en_US.json").unwrap();
+builder.add_crate_namespace(&billing::LOCALES).unwrap();
+builder.add_crate_namespace(&users::LOCALES).unwrap();
+
+let ctx = builder.build().unwrap();
+
+println!("{:?}", get_text_ns!(ctx, "billing", "invoice"));
+```
+**/
+#[macro_export]
+macro_rules! json_gettext_namespace {
+    ( $vis:vis $name:ident, $namespace:literal $(,)* ) => {
+        $crate::json_gettext_namespace!($vis $name, $namespace, "locales");
+    };
+    ( $vis:vis $name:ident, $namespace:literal, $path:literal $(,)* ) => {
+        $vis static $name: $crate::CrateNamespace = $crate::CrateNamespace {
+            crate_name: env!("CARGO_PKG_NAME"),
+            namespace: $namespace,
+            files: &$crate::json_files!($path),
+        };
+    };
+}
+
+/// Get the key of a file embedded by `static_json_gettext_build_dir`.
+#[doc(hidden)]
+#[inline]
+pub fn key_from_file_stem(file_stem: &str) -> Option<crate::Key> {
+    crate::locale::key_from_tag(file_stem)
+}
+
 /**
 Used for getting single or multiple text from context.
 
@@ -79,3 +149,188 @@ macro_rules! get_text {
         }
     };
 }
+
+/**
+Used for getting text from context, never returning `None`. A text which the key does not have is looked up in the default key, and a text which is not found at all is returned as its id.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+)
+.unwrap();
+
+assert_eq!("哈囉，世界！", get_text_or_default!(ctx, "zh_TW", "hello"));
+assert_eq!("Rust!", get_text_or_default!(ctx, "zh_TW", "rust"));
+assert_eq!("missing.text", get_text_or_default!(ctx, "missing.text"));
+```
+*/
+#[macro_export]
+macro_rules! get_text_or_default {
+    ($ctx:ident, $text:expr) => {{
+        $ctx.get_text_or_default($text)
+    }};
+    ($ctx:ident, $key:expr, $text:expr) => {{
+        $ctx.get_text_with_key_or_default($key, $text)
+    }};
+}
+
+/**
+Used for getting text in a namespace from context.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let mut builder = json_gettext::JSONGetText::build("en_US");
+
+builder.add_json_file("en_US", "langs/en_US.json").unwrap();
+builder.add_json_file_ns("en_US", "errors", "langs/errors/en_US.json").unwrap();
+builder.add_json_file("zh_TW", "langs/zh_TW.json").unwrap();
+builder.add_json_file_ns("zh_TW", "errors", "langs/errors/zh_TW.json").unwrap();
+
+let ctx = builder.build().unwrap();
+
+assert_eq!("Not found", get_text_ns!(ctx, "errors", "not_found").unwrap());
+assert_eq!("找不到", get_text_ns!(ctx, "zh_TW", "errors", "not_found").unwrap());
+```
+*/
+#[macro_export]
+macro_rules! get_text_ns {
+    ($ctx:ident, $namespace:expr, $text:expr) => {{
+        $ctx.get_text_ns($namespace, $text)
+    }};
+    ($ctx:ident, $key:expr, $namespace:expr, $text:expr) => {{
+        $ctx.get_text_ns_with_key($key, $namespace, $text)
+    }};
+}
+
+/**
+Get text from context and interpolate named arguments into its placeholders. Arguments can be any type which can be converted into `JSONGetTextValue`.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+)
+.unwrap();
+
+assert_eq!("Hello, Alice!", get_text_fmt!(ctx, "greeting", name = "Alice").unwrap());
+assert_eq!("哈囉，Alice！", get_text_fmt!(ctx, "zh_TW", "greeting", name = "Alice").unwrap());
+```
+*/
+#[macro_export]
+macro_rules! get_text_fmt {
+    ( $ctx:ident, $text:expr $(, $name:ident = $value:expr)* $(,)? ) => {
+        {
+            #[allow(unused_mut)]
+            let mut args: ::std::collections::HashMap<&str, $crate::JSONGetTextValue> = ::std::collections::HashMap::new();
+
+            $(
+                args.insert(stringify!($name), $crate::JSONGetTextValue::from($value));
+            )*
+
+            $ctx.format_text($text, &args)
+        }
+    };
+    ( $ctx:ident, $key:expr, $text:expr $(, $name:ident = $value:expr)* $(,)? ) => {
+        {
+            #[allow(unused_mut)]
+            let mut args: ::std::collections::HashMap<&str, $crate::JSONGetTextValue> = ::std::collections::HashMap::new();
+
+            $(
+                args.insert(stringify!($name), $crate::JSONGetTextValue::from($value));
+            )*
+
+            $ctx.format_text_with_key($key, $text, &args)
+        }
+    };
+}
+
+/**
+Log a text with the `log` crate. The message is the text of the default key, so logs stay greppable, and the text id is attached as the `id` field. If a key is given, its text is attached as the `localized` field. A map of arguments can be given to interpolate the texts.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+)
+.unwrap();
+
+log_t!(log::Level::Info, ctx, "hello");
+log_t!(log::Level::Warn, ctx, "zh_TW" => "hello");
+log_t!(log::Level::Warn, ctx, "zh_TW" => "greeting", &args);
+```
+*/
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! log_t {
+    ( $level:expr, $ctx:expr, $key:expr => $text:expr $(, $args:expr)? ) => {
+        {
+            let text = $text;
+            let args = ::core::option::Option::None::<&::std::collections::HashMap<&str, $crate::JSONGetTextValue>>$(.or(::core::option::Option::Some($args)))?;
+            let (message, localized) = $ctx.__log_texts(::core::option::Option::Some($key), text, args);
+            let localized = localized.unwrap_or_default();
+
+            $crate::log::log!($level, id = text, localized = localized.as_str(); "{}", message);
+        }
+    };
+    ( $level:expr, $ctx:expr, $text:expr $(, $args:expr)? ) => {
+        {
+            let text = $text;
+            let args = ::core::option::Option::None::<&::std::collections::HashMap<&str, $crate::JSONGetTextValue>>$(.or(::core::option::Option::Some($args)))?;
+            let (message, _) = $ctx.__log_texts(::core::option::Option::None::<&$crate::Key>, text, args);
+
+            $crate::log::log!($level, id = text; "{}", message);
+        }
+    };
+}
+
+/**
+Record an event with the `tracing` crate, in the same way as `log_t!`. The level must be a constant, e.g. `tracing::Level::INFO`.
+
+```ignore
+#[macro_use] extern crate json_gettext;
+
+let ctx = static_json_gettext_build!(
+    "en_US";
+    "en_US" => "langs/en_US.json",
+    "zh_TW" => "langs/zh_TW.json"
+)
+.unwrap();
+
+tracing_t!(tracing::Level::INFO, ctx, "hello");
+tracing_t!(tracing::Level::WARN, ctx, "zh_TW" => "hello");
+```
+*/
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! tracing_t {
+    ( $level:expr, $ctx:expr, $key:expr => $text:expr $(, $args:expr)? ) => {
+        {
+            let text = $text;
+            let args = ::core::option::Option::None::<&::std::collections::HashMap<&str, $crate::JSONGetTextValue>>$(.or(::core::option::Option::Some($args)))?;
+            let (message, localized) = $ctx.__log_texts(::core::option::Option::Some($key), text, args);
+            let localized = localized.unwrap_or_default();
+
+            $crate::tracing::event!($level, id = text, localized = localized.as_str(), "{}", message);
+        }
+    };
+    ( $level:expr, $ctx:expr, $text:expr $(, $args:expr)? ) => {
+        {
+            let text = $text;
+            let args = ::core::option::Option::None::<&::std::collections::HashMap<&str, $crate::JSONGetTextValue>>$(.or(::core::option::Option::Some($args)))?;
+            let (message, _) = $ctx.__log_texts(::core::option::Option::None::<&$crate::Key>, text, args);
+
+            $crate::tracing::event!($level, id = text, "{}", message);
+        }
+    };
+}
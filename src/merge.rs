@@ -0,0 +1,338 @@
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::sync::Arc;
+
+#[cfg(feature = "fs")]
+use serde_json::{Map, Value};
+
+use crate::fallback::collect_fallback_texts;
+use crate::format::validate_placeholder_defaults;
+use crate::modified::record_modified;
+use crate::pipeline::Findings;
+use crate::reference::inline_references;
+use crate::strictness::{ExtraText, Strictness};
+#[cfg(feature = "fs")]
+use crate::CatalogId;
+use crate::{
+    Context, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+    LocaleShard,
+};
+
+/// How to resolve a text which is in both catalogs being merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ConflictPolicy {
+    /// Fail with `JSONGetTextBuildError::DuplicatedText`.
+    #[default]
+    Error,
+    /// Keep the text which is already there.
+    KeepFirst,
+    /// Replace the text which is already there.
+    Overwrite,
+}
+
+/// Merge texts into a string map. `filled` holds the texts of the map which were only copied from another key, which are replaced without a conflict and are no longer filled afterwards.
+fn merge_texts<'a, I: IntoIterator<Item = (String, JSONGetTextValue<'a>)>>(
+    key: &Key,
+    map: &mut HashMap<String, JSONGetTextValue<'a>>,
+    filled: &mut HashSet<String>,
+    texts: I,
+    policy: ConflictPolicy,
+) -> Result<(), JSONGetTextBuildError> {
+    for (text, value) in texts {
+        if map.contains_key(&text) && !filled.contains(&text) {
+            match policy {
+                ConflictPolicy::Error => {
+                    return Err(JSONGetTextBuildError::DuplicatedText {
+                        key: key.to_owned(),
+                        text,
+                    })
+                }
+                ConflictPolicy::KeepFirst => continue,
+                ConflictPolicy::Overwrite => (),
+            }
+        }
+
+        filled.remove(&text);
+
+        map.insert(text, value);
+    }
+
+    Ok(())
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Merge the catalogs of another instance into this one, e.g. the translations shipped by a plugin. The texts of the other instance which were filled from its default key are ignored, and a text which is in both instances is resolved by the conflict policy. The merged texts go through the build steps of this instance as when building, i.e. its content scanner, empty-text policies, quotation normalization, strictness and conditionals checks, and the keys whose texts may have changed are filled again from their fallback keys and the default key, with `{#text}` references inlined. The extra and empty texts of the other instance are not added, since the merged texts are checked by the policies of this instance instead. If merging fails, this instance is left unchanged.
+    ///
+    /// The deprecations, migrations, number symbols, locale groups and modification times of the other instance are added too, replacing the ones of this instance only with `ConflictPolicy::Overwrite`, except that the later modification time of a catalog is kept. The default key, the observer, the missing handler, the build steps and the secondary context of this instance are kept.
+    pub fn merge(
+        &mut self,
+        other: JSONGetText<'a>,
+        policy: ConflictPolicy,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let JSONGetText {
+            context: other_context,
+            filled: other_filled,
+            deprecated: other_deprecated,
+            migrations: other_migrations,
+            quarantined: other_quarantined,
+            number_symbols: other_number_symbols,
+            locale_groups: other_locale_groups,
+            last_modified: other_last_modified,
+            namespaces: other_namespaces,
+            ..
+        } = other;
+
+        self.merge_context(other_context, &other_filled, policy)?;
+
+        let overwrite = policy == ConflictPolicy::Overwrite;
+
+        for (text, replacement) in other_deprecated {
+            if overwrite || !self.deprecated.contains_key(&text) {
+                self.deprecated.insert(text, replacement);
+            }
+        }
+
+        for (from, to) in other_migrations {
+            if overwrite || !self.migrations.contains_key(&from) {
+                self.migrations.insert(from, to);
+            }
+        }
+
+        for (key, symbols) in other_number_symbols {
+            if overwrite || !self.number_symbols.contains_key(&key) {
+                self.number_symbols.insert(key, symbols);
+            }
+        }
+
+        for (name, keys) in other_locale_groups {
+            if overwrite || !self.locale_groups.contains_key(&name) {
+                self.locale_groups.insert(name, keys);
+            }
+        }
+
+        for (id, time) in other_last_modified {
+            record_modified(&mut self.last_modified, id, time);
+        }
+
+        self.namespaces.extend(other_namespaces);
+        self.quarantined.extend(other_quarantined);
+
+        Ok(())
+    }
+
+    /// Merge a context into the catalogs of this instance like `merge`, ignoring the texts in `other_filled`. The merged texts go through the build steps of this instance, and the keys whose texts may have changed are filled again from their fallback keys and the default key. If merging fails, this instance is left unchanged.
+    pub(crate) fn merge_context(
+        &mut self,
+        other_context: HashMap<Key, LocaleShard<'a>>,
+        other_filled: &HashMap<Key, HashSet<String>>,
+        policy: ConflictPolicy,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let default_key = self.default_entry().0.to_owned();
+
+        let mut other_context: Context<'a> = other_context
+            .into_iter()
+            .map(|(key, shard)| {
+                let other_filled = other_filled.get(&key);
+
+                let mut map = Arc::try_unwrap(shard).unwrap_or_else(|shard| shard.as_ref().clone());
+
+                map.retain(|text, _| !other_filled.is_some_and(|f| f.contains(text)));
+
+                (key, map)
+            })
+            .collect();
+
+        let mut findings = Findings::default();
+
+        self.pipeline.prepare(&default_key, &mut other_context, &mut findings)?;
+
+        let mut maps: HashMap<Key, (HashMap<String, JSONGetTextValue<'a>>, HashSet<String>)> =
+            HashMap::with_capacity(other_context.len());
+
+        for (key, texts) in other_context {
+            let mut map = match self.context.get(&key) {
+                Some(map) => map.as_ref().clone(),
+                None => HashMap::new(),
+            };
+
+            let mut filled = self.filled.get(&key).cloned().unwrap_or_default();
+
+            merge_texts(&key, &mut map, &mut filled, texts, policy)?;
+
+            maps.insert(key, (map, filled));
+        }
+
+        let default_map = maps.remove(&default_key).map(|(map, _)| map);
+
+        self.refill_keys(maps, default_map, &mut findings.extra_texts)?;
+
+        self.empty_texts.retain(|empty| {
+            !findings.empty_texts.iter().any(|e| e.key == empty.key && e.text == empty.text)
+        });
+        self.empty_texts.extend(findings.empty_texts);
+        self.empty_texts.sort_unstable();
+
+        self.quarantined.extend(findings.quarantined);
+
+        Ok(())
+    }
+
+    /// Fill the keys in `maps` again, along with every other key if the default key changed and the keys which take texts from a changed key by their fallback chains, and replace them and the default key in this instance. `maps` holds the new texts of the keys other than the default key, with the ones which were filled, `default_map` the new texts of the default key if they changed, and `extra_texts` the extra texts found in the new texts so far. If filling fails, this instance is left unchanged.
+    pub(crate) fn refill_keys(
+        &mut self,
+        mut maps: HashMap<Key, (HashMap<String, JSONGetTextValue<'a>>, HashSet<String>)>,
+        mut default_map: Option<HashMap<String, JSONGetTextValue<'a>>>,
+        extra_texts: &mut Vec<ExtraText>,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let default_key = self.default_entry().0.to_owned();
+
+        let default_changed = default_map.is_some();
+
+        if let Some(default_map) = default_map.as_mut() {
+            inline_references(&default_key, default_map)?;
+            validate_placeholder_defaults(&default_key, default_map)?;
+        }
+
+        let new_default_map = default_map.as_ref().unwrap_or_else(|| self.default_map());
+
+        // the keys which take texts from a changed key are filled again too
+        for (key, map) in self.context.iter() {
+            if *key == default_key || maps.contains_key(key) {
+                continue;
+            }
+
+            if default_changed
+                || self
+                    .pipeline
+                    .fallbacks
+                    .get(key)
+                    .is_some_and(|chain| chain.iter().any(|fallback| maps.contains_key(fallback)))
+            {
+                let filled = self.filled.get(key).cloned().unwrap_or_default();
+
+                maps.insert(key.to_owned(), (map.as_ref().clone(), filled));
+            }
+        }
+
+        // only the texts which are not filled are kept, so that every key is filled from scratch
+        for (map, filled) in maps.values_mut() {
+            map.retain(|text, _| !filled.contains(text));
+        }
+
+        let fallbacks: HashMap<Key, Vec<Key>> = self
+            .pipeline
+            .fallbacks
+            .iter()
+            .filter(|(key, _)| maps.contains_key(*key))
+            .map(|(key, chain)| (key.to_owned(), chain.clone()))
+            .collect();
+
+        let mut own_context: Context<'a> = HashMap::new();
+
+        for key in fallbacks.iter().flat_map(|(key, chain)| std::iter::once(key).chain(chain)) {
+            if *key == default_key || own_context.contains_key(key) {
+                continue;
+            }
+
+            let map = match maps.get(key) {
+                Some((map, _)) => map.clone(),
+                None => {
+                    match self.context.get(key) {
+                        Some(map) => {
+                            let filled = self.filled.get(key);
+
+                            map.iter()
+                                .filter(|(text, _)| !filled.is_some_and(|f| f.contains(*text)))
+                                .map(|(text, value)| (text.clone(), value.clone()))
+                                .collect()
+                        }
+                        None => continue,
+                    }
+                }
+            };
+
+            own_context.insert(key.to_owned(), map);
+        }
+
+        let mut fallback_texts = collect_fallback_texts(&own_context, &default_key, &fallbacks)?;
+
+        let mut stale = HashMap::with_capacity(maps.len());
+
+        for (key, (map, filled)) in maps.iter_mut() {
+            let (filled_texts, stale_texts) = self.pipeline.complete(
+                key,
+                map,
+                new_default_map,
+                fallback_texts.remove(key),
+                extra_texts,
+            )?;
+
+            *filled = filled_texts;
+
+            stale.insert(key.to_owned(), stale_texts);
+        }
+
+        let refilled: HashSet<String> = maps.keys().map(|key| key.to_string()).collect();
+
+        // pruned texts are not in the maps to be found again, so their records are kept
+        if self.pipeline.strictness != Strictness::Prune {
+            self.extra_texts.retain(|extra| !refilled.contains(&extra.key));
+        }
+
+        self.extra_texts.append(extra_texts);
+        self.extra_texts.sort_unstable();
+        self.extra_texts.dedup();
+
+        self.stale.extend(stale);
+
+        for (key, (map, filled)) in maps {
+            #[cfg(feature = "plural_rules")]
+            self.plural_rules.cache(&key);
+
+            self.filled.insert(key.to_owned(), filled);
+            self.context.insert(key, Arc::new(map));
+        }
+
+        if let Some(default_map) = default_map {
+            self.context.insert(default_key, Arc::new(default_map));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Merge a JSON file into the texts which have been added for a key, or add it if there are none, resolving a text which is already there by the conflict policy. If merging fails, the texts of the key are left unchanged. The JSON file must represent a map object (key-value). Merged files are not watched by `JSONGetTextReloadable`.
+    #[cfg(feature = "fs")]
+    pub fn add_json_file_merge<K: Into<Key>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+        policy: ConflictPolicy,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let limits = self.options.ingest_limits;
+
+        let json = limits.read_file(&key, path.as_ref())?;
+
+        limits.check_json(&json).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let value: Map<String, Value> = serde_json::from_slice(&json)?;
+
+        let mut map = self.context.get(&key).cloned().unwrap_or_default();
+
+        let texts = value.into_iter().map(|(k, v)| (k, JSONGetTextValue::from_json_value(v)));
+
+        merge_texts(&key, &mut map, &mut HashSet::new(), texts, policy)?;
+
+        limits.check_texts(map.len()).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        self.record_file_modified(CatalogId::new(key.to_owned(), None), path.as_ref());
+
+        self.context.insert(key, map);
+
+        Ok(self)
+    }
+}
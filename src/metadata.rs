@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+/// Texts starting with `@` are considered as metadata, just like the ARB format. For example, `"@hello"` describes `"hello"`.
+#[inline]
+pub(crate) fn is_metadata_text(text: &str) -> bool {
+    text.starts_with('@')
+}
+
+/// Whether a text of a key other than the default key is in the default key. A metadata text is also accepted if the default key has the text it describes, e.g. `"@hello"` when the default key has `"hello"`.
+#[inline]
+pub(crate) fn is_in_default_map<V>(text: &str, default_map: &HashMap<String, V>) -> bool {
+    default_map.contains_key(text)
+        || text.strip_prefix('@').is_some_and(|text| default_map.contains_key(text))
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::{JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue};
+
+/// Make sure every migration points to a text in the default key.
+pub(crate) fn validate_migrations(
+    migrations: &HashMap<String, String>,
+    default_map: &HashMap<String, JSONGetTextValue>,
+) -> Result<(), JSONGetTextBuildError> {
+    for (from, to) in migrations.iter() {
+        if !default_map.contains_key(to) {
+            return Err(JSONGetTextBuildError::MigrationTargetNotFound {
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Let an old text keep resolving to a new text. If a lookup misses the old text, the new text is used instead and the usage is reported to the observer.
+    #[inline]
+    pub fn add_migration<F: Into<String>, T: Into<String>>(&mut self, from: F, to: T) -> &mut Self {
+        self.options.migrations.insert(from.into(), to.into());
+
+        self
+    }
+
+    /// Add multiple migrations from a table of old texts and new texts.
+    pub fn add_migrations<I: IntoIterator<Item = (F, T)>, F: Into<String>, T: Into<String>>(
+        &mut self,
+        migrations: I,
+    ) -> &mut Self {
+        for (from, to) in migrations {
+            self.add_migration(from, to);
+        }
+
+        self
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the new text which an old text has been migrated to.
+    #[inline]
+    pub fn get_migration<T: AsRef<str>>(&self, text: T) -> Option<&str> {
+        self.migrations.get(text.as_ref()).map(|s| s.as_str())
+    }
+}
@@ -0,0 +1,96 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::{JSONGetText, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+type Handler = dyn Fn(&Key, &str) -> Option<JSONGetTextValue<'static>> + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct MissingHandler(pub(crate) Arc<Handler>);
+
+impl Debug for MissingHandler {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("MissingHandler")
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get text from the string map of a key like `lookup_text_read_through`, and let the missing handler handle a miss.
+    #[inline]
+    pub(crate) fn lookup_text_or_missing<'b>(
+        &'b self,
+        key: &Key,
+        map: &'b HashMap<String, JSONGetTextValue<'a>>,
+        text: &str,
+    ) -> Option<JSONGetTextValue<'b>> {
+        match self.lookup_text_read_through(key, map, text) {
+            Some(value) => Some(value),
+            None => self.missing_handler.as_ref().and_then(|handler| (handler.0)(key, text)),
+        }
+    }
+
+    /// Get text from context, never returning `None`. If the text is not found, even by the missing handler, the text id itself is returned.
+    #[inline]
+    pub fn get_text_or_default<T: AsRef<str>>(&'a self, text: T) -> JSONGetTextValue<'a> {
+        let (key, map) = self.default_entry();
+        let text = text.as_ref();
+
+        match self.lookup_text_or_missing(key, map, text) {
+            Some(value) => value,
+            None => JSONGetTextValue::from_string(text),
+        }
+    }
+
+    /// Get text from context with a specific key, never returning `None`. If the key is not in context or does not have the text, the default key is used, and if the text is not found there either, nor by the missing handler, the text id itself is returned.
+    pub fn get_text_with_key_or_default<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &'a self,
+        key: &Q,
+        text: T,
+    ) -> JSONGetTextValue<'a>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry_or_default(key);
+        let text = text.as_ref();
+
+        let value = self.lookup_text_read_through(key, map, text).or_else(|| {
+            let default_map = self.default_map();
+
+            if ptr::eq(map, default_map) {
+                None
+            } else {
+                self.lookup_text(default_map, text)
+            }
+        });
+
+        if let Some(value) = value {
+            return value;
+        }
+
+        if let Some(value) =
+            self.missing_handler.as_ref().and_then(|handler| (handler.0)(key, text))
+        {
+            return value;
+        }
+
+        JSONGetTextValue::from_string(text)
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set a handler which is called with the key and the text whenever `get_text`, `get_text_with_key`, `get_multiple_text` or `get_multiple_text_with_key` misses a text, e.g. to log it, to return a marker like `⟦text⟧`, or to fetch it from a remote source. The value it returns, if any, is returned instead of `None`. If the key of a lookup is not in context, the handler gets the default key, whose catalog was searched.
+    pub fn set_missing_handler<
+        F: Fn(&Key, &str) -> Option<JSONGetTextValue<'static>> + Send + Sync + 'static,
+    >(
+        &mut self,
+        handler: F,
+    ) -> &mut Self {
+        self.options.missing_handler = Some(MissingHandler(Arc::new(handler)));
+
+        self
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// The texts of every key of a `JSONGetText` instance as plain JSON values, without metadata (texts starting with `@`). Texts filled from the default key are included, so two instances with the same texts have equal models regardless of how their catalogs were written, which makes it suitable for checking that an exporter and an importer round-trip.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogModel {
+    pub default_key: Key,
+    pub catalogs: HashMap<Key, BTreeMap<String, Value>>,
+}
+
+impl CatalogModel {
+    /// Build a `JSONGetText` instance with the texts of this model.
+    pub fn build(&self) -> Result<JSONGetText<'static>, JSONGetTextBuildError> {
+        let mut builder = JSONGetTextBuilder::from(self.default_key.to_owned());
+
+        for (key, texts) in self.catalogs.iter() {
+            let map = texts
+                .iter()
+                .map(|(text, value)| {
+                    (text.clone(), JSONGetTextValue::from_json_value(value.clone()))
+                })
+                .collect();
+
+            builder.add_map(key.to_owned(), map)?;
+        }
+
+        builder.build()
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the model of this instance.
+    pub fn to_model(&self) -> CatalogModel {
+        let catalogs = self
+            .context
+            .iter()
+            .map(|(key, map)| {
+                let texts = map
+                    .iter()
+                    .filter(|(text, _)| !is_metadata_text(text))
+                    .map(|(text, value)| (text.clone(), serde_json::to_value(value).unwrap()))
+                    .collect();
+
+                (key.to_owned(), texts)
+            })
+            .collect();
+
+        CatalogModel {
+            default_key: self.default_entry().0.to_owned(),
+            catalogs,
+        }
+    }
+}
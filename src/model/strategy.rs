@@ -0,0 +1,98 @@
+//! `proptest` strategies generating catalogs, for checking that exporters and importers round-trip.
+
+use std::collections::{BTreeMap, HashMap};
+
+use proptest::collection::{btree_map, vec};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use serde_json::{Map, Value};
+
+use crate::{CatalogModel, JSONGetText, JSONGetTextBuildError, Key};
+
+/// Generate text ids like `menu.file_open`. Ids never start with `@`, which is reserved for metadata.
+pub fn text_id() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_]{0,11}(\\.[a-z][a-z0-9_]{0,11}){0,2}"
+}
+
+/// Generate text values: mostly strings (with placeholders, escaped braces and non-ASCII characters), as well as other JSON values nested up to 2 levels. Floating-point numbers are not generated since `serde_json` does not round-trip all of them exactly by default.
+pub fn text_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        4 => "([^{}]|\\{[a-z_]{1,8}\\}|\\{\\{|\\}\\}){0,24}".prop_map(Value::String),
+        1 => any::<bool>().prop_map(Value::Bool),
+        1 => any::<i64>().prop_map(Value::from),
+        1 => any::<u64>().prop_map(Value::from),
+        1 => Just(Value::Null),
+    ];
+
+    leaf.prop_recursive(2, 16, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::Array),
+            btree_map("[a-z]{1,8}", inner, 0..4)
+                .prop_map(|map| Value::Object(map.into_iter().collect::<Map<String, Value>>())),
+        ]
+    })
+}
+
+/// Generate the texts of a catalog.
+pub fn catalog() -> impl Strategy<Value = BTreeMap<String, Value>> {
+    btree_map(text_id(), text_value(), 0..16)
+}
+
+/// Generate a model with the given keys. The catalog of every key other than the default key has a random subset of the texts of the default key, and the rest of the texts are filled from the default key, as `JSONGetText` does.
+pub fn catalog_model(default_key: Key, keys: Vec<Key>) -> impl Strategy<Value = CatalogModel> {
+    catalog().prop_flat_map(move |default_texts| {
+        let others: Vec<_> = keys
+            .iter()
+            .filter(|&key| *key != default_key)
+            .map(|key| {
+                let key = key.to_owned();
+                let default_texts = default_texts.clone();
+
+                vec(proptest::option::of(text_value()), default_texts.len()).prop_map(
+                    move |values| {
+                        let texts = default_texts
+                            .iter()
+                            .zip(values)
+                            .map(|((text, default_value), value)| {
+                                (text.clone(), value.unwrap_or_else(|| default_value.clone()))
+                            })
+                            .collect::<BTreeMap<String, Value>>();
+
+                        (key.to_owned(), texts)
+                    },
+                )
+            })
+            .collect();
+
+        let default_key = default_key.to_owned();
+
+        (Just(default_texts), others).prop_map(move |(default_texts, others)| {
+            let mut catalogs: HashMap<Key, BTreeMap<String, Value>> = others.into_iter().collect();
+
+            catalogs.insert(default_key.to_owned(), default_texts);
+
+            CatalogModel {
+                default_key: default_key.to_owned(),
+                catalogs,
+            }
+        })
+    })
+}
+
+/// Build a model, export it with `export`, import the result with `import`, and check that the imported instance has the same model.
+pub fn check_round_trip<T, E, I>(
+    model: &CatalogModel,
+    export: E,
+    import: I,
+) -> Result<(), TestCaseError>
+where
+    E: FnOnce(&JSONGetText<'static>) -> T,
+    I: FnOnce(T) -> Result<JSONGetText<'static>, JSONGetTextBuildError>, {
+    let ctx = model.build().map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+    let imported = import(export(&ctx)).map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+    prop_assert_eq!(model, &imported.to_model());
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::fs;
+use std::hash::Hash;
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::{JSONGetText, JSONGetTextBuilder, Key};
+
+/// A catalog whose modification time is tracked, which is either the texts of a key or the texts of a namespace of a key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CatalogId {
+    pub key: Key,
+    pub namespace: Option<String>,
+}
+
+impl CatalogId {
+    #[inline]
+    pub(crate) fn new(key: Key, namespace: Option<String>) -> CatalogId {
+        CatalogId {
+            key,
+            namespace,
+        }
+    }
+}
+
+/// Record a modification time, keeping the later one if the catalog already has one.
+#[inline]
+pub(crate) fn record_modified(
+    last_modified: &mut HashMap<CatalogId, SystemTime>,
+    id: CatalogId,
+    time: SystemTime,
+) {
+    let entry = last_modified.entry(id).or_insert(time);
+
+    if *entry < time {
+        *entry = time;
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set the modification time of the texts of a key, e.g. from metadata of the storage they come from. The modification times of JSON files are recorded when they are added.
+    #[inline]
+    pub fn set_last_modified<K: Into<Key>>(&mut self, key: K, time: SystemTime) -> &mut Self {
+        self.options.last_modified.insert(CatalogId::new(key.into(), None), time);
+
+        self
+    }
+
+    /// Set the modification time of the texts of a namespace of a key, like `set_last_modified`.
+    #[inline]
+    pub fn set_last_modified_ns<K: Into<Key>, N: Into<String>>(
+        &mut self,
+        key: K,
+        namespace: N,
+        time: SystemTime,
+    ) -> &mut Self {
+        self.options.last_modified.insert(CatalogId::new(key.into(), Some(namespace.into())), time);
+
+        self
+    }
+
+    /// Record the modification time of a file added to a catalog. Platforms without modification times of files are ignored.
+    #[cfg(feature = "fs")]
+    pub(crate) fn record_file_modified(&mut self, id: CatalogId, path: &Path) {
+        if let Ok(time) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            record_modified(&mut self.options.last_modified, id, time);
+        }
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the modification time of the texts of a key, if it is known.
+    #[inline]
+    pub fn last_modified<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<SystemTime>
+    where
+        Key: Borrow<Q>, {
+        let (key, _) = self.find_entry(key)?;
+
+        self.last_modified.get(&CatalogId::new(key.to_owned(), None)).copied()
+    }
+
+    /// Get the modification time of the texts of a namespace of a key, if it is known.
+    #[inline]
+    pub fn last_modified_ns<Q: ?Sized + Hash + Eq, N: Into<String>>(
+        &self,
+        key: &Q,
+        namespace: N,
+    ) -> Option<SystemTime>
+    where
+        Key: Borrow<Q>, {
+        let (key, _) = self.find_entry(key)?;
+
+        self.last_modified.get(&CatalogId::new(key.to_owned(), Some(namespace.into()))).copied()
+    }
+
+    /// Get the catalogs which were modified after a time, ordered by their modification times, e.g. to sync only them to downstream caches and clients. Catalogs whose modification times are unknown are not included.
+    pub fn changed_since(&self, since: SystemTime) -> Vec<(&CatalogId, SystemTime)> {
+        let mut changed: Vec<(&CatalogId, SystemTime)> = self
+            .last_modified
+            .iter()
+            .filter(|(_, time)| **time > since)
+            .map(|(id, time)| (id, *time))
+            .collect();
+
+        changed.sort_by_key(|(_, time)| *time);
+
+        changed
+    }
+}
@@ -0,0 +1,129 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::migration::validate_migrations;
+use crate::{
+    ConflictPolicy, JSONGetText, JSONGetTextBuildError, JSONGetTextValue, Key, LocaleShard,
+};
+
+impl<'a> JSONGetText<'a> {
+    /// Insert a text into a key, replacing the text which is already there, e.g. after it is updated in a database. The text goes through the build steps and is filled into the other keys as `merge` does, so a text which is not in the default key is an error unless the strictness allows it. If inserting fails, this instance is left unchanged.
+    #[inline]
+    pub fn insert_text<K: Into<Key>, T: Into<String>>(
+        &mut self,
+        key: K,
+        text: T,
+        value: JSONGetTextValue<'a>,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let mut map = HashMap::with_capacity(1);
+
+        map.insert(text.into(), value);
+
+        self.merge_map(key.into(), map, ConflictPolicy::Overwrite)
+    }
+
+    /// Remove a text from a key and return its value, or `None` if the key does not have the text or only has it filled from another key. The text is filled again from the fallback keys and the default key, as when building, and so are the keys which take texts from this key by their fallback chains. Removing a text from the default key removes it from every key, since the other keys cannot have texts which are not in the default key, and fails if a migration or a `{name|text_id}` placeholder default still points at it. If removing fails, this instance is left unchanged.
+    pub fn remove_text<Q: ?Sized + Hash + Eq>(
+        &mut self,
+        key: &Q,
+        text: &str,
+    ) -> Result<Option<JSONGetTextValue<'a>>, JSONGetTextBuildError>
+    where
+        Key: Borrow<Q>, {
+        let key = match self.find_entry(key) {
+            Some((key, _)) => key.to_owned(),
+            None => return Ok(None),
+        };
+
+        if self.is_filled_text(&key, text) {
+            return Ok(None);
+        }
+
+        let (default_key, default_map) = self.default_entry();
+
+        if key == *default_key {
+            let mut default_map = default_map.clone();
+
+            let value = match default_map.remove(text) {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+
+            validate_migrations(&self.migrations, &default_map)?;
+
+            let maps = self
+                .context
+                .iter()
+                .filter(|(other_key, _)| **other_key != key)
+                .map(|(other_key, map)| {
+                    let mut map = map.as_ref().clone();
+                    let mut filled = self.filled.get::<Key>(other_key).cloned().unwrap_or_default();
+
+                    map.remove(text);
+                    filled.remove(text);
+
+                    (other_key.to_owned(), (map, filled))
+                })
+                .collect();
+
+            self.refill_keys(maps, Some(default_map), &mut Vec::new())?;
+
+            self.empty_texts.retain(|empty| empty.text != text);
+
+            return Ok(Some(value));
+        }
+
+        let mut map = self.context.get::<Key>(&key).unwrap().as_ref().clone();
+
+        let value = match map.remove(text) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let filled = self.filled.get::<Key>(&key).cloned().unwrap_or_default();
+
+        let key_name = key.to_string();
+
+        let mut maps = HashMap::with_capacity(1);
+
+        maps.insert(key, (map, filled));
+
+        self.refill_keys(maps, None, &mut Vec::new())?;
+
+        self.empty_texts.retain(|empty| empty.key != key_name || empty.text != text);
+
+        Ok(Some(value))
+    }
+
+    /// Add a key with its string map, e.g. when a new locale is enabled. Its texts go through the build steps and its missing texts are filled from its fallback keys and the default key as `merge` does, so a text which is not in the default key is an error unless the strictness allows it. If adding fails, this instance is left unchanged.
+    #[inline]
+    pub fn add_language<K: Into<Key>>(
+        &mut self,
+        key: K,
+        map: HashMap<String, JSONGetTextValue<'a>>,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let key = key.into();
+
+        if self.context.contains_key(&key) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key));
+        }
+
+        self.merge_map(key, map, ConflictPolicy::Error)
+    }
+
+    /// Merge a string map into a key like `merge`.
+    #[inline]
+    pub(crate) fn merge_map(
+        &mut self,
+        key: Key,
+        map: HashMap<String, JSONGetTextValue<'a>>,
+        policy: ConflictPolicy,
+    ) -> Result<(), JSONGetTextBuildError> {
+        let mut context = HashMap::with_capacity(1);
+
+        context.insert(key, LocaleShard::new(map));
+
+        self.merge_context(context, &HashMap::new(), policy)
+    }
+}
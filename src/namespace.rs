@@ -0,0 +1,195 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+#[cfg(feature = "fs")]
+use crate::CatalogId;
+use crate::{
+    Context, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+/// The catalogs added to namespaces, as `(key, namespace, map)`, in the order they are added.
+pub(crate) type Namespaces = Vec<(Key, String, HashMap<String, JSONGetTextValue<'static>>)>;
+
+/// The catalogs of a namespace embedded from a directory of a crate, e.g. its `locales` directory, by `json_gettext_namespace!`. Each crate of a workspace can register its own namespace, which an application composes with `JSONGetTextBuilder::add_crate_namespace`.
+#[cfg(feature = "embed_dir")]
+#[derive(Debug, Clone, Copy)]
+pub struct CrateNamespace {
+    /// The name of the crate which registers the namespace.
+    pub crate_name: &'static str,
+    pub namespace: &'static str,
+    /// The JSON files of the directory as `(file_stem, content)`, sorted by file names.
+    pub files: &'static [(&'static str, &'static str)],
+}
+
+/// Get the text id of a text in a namespace, which is the text prefixed with the namespace and a dot. The metadata text of a text in a namespace is `@namespace.text`.
+#[inline]
+fn namespaced_text(namespace: &str, text: &str) -> String {
+    match text.strip_prefix('@') {
+        Some(text) => format!("@{}.{}", namespace, text),
+        None => format!("{}.{}", namespace, text),
+    }
+}
+
+/// Move the catalogs of namespaces into the string maps of their keys. A text which is already in the key is an error. Returns the names of the namespaces.
+pub(crate) fn merge_namespaces(
+    context: &mut Context,
+    namespaces: Namespaces,
+) -> Result<HashSet<String>, JSONGetTextBuildError> {
+    let mut names = HashSet::with_capacity(namespaces.len());
+
+    for (key, namespace, map) in namespaces {
+        let target = context.entry(key.to_owned()).or_default();
+
+        for (text, value) in map {
+            let text = namespaced_text(&namespace, &text);
+
+            if target.contains_key(&text) {
+                return Err(JSONGetTextBuildError::DuplicatedText {
+                    key,
+                    text,
+                });
+            }
+
+            target.insert(text, value);
+        }
+
+        names.insert(namespace);
+    }
+
+    Ok(names)
+}
+
+/// Convert a parsed JSON object into a string map.
+#[inline]
+fn map_of(value: Map<String, Value>) -> HashMap<String, JSONGetTextValue<'static>> {
+    value.into_iter().map(|(k, v)| (k, JSONGetTextValue::from_json_value(v))).collect()
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add a JSON string to a namespace of a key, e.g. `errors` or `emails`. The JSON string must represent a map object (key-value). Its texts are looked up by `get_text_ns`, or as `namespace.text` by the other methods, and are filled from the default key, checked and referenced like the other texts of the key. A namespace can be split across several JSON strings, but a text which is already in the key is an error when building.
+    pub fn add_json_ns<K: Into<Key>, N: Into<String>, J: AsRef<str>>(
+        &mut self,
+        key: K,
+        namespace: N,
+        json: J,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+        let json = json.as_ref();
+
+        self.options
+            .ingest_limits
+            .check_json(json.as_bytes())
+            .map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let value: Map<String, Value> = serde_json::from_str(json)?;
+
+        self.options
+            .ingest_limits
+            .check_texts(value.len())
+            .map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        self.options.namespaces.push((key, namespace.into(), map_of(value)));
+
+        Ok(self)
+    }
+
+    /// Add the catalogs a crate registered by `json_gettext_namespace!` to its namespace, with the keys given by the file stems like `static_json_gettext_build_dir!`. Files whose stems are not language tags are skipped. A namespace which was already registered, by another crate or the same one, is an error, and so is a text which is already in a key when building.
+    #[cfg(feature = "embed_dir")]
+    pub fn add_crate_namespace(
+        &mut self,
+        namespace: &CrateNamespace,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        if let Some(crate_name) = self.options.crate_namespaces.get(namespace.namespace) {
+            return Err(JSONGetTextBuildError::NamespaceCollision {
+                namespace: namespace.namespace.to_string(),
+                crates: [crate_name.to_string(), namespace.crate_name.to_string()],
+            });
+        }
+
+        for (file_stem, json) in namespace.files {
+            if let Some(key) = crate::key_from_file_stem(file_stem) {
+                self.add_json_ns(key, namespace.namespace, json)?;
+            }
+        }
+
+        self.options.crate_namespaces.insert(namespace.namespace, namespace.crate_name);
+
+        Ok(self)
+    }
+
+    /// Add a JSON file to a namespace of a key, like `add_json_ns`. The JSON file must represent a map object (key-value). Files in namespaces are not watched by `JSONGetTextReloadable`.
+    #[cfg(feature = "fs")]
+    pub fn add_json_file_ns<K: Into<Key>, N: Into<String>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        namespace: N,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let limits = self.options.ingest_limits;
+
+        let json = limits.read_file(&key, path.as_ref())?;
+
+        limits.check_json(&json).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let value: Map<String, Value> = serde_json::from_slice(&json)?;
+
+        limits.check_texts(value.len()).map_err(|limit| limit.exceeded_by(key.to_owned()))?;
+
+        let namespace = namespace.into();
+
+        self.record_file_modified(
+            CatalogId::new(key.to_owned(), Some(namespace.clone())),
+            path.as_ref(),
+        );
+
+        self.options.namespaces.push((key, namespace, map_of(value)));
+
+        Ok(self)
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the namespace which a text id is in, preferring the longest name if namespaces are nested.
+    pub(crate) fn namespace_of(&self, id: &str) -> Option<&str> {
+        self.namespaces
+            .iter()
+            .filter(|namespace| {
+                id.strip_prefix(namespace.as_str()).is_some_and(|rest| rest.starts_with('.'))
+            })
+            .max_by_key(|namespace| namespace.len())
+            .map(|namespace| namespace.as_str())
+    }
+
+    /// Get text in a namespace from context.
+    #[inline]
+    pub fn get_text_ns<N: AsRef<str>, T: AsRef<str>>(
+        &'a self,
+        namespace: N,
+        text: T,
+    ) -> Option<JSONGetTextValue<'a>> {
+        self.lookup_text(self.default_map(), &namespaced_text(namespace.as_ref(), text.as_ref()))
+    }
+
+    /// Get text in a namespace from context with a specific key. If the key is not in context, the default key is used.
+    #[inline]
+    pub fn get_text_ns_with_key<Q: ?Sized + Hash + Eq, N: AsRef<str>, T: AsRef<str>>(
+        &'a self,
+        key: &Q,
+        namespace: N,
+        text: T,
+    ) -> Option<JSONGetTextValue<'a>>
+    where
+        Key: Borrow<Q>, {
+        self.lookup_text(
+            self.find_map_or_default(key),
+            &namespaced_text(namespace.as_ref(), text.as_ref()),
+        )
+    }
+}
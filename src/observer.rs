@@ -0,0 +1,23 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+/// Gets notified about how texts are looked up, e.g. for tracing or metrics. All methods do nothing by default.
+pub trait LookupObserver: Send + Sync {
+    /// Called when a deprecated text is looked up.
+    #[allow(unused_variables)]
+    fn deprecated_text_used(&self, text: &str, replacement: Option<&str>) {}
+
+    /// Called when a migrated (old) text is looked up and resolved to the new text.
+    #[allow(unused_variables)]
+    fn migrated_text_used(&self, from: &str, to: &str) {}
+}
+
+#[derive(Clone)]
+pub(crate) struct Observer(pub(crate) Arc<dyn LookupObserver>);
+
+impl Debug for Observer {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("LookupObserver")
+    }
+}
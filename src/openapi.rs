@@ -0,0 +1,169 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use serde_json::Value;
+
+use crate::{JSONGetText, Key};
+
+const METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+#[inline]
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Find the translatable strings of an OpenAPI document as pairs of a text id and a JSON pointer.
+fn collect_texts(namespace: &str, document: &Value) -> Vec<(String, String)> {
+    let mut texts = Vec::new();
+
+    let mut push = |id: String, pointer: String| {
+        if document.pointer(&pointer).is_some_and(Value::is_string) {
+            texts.push((id, pointer));
+        }
+    };
+
+    for field in ["title", "summary", "description"] {
+        push(format!("{}.info.{}", namespace, field), format!("/info/{}", field));
+    }
+
+    if let Some(tags) = document.get("tags").and_then(Value::as_array) {
+        for (i, tag) in tags.iter().enumerate() {
+            if let Some(name) = tag.get("name").and_then(Value::as_str) {
+                push(
+                    format!("{}.tags.{}.description", namespace, name),
+                    format!("/tags/{}/description", i),
+                );
+            }
+        }
+    }
+
+    if let Some(paths) = document.get("paths").and_then(Value::as_object) {
+        for (path, item) in paths {
+            let path = escape_pointer(path);
+
+            for method in METHODS {
+                let Some(operation) = item.get(method) else {
+                    continue;
+                };
+
+                let Some(operation_id) = operation.get("operationId").and_then(Value::as_str)
+                else {
+                    continue;
+                };
+
+                let id = format!("{}.operations.{}", namespace, operation_id);
+                let pointer = format!("/paths/{}/{}", path, method);
+
+                for field in ["summary", "description"] {
+                    push(format!("{}.{}", id, field), format!("{}/{}", pointer, field));
+                }
+
+                if let Some(parameters) = operation.get("parameters").and_then(Value::as_array) {
+                    for (i, parameter) in parameters.iter().enumerate() {
+                        if let Some(name) = parameter.get("name").and_then(Value::as_str) {
+                            push(
+                                format!("{}.parameters.{}", id, name),
+                                format!("{}/parameters/{}/description", pointer, i),
+                            );
+                        }
+                    }
+                }
+
+                if let Some(responses) = operation.get("responses").and_then(Value::as_object) {
+                    for status in responses.keys() {
+                        push(
+                            format!("{}.responses.{}", id, status),
+                            format!("{}/responses/{}/description", pointer, escape_pointer(status)),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(schemas) = document.pointer("/components/schemas").and_then(Value::as_object) {
+        for (name, schema) in schemas {
+            let id = format!("{}.schemas.{}", namespace, name);
+            let pointer = format!("/components/schemas/{}", escape_pointer(name));
+
+            for field in ["title", "description"] {
+                push(format!("{}.{}", id, field), format!("{}/{}", pointer, field));
+            }
+
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for property in properties.keys() {
+                    push(
+                        format!("{}.properties.{}", id, property),
+                        format!("{}/properties/{}/description", pointer, escape_pointer(property)),
+                    );
+                }
+            }
+        }
+    }
+
+    texts
+}
+
+/// Extract the translatable strings (titles, summaries and descriptions) of an OpenAPI document as texts under a namespace, which can be used as the default-key catalog. The ids are
+///
+/// * `{namespace}.info.{title|summary|description}`
+/// * `{namespace}.tags.{tag}.description`
+/// * `{namespace}.operations.{operationId}.{summary|description}`
+/// * `{namespace}.operations.{operationId}.parameters.{parameter}`
+/// * `{namespace}.operations.{operationId}.responses.{status}`
+/// * `{namespace}.schemas.{schema}.{title|description}`
+/// * `{namespace}.schemas.{schema}.properties.{property}`
+///
+/// Operations without an `operationId` are skipped.
+pub fn extract_openapi_texts<N: AsRef<str>>(
+    namespace: N,
+    document: &Value,
+) -> BTreeMap<String, String> {
+    collect_texts(namespace.as_ref(), document)
+        .into_iter()
+        .map(|(id, pointer)| {
+            let text = document.pointer(&pointer).and_then(Value::as_str).unwrap().to_string();
+
+            (id, text)
+        })
+        .collect()
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Create a copy of an OpenAPI document whose strings are replaced with the texts of a specific key. The ids are the same as the ones of `extract_openapi_texts`. Strings without a text are kept. If the key is not in context, the default key is used.
+    pub fn localize_openapi<Q: ?Sized + Hash + Eq, N: AsRef<str>>(
+        &self,
+        key: &Q,
+        namespace: N,
+        document: &Value,
+    ) -> Value
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map_or_default(key);
+
+        let mut localized = document.clone();
+
+        for (id, pointer) in collect_texts(namespace.as_ref(), document) {
+            if let Some(text) = self.lookup_text(map, &id) {
+                *localized.pointer_mut(&pointer).unwrap() = Value::String(text.to_string());
+            }
+        }
+
+        localized
+    }
+
+    /// Create localized copies of an OpenAPI document for all keys in context.
+    pub fn localize_openapi_all<N: AsRef<str>>(
+        &self,
+        namespace: N,
+        document: &Value,
+    ) -> HashMap<Key, Value> {
+        let namespace = namespace.as_ref();
+
+        self.context
+            .keys()
+            .map(|key| (key.to_owned(), self.localize_openapi(key, namespace, document)))
+            .collect()
+    }
+}
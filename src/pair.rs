@@ -0,0 +1,34 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// A text of the default key along with the text of another key, e.g. for audit trails and support tools which show what a user saw next to the source text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TextPair<'b> {
+    pub default: JSONGetTextValue<'b>,
+    pub localized: JSONGetTextValue<'b>,
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get a text of the default key and of a specific key in one call. If the key is not in context, the default key is used, so both texts are the same. Return `None` if the text is not found in either key.
+    pub fn get_text_pair<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        text: T,
+    ) -> Option<TextPair<'_>>
+    where
+        Key: Borrow<Q>, {
+        let text = text.as_ref();
+
+        let default = self.lookup_text(self.default_map(), text)?;
+        let localized = self.lookup_text(self.find_map_or_default(key), text)?;
+
+        Some(TextPair {
+            default,
+            localized,
+        })
+    }
+}
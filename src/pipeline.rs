@@ -0,0 +1,98 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::empty::{collect_empty_texts, EmptyText, EmptyTextPolicies};
+use crate::export::collect_stale_texts;
+use crate::fill::fill_nested_texts;
+use crate::format::{validate_context_conditionals, validate_placeholder_defaults};
+use crate::reference::inline_references;
+use crate::scan::{QuarantinedText, Scanner};
+use crate::strictness::{check_extra_texts, ExtraText, Strictness};
+use crate::typography::normalize_context_quotation;
+use crate::{Context, JSONGetTextBuildError, JSONGetTextValue, Key};
+
+/// The options of the steps which the texts of every key go through when building. The built instance keeps them, so that the texts added by `merge`, `insert_text`, `add_language` and the like go through the same steps.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Pipeline {
+    pub(crate) scanner: Option<Scanner>,
+    pub(crate) normalize_quotation: bool,
+    pub(crate) strictness: Strictness,
+    pub(crate) empty_texts: EmptyTextPolicies,
+    pub(crate) fill_missing_from_default: bool,
+    pub(crate) fallbacks: HashMap<Key, Vec<Key>>,
+}
+
+/// The texts which the steps of a `Pipeline` quarantined or found empty or extra.
+#[derive(Debug, Default)]
+pub(crate) struct Findings {
+    pub(crate) quarantined: Vec<QuarantinedText>,
+    pub(crate) empty_texts: Vec<EmptyText>,
+    pub(crate) extra_texts: Vec<ExtraText>,
+}
+
+impl Pipeline {
+    /// Scan incoming texts, apply the empty-text policies, check conditionals and normalize quotation, before the texts are added to their keys.
+    pub(crate) fn prepare<Q: ?Sized + Hash + Eq>(
+        &self,
+        default_key: &Q,
+        context: &mut Context,
+        findings: &mut Findings,
+    ) -> Result<(), JSONGetTextBuildError>
+    where
+        Key: Borrow<Q>, {
+        if let Some(scanner) = self.scanner.as_ref() {
+            findings.quarantined.extend(scanner.scan_context(default_key, context)?);
+        }
+
+        findings.empty_texts.extend(collect_empty_texts(default_key, context, self.empty_texts));
+
+        // checked before the quotes in a malformed conditional are normalized into a literal
+        validate_context_conditionals(context)?;
+
+        if self.normalize_quotation {
+            normalize_context_quotation(context);
+        }
+
+        Ok(())
+    }
+
+    /// Check the texts of a key other than the default key by the strictness, adding the extra ones to `extra_texts`, fill its missing texts from its fallback texts (see `collect_fallback_texts`) and then from the default key, and inline its `{#text}` references. Returns the filled texts and the stale texts.
+    pub(crate) fn complete<'a>(
+        &self,
+        key: &Key,
+        map: &mut HashMap<String, JSONGetTextValue<'a>>,
+        default_map: &HashMap<String, JSONGetTextValue<'a>>,
+        fallback_texts: Option<Vec<(String, JSONGetTextValue<'a>)>>,
+        extra_texts: &mut Vec<ExtraText>,
+    ) -> Result<(HashSet<String>, HashSet<String>), JSONGetTextBuildError> {
+        check_extra_texts(key, map, default_map, self.strictness, extra_texts)?;
+
+        let stale_texts = collect_stale_texts(default_map, map);
+
+        let mut filled_texts = HashSet::new();
+
+        for (text, value) in fallback_texts.into_iter().flatten() {
+            map.insert(text.clone(), value);
+
+            filled_texts.insert(text);
+        }
+
+        for (text, value) in default_map.iter() {
+            if !map.contains_key(text) {
+                map.insert(text.clone(), value.clone());
+
+                filled_texts.insert(text.clone());
+            }
+        }
+
+        if self.fill_missing_from_default {
+            fill_nested_texts(map, default_map, &filled_texts);
+        }
+
+        inline_references(key, map)?;
+        validate_placeholder_defaults(key, map)?;
+
+        Ok((filled_texts, stale_texts))
+    }
+}
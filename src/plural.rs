@@ -0,0 +1,224 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+#[cfg(feature = "plural_rules")]
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+#[cfg(feature = "plural_rules")]
+use intl_pluralrules::{PluralCategory as RuleCategory, PluralRuleType, PluralRules};
+use serde_json::{Map, Value};
+#[cfg(feature = "plural_rules")]
+use unic_langid::LanguageIdentifier;
+
+#[cfg(feature = "plural_rules")]
+use crate::locale::language_of;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// A CLDR plural category. Plural texts are JSON objects whose fields are these categories, e.g. `{"apple": {"one": "{count} apple", "other": "{count} apples"}}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Get the field name of the category, e.g. `one`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+#[cfg(feature = "plural_rules")]
+impl From<RuleCategory> for PluralCategory {
+    #[inline]
+    fn from(category: RuleCategory) -> Self {
+        match category {
+            RuleCategory::ZERO => PluralCategory::Zero,
+            RuleCategory::ONE => PluralCategory::One,
+            RuleCategory::TWO => PluralCategory::Two,
+            RuleCategory::FEW => PluralCategory::Few,
+            RuleCategory::MANY => PluralCategory::Many,
+            RuleCategory::OTHER => PluralCategory::Other,
+        }
+    }
+}
+
+/// The field names of the plural categories.
+const CATEGORY_NAMES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Returns `true` if an object is a plural text, whose fields are all plural categories including `other`.
+pub(crate) fn is_plural_object(object: &Map<String, Value>) -> bool {
+    object.contains_key("other")
+        && object.keys().all(|name| CATEGORY_NAMES.contains(&name.as_str()))
+}
+
+/// Get the cardinal plural rules of a key by its full locale (e.g. `pt_PT`), or by its language if the locale has no rules of its own.
+#[cfg(feature = "plural_rules")]
+fn plural_rules_of(key: &Key) -> Option<PluralRules> {
+    let language = language_of(key)?;
+
+    let create = |locale: &str| {
+        let locale: LanguageIdentifier = locale.parse().ok()?;
+
+        PluralRules::create(locale, PluralRuleType::CARDINAL).ok()
+    };
+
+    create(&key.to_string().replace('_', "-")).or_else(|| create(&language))
+}
+
+/// The cardinal plural rules of the keys in context, cached when the keys are added.
+#[cfg(feature = "plural_rules")]
+#[derive(Clone, Default)]
+pub(crate) struct PluralRulesCache {
+    rules: HashMap<Key, PluralRules>,
+}
+
+#[cfg(feature = "plural_rules")]
+impl PluralRulesCache {
+    /// Cache the rules of a key, unless they are cached already or it has none.
+    pub(crate) fn cache(&mut self, key: &Key) {
+        if self.rules.contains_key(key) {
+            return;
+        }
+
+        if let Some(rules) = plural_rules_of(key) {
+            self.rules.insert(key.to_owned(), rules);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, key: &Key) -> Option<&PluralRules> {
+        self.rules.get(key)
+    }
+
+    #[inline]
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.rules.shrink_to_fit();
+    }
+}
+
+#[cfg(feature = "plural_rules")]
+impl Debug for PluralRulesCache {
+    /// `PluralRules` is not `Debug`, so only the keys with cached rules are listed.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.rules.keys()).finish()
+    }
+}
+
+/// Get the plural categories which integers can have in the language of a key, in CLDR order (e.g. `one`, `few`, `many` for Polish). This is the order of the plural forms of gettext. If the language has no plural rules, `one` and `other` are returned.
+#[cfg(feature = "po")]
+pub(crate) fn integer_categories_of(key: &Key) -> Vec<PluralCategory> {
+    let rules = match plural_rules_of(key) {
+        Some(rules) => rules,
+        None => return vec![PluralCategory::One, PluralCategory::Other],
+    };
+
+    let mut categories: Vec<PluralCategory> = (0..=1000u64)
+        .chain([10_000, 100_000, 1_000_000, 10_000_000])
+        .filter_map(|n| rules.select(n).ok())
+        .map(PluralCategory::from)
+        .collect();
+
+    categories.sort_unstable();
+    categories.dedup();
+
+    categories
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the plural category of a count by the cached CLDR rules of a key, if the `plural_rules` feature is enabled and the key has rules.
+    #[inline]
+    fn select_category(&self, key: &Key, count: u64) -> Option<PluralCategory> {
+        #[cfg(feature = "plural_rules")]
+        {
+            self.plural_rules.get(key)?.select(count).ok().map(PluralCategory::from)
+        }
+
+        #[cfg(not(feature = "plural_rules"))]
+        {
+            let _ = (key, count);
+
+            None
+        }
+    }
+
+    /// Get the plural category of a count in the language of a key. If the language has no plural rules (or is unknown, e.g. for region keys), the rules of the default key are used, and then the English rules. Without the `plural_rules` feature, the English rules are always used. If the key is not in context, the default key is used.
+    pub fn plural_category<Q: ?Sized + Hash + Eq>(&self, key: &Q, count: u64) -> PluralCategory
+    where
+        Key: Borrow<Q>, {
+        let (key, _) = self.find_entry_or_default(key);
+
+        let category = self
+            .select_category(key, count)
+            .or_else(|| self.select_category(self.default_entry().0, count));
+
+        match category {
+            Some(category) => category,
+            None if count == 1 => PluralCategory::One,
+            None => PluralCategory::Other,
+        }
+    }
+
+    /// Get a plural text of a key for a count, like `ngettext`. The text should be a JSON object of plural categories (see `PluralCategory`), and `other` is used if the category of the count is missing. Texts which are not objects are returned as they are. If the key is not in context, the default key is used.
+    pub fn get_plural_text<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        text: T,
+        count: u64,
+    ) -> Option<JSONGetTextValue<'_>>
+    where
+        Key: Borrow<Q>, {
+        let value = self.lookup_text(self.find_map_or_default(key), text.as_ref())?;
+
+        let forms = match value {
+            JSONGetTextValue::JSONValueRef(Value::Object(forms)) => forms,
+            _ => return Some(value),
+        };
+
+        let category = self.plural_category(key, count);
+
+        match forms.get(category.as_str()).or_else(|| forms.get("other"))? {
+            Value::String(s) => Some(JSONGetTextValue::Str(s)),
+            v => Some(JSONGetTextValue::from_json_value_ref(v)),
+        }
+    }
+
+    /// Get a plural text of a key for a count by `get_plural_text`, and format it like `format_text_with_key`. The count is the `{count}` argument unless `args` has one. If the key is not in context, the default key is used.
+    pub fn format_plural_text_with_key<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &self,
+        key: &Q,
+        text: T,
+        count: u64,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let value = self.get_plural_text(key, text, count)?;
+
+        let map = self.find_map_or_default(key);
+        let symbols = self.number_symbols(key);
+
+        if args.contains_key("count") {
+            return Some(self.format_value(map, value, args, &symbols));
+        }
+
+        let mut args: HashMap<&str, JSONGetTextValue> =
+            args.iter().map(|(name, value)| (*name, value.clone_borrowed())).collect();
+
+        args.insert("count", JSONGetTextValue::from_u64(count));
+
+        Some(self.format_value(map, value, &args, &symbols))
+    }
+}
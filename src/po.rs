@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::io::{self, ErrorKind};
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::plural::{integer_categories_of, PluralCategory};
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// The separator of `msgctxt` and `msgid` in text ids, the same as in the compiled catalogs of gettext.
+const CONTEXT_SEPARATOR: char = '\u{4}';
+
+/// The field which the following string lines belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Context,
+    Id,
+    IdPlural,
+    Str(usize),
+}
+
+#[derive(Debug, Default)]
+struct Entry {
+    context: Option<String>,
+    id: Option<String>,
+    id_plural: Option<String>,
+    strs: Vec<(usize, String)>,
+    fuzzy: bool,
+}
+
+impl Entry {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.context.is_none() && self.id.is_none()
+    }
+
+    fn field_mut(&mut self, field: Field) -> &mut String {
+        match field {
+            Field::Context => self.context.get_or_insert_with(String::new),
+            Field::Id => self.id.get_or_insert_with(String::new),
+            Field::IdPlural => self.id_plural.get_or_insert_with(String::new),
+            Field::Str(index) => {
+                match self.strs.iter().position(|(i, _)| *i == index) {
+                    Some(position) => &mut self.strs[position].1,
+                    None => {
+                        self.strs.push((index, String::new()));
+
+                        &mut self.strs.last_mut().unwrap().1
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn po_error(line: usize, reason: &str) -> JSONGetTextBuildError {
+    JSONGetTextBuildError::InvalidPO {
+        line,
+        reason: reason.to_string(),
+    }
+}
+
+/// Parse a quoted PO string, e.g. `"Hello,\n"`.
+fn parse_quoted(s: &str, line: usize) -> Result<String, JSONGetTextBuildError> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| po_error(line, "a string must be quoted"))?;
+
+    let mut output = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                output.push(match chars.next() {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    _ => return Err(po_error(line, "unknown escape sequence")),
+                });
+            }
+            '"' => return Err(po_error(line, "unescaped quotation mark")),
+            _ => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
+
+/// Parse a keyword line, e.g. `msgstr[1] "..."`.
+fn parse_keyword(line: &str, line_number: usize) -> Result<(Field, &str), JSONGetTextBuildError> {
+    let (keyword, rest) = line
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| po_error(line_number, "a keyword must be followed by a string"))?;
+
+    let field = match keyword {
+        "msgctxt" => Field::Context,
+        "msgid" => Field::Id,
+        "msgid_plural" => Field::IdPlural,
+        "msgstr" => Field::Str(0),
+        _ => {
+            let index = keyword
+                .strip_prefix("msgstr[")
+                .and_then(|s| s.strip_suffix(']'))
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| po_error(line_number, "unknown keyword"))?;
+
+            Field::Str(index)
+        }
+    };
+
+    Ok((field, rest.trim_start()))
+}
+
+/// Parse a PO catalog into entries.
+fn parse_entries(po: &str) -> Result<Vec<Entry>, JSONGetTextBuildError> {
+    let mut entries = Vec::new();
+    let mut entry = Entry::default();
+    let mut field = None;
+
+    for (i, line) in po.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            // comments end an entry whose strings have been read
+            if !entry.strs.is_empty() {
+                entries.push(std::mem::take(&mut entry));
+                field = None;
+            }
+
+            if let Some(flags) = comment.strip_prefix(',') {
+                if flags.split(',').any(|flag| flag.trim() == "fuzzy") {
+                    entry.fuzzy = true;
+                }
+            }
+
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let field = field.ok_or_else(|| po_error(line_number, "a string without a keyword"))?;
+
+            let s = parse_quoted(line, line_number)?;
+
+            entry.field_mut(field).push_str(&s);
+
+            continue;
+        }
+
+        let (new_field, rest) = parse_keyword(line, line_number)?;
+
+        if matches!(new_field, Field::Context | Field::Id) && !entry.strs.is_empty() {
+            entries.push(std::mem::take(&mut entry));
+        }
+
+        let s = parse_quoted(rest, line_number)?;
+
+        let target = entry.field_mut(new_field);
+
+        if !target.is_empty() {
+            return Err(po_error(line_number, "duplicated keyword"));
+        }
+
+        target.push_str(&s);
+
+        field = Some(new_field);
+    }
+
+    if !entry.is_empty() {
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+/// Map the plural forms of an entry to the plural categories of a language in order. If the numbers of forms and categories are different, the last form is used as `other`.
+fn plural_forms(categories: &[PluralCategory], mut strs: Vec<(usize, String)>) -> Value {
+    strs.sort_unstable_by_key(|(index, _)| *index);
+
+    let exact = strs.len() == categories.len();
+    let last = strs.len() - 1;
+
+    let mut forms = Map::new();
+
+    for (i, (_, s)) in strs.into_iter().enumerate() {
+        if s.is_empty() {
+            continue;
+        }
+
+        let category = if exact || i < last {
+            categories.get(i).copied().unwrap_or(PluralCategory::Other)
+        } else {
+            PluralCategory::Other
+        };
+
+        forms.insert(category.as_str().to_string(), Value::String(s));
+    }
+
+    Value::Object(forms)
+}
+
+/// Convert PO entries into a string map. Fuzzy entries, untranslated entries and the header are skipped.
+fn po_to_map(key: &Key, entries: Vec<Entry>) -> HashMap<String, JSONGetTextValue<'static>> {
+    let categories = integer_categories_of(key);
+
+    let mut map = HashMap::with_capacity(entries.len());
+
+    for entry in entries {
+        let id = match entry.id {
+            Some(id) => id,
+            None => continue,
+        };
+
+        if entry.fuzzy || (id.is_empty() && entry.context.is_none()) {
+            continue;
+        }
+
+        if entry.strs.iter().all(|(_, s)| s.is_empty()) {
+            continue;
+        }
+
+        let text = match entry.context {
+            Some(context) => format!("{}{}{}", context, CONTEXT_SEPARATOR, id),
+            None => id,
+        };
+
+        let value = if entry.id_plural.is_some() {
+            JSONGetTextValue::from_json_value(plural_forms(&categories, entry.strs))
+        } else {
+            JSONGetTextValue::from_string(entry.strs.into_iter().next().unwrap().1)
+        };
+
+        map.insert(text, value);
+    }
+
+    map
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add a gettext PO catalog to the context for a specify key. Its `msgid`s become the texts, and entries with `msgctxt` use `msgctxt` and `msgid` joined by `\u{4}` (the same as gettext). Plural entries become plural texts (see `PluralCategory`), whose forms are mapped to the plural categories of the language of the key in order. Fuzzy and untranslated entries are skipped, so they are filled from the default key.
+    pub fn add_po<K: Into<Key>, S: AsRef<str>>(
+        &mut self,
+        key: K,
+        po: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let map = po_to_map(&key, parse_entries(po.as_ref())?);
+
+        self.add_map(key, map)
+    }
+
+    /// Add a gettext PO file to the context for a specify key. See `add_po`.
+    #[cfg(feature = "fs")]
+    pub fn add_po_file<K: Into<Key>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let po = self.options.ingest_limits.read_file(&key, path.as_ref())?;
+
+        let po =
+            String::from_utf8(po).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        self.add_po(key, po)
+    }
+}
@@ -0,0 +1,110 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use serde::Serialize;
+
+#[cfg(feature = "regex")]
+use crate::DeserializeErrorInfo;
+use crate::{JSONGetText, Key};
+
+/// A problem found by `JSONGetText::preflight`. Keys are in their display forms.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PreflightFailure {
+    /// A critical locale is not in context, so its texts would be looked up from the default key.
+    MissingLocale {
+        key: String,
+    },
+    /// A critical text is not in context at all.
+    MissingText {
+        key: String,
+        text: String,
+    },
+    /// A critical text of a critical locale is only filled from another key.
+    UntranslatedText {
+        key: String,
+        text: String,
+    },
+    /// The locale group passed to `JSONGetText::preflight_group` is not defined.
+    UnknownLocaleGroup {
+        group: String,
+    },
+}
+
+impl Display for PreflightFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PreflightFailure::MissingLocale {
+                key,
+            } => f.write_fmt(format_args!("The locale `{}` is missing.", key)),
+            PreflightFailure::MissingText {
+                key,
+                text,
+            } => f.write_fmt(format_args!("The text `{}` is missing in the key `{}`.", text, key)),
+            PreflightFailure::UntranslatedText {
+                key,
+                text,
+            } => {
+                f.write_fmt(format_args!(
+                    "The text `{}` is not translated in the key `{}`.",
+                    text, key
+                ))
+            }
+            PreflightFailure::UnknownLocaleGroup {
+                group,
+            } => f.write_fmt(format_args!("The locale group `{}` is not defined.", group)),
+        }
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Check that every critical text is translated in every critical locale, e.g. before a deployment goes live, and warm up by looking them up and initializing the data which is otherwise initialized on first use. Return every failure found, in the order of the locales and then the texts.
+    pub fn preflight<Q: ?Sized + Hash + Eq + Display, T: AsRef<str>>(
+        &self,
+        keys: &[&Q],
+        texts: &[T],
+    ) -> Result<(), Vec<PreflightFailure>>
+    where
+        Key: Borrow<Q>, {
+        #[cfg(feature = "regex")]
+        DeserializeErrorInfo::parse("");
+
+        let mut failures = Vec::new();
+
+        for key in keys {
+            let (key, map) = match self.find_entry(*key) {
+                Some(entry) => entry,
+                None => {
+                    failures.push(PreflightFailure::MissingLocale {
+                        key: key.to_string(),
+                    });
+
+                    continue;
+                }
+            };
+
+            for text in texts {
+                let text = text.as_ref();
+
+                if self.lookup_text(map, text).is_none() {
+                    failures.push(PreflightFailure::MissingText {
+                        key: key.to_string(),
+                        text: text.to_string(),
+                    });
+                } else if self.is_filled_text(key, text) {
+                    failures.push(PreflightFailure::UntranslatedText {
+                        key: key.to_string(),
+                        text: text.to_string(),
+                    });
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "fs")]
+use crate::write_back::WriteBack;
+use crate::{JSONGetText, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// A secondary source of texts, e.g. a live CMS, which is consulted when a key misses a text. See `JSONGetTextBuilder::set_secondary_context`.
+pub trait SecondaryContext: Send + Sync {
+    /// Get the text of a key, or `None` if the source does not have it.
+    fn get_text(&self, key: &Key, text: &str) -> Option<String>;
+}
+
+impl<F: Fn(&Key, &str) -> Option<String> + Send + Sync> SecondaryContext for F {
+    #[inline]
+    fn get_text(&self, key: &Key, text: &str) -> Option<String> {
+        self(key, text)
+    }
+}
+
+/// How a secondary context is consulted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadThroughOptions {
+    /// How long a lookup waits for the secondary context. A lookup which takes longer is a miss, but its result is still cached when it arrives. While a lookup is in progress, other lookups of the text miss instead of waiting too, for at most the timeout, so a secondary context which hangs does not keep the text from being looked up again.
+    pub timeout: Duration,
+    /// How long a text found in the secondary context is cached.
+    pub ttl: Duration,
+    /// How long a text which the secondary context does not have is cached as missing.
+    pub negative_ttl: Duration,
+    /// How many threads consult the secondary context. Lookups which find every thread busy and the queue of each thread full are misses.
+    pub workers: usize,
+}
+
+impl Default for ReadThroughOptions {
+    #[inline]
+    fn default() -> Self {
+        ReadThroughOptions {
+            timeout: Duration::from_millis(100),
+            ttl: Duration::from_secs(60),
+            negative_ttl: Duration::from_secs(60),
+            workers: 4,
+        }
+    }
+}
+
+/// How many lookups may wait for each worker.
+const QUEUE_PER_WORKER: usize = 16;
+
+/// The cache is split into shards by texts, so that lookups of different texts do not contend for one lock.
+const SHARDS: usize = 16;
+
+#[derive(Debug)]
+enum Entry {
+    /// A lookup is in progress, so other lookups of the text miss instead of waiting too. It expires with the timeout, so a lookup which hangs or panics does not keep the text from being looked up again.
+    Pending(Instant),
+    Found(String, Instant),
+    NotFound(Instant),
+}
+
+impl Entry {
+    /// Get the cached result if the entry has not expired.
+    #[inline]
+    fn get(&self, now: Instant) -> Option<Option<String>> {
+        match self {
+            Entry::Pending(expires_at) | Entry::NotFound(expires_at) if now < *expires_at => {
+                Some(None)
+            }
+            Entry::Found(s, expires_at) if now < *expires_at => Some(Some(s.clone())),
+            _ => None,
+        }
+    }
+}
+
+type Shard = RwLock<HashMap<Key, HashMap<String, Entry>>>;
+
+struct Cache {
+    shards: [Shard; SHARDS],
+    /// Increased by `clear`, so that the results of the lookups started before it are not cached.
+    generation: AtomicU64,
+}
+
+impl Cache {
+    #[inline]
+    fn new() -> Cache {
+        Cache {
+            shards: std::array::from_fn(|_| RwLock::new(HashMap::new())),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    #[inline]
+    fn shard(&self, key: &Key, text: &str) -> &Shard {
+        let mut hasher = DefaultHasher::new();
+
+        key.hash(&mut hasher);
+        text.hash(&mut hasher);
+
+        &self.shards[hasher.finish() as usize % SHARDS]
+    }
+
+    #[cfg(feature = "fs")]
+    #[inline]
+    fn insert(&self, key: &Key, text: &str, entry: Entry) {
+        let mut shard = self.shard(key, text).write().unwrap_or_else(PoisonError::into_inner);
+
+        shard.entry(key.to_owned()).or_default().insert(text.to_string(), entry);
+    }
+
+    fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+
+        for shard in self.shards.iter() {
+            shard.write().unwrap_or_else(PoisonError::into_inner).clear();
+        }
+    }
+}
+
+/// A lookup for a worker, with the generation of the cache when it started and where to send its result.
+struct Job {
+    key: Key,
+    text: String,
+    generation: u64,
+    result: mpsc::Sender<Option<String>>,
+}
+
+#[derive(Clone)]
+pub(crate) struct ReadThrough {
+    source: Arc<dyn SecondaryContext>,
+    options: ReadThroughOptions,
+    cache: Arc<Cache>,
+    /// The queue of the workers, which are started by the first lookup and stop once every clone of this is dropped.
+    jobs: Arc<OnceLock<SyncSender<Job>>>,
+    #[cfg(feature = "fs")]
+    write_back: Option<Arc<WriteBack>>,
+}
+
+impl Debug for ReadThrough {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadThrough").field("options", &self.options).finish()
+    }
+}
+
+impl ReadThrough {
+    #[inline]
+    fn new(source: Arc<dyn SecondaryContext>, options: ReadThroughOptions) -> ReadThrough {
+        ReadThrough {
+            source,
+            options,
+            cache: Arc::new(Cache::new()),
+            jobs: Arc::new(OnceLock::new()),
+            #[cfg(feature = "fs")]
+            write_back: None,
+        }
+    }
+
+    /// Persist the texts found from now on to a write-back file, and cache the texts already in it.
+    #[cfg(feature = "fs")]
+    pub(crate) fn attach_write_back(&mut self, write_back: Arc<WriteBack>) {
+        let expires_at = Instant::now() + self.options.ttl;
+
+        for ((key, text), value) in write_back.entries() {
+            self.cache.insert(&key, &text, Entry::Found(value, expires_at));
+        }
+
+        self.write_back = Some(write_back);
+    }
+
+    /// Start the workers and get their queue.
+    fn jobs(&self) -> &SyncSender<Job> {
+        self.jobs.get_or_init(|| {
+            let workers = self.options.workers.max(1);
+
+            let (sender, receiver) = mpsc::sync_channel::<Job>(workers * QUEUE_PER_WORKER);
+
+            let receiver = Arc::new(Mutex::new(receiver));
+
+            for _ in 0..workers {
+                let receiver = receiver.clone();
+                let source = self.source.clone();
+                let cache = self.cache.clone();
+                let options = self.options;
+                #[cfg(feature = "fs")]
+                let write_back = self.write_back.clone();
+
+                thread::spawn(move || {
+                    loop {
+                        let job =
+                            match receiver.lock().unwrap_or_else(PoisonError::into_inner).recv() {
+                                Ok(job) => job,
+                                Err(_) => return,
+                            };
+
+                        // a panic is a miss which is not cached, so the text is looked up again once its pending entry expires
+                        let result = match panic::catch_unwind(AssertUnwindSafe(|| {
+                            source.get_text(&job.key, &job.text)
+                        })) {
+                            Ok(result) => result,
+                            Err(_) => {
+                                let _ = job.result.send(None);
+
+                                continue;
+                            }
+                        };
+
+                        let entry = match result.as_ref() {
+                            Some(s) => Entry::Found(s.clone(), Instant::now() + options.ttl),
+                            None => Entry::NotFound(Instant::now() + options.negative_ttl),
+                        };
+
+                        let current = {
+                            let mut shard = cache
+                                .shard(&job.key, &job.text)
+                                .write()
+                                .unwrap_or_else(PoisonError::into_inner);
+
+                            // the cache may have been cleared since the lookup started
+                            let current = cache.generation.load(Ordering::SeqCst) == job.generation;
+
+                            if current {
+                                shard
+                                    .entry(job.key.to_owned())
+                                    .or_default()
+                                    .insert(job.text.clone(), entry);
+                            }
+
+                            current
+                        };
+
+                        #[cfg(feature = "fs")]
+                        if let (true, Some(write_back), Some(s)) =
+                            (current, write_back.as_ref(), result.as_ref())
+                        {
+                            write_back.record(&job.key, &job.text, s);
+                        }
+
+                        #[cfg(not(feature = "fs"))]
+                        let _ = current;
+
+                        // the lookup may have timed out already
+                        let _ = job.result.send(result);
+                    }
+                });
+            }
+
+            sender
+        })
+    }
+
+    /// Get the text of a key from the cache, or from the secondary context if it is not cached or has expired. Cached texts only take a read lock of one shard of the cache.
+    fn get(&self, key: &Key, text: &str) -> Option<String> {
+        let now = Instant::now();
+
+        let shard = self.cache.shard(key, text);
+
+        if let Some(result) = shard
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .and_then(|texts| texts.get(text))
+            .and_then(|entry| entry.get(now))
+        {
+            return result;
+        }
+
+        let generation = {
+            let mut shard = shard.write().unwrap_or_else(PoisonError::into_inner);
+
+            let texts = shard.entry(key.to_owned()).or_default();
+
+            // another lookup may have started in the meantime
+            if let Some(result) = texts.get(text).and_then(|entry| entry.get(now)) {
+                return result;
+            }
+
+            texts.insert(text.to_string(), Entry::Pending(now + self.options.timeout));
+
+            self.cache.generation.load(Ordering::SeqCst)
+        };
+
+        let (sender, receiver) = mpsc::channel();
+
+        let job = Job {
+            key: key.to_owned(),
+            text: text.to_string(),
+            generation,
+            result: sender,
+        };
+
+        // the queue is full, so this is a miss until the pending entry expires
+        if self.jobs().try_send(job).is_err() {
+            return None;
+        }
+
+        receiver.recv_timeout(self.options.timeout).ok().flatten()
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get text from the string map of a key like `lookup_text`, and consult the secondary context if the key misses the text or only has it filled from another key.
+    #[inline]
+    pub(crate) fn lookup_text_read_through<'b>(
+        &'b self,
+        key: &Key,
+        map: &'b HashMap<String, JSONGetTextValue<'a>>,
+        text: &str,
+    ) -> Option<JSONGetTextValue<'b>> {
+        let value = self.lookup_text(map, text);
+
+        if let Some(read_through) = self.read_through.as_ref() {
+            if value.is_none() || self.is_filled_text(key, text) {
+                if let Some(s) = read_through.get(key, text) {
+                    return Some(JSONGetTextValue::from_string(s));
+                }
+            }
+        }
+
+        value
+    }
+
+    /// Clear the cache of the secondary context, so that every text is looked up again, including the texts whose lookups are in progress. The results of those lookups are not cached.
+    #[inline]
+    pub fn clear_secondary_cache(&self) {
+        if let Some(read_through) = self.read_through.as_ref() {
+            read_through.cache.clear();
+        }
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set a secondary context, e.g. a live CMS, which is consulted when a key misses a text or only has it filled from another key, so that urgent fixes of texts can be served before the next release of the catalogs. It is consulted by `get_text`, `get_text_with_key`, `get_multiple_text`, `get_multiple_text_with_key` and their variants which never return `None`, before the missing handler. Its results, including misses, are cached as the options say, and a lookup which is slower than the timeout is a miss. It is consulted by a fixed number of threads, which are started by the first lookup.
+    pub fn set_secondary_context<S: SecondaryContext + 'static>(
+        &mut self,
+        source: S,
+        options: ReadThroughOptions,
+    ) -> &mut Self {
+        #[allow(unused_mut)]
+        let mut read_through = ReadThrough::new(Arc::new(source), options);
+
+        #[cfg(feature = "fs")]
+        if let Some(write_back) = self.options.write_back.clone() {
+            read_through.attach_write_back(write_back);
+        }
+
+        self.options.read_through = Some(read_through);
+
+        self
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::format::{find_byte, is_placeholder_char};
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetTextBuildError, JSONGetTextValue, Key};
+
+/// Find the `{#text}` references in a string, as the ranges of the references (including the braces) and the referenced texts. `{{` is skipped, so `{{#text}}` is not a reference.
+fn references_of(s: &str) -> Vec<(Range<usize>, &str)> {
+    let mut references = Vec::new();
+
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+
+    while let Some(offset) = find_byte(b'{', &bytes[i..]) {
+        let start = i + offset;
+
+        match bytes.get(start + 1) {
+            Some(b'{') => {
+                i = start + 2;
+
+                continue;
+            }
+            Some(b'#') => {
+                let rest = &s[start + 2..];
+
+                let len = rest.find(|c| !is_placeholder_char(c)).unwrap_or(rest.len());
+
+                if len > 0 && rest[len..].starts_with('}') {
+                    references.push((start..start + len + 3, &rest[..len]));
+
+                    i = start + len + 3;
+
+                    continue;
+                }
+            }
+            _ => (),
+        }
+
+        i = start + 1;
+    }
+
+    references
+}
+
+/// Inline the `{#text}` references of a string map of a key with the texts they refer to, recursively.
+pub(crate) fn inline_references(
+    key: &Key,
+    map: &mut HashMap<String, JSONGetTextValue>,
+) -> Result<(), JSONGetTextBuildError> {
+    let texts: Vec<String> = map
+        .iter()
+        .filter(|(text, value)| {
+            !is_metadata_text(text) && value.as_str().is_some_and(|s| !references_of(s).is_empty())
+        })
+        .map(|(text, _)| text.clone())
+        .collect();
+
+    if texts.is_empty() {
+        return Ok(());
+    }
+
+    let mut resolved: HashMap<String, String> = HashMap::new();
+
+    for text in texts.iter() {
+        resolve(key, map, text, &mut resolved, &mut Vec::new())?;
+    }
+
+    for (text, s) in resolved {
+        if texts.contains(&text) {
+            map.insert(text, JSONGetTextValue::from_string(s));
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve the references of a text. `resolving` holds the texts being resolved, to detect cycles.
+fn resolve(
+    key: &Key,
+    map: &HashMap<String, JSONGetTextValue>,
+    text: &str,
+    resolved: &mut HashMap<String, String>,
+    resolving: &mut Vec<String>,
+) -> Result<(), JSONGetTextBuildError> {
+    if resolved.contains_key(text) {
+        return Ok(());
+    }
+
+    if let Some(i) = resolving.iter().position(|t| t == text) {
+        let mut texts = resolving[i..].to_vec();
+
+        texts.push(text.to_string());
+
+        return Err(JSONGetTextBuildError::MessageReferenceCycle {
+            key: key.to_owned(),
+            texts,
+        });
+    }
+
+    let value = map.get(text).unwrap();
+
+    let s = match value.as_str() {
+        Some(s) => s,
+        None => {
+            resolved.insert(text.to_string(), value.to_string());
+
+            return Ok(());
+        }
+    };
+
+    resolving.push(text.to_string());
+
+    let mut output = String::with_capacity(s.len());
+
+    let mut last = 0;
+
+    for (range, reference) in references_of(s) {
+        if !map.contains_key(reference) {
+            return Err(JSONGetTextBuildError::MessageReferenceNotFound {
+                key: key.to_owned(),
+                text: text.to_string(),
+                reference: reference.to_string(),
+            });
+        }
+
+        resolve(key, map, reference, resolved, resolving)?;
+
+        output.push_str(&s[last..range.start]);
+        output.push_str(&resolved[reference]);
+
+        last = range.end;
+    }
+
+    output.push_str(&s[last..]);
+
+    resolving.pop();
+
+    resolved.insert(text.to_string(), output);
+
+    Ok(())
+}
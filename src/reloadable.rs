@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+use std::fmt::{self, Debug, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{
+    HealthSummary, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, Key, ReadGuard,
+    ReloadHealth, SharedJSONGetText,
+};
+
+type ErrorHandler = Box<dyn Fn(JSONGetTextBuildError) + Send + Sync>;
+
+/// The quiet period used if `JSONGetTextBuilder::set_reload_quiet_period` is not called.
+const DEFAULT_QUIET_PERIOD: Duration = Duration::from_millis(50);
+
+/// Statistics of the rebuildings of a `JSONGetTextReloadable`, including the ones by `reload`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReloadStats {
+    /// The number of rebuildings which succeeded.
+    pub reloads: u64,
+    /// The number of rebuildings which failed, keeping the current instance.
+    pub failures: u64,
+    /// The number of file events which did not trigger a rebuilding of their own, because they came within the quiet period of an earlier event.
+    pub coalesced_events: u64,
+    /// How long the last rebuilding took.
+    pub last_duration: Option<Duration>,
+    /// When the last rebuilding finished.
+    pub last_reload: Option<SystemTime>,
+    /// The error of the last rebuilding if it failed.
+    pub last_error: Option<String>,
+}
+
+struct Inner {
+    json_gettext: SharedJSONGetText,
+    /// The builder without the catalogs of the watched files, which are read again for every rebuilding.
+    template: Mutex<JSONGetTextBuilder<'static>>,
+    files: Vec<(Key, PathBuf)>,
+    on_error: ErrorHandler,
+    stats: Mutex<ReloadStats>,
+}
+
+impl Inner {
+    fn rebuild(&self) -> Result<(), JSONGetTextBuildError> {
+        // rebuilding one at a time, so that an older rebuilding cannot replace a newer one
+        let template = self.template.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let start = Instant::now();
+
+        let result = Self::build(&template, &self.files).map(|ctx| {
+            self.json_gettext.replace(ctx);
+        });
+
+        let mut stats = self.stats.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match result.as_ref() {
+            Ok(()) => stats.reloads += 1,
+            Err(_) => stats.failures += 1,
+        }
+
+        stats.last_duration = Some(start.elapsed());
+        stats.last_reload = Some(SystemTime::now());
+        stats.last_error = result.as_ref().err().map(|err| err.to_string());
+
+        result
+    }
+
+    fn build(
+        template: &JSONGetTextBuilder<'static>,
+        files: &[(Key, PathBuf)],
+    ) -> Result<JSONGetText<'static>, JSONGetTextBuildError> {
+        let mut builder = template.clone();
+
+        for (key, path) in files.iter() {
+            builder.add_json_file(key.to_owned(), path)?;
+        }
+
+        builder.build()
+    }
+
+    /// Rebuild once after each burst of file events, when no event has come for the quiet period. This runs on its own thread until the watcher is dropped.
+    fn debounce(&self, events: mpsc::Receiver<()>, quiet_period: Duration) {
+        while events.recv().is_ok() {
+            loop {
+                match events.recv_timeout(quiet_period) {
+                    Ok(()) => {
+                        let mut stats = self.stats.lock().unwrap_or_else(PoisonError::into_inner);
+
+                        stats.coalesced_events += 1;
+                    }
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+
+            if let Err(err) = self.rebuild() {
+                (self.on_error)(err);
+            }
+        }
+    }
+}
+
+/// Make the path of a file absolute without resolving the file itself, which may be replaced (e.g. renamed over by an editor) while it is watched.
+fn absolute_path(path: &Path) -> io::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    Ok(parent.canonicalize()?.join(file_name))
+}
+
+/// A `JSONGetText` instance which is rebuilt when the JSON files added by `JSONGetTextBuilder::add_json_file` change. The rebuilt instance is swapped in atomically (see `SharedJSONGetText`), so readers never wait and always see a complete instance.
+///
+/// Other catalogs and options of the builder are kept as they are. If rebuilding fails, e.g. because a file is invalid in the middle of being edited, the current instance is kept. Files are watched until this is dropped.
+///
+/// Editors often write a file in several steps, so the file events are coalesced: the instance is rebuilt once no event has come for a quiet period (see `JSONGetTextBuilder::set_reload_quiet_period`). Rebuilding happens synchronously on one thread of its own, not on a thread pool.
+pub struct JSONGetTextReloadable {
+    inner: Arc<Inner>,
+    _watcher: RecommendedWatcher,
+}
+
+impl JSONGetTextReloadable {
+    /// Build a `JSONGetText` instance and start watching the JSON files of the builder. Errors of rebuilding afterwards are ignored. See `with_error_handler`.
+    #[inline]
+    pub fn new(builder: JSONGetTextBuilder<'static>) -> Result<Self, JSONGetTextBuildError> {
+        Self::with_error_handler(builder, |_| ())
+    }
+
+    /// Build a `JSONGetText` instance and start watching the JSON files of the builder. `on_error` is called with the errors of rebuilding, on the thread of the watcher.
+    pub fn with_error_handler<F: Fn(JSONGetTextBuildError) + Send + Sync + 'static>(
+        builder: JSONGetTextBuilder<'static>,
+        on_error: F,
+    ) -> Result<Self, JSONGetTextBuildError> {
+        let mut template = builder.clone();
+
+        let quiet_period = template.options.reload_quiet_period.unwrap_or(DEFAULT_QUIET_PERIOD);
+
+        let files = std::mem::take(&mut template.options.json_files);
+
+        for (key, _) in files.iter() {
+            template.context.remove(key);
+        }
+
+        let files = files
+            .into_iter()
+            .map(|(key, path)| Ok((key, absolute_path(&path)?)))
+            .collect::<io::Result<Vec<(Key, PathBuf)>>>()?;
+
+        let inner = Arc::new(Inner {
+            json_gettext: SharedJSONGetText::new(builder.build()?),
+            template: Mutex::new(template),
+            files,
+            on_error: Box::new(on_error),
+            stats: Mutex::new(ReloadStats::default()),
+        });
+
+        let watched: HashSet<PathBuf> = inner.files.iter().map(|(_, path)| path.clone()).collect();
+
+        let (sender, receiver) = mpsc::channel();
+
+        let debounce_inner = inner.clone();
+
+        thread::Builder::new()
+            .name(String::from("json-gettext-reload"))
+            .spawn(move || debounce_inner.debounce(receiver, quiet_period))?;
+
+        let handler_inner = inner.clone();
+
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            let event = match event {
+                Ok(event) => event,
+                Err(err) => return (handler_inner.on_error)(io::Error::other(err).into()),
+            };
+
+            if matches!(event.kind, EventKind::Access(_))
+                || !event.paths.iter().any(|path| watched.contains(path))
+            {
+                return;
+            }
+
+            let _ = sender.send(());
+        })
+        .map_err(io::Error::other)?;
+
+        // watch the directories rather than the files, because editors often save a file by replacing it
+        let directories: HashSet<&Path> =
+            inner.files.iter().filter_map(|(_, path)| path.parent()).collect();
+
+        for directory in directories {
+            watcher.watch(directory, RecursiveMode::NonRecursive).map_err(io::Error::other)?;
+        }
+
+        Ok(JSONGetTextReloadable {
+            inner,
+            _watcher: watcher,
+        })
+    }
+
+    /// Get the current `JSONGetText` instance. This never waits for a rebuilding.
+    #[inline]
+    pub fn read(&self) -> ReadGuard {
+        self.inner.json_gettext.read()
+    }
+
+    /// Rebuild the `JSONGetText` instance now, without waiting for the files to change.
+    #[inline]
+    pub fn reload(&self) -> Result<(), JSONGetTextBuildError> {
+        self.inner.rebuild()
+    }
+
+    /// Get the statistics of the rebuildings so far.
+    #[inline]
+    pub fn stats(&self) -> ReloadStats {
+        self.inner.stats.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Get a health summary of the current `JSONGetText` instance, with the outcome of the last rebuilding.
+    pub fn health_summary(&self) -> HealthSummary {
+        let mut summary = self.read().health_summary();
+
+        let stats = self.stats();
+
+        summary.last_reload = stats.last_reload.map(|finished_at| {
+            ReloadHealth {
+                finished_at: finished_at.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+                ok: stats.last_error.is_none(),
+                error: stats.last_error,
+            }
+        });
+
+        summary
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set how long `JSONGetTextReloadable` waits after a file event, for no other event to come, before rebuilding. The default is 50 milliseconds.
+    #[inline]
+    pub fn set_reload_quiet_period(&mut self, quiet_period: Duration) -> &mut Self {
+        self.options.reload_quiet_period = Some(quiet_period);
+
+        self
+    }
+}
+
+impl Debug for JSONGetTextReloadable {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JSONGetTextReloadable")
+            .field("json_gettext", &self.inner.json_gettext)
+            .field("files", &self.inner.files)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
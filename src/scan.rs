@@ -0,0 +1,158 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::{
+    Context, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+/// The decision of a `ContentScanner`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanVerdict {
+    /// The text can go live.
+    Accept,
+    /// The whole build fails with the reason.
+    Reject(String),
+    /// The text is dropped (the text which is already there, or else the text of the default key, is used instead if possible) and recorded with the reason.
+    Quarantine(String),
+}
+
+/// Scans every text of incoming catalogs when building a `JSONGetText` instance, and later when texts are merged or inserted into it (e.g. by `merge`, `insert_text`, `add_language` or `SharedJSONGetText::patch_language`), e.g. for profanity or PII.
+pub trait ContentScanner: Send + Sync {
+    fn scan(&self, key: &Key, text: &str, value: &JSONGetTextValue) -> ScanVerdict;
+}
+
+impl<F: Fn(&Key, &str, &JSONGetTextValue) -> ScanVerdict + Send + Sync> ContentScanner for F {
+    #[inline]
+    fn scan(&self, key: &Key, text: &str, value: &JSONGetTextValue) -> ScanVerdict {
+        self(key, text, value)
+    }
+}
+
+/// A text which has been quarantined by a `ContentScanner`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuarantinedText {
+    pub key: String,
+    pub text: String,
+    pub reason: String,
+}
+
+#[derive(Clone)]
+pub(crate) struct Scanner(pub(crate) Arc<dyn ContentScanner>);
+
+impl Debug for Scanner {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("ContentScanner")
+    }
+}
+
+impl Scanner {
+    /// Scan the context and drop quarantined texts. Texts quarantined in the default key are dropped from every key.
+    pub(crate) fn scan_context<Q: ?Sized + Hash + Eq>(
+        &self,
+        default_key: &Q,
+        context: &mut Context,
+    ) -> Result<Vec<QuarantinedText>, JSONGetTextBuildError>
+    where
+        Key: Borrow<Q>, {
+        let mut quarantined = Vec::new();
+        let mut quarantined_default_texts = HashSet::new();
+
+        if let Some((key, map)) = context.get_key_value(default_key) {
+            for (text, value) in map.iter() {
+                if let Some(reason) = self.verdict(key, text, value)? {
+                    quarantined_default_texts.insert(text.clone());
+
+                    quarantined.push(QuarantinedText {
+                        key: key.to_string(),
+                        text: text.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        let mut quarantined_texts = Vec::new();
+
+        for (key, map) in context.iter() {
+            if key.borrow() == default_key {
+                continue;
+            }
+
+            for (text, value) in map.iter() {
+                if quarantined_default_texts.contains(text) {
+                    quarantined_texts.push((key.to_owned(), text.clone()));
+
+                    continue;
+                }
+
+                if let Some(reason) = self.verdict(key, text, value)? {
+                    quarantined_texts.push((key.to_owned(), text.clone()));
+
+                    quarantined.push(QuarantinedText {
+                        key: key.to_string(),
+                        text: text.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        for (key, text) in quarantined_texts {
+            context.get_mut::<Key>(&key).unwrap().remove(&text);
+        }
+
+        if let Some(map) = context.get_mut(default_key) {
+            for text in quarantined_default_texts {
+                map.remove(&text);
+            }
+        }
+
+        quarantined.sort_unstable_by(|a, b| (&a.key, &a.text).cmp(&(&b.key, &b.text)));
+
+        Ok(quarantined)
+    }
+
+    #[inline]
+    fn verdict(
+        &self,
+        key: &Key,
+        text: &str,
+        value: &JSONGetTextValue,
+    ) -> Result<Option<String>, JSONGetTextBuildError> {
+        match self.0.scan(key, text, value) {
+            ScanVerdict::Accept => Ok(None),
+            ScanVerdict::Reject(reason) => {
+                Err(JSONGetTextBuildError::ContentRejected {
+                    key: key.to_owned(),
+                    text: text.to_string(),
+                    reason,
+                })
+            }
+            ScanVerdict::Quarantine(reason) => Ok(Some(reason)),
+        }
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set a scanner which checks every text when building. It is kept by the built instance to check the texts added to it later.
+    #[inline]
+    pub fn set_content_scanner<S: ContentScanner + 'static>(&mut self, scanner: S) -> &mut Self {
+        self.options.pipeline.scanner = Some(Scanner(Arc::new(scanner)));
+
+        self
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get texts which have been quarantined by the content scanner when building or when texts were added later.
+    #[inline]
+    pub fn get_quarantined_texts(&self) -> &[QuarantinedText] {
+        &self.quarantined
+    }
+}
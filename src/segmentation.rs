@@ -0,0 +1,16 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::JSONGetTextValue;
+
+/// Split a text into sentences according to the Unicode sentence boundaries ([UAX #29](https://www.unicode.org/reports/tr29/)). Trailing whitespaces of sentences are trimmed and blank sentences are skipped.
+pub fn split_sentences(text: &str) -> Vec<&str> {
+    text.split_sentence_bounds().map(|s| s.trim_end()).filter(|s| !s.is_empty()).collect()
+}
+
+impl<'a> JSONGetTextValue<'a> {
+    /// Split the value into sentences if it is a string. See `split_sentences`.
+    #[inline]
+    pub fn split_sentences(&self) -> Option<Vec<&str>> {
+        self.as_str().map(split_sentences)
+    }
+}
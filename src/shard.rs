@@ -0,0 +1,32 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// The string map of a key (locale). Every key in a `JSONGetText` instance has its own allocation, so a shard can be cloned to another thread without the rest of the context, and its memory is released when the key is evicted and the last clone is dropped.
+pub type LocaleShard<'a> = Arc<HashMap<String, JSONGetTextValue<'a>>>;
+
+impl<'a> JSONGetText<'a> {
+    /// Get the shard of a key. Unlike the lookup methods, this does not fall back to the default key.
+    #[inline]
+    pub fn shard<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<LocaleShard<'a>>
+    where
+        Key: Borrow<Q>, {
+        self.context.get(key).cloned()
+    }
+
+    /// Remove a key from context and return its shard. The default key cannot be evicted. Texts of an evicted key are looked up from the default key afterwards, as for any other unknown key.
+    pub fn evict_locale<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<LocaleShard<'a>>
+    where
+        Key: Borrow<Q>, {
+        if self.default_entry().0.borrow() == key {
+            return None;
+        }
+
+        self.filled.remove(key);
+
+        self.context.remove(key)
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::ops::Deref;
+use std::sync::PoisonError;
+
+#[cfg(loom)]
+use loom::{
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread::yield_now,
+};
+
+#[cfg(not(loom))]
+use std::{
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread::yield_now,
+};
+
+use crate::{ConflictPolicy, JSONGetText, JSONGetTextBuildError, JSONGetTextValue, Key};
+
+// `SharedJSONGetText` is `Send` and `Sync` because of its atomics, so make sure what it hands out between threads really is.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<JSONGetText<'static>>();
+};
+
+/// A `JSONGetText` instance which can be replaced while other threads are reading it.
+///
+/// Reading is lock-free: `read` never waits for a lock, and a reload in progress never makes a reader wait for it. Only `replace` may wait, for the readers which are in the middle of `read` (a few atomic operations, not the lifetime of their `ReadGuard`s) to finish.
+pub struct SharedJSONGetText {
+    current: AtomicPtr<JSONGetText<'static>>,
+    epoch: AtomicUsize,
+    readers: [AtomicUsize; 2],
+    writer: Mutex<()>,
+}
+
+impl SharedJSONGetText {
+    #[inline]
+    pub fn new(ctx: JSONGetText<'static>) -> SharedJSONGetText {
+        SharedJSONGetText::from_arc(Arc::new(ctx))
+    }
+
+    #[inline]
+    pub fn from_arc(ctx: Arc<JSONGetText<'static>>) -> SharedJSONGetText {
+        SharedJSONGetText {
+            current: AtomicPtr::new(Arc::into_raw(ctx) as *mut JSONGetText<'static>),
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            writer: Mutex::new(()),
+        }
+    }
+
+    /// Get the current `JSONGetText` instance. The returned guard keeps it alive even if it is replaced afterwards.
+    #[inline]
+    pub fn read(&self) -> ReadGuard {
+        ReadGuard {
+            inner: self.load_full(),
+        }
+    }
+
+    /// Get the current `JSONGetText` instance as an `Arc`.
+    pub fn load_full(&self) -> Arc<JSONGetText<'static>> {
+        loop {
+            let epoch = self.epoch.load(Ordering::SeqCst);
+
+            let readers = &self.readers[epoch & 1];
+
+            readers.fetch_add(1, Ordering::SeqCst);
+
+            // a writer has flipped the epoch before we were counted, so it may not wait for us
+            // read-modify-write operations always see the latest value, which, together with the one in `replace`, makes this handshake hold without relying on the store-load ordering of `SeqCst`
+            if self.epoch.fetch_add(0, Ordering::SeqCst) != epoch {
+                readers.fetch_sub(1, Ordering::SeqCst);
+
+                continue;
+            }
+
+            let ptr = self.current.load(Ordering::SeqCst);
+
+            // SAFETY: the writer which swaps `ptr` out flips the epoch afterwards and then waits for `readers` to drop to zero before releasing its reference, so `ptr` is still alive here
+            let ctx = unsafe {
+                Arc::increment_strong_count(ptr);
+
+                Arc::from_raw(ptr)
+            };
+
+            readers.fetch_sub(1, Ordering::SeqCst);
+
+            return ctx;
+        }
+    }
+
+    /// Replace the current `JSONGetText` instance and return the old one. Readers holding a `ReadGuard` of the old instance keep using it until they drop their guards.
+    #[inline]
+    pub fn replace(&self, ctx: JSONGetText<'static>) -> Arc<JSONGetText<'static>> {
+        self.replace_arc(Arc::new(ctx))
+    }
+
+    /// Replace the current `JSONGetText` instance with an `Arc` and return the old one.
+    pub fn replace_arc(&self, ctx: Arc<JSONGetText<'static>>) -> Arc<JSONGetText<'static>> {
+        let _writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+
+        self.swap(ctx)
+    }
+
+    /// Replace the current `JSONGetText` instance with a modified copy of it and return the old one. Other writers wait until `f` returns, so no update is lost, while readers keep using the old instance. If `f` fails, the current instance is kept. Copying an instance shares the texts of its keys, so only the keys `f` modifies are copied.
+    pub fn update<E, F: FnOnce(&mut JSONGetText<'static>) -> Result<(), E>>(
+        &self,
+        f: F,
+    ) -> Result<Arc<JSONGetText<'static>>, E> {
+        let _writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let mut ctx = JSONGetText::clone(&self.load_full());
+
+        f(&mut ctx)?;
+
+        Ok(self.swap(Arc::new(ctx)))
+    }
+
+    /// Merge texts into the catalog of a key, adding the key if it is not there, and return the old instance. The texts replace the ones which are already there and are merged as `JSONGetText::merge` does, so the missing texts of a new key are filled from the default key and a text which is not in the default key is an error.
+    pub fn patch_language<K: Into<Key>>(
+        &self,
+        key: K,
+        texts: HashMap<String, JSONGetTextValue<'static>>,
+    ) -> Result<Arc<JSONGetText<'static>>, JSONGetTextBuildError> {
+        self.update(|ctx| ctx.merge_map(key.into(), texts, ConflictPolicy::Overwrite))
+    }
+
+    /// Insert a text into the catalog of a key like `JSONGetText::insert_text`, and return the old instance.
+    #[inline]
+    pub fn insert_text<K: Into<Key>, T: Into<String>>(
+        &self,
+        key: K,
+        text: T,
+        value: JSONGetTextValue<'static>,
+    ) -> Result<Arc<JSONGetText<'static>>, JSONGetTextBuildError> {
+        self.update(|ctx| ctx.insert_text(key, text, value))
+    }
+
+    /// Swap in a `JSONGetText` instance while holding the writer lock.
+    fn swap(&self, ctx: Arc<JSONGetText<'static>>) -> Arc<JSONGetText<'static>> {
+        let old =
+            self.current.swap(Arc::into_raw(ctx) as *mut JSONGetText<'static>, Ordering::SeqCst);
+
+        let epoch = self.epoch.fetch_add(1, Ordering::SeqCst);
+
+        // readers counted in the old epoch may have loaded `old` without having taken a reference yet
+        while self.readers[epoch & 1].fetch_add(0, Ordering::SeqCst) != 0 {
+            yield_now();
+        }
+
+        // SAFETY: `old` came from `Arc::into_raw` and no reader can load it anymore
+        unsafe { Arc::from_raw(old) }
+    }
+}
+
+impl Drop for SharedJSONGetText {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: we have exclusive access, and `current` always holds a reference from `Arc::into_raw`
+        unsafe { drop(Arc::from_raw(self.current.load(Ordering::SeqCst))) }
+    }
+}
+
+impl Debug for SharedJSONGetText {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedJSONGetText").field(&*self.read()).finish()
+    }
+}
+
+impl From<JSONGetText<'static>> for SharedJSONGetText {
+    #[inline]
+    fn from(ctx: JSONGetText<'static>) -> SharedJSONGetText {
+        SharedJSONGetText::new(ctx)
+    }
+}
+
+/// A snapshot of the `JSONGetText` instance in a `SharedJSONGetText`. Holding it does not block readers or writers.
+#[derive(Debug, Clone)]
+pub struct ReadGuard {
+    inner: Arc<JSONGetText<'static>>,
+}
+
+impl ReadGuard {
+    #[inline]
+    pub fn into_arc(self) -> Arc<JSONGetText<'static>> {
+        self.inner
+    }
+}
+
+impl Deref for ReadGuard {
+    type Target = JSONGetText<'static>;
+
+    #[inline]
+    fn deref(&self) -> &JSONGetText<'static> {
+        &self.inner
+    }
+}
@@ -0,0 +1,257 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+#[cfg(feature = "slug")]
+use crate::locale::language_of;
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, Key};
+
+/// The prefix of the ids of translated URL slugs, e.g. `slugs.about` for the slug of `about`.
+pub const SLUG_TEXT_PREFIX: &str = "slugs.";
+
+/// Transliterations which depend on the language, as `(languages, character, replacement)`.
+#[cfg(feature = "slug")]
+const LANGUAGE_TRANSLITERATIONS: [(&[&str], char, &str); 17] = [
+    (&["de"], 'ä', "ae"),
+    (&["de"], 'ö', "oe"),
+    (&["de"], 'ü', "ue"),
+    (&["da", "nb", "nn", "no"], 'å', "aa"),
+    (&["da", "nb", "nn", "no"], 'ø', "oe"),
+    (&["uk"], 'г', "h"),
+    (&["uk"], 'ґ', "g"),
+    (&["uk"], 'и', "y"),
+    (&["uk"], 'і', "i"),
+    (&["uk"], 'ї', "yi"),
+    (&["uk"], 'є', "ye"),
+    (&["uk"], 'й', "i"),
+    (&["uk"], 'щ', "shch"),
+    (&["bg"], 'ъ', "a"),
+    (&["bg"], 'щ', "sht"),
+    (&["sr", "mk"], 'ј', "j"),
+    (&["sr"], 'ћ', "c"),
+];
+
+/// Transliterations of Latin letters with diacritics and of Cyrillic letters, which are used for every language.
+#[cfg(feature = "slug")]
+const TRANSLITERATIONS: [(char, &str); 112] = [
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "a"),
+    ('å', "a"),
+    ('ā', "a"),
+    ('ă', "a"),
+    ('ą', "a"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('ć', "c"),
+    ('č', "c"),
+    ('ď', "d"),
+    ('đ', "d"),
+    ('ð', "d"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ē', "e"),
+    ('ę', "e"),
+    ('ě', "e"),
+    ('ğ', "g"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ī', "i"),
+    ('ı', "i"),
+    ('ł', "l"),
+    ('ľ', "l"),
+    ('ñ', "n"),
+    ('ń', "n"),
+    ('ň', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "o"),
+    ('ø', "o"),
+    ('ō', "o"),
+    ('ő', "o"),
+    ('œ', "oe"),
+    ('ř', "r"),
+    ('ś', "s"),
+    ('š', "s"),
+    ('ş', "s"),
+    ('ș', "s"),
+    ('ß', "ss"),
+    ('ť', "t"),
+    ('ț', "t"),
+    ('þ', "th"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "u"),
+    ('ū', "u"),
+    ('ů', "u"),
+    ('ű', "u"),
+    ('ý', "y"),
+    ('ÿ', "y"),
+    ('ź', "z"),
+    ('ż', "z"),
+    ('ž', "z"),
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('е', "e"),
+    ('ё', "e"),
+    ('ж', "zh"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "kh"),
+    ('ц', "ts"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('щ', "shch"),
+    ('ъ', ""),
+    ('ы', "y"),
+    ('ь', ""),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+    ('є', "ye"),
+    ('і', "i"),
+    ('ї', "yi"),
+    ('ґ', "g"),
+    ('ђ', "dj"),
+    ('ј', "j"),
+    ('љ', "lj"),
+    ('њ', "nj"),
+    ('ћ', "c"),
+    ('џ', "dz"),
+    ('ў', "u"),
+    ('ѓ', "gj"),
+    ('ќ', "kj"),
+    ('ѕ', "dz"),
+];
+
+/// Get the transliteration of a lowercase character in a language, if it is not an ASCII character.
+#[cfg(feature = "slug")]
+fn transliterate(language: &str, c: char) -> Option<&'static str> {
+    LANGUAGE_TRANSLITERATIONS
+        .iter()
+        .find(|(languages, l, _)| *l == c && languages.contains(&language))
+        .map(|(_, _, replacement)| *replacement)
+        .or_else(|| TRANSLITERATIONS.iter().find(|(l, _)| *l == c).map(|(_, r)| *r))
+}
+
+/// Turn a text into a URL slug in the language of a key, e.g. `Über uns` into `ueber-uns` in German and `Контакты` into `kontakty` in Russian. Letters are lowercased and transliterated into ASCII where the language has a common transliteration, other letters and digits are kept, and the other characters become hyphens, without leading, trailing or repeated ones.
+#[cfg(feature = "slug")]
+pub fn slugify<T: AsRef<str>>(key: &Key, text: T) -> String {
+    let language = language_of(key).unwrap_or_default();
+
+    let text = text.as_ref();
+
+    let mut output = String::with_capacity(text.len());
+
+    let mut hyphen = false;
+
+    for c in text.chars().flat_map(char::to_lowercase) {
+        let transliteration = if c.is_ascii() {
+            None
+        } else {
+            transliterate(&language, c)
+        };
+
+        let mut push = |s: &str| {
+            if hyphen && !output.is_empty() {
+                output.push('-');
+            }
+
+            hyphen = false;
+
+            output.push_str(s);
+        };
+
+        match transliteration {
+            Some("") => (),
+            Some(s) => push(s),
+            None if c.is_alphanumeric() => push(c.encode_utf8(&mut [0; 4])),
+            None => hyphen = true,
+        }
+    }
+
+    output
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the URL slug of a text id with a specific key, e.g. for localized routes. The `slugs.{id}` text of the key is used if there is one, otherwise, with the `slug` feature, the slug is made from the text of the id by `slugify`. If the key is not in context, the default key is used.
+    pub fn get_slug<Q: ?Sized + Hash + Eq, T: AsRef<str>>(&self, key: &Q, id: T) -> Option<String>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry_or_default(key);
+
+        let id = id.as_ref();
+
+        let slug_text = format!("{}{}", SLUG_TEXT_PREFIX, id);
+
+        if let Some(slug) = self.lookup_own_text(key, map, &slug_text) {
+            return Some(slug);
+        }
+
+        // a translated text is preferred to the slug of another key
+        #[cfg(feature = "slug")]
+        if !self.is_filled_text(key, id) {
+            if let Some(slug) =
+                self.lookup_text(map, id).and_then(|value| value.as_str().map(|s| slugify(key, s)))
+            {
+                return Some(slug);
+            }
+        }
+
+        let slug = self.lookup_text(map, &slug_text).map(|value| value.to_string());
+
+        #[cfg(feature = "slug")]
+        let slug = slug.or_else(|| {
+            self.lookup_text(map, id).and_then(|value| value.as_str().map(|s| slugify(key, s)))
+        });
+
+        slug
+    }
+
+    /// Find the text id whose slug with a specific key is the given one, e.g. to route a request for a localized URL. Every text of the key is tried, so the time taken grows with the size of the catalog. If the key is not in context, the default key is used.
+    pub fn find_slug_id<Q: ?Sized + Hash + Eq, S: AsRef<str>>(
+        &self,
+        key: &Q,
+        slug: S,
+    ) -> Option<&str>
+    where
+        Key: Borrow<Q>, {
+        let map = self.find_map_or_default(key);
+
+        let slug = slug.as_ref();
+
+        map.keys().filter(|text| !is_metadata_text(text)).find_map(|text| {
+            let id = match text.strip_prefix(SLUG_TEXT_PREFIX) {
+                Some(id) => id,
+                None if cfg!(feature = "slug") => text.as_str(),
+                None => return None,
+            };
+
+            (self.get_slug(key, id).as_deref() == Some(slug)).then_some(id)
+        })
+    }
+}
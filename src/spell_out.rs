@@ -0,0 +1,75 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::metadata::is_metadata_text;
+use crate::{
+    placeholders_of, Context, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+/// Escape braces so that interpolation writes them as they are.
+#[inline]
+fn escape_braces(s: &str) -> String {
+    s.replace('{', "{{").replace('}', "}}")
+}
+
+/// Spell out a text as its id followed by the names of the placeholders of its template, e.g. `[cart.summary {count} {total}]`. Braces are escaped, so the result is shown as it is after interpolation.
+pub fn spell_out<T: AsRef<str>>(text: T, template: Option<&str>) -> String {
+    let mut output = String::from("[");
+
+    output.push_str(&escape_braces(text.as_ref()));
+
+    if let Some(template) = template {
+        for name in placeholders_of(template) {
+            output.push_str(" {{");
+            output.push_str(&escape_braces(name));
+            output.push_str("}}");
+        }
+    }
+
+    output.push(']');
+
+    output
+}
+
+/// Add the spell-out key to the context, whose strings are spelled out from the texts of the default key.
+pub(crate) fn add_spell_out_key<Q: ?Sized + Hash + Eq>(
+    context: &mut Context,
+    default_key: &Q,
+    key: &Key,
+) -> Result<(), JSONGetTextBuildError>
+where
+    Key: Borrow<Q>, {
+    if context.contains_key::<Key>(key) {
+        return Err(JSONGetTextBuildError::DuplicatedKey(key.to_owned()));
+    }
+
+    let map = context
+        .get(default_key)
+        .unwrap()
+        .iter()
+        .map(|(text, value)| {
+            let value = match value.as_str() {
+                Some(template) if !is_metadata_text(text) => {
+                    JSONGetTextValue::from_string(spell_out(text, Some(template)))
+                }
+                _ => value.clone(),
+            };
+
+            (text.clone(), value)
+        })
+        .collect();
+
+    context.insert(key.to_owned(), map);
+
+    Ok(())
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add a synthetic key when building, whose strings are replaced by their ids and the names of their placeholders (see `spell_out`). It can be used by screenshot tools to map the texts in a UI to their ids. Values which are not strings are the same as the default key. The key must not be added in other ways.
+    #[inline]
+    pub fn set_spell_out_key<K: Into<Key>>(&mut self, key: K) -> &mut Self {
+        self.options.spell_out_key = Some(key.into());
+
+        self
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use rusqlite::types::{FromSql, FromSqlResult, Type, ValueRef};
+use rusqlite::{Connection, Params};
+
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+impl FromSql for Key {
+    #[inline]
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Key> {
+        let s = value.as_str()?;
+
+        #[cfg(not(feature = "langid"))]
+        {
+            Ok(Key::from(s))
+        }
+
+        #[cfg(feature = "langid")]
+        {
+            crate::locale::key_from_tag(s).ok_or(rusqlite::types::FromSqlError::InvalidType)
+        }
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add the texts returned by a SQLite query to the context. Every row has the key, the text and the value in its first three columns. A `TEXT` value is a string, an `INTEGER` or `REAL` value is a number and `NULL` is null, while a `BLOB` value is an error. The keys must not have been added, and the texts are checked in the same way as a JSON object added by `add_json`, including the consistency with the default key when building.
+    pub fn add_from_query<P: Params>(
+        &mut self,
+        connection: &Connection,
+        sql: &str,
+        params: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let mut statement = connection.prepare(sql)?;
+
+        let mut rows = statement.query(params)?;
+
+        let mut context: HashMap<Key, HashMap<String, JSONGetTextValue<'static>>> = HashMap::new();
+
+        while let Some(row) = rows.next()? {
+            let key: Key = row.get(0)?;
+            let text: String = row.get(1)?;
+
+            let value = match row.get_ref(2)? {
+                ValueRef::Null => JSONGetTextValue::null(),
+                ValueRef::Integer(n) => JSONGetTextValue::from_i64(n),
+                ValueRef::Real(n) => JSONGetTextValue::from_f64(n),
+                ValueRef::Text(_) => JSONGetTextValue::from_string(row.get::<_, String>(2)?),
+                ValueRef::Blob(_) => {
+                    return Err(rusqlite::Error::InvalidColumnType(
+                        2,
+                        row.as_ref().column_name(2)?.to_string(),
+                        Type::Blob,
+                    )
+                    .into())
+                }
+            };
+
+            match context.entry(key.to_owned()).or_default().entry(text) {
+                Entry::Occupied(entry) => {
+                    return Err(JSONGetTextBuildError::DuplicatedText {
+                        key,
+                        text: entry.key().clone(),
+                    })
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+
+        if let Some(key) = context.keys().find(|key| self.context.contains_key(*key)) {
+            return Err(JSONGetTextBuildError::DuplicatedKey(key.to_owned()));
+        }
+
+        for (key, map) in context {
+            self.add_map(key, map)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Add the texts in a SQLite table to the context. See `add_from_query`. The table has the following schema, where `value` has no type so that it can hold strings, numbers and null.
+    ///
+    /// ```sql
+    /// CREATE TABLE translations (
+    ///     locale TEXT NOT NULL,
+    ///     text_id TEXT NOT NULL,
+    ///     value,
+    ///     PRIMARY KEY (locale, text_id)
+    /// );
+    /// ```
+    #[inline]
+    pub fn add_from_table(
+        &mut self,
+        connection: &Connection,
+        table: &str,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let sql = format!("SELECT locale, text_id, value FROM \"{}\"", table.replace('"', "\"\""));
+
+        self.add_from_query(connection, &sql, ())
+    }
+}
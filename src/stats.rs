@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::metadata::is_metadata_text;
+use crate::plural::is_plural_object;
+use crate::{placeholders_of, JSONGetText, JSONGetTextValue};
+
+/// The upper bounds (inclusive) of the buckets of the length histogram, in characters. Longer strings are in the last bucket.
+const LENGTH_BUCKETS: [usize; 7] = [10, 20, 50, 100, 200, 500, 1000];
+
+/// A bucket of the length histogram of a `CatalogStats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LengthBucket {
+    /// The minimum length of the bucket, in characters.
+    pub min: usize,
+    /// The maximum length of the bucket, in characters, or `None` for the last bucket.
+    pub max: Option<usize>,
+    pub count: usize,
+}
+
+/// Statistics of the texts of a key, in `JSONGetText::stats`. Strings are counted one by one, so a plural text or an object of nested texts counts as each of its strings. Metadata is not counted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CatalogStats {
+    pub text_count: usize,
+    /// The number of texts which were filled from another key.
+    pub filled_count: usize,
+    pub string_count: usize,
+    pub character_count: usize,
+    /// The number of whitespace-separated words, e.g. for estimating the cost of translation.
+    pub word_count: usize,
+    pub max_length: usize,
+    pub mean_length: f64,
+    pub length_histogram: Vec<LengthBucket>,
+    /// The number of placeholders of all strings, counting a placeholder once per string.
+    pub placeholder_count: usize,
+    /// The number of plural texts (see `PluralCategory`).
+    pub plural_count: usize,
+    /// The number of texts in each namespace.
+    pub namespace_sizes: BTreeMap<String, usize>,
+}
+
+impl CatalogStats {
+    fn new() -> CatalogStats {
+        let mut min = 0;
+
+        let mut length_histogram: Vec<LengthBucket> = LENGTH_BUCKETS
+            .iter()
+            .map(|&max| {
+                let bucket = LengthBucket {
+                    min,
+                    max: Some(max),
+                    count: 0,
+                };
+
+                min = max + 1;
+
+                bucket
+            })
+            .collect();
+
+        length_histogram.push(LengthBucket {
+            min,
+            max: None,
+            count: 0,
+        });
+
+        CatalogStats {
+            text_count: 0,
+            filled_count: 0,
+            string_count: 0,
+            character_count: 0,
+            word_count: 0,
+            max_length: 0,
+            mean_length: 0.0,
+            length_histogram,
+            placeholder_count: 0,
+            plural_count: 0,
+            namespace_sizes: BTreeMap::new(),
+        }
+    }
+
+    fn add_string(&mut self, s: &str) {
+        let length = s.chars().count();
+
+        self.string_count += 1;
+        self.character_count += length;
+        self.word_count += s.split_whitespace().count();
+        self.max_length = self.max_length.max(length);
+        self.placeholder_count += placeholders_of(s).len();
+
+        let i =
+            LENGTH_BUCKETS.iter().position(|&max| length <= max).unwrap_or(LENGTH_BUCKETS.len());
+
+        self.length_histogram[i].count += 1;
+    }
+
+    fn add_value(&mut self, value: &Value) {
+        match value {
+            Value::String(s) => self.add_string(s),
+            Value::Object(object) => {
+                if is_plural_object(object) {
+                    self.plural_count += 1;
+                }
+
+                for (name, value) in object {
+                    if !is_metadata_text(name) {
+                        self.add_value(value);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get statistics of the texts of every key, e.g. for capacity planning and estimating the cost of translation.
+    pub fn stats(&self) -> BTreeMap<String, CatalogStats> {
+        self.context
+            .iter()
+            .map(|(key, map)| {
+                let mut stats = CatalogStats::new();
+
+                for namespace in self.namespaces.iter() {
+                    stats.namespace_sizes.insert(namespace.clone(), 0);
+                }
+
+                for (text, value) in map.iter() {
+                    if is_metadata_text(text) {
+                        continue;
+                    }
+
+                    stats.text_count += 1;
+
+                    if self.is_filled_text(key, text) {
+                        stats.filled_count += 1;
+                    }
+
+                    if let Some(namespace) = self.namespace_of(text) {
+                        *stats.namespace_sizes.get_mut(namespace).unwrap() += 1;
+                    }
+
+                    match value {
+                        JSONGetTextValue::Str(s) => stats.add_string(s),
+                        JSONGetTextValue::JSONValue(v) => stats.add_value(v),
+                        JSONGetTextValue::JSONValueRef(v) => stats.add_value(v),
+                    }
+                }
+
+                if stats.string_count > 0 {
+                    stats.mean_length = stats.character_count as f64 / stats.string_count as f64;
+                }
+
+                (key.to_string(), stats)
+            })
+            .collect()
+    }
+}
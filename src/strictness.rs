@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::metadata::is_in_default_map;
+use crate::{JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// How building handles a text which is in a key but not in the default key, e.g. a stray text added by a translator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Strictness {
+    /// Fail with `JSONGetTextBuildError::TextInKeyNotInDefaultKey`.
+    #[default]
+    Strict,
+    /// Keep the text and report it by `JSONGetText::get_extra_texts`.
+    Warn,
+    /// Drop the text, along with its metadata, and report it by `JSONGetText::get_extra_texts`.
+    Prune,
+}
+
+/// A text which is in a key but not in the default key, found when building with `Strictness::Warn` or `Strictness::Prune`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ExtraText {
+    pub key: String,
+    pub text: String,
+}
+
+/// Check the texts of a key which are not in the default key by the strictness, adding the ones which are kept or dropped to `extra_texts`.
+pub(crate) fn check_extra_texts(
+    key: &Key,
+    map: &mut HashMap<String, JSONGetTextValue>,
+    default_map: &HashMap<String, JSONGetTextValue>,
+    strictness: Strictness,
+    extra_texts: &mut Vec<ExtraText>,
+) -> Result<(), JSONGetTextBuildError> {
+    let mut texts: Vec<String> =
+        map.keys().filter(|text| !is_in_default_map(text, default_map)).cloned().collect();
+
+    if texts.is_empty() {
+        return Ok(());
+    }
+
+    if strictness == Strictness::Strict {
+        return Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey {
+            key: key.to_owned(),
+            text: texts.swap_remove(0),
+        });
+    }
+
+    texts.sort_unstable();
+
+    for text in texts {
+        if strictness == Strictness::Prune {
+            map.remove(&text);
+            map.remove(&format!("@{}", text));
+        }
+
+        extra_texts.push(ExtraText {
+            key: key.to_string(),
+            text,
+        });
+    }
+
+    Ok(())
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Set how texts which are in a key but not in the default key are handled when building. `Strictness::Strict` is used if this is not called.
+    #[inline]
+    pub fn set_strictness(&mut self, strictness: Strictness) -> &mut Self {
+        self.options.pipeline.strictness = strictness;
+
+        self
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get the texts which were in a key but not in the default key when building with `Strictness::Warn` or `Strictness::Prune`, sorted by their keys and texts.
+    #[inline]
+    pub fn get_extra_texts(&self) -> &[ExtraText] {
+        &self.extra_texts
+    }
+}
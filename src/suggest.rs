@@ -0,0 +1,161 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+use serde_json::Value;
+
+use crate::metadata::is_metadata_text;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// The maximum number of suggestions in a `TextNotFound` error.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A text which is not found by `try_get_text` or `try_get_text_with_key`, with the existing text ids nearest to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextNotFound {
+    pub key: String,
+    pub text: String,
+    /// The text ids within a small edit distance of the text, nearest first.
+    pub suggestions: Vec<String>,
+}
+
+impl Display for TextNotFound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "The text `{}` is not found in the key `{}`.",
+            self.text, self.key
+        ))?;
+
+        if let Some((first, rest)) = self.suggestions.split_first() {
+            f.write_fmt(format_args!(" Did you mean `{}`", first))?;
+
+            for suggestion in rest {
+                f.write_fmt(format_args!(", `{}`", suggestion))?;
+            }
+
+            f.write_str("?")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for TextNotFound {}
+
+/// Get the Levenshtein distance between two strings, counted in characters.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+
+            diagonal = row[j + 1];
+
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Collect the dot paths of the texts in nested objects.
+fn collect_nested_ids(id: &str, value: &Value, ids: &mut Vec<String>) {
+    if let Value::Object(object) = value {
+        for (name, value) in object {
+            if !is_metadata_text(name) {
+                let id = format!("{}.{}", id, name);
+
+                collect_nested_ids(&id, value, ids);
+
+                ids.push(id);
+            }
+        }
+    }
+}
+
+/// Find the text ids of a string map, including the dot paths of nested texts, which are within a small edit distance of a text, nearest first.
+pub(crate) fn similar_texts(map: &HashMap<String, JSONGetTextValue>, text: &str) -> Vec<String> {
+    let mut ids = Vec::with_capacity(map.len());
+
+    for (id, value) in map.iter() {
+        if is_metadata_text(id) {
+            continue;
+        }
+
+        match value {
+            JSONGetTextValue::JSONValue(v) => collect_nested_ids(id, v, &mut ids),
+            JSONGetTextValue::JSONValueRef(v) => collect_nested_ids(id, v, &mut ids),
+            JSONGetTextValue::Str(_) => (),
+        }
+
+        ids.push(id.clone());
+    }
+
+    // allow about one typo per three characters
+    let max_distance = (text.chars().count() / 3).max(1);
+
+    let mut suggestions: Vec<(usize, String)> = ids
+        .into_iter()
+        .filter_map(|id| {
+            let distance = levenshtein(text, &id);
+
+            (distance <= max_distance).then_some((distance, id))
+        })
+        .collect();
+
+    suggestions.sort_unstable();
+    suggestions.truncate(MAX_SUGGESTIONS);
+
+    suggestions.into_iter().map(|(_, id)| id).collect()
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Get text from context like `get_text`, but if the text is not found, return an error with the nearest existing text ids, e.g. to diagnose typos in debug builds or tests.
+    #[inline]
+    pub fn try_get_text<T: AsRef<str>>(
+        &'a self,
+        text: T,
+    ) -> Result<JSONGetTextValue<'a>, TextNotFound> {
+        let (key, map) = self.default_entry();
+
+        self.try_lookup_text(key, map, text.as_ref())
+    }
+
+    /// Get text from context with a specific key like `get_text_with_key`, but if the text is not found, return an error with the nearest existing text ids. If the key is not in context, the default key is used.
+    #[inline]
+    pub fn try_get_text_with_key<Q: ?Sized + Hash + Eq, T: AsRef<str>>(
+        &'a self,
+        key: &Q,
+        text: T,
+    ) -> Result<JSONGetTextValue<'a>, TextNotFound>
+    where
+        Key: Borrow<Q>, {
+        let (key, map) = self.find_entry_or_default(key);
+
+        self.try_lookup_text(key, map, text.as_ref())
+    }
+
+    fn try_lookup_text<'b>(
+        &'b self,
+        key: &Key,
+        map: &'b HashMap<String, JSONGetTextValue<'a>>,
+        text: &str,
+    ) -> Result<JSONGetTextValue<'b>, TextNotFound> {
+        self.lookup_text_or_missing(key, map, text).ok_or_else(|| {
+            TextNotFound {
+                key: key.to_string(),
+                text: text.to_string(),
+                suggestions: similar_texts(map, text),
+            }
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::io::{self, ErrorKind};
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add a TOML document to the context for a specify key. Its top-level keys are the texts, which are checked in the same way as a JSON object added by `add_json`, including the consistency with the default key when building. A table becomes a JSON object, e.g. a plural text (see `PluralCategory`).
+    pub fn add_toml<K: Into<Key>, S: AsRef<str>>(
+        &mut self,
+        key: K,
+        toml: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let value: Map<String, Value> = ::toml::from_str(toml.as_ref())?;
+
+        let map: HashMap<String, JSONGetTextValue<'static>> =
+            value.into_iter().map(|(k, v)| (k, JSONGetTextValue::from_json_value(v))).collect();
+
+        self.add_map(key.into(), map)
+    }
+
+    /// Add a TOML file to the context for a specify key. See `add_toml`.
+    #[cfg(feature = "fs")]
+    pub fn add_toml_file<K: Into<Key>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let toml = self.options.ingest_limits.read_file(&key, path.as_ref())?;
+
+        let toml =
+            String::from_utf8(toml).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        self.add_toml(key, toml)
+    }
+}
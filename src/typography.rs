@@ -0,0 +1,130 @@
+use crate::format::map_outside_conditionals;
+use crate::locale::{language_of, region_of};
+use crate::{Context, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// Quotation delimiters of a locale: the primary pair and the alternate (nested) pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Delimiters {
+    quotation_start: char,
+    quotation_end: char,
+    alternate_quotation_start: char,
+    alternate_quotation_end: char,
+}
+
+const fn delimiters(q_start: char, q_end: char, a_start: char, a_end: char) -> Delimiters {
+    Delimiters {
+        quotation_start: q_start,
+        quotation_end: q_end,
+        alternate_quotation_start: a_start,
+        alternate_quotation_end: a_end,
+    }
+}
+
+/// Get the delimiters of a key, based on the CLDR delimiters data.
+pub(crate) fn delimiters_of(key: &Key) -> Option<Delimiters> {
+    let language = language_of(key)?;
+
+    let d = match language.as_str() {
+        "en" | "pt" | "ko" | "tr" | "da" | "id" | "vi" | "th" => delimiters('“', '”', '‘', '’'),
+        "zh" => {
+            match region_of(key).as_deref() {
+                Some("TW") | Some("HK") | Some("MO") => delimiters('「', '」', '『', '』'),
+                _ => delimiters('“', '”', '‘', '’'),
+            }
+        }
+        "ja" => delimiters('「', '」', '『', '』'),
+        "de" | "cs" | "sk" | "sl" | "is" | "lt" => delimiters('„', '“', '‚', '‘'),
+        "fr" | "nb" | "no" => delimiters('«', '»', '«', '»'),
+        "es" | "it" | "el" | "ca" => delimiters('«', '»', '“', '”'),
+        "ru" | "uk" | "be" => delimiters('«', '»', '„', '“'),
+        "pl" | "ro" => delimiters('„', '”', '«', '»'),
+        "hu" => delimiters('„', '”', '»', '«'),
+        "nl" => delimiters('‘', '’', '“', '”'),
+        "sv" | "fi" | "he" => delimiters('”', '”', '’', '’'),
+        _ => return None,
+    };
+
+    Some(d)
+}
+
+/// Convert straight quotes and apostrophes into typographic ones.
+pub(crate) fn normalize_quotation(s: &str, delimiters: Delimiters) -> String {
+    let chars: Vec<char> = s.chars().collect();
+
+    let mut output = String::with_capacity(s.len());
+
+    let mut double_opened = false;
+    let mut single_opened = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = if i > 0 {
+            Some(chars[i - 1])
+        } else {
+            None
+        };
+        let next = chars.get(i + 1).copied();
+
+        let after_word = prev.map(|c| c.is_alphanumeric()).unwrap_or(false);
+        let before_word = next.map(|c| c.is_alphanumeric()).unwrap_or(false);
+        let before_space = next.map(|c| c.is_whitespace()).unwrap_or(true);
+
+        match c {
+            '"' => {
+                if double_opened || (after_word && prev.unwrap().is_ascii() && before_space) {
+                    double_opened = false;
+                    output.push(delimiters.quotation_end);
+                } else {
+                    double_opened = true;
+                    output.push(delimiters.quotation_start);
+                }
+            }
+            '\'' => {
+                if after_word && before_word {
+                    output.push('’');
+                } else if single_opened {
+                    single_opened = false;
+                    output.push(delimiters.alternate_quotation_end);
+                } else if after_word {
+                    output.push('’');
+                } else {
+                    single_opened = true;
+                    output.push(delimiters.alternate_quotation_start);
+                }
+            }
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// Normalize quotation of every string in the context. Keys without known delimiters are left unchanged, and so is the syntax of conditionals.
+pub(crate) fn normalize_context_quotation(context: &mut Context) {
+    for (key, map) in context.iter_mut() {
+        let delimiters = match delimiters_of(key) {
+            Some(delimiters) => delimiters,
+            None => continue,
+        };
+
+        for value in map.values_mut() {
+            let s = match value.as_str() {
+                Some(s) if s.contains(['"', '\'']) => s,
+                _ => continue,
+            };
+
+            *value = JSONGetTextValue::from_string(map_outside_conditionals(s, &mut |s| {
+                normalize_quotation(s, delimiters)
+            }));
+        }
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Convert straight quotes and apostrophes into the typographic equivalents of each key (e.g. `« »` for French, `„ “` for German) when building. It is disabled by default.
+    #[inline]
+    pub fn normalize_quotation(&mut self, enable: bool) -> &mut Self {
+        self.options.pipeline.normalize_quotation = enable;
+
+        self
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(feature = "validator")]
+mod validator;
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::format::interpolate_with;
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+/// The prefix of the ids of validation messages, e.g. `validation.length` or `validation.email.required` for a specific field.
+pub const VALIDATION_TEXT_PREFIX: &str = "validation.";
+
+/// The prefix of the ids of field names, e.g. `fields.email`.
+pub const FIELD_TEXT_PREFIX: &str = "fields.";
+
+/// The code of the message used for validation errors which have no message of their own.
+pub const GENERIC_VALIDATION_CODE: &str = "invalid";
+
+/// The message used if the context has no `validation.invalid` text.
+pub const FALLBACK_VALIDATION_MESSAGE: &str = "{field} is invalid.";
+
+impl<'a> JSONGetText<'a> {
+    /// Get the localized name of a field from `fields.{field}` with a specific key. If there is no such text, the field itself is returned.
+    pub fn localize_field_name<Q: ?Sized + Hash + Eq, F: AsRef<str>>(
+        &self,
+        key: &Q,
+        field: F,
+    ) -> String
+    where
+        Key: Borrow<Q>, {
+        let field = field.as_ref();
+
+        let map = self.find_map_or_default(key);
+
+        match self.lookup_text(map, &format!("{}{}", FIELD_TEXT_PREFIX, field)) {
+            Some(value) => value.to_string(),
+            None => field.to_string(),
+        }
+    }
+
+    /// Get the message of a validation error of a field with a specific key. The message comes from the first one found of
+    ///
+    /// 1. `validation.{field}.{code}`
+    /// 1. `validation.{code}`
+    /// 1. `message`, the message carried by the error itself
+    /// 1. `validation.invalid`
+    /// 1. `FALLBACK_VALIDATION_MESSAGE`
+    ///
+    /// `{name}` placeholders are replaced with `params`, and `{field}` is replaced with the localized field name unless `params` has it. If the key is not in context, the default key is used.
+    ///
+    /// With the `validator` feature, `localize_validation_errors` does this for `validator::ValidationErrors`. Errors of other crates, such as `garde`, can be passed here directly.
+    pub fn localize_field_error<Q: ?Sized + Hash + Eq, F: AsRef<str>, C: AsRef<str>>(
+        &self,
+        key: &Q,
+        field: F,
+        code: C,
+        message: Option<&str>,
+        params: &HashMap<&str, JSONGetTextValue>,
+    ) -> String
+    where
+        Key: Borrow<Q>, {
+        let field = field.as_ref();
+        let code = code.as_ref();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        let template = self
+            .lookup_text(map, &format!("{}{}.{}", VALIDATION_TEXT_PREFIX, field, code))
+            .or_else(|| self.lookup_text(map, &format!("{}{}", VALIDATION_TEXT_PREFIX, code)))
+            .map(|value| value.to_string())
+            .or_else(|| message.map(String::from))
+            .or_else(|| {
+                self.lookup_text(
+                    map,
+                    &format!("{}{}", VALIDATION_TEXT_PREFIX, GENERIC_VALIDATION_CODE),
+                )
+                .map(|value| value.to_string())
+            })
+            .unwrap_or_else(|| String::from(FALLBACK_VALIDATION_MESSAGE));
+
+        interpolate_with(&template, |name| {
+            match params.get(name) {
+                Some(value) => Some(value.to_string()),
+                None if name == "field" => Some(self.localize_field_name::<Key, _>(key, field)),
+                None => None,
+            }
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+use crate::{JSONGetText, JSONGetTextValue, Key};
+
+impl<'a> JSONGetText<'a> {
+    /// Localize the errors of the `validator` crate with a specific key by `localize_field_error`, using the codes, messages and params (e.g. `min` and `max`) of the errors. Errors of nested structs and lists are keyed by paths like `address.city` and `items[0].name`. If the key is not in context, the default key is used.
+    pub fn localize_validation_errors<Q: ?Sized + Hash + Eq>(
+        &self,
+        key: &Q,
+        errors: &ValidationErrors,
+    ) -> BTreeMap<String, Vec<String>>
+    where
+        Key: Borrow<Q>, {
+        let key = self.find_entry_or_default(key).0;
+
+        let mut output = BTreeMap::new();
+
+        self.collect_validation_errors(key, None, errors, &mut output);
+
+        output
+    }
+
+    fn collect_validation_errors(
+        &self,
+        key: &Key,
+        prefix: Option<&str>,
+        errors: &ValidationErrors,
+        output: &mut BTreeMap<String, Vec<String>>,
+    ) {
+        for (field, kind) in errors.errors() {
+            let path = match prefix {
+                Some(prefix) => format!("{}.{}", prefix, field),
+                None => field.to_string(),
+            };
+
+            match kind {
+                ValidationErrorsKind::Field(errors) => {
+                    let messages = output.entry(path.clone()).or_default();
+
+                    for error in errors {
+                        let params: HashMap<&str, JSONGetTextValue> = error
+                            .params
+                            .iter()
+                            .map(|(name, value)| {
+                                (name.as_ref(), JSONGetTextValue::from_json_value_ref(value))
+                            })
+                            .collect();
+
+                        messages.push(self.localize_field_error::<Key, _, _>(
+                            key,
+                            &path,
+                            &error.code,
+                            error.message.as_deref(),
+                            &params,
+                        ));
+                    }
+                }
+                ValidationErrorsKind::Struct(errors) => {
+                    self.collect_validation_errors(key, Some(&path), errors, output);
+                }
+                ValidationErrorsKind::List(list) => {
+                    for (index, errors) in list {
+                        let path = format!("{}[{}]", path, index);
+
+                        self.collect_validation_errors(key, Some(&path), errors, output);
+                    }
+                }
+            }
+        }
+    }
+}
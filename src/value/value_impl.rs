@@ -216,7 +216,7 @@ impl<'a> JSONGetTextValue<'a> {
 
     /// Clone the reference of this `JSONGetTextValue` instance.
     #[inline]
-    pub fn clone_borrowed(&self) -> JSONGetTextValue {
+    pub fn clone_borrowed(&self) -> JSONGetTextValue<'_> {
         match self {
             JSONGetTextValue::Str(s) => JSONGetTextValue::Str(s),
             JSONGetTextValue::JSONValue(v) => JSONGetTextValue::JSONValueRef(v),
@@ -454,18 +454,18 @@ struct JSONGetTextValueVisitor;
 impl<'de> Visitor<'de> for JSONGetTextValueVisitor {
     type Value = JSONGetTextValue<'de>;
 
-    serde::serde_if_integer128! {
-        #[inline]
-        fn visit_i128<E>(self, v: i128) -> Result<JSONGetTextValue<'static>, E> where E: DeError {
-            JSONGetTextValue::from_i128(v).map_err(DeError::custom)
-        }
+    #[inline]
+    fn visit_i128<E>(self, v: i128) -> Result<JSONGetTextValue<'static>, E>
+    where
+        E: DeError, {
+        JSONGetTextValue::from_i128(v).map_err(DeError::custom)
     }
 
-    serde::serde_if_integer128! {
-        #[inline]
-        fn visit_u128<E>(self, v: u128) -> Result<JSONGetTextValue<'static>, E> where E: DeError {
-            JSONGetTextValue::from_u128(v).map_err(DeError::custom)
-        }
+    #[inline]
+    fn visit_u128<E>(self, v: u128) -> Result<JSONGetTextValue<'static>, E>
+    where
+        E: DeError, {
+        JSONGetTextValue::from_u128(v).map_err(DeError::custom)
     }
 
     #[inline]
@@ -529,6 +529,13 @@ impl<'de> Visitor<'de> for JSONGetTextValueVisitor {
         Ok(JSONGetTextValue::null())
     }
 
+    #[inline]
+    fn visit_unit<E>(self) -> Result<JSONGetTextValue<'static>, E>
+    where
+        E: DeError, {
+        Ok(JSONGetTextValue::null())
+    }
+
     #[inline]
     fn visit_seq<A>(self, mut seq: A) -> Result<JSONGetTextValue<'static>, A::Error>
     where
@@ -567,7 +574,7 @@ impl<'de> Deserialize<'de> for JSONGetTextValue<'de> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>, {
-        deserializer.deserialize_str(JSONGetTextValueVisitor)
+        deserializer.deserialize_any(JSONGetTextValueVisitor)
     }
 }
 
@@ -0,0 +1,149 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::locale::key_from_tag;
+use crate::Key;
+
+/// A language range of an `Accept-Language` header with its quality value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRange {
+    pub tag: String,
+    pub quality: f32,
+}
+
+impl LanguageRange {
+    /// Returns `true` if the range is `*`.
+    #[inline]
+    pub fn is_wildcard(&self) -> bool {
+        self.tag == "*"
+    }
+
+    /// Returns `true` if the quality value is greater than zero.
+    #[inline]
+    pub fn is_acceptable(&self) -> bool {
+        self.quality > 0.0
+    }
+
+    /// Convert the tag into a key. The wildcard and invalid tags cannot be converted.
+    #[inline]
+    pub fn to_key(&self) -> Option<Key> {
+        key_from_tag(&self.tag)
+    }
+}
+
+impl Display for LanguageRange {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.quality < 1.0 {
+            f.write_fmt(format_args!("{};q={}", self.tag, self.quality))
+        } else {
+            f.write_str(&self.tag)
+        }
+    }
+}
+
+/// A parsed `Accept-Language` header. Language ranges are sorted by their quality values in descending order, and ranges with the same quality value keep their order in the header.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AcceptLanguage {
+    ranges: Vec<LanguageRange>,
+}
+
+impl AcceptLanguage {
+    /// Parse an `Accept-Language` header. Malformed ranges are skipped.
+    pub fn parse<S: AsRef<str>>(header: S) -> AcceptLanguage {
+        let mut ranges = Vec::new();
+
+        for item in header.as_ref().split(',') {
+            let mut parts = item.split(';');
+
+            let tag = parts.next().unwrap().trim();
+
+            if tag.is_empty()
+                || !(tag == "*" || tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+            {
+                continue;
+            }
+
+            let mut quality = 1.0;
+
+            for param in parts {
+                let param = param.trim();
+
+                if let Some(q) = param.strip_prefix("q=").or_else(|| param.strip_prefix("Q=")) {
+                    quality = match q.trim().parse::<f32>() {
+                        Ok(q) if (0.0..=1.0).contains(&q) => q,
+                        _ => 0.0,
+                    };
+                }
+            }
+
+            ranges.push(LanguageRange {
+                tag: tag.to_string(),
+                quality,
+            });
+        }
+
+        ranges.sort_by(|a, b| b.quality.total_cmp(&a.quality));
+
+        AcceptLanguage {
+            ranges,
+        }
+    }
+
+    /// Iterate over language ranges, including the wildcard and unacceptable ranges.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, LanguageRange> {
+        self.ranges.iter()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns `true` if the header contains an acceptable wildcard, which means any language is acceptable.
+    #[inline]
+    pub fn accepts_any(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_wildcard() && range.is_acceptable())
+    }
+
+    /// Convert acceptable language ranges into keys, in order of preference. The wildcard and tags which cannot be converted are skipped.
+    pub fn to_keys(&self) -> Vec<Key> {
+        let mut keys: Vec<Key> = Vec::with_capacity(self.ranges.len());
+
+        for range in self.ranges.iter().filter(|range| range.is_acceptable()) {
+            if let Some(key) = range.to_key() {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        keys
+    }
+}
+
+impl FromStr for AcceptLanguage {
+    type Err = Infallible;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AcceptLanguage::parse(s))
+    }
+}
+
+impl<'a> IntoIterator for &'a AcceptLanguage {
+    type IntoIter = std::slice::Iter<'a, LanguageRange>;
+    type Item = &'a LanguageRange;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,155 @@
+extern crate actix_web;
+
+use std::future::{ready, Ready};
+use std::sync::Arc;
+
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::header::{ACCEPT_LANGUAGE, COOKIE};
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest};
+
+use crate::{
+    JSONGetText, JSONGetTextValue, Key, LocaleRequest, LocaleResolverChain, SharedJSONGetText,
+};
+
+/// The middleware which negotiates the locale of each request, from its query string, cookie, `Accept-Language` header and client IP, for the `I18n` extractor. Register it with `App::wrap`.
+#[derive(Debug, Clone)]
+pub struct JSONGetTextMiddleware {
+    ctx: Arc<SharedJSONGetText>,
+    resolvers: Arc<LocaleResolverChain>,
+}
+
+impl JSONGetTextMiddleware {
+    /// Create a `JSONGetTextMiddleware` instance which resolves locales with the default `LocaleResolverChain`.
+    #[inline]
+    pub fn new(ctx: JSONGetText<'static>) -> JSONGetTextMiddleware {
+        JSONGetTextMiddleware::from_shared(Arc::new(SharedJSONGetText::new(ctx)))
+    }
+
+    /// Create a `JSONGetTextMiddleware` instance from a `SharedJSONGetText` instance, which can be replaced later, e.g. after reloading catalogs.
+    #[inline]
+    pub fn from_shared(ctx: Arc<SharedJSONGetText>) -> JSONGetTextMiddleware {
+        JSONGetTextMiddleware {
+            ctx,
+            resolvers: Arc::new(LocaleResolverChain::default()),
+        }
+    }
+
+    /// Resolve locales with another chain of resolvers.
+    #[inline]
+    pub fn with_resolvers(mut self, resolvers: LocaleResolverChain) -> JSONGetTextMiddleware {
+        self.resolvers = Arc::new(resolvers);
+
+        self
+    }
+
+    /// Get the `SharedJSONGetText` instance.
+    #[inline]
+    pub fn shared(&self) -> &Arc<SharedJSONGetText> {
+        &self.ctx
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for JSONGetTextMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+{
+    type Error = Error;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+    type InitError = ();
+    type Response = ServiceResponse<B>;
+    type Transform = JSONGetTextMiddlewareService<S>;
+
+    #[inline]
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(JSONGetTextMiddlewareService {
+            service,
+            middleware: self.clone(),
+        }))
+    }
+}
+
+/// The service of `JSONGetTextMiddleware`.
+#[derive(Debug)]
+pub struct JSONGetTextMiddlewareService<S> {
+    service: S,
+    middleware: JSONGetTextMiddleware,
+}
+
+impl<S, B> Service<ServiceRequest> for JSONGetTextMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+{
+    type Error = Error;
+    type Future = S::Future;
+    type Response = ServiceResponse<B>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let ctx = self.middleware.ctx.load_full();
+
+        let key = {
+            let request = LocaleRequest {
+                query: Some(req.query_string()).filter(|query| !query.is_empty()),
+                cookie: req.headers().get(COOKIE).and_then(|value| value.to_str().ok()),
+                accept_language: req
+                    .headers()
+                    .get(ACCEPT_LANGUAGE)
+                    .and_then(|value| value.to_str().ok()),
+                client_ip: req.peer_addr().map(|addr| addr.ip()),
+                ..LocaleRequest::default()
+            };
+
+            self.middleware.resolvers.resolve(&ctx, &request)
+        };
+
+        req.extensions_mut().insert(I18n {
+            ctx,
+            key,
+        });
+
+        self.service.call(req)
+    }
+}
+
+/// An extractor for the `JSONGetText` instance and the locale negotiated for a request by `JSONGetTextMiddleware`. Extracting it fails with `500 Internal Server Error` if the middleware is not registered.
+#[derive(Debug, Clone)]
+pub struct I18n {
+    ctx: Arc<JSONGetText<'static>>,
+    key: Key,
+}
+
+impl I18n {
+    /// Get the key negotiated for the request.
+    #[inline]
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Get the `JSONGetText` instance which was current when the request arrived.
+    #[inline]
+    pub fn context(&self) -> &JSONGetText<'static> {
+        &self.ctx
+    }
+
+    /// Get text with the negotiated key.
+    #[inline]
+    pub fn t<T: AsRef<str>>(&self, text: T) -> Option<JSONGetTextValue<'_>> {
+        self.ctx.lookup_text(self.ctx.find_map_or_default(&self.key), text.as_ref())
+    }
+}
+
+impl FromRequest for I18n {
+    type Error = Error;
+    type Future = Ready<Result<I18n, Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions().get::<I18n>().cloned().ok_or_else(|| {
+                ErrorInternalServerError("`JSONGetTextMiddleware` is not registered.")
+            }),
+        )
+    }
+}
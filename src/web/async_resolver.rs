@@ -0,0 +1,93 @@
+use std::fmt::{self, Debug, Formatter};
+use std::future::{ready, Future};
+use std::pin::Pin;
+
+use super::resolver::available;
+use crate::{
+    AcceptLanguageResolver, CookieResolver, FormResolver, JSONGetText, Key, LocaleRequest,
+    LocaleResolver, QueryResolver,
+};
+
+/// The future returned by `AsyncLocaleResolver::resolve`.
+pub type ResolveFuture<'f> = Pin<Box<dyn Future<Output = Option<Key>> + Send + 'f>>;
+
+/// Finds a key for a request asynchronously, e.g. from a preference stored in a database. Resolvers should only return keys which are in the context.
+///
+/// Every `LocaleResolver` is also an `AsyncLocaleResolver`, so both kinds can be mixed in an `AsyncLocaleResolverChain`.
+pub trait AsyncLocaleResolver: Send + Sync {
+    fn resolve<'f>(
+        &'f self,
+        ctx: &'f JSONGetText<'_>,
+        request: &'f LocaleRequest<'_>,
+    ) -> ResolveFuture<'f>;
+}
+
+impl<R: LocaleResolver> AsyncLocaleResolver for R {
+    #[inline]
+    fn resolve<'f>(
+        &'f self,
+        ctx: &'f JSONGetText<'_>,
+        request: &'f LocaleRequest<'_>,
+    ) -> ResolveFuture<'f> {
+        Box::pin(ready(LocaleResolver::resolve(self, ctx, request)))
+    }
+}
+
+/// Runs resolvers in order and uses the first key found. If none is found, the default key is used.
+pub struct AsyncLocaleResolverChain {
+    resolvers: Vec<Box<dyn AsyncLocaleResolver>>,
+}
+
+impl AsyncLocaleResolverChain {
+    /// Create an empty chain.
+    #[inline]
+    pub fn new() -> AsyncLocaleResolverChain {
+        AsyncLocaleResolverChain {
+            resolvers: Vec::new(),
+        }
+    }
+
+    /// Append a resolver to the chain.
+    #[inline]
+    pub fn push<R: AsyncLocaleResolver + 'static>(&mut self, resolver: R) -> &mut Self {
+        self.resolvers.push(Box::new(resolver));
+
+        self
+    }
+
+    /// Resolve the key of a request.
+    pub async fn resolve(&self, ctx: &JSONGetText<'_>, request: &LocaleRequest<'_>) -> Key {
+        for resolver in self.resolvers.iter() {
+            if let Some(key) = available(ctx, resolver.resolve(ctx, request).await) {
+                return key;
+            }
+        }
+
+        ctx.default_entry().0.to_owned()
+    }
+}
+
+impl Default for AsyncLocaleResolverChain {
+    /// The same resolvers as the default `LocaleResolverChain`.
+    #[inline]
+    fn default() -> Self {
+        let mut chain = AsyncLocaleResolverChain::new();
+
+        chain
+            .push(QueryResolver::default())
+            .push(FormResolver::default())
+            .push(CookieResolver::default())
+            .push(AcceptLanguageResolver);
+
+        chain
+    }
+}
+
+impl Debug for AsyncLocaleResolverChain {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncLocaleResolverChain")
+            .field("resolvers", &self.resolvers.len())
+            .finish()
+    }
+}
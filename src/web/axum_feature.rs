@@ -0,0 +1,108 @@
+extern crate axum;
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::header::{ACCEPT_LANGUAGE, COOKIE};
+use axum::http::request::Parts;
+
+use crate::{
+    JSONGetText, JSONGetTextValue, Key, LocaleRequest, LocaleResolverChain, SharedJSONGetText,
+};
+
+/// The state which the `Text` extractor needs. Put it in the state of a `Router` with `with_state`, or make it a field of your own state and implement `FromRef` for it.
+#[derive(Debug, Clone)]
+pub struct JSONGetTextState {
+    ctx: Arc<SharedJSONGetText>,
+    resolvers: Arc<LocaleResolverChain>,
+}
+
+impl JSONGetTextState {
+    /// Create a `JSONGetTextState` instance which resolves locales with the default `LocaleResolverChain`.
+    #[inline]
+    pub fn new(ctx: JSONGetText<'static>) -> JSONGetTextState {
+        JSONGetTextState::from_shared(Arc::new(SharedJSONGetText::new(ctx)))
+    }
+
+    /// Create a `JSONGetTextState` instance from a `SharedJSONGetText` instance, which can be replaced later, e.g. after reloading catalogs.
+    #[inline]
+    pub fn from_shared(ctx: Arc<SharedJSONGetText>) -> JSONGetTextState {
+        JSONGetTextState {
+            ctx,
+            resolvers: Arc::new(LocaleResolverChain::default()),
+        }
+    }
+
+    /// Resolve locales with another chain of resolvers.
+    #[inline]
+    pub fn with_resolvers(mut self, resolvers: LocaleResolverChain) -> JSONGetTextState {
+        self.resolvers = Arc::new(resolvers);
+
+        self
+    }
+
+    /// Get the `SharedJSONGetText` instance.
+    #[inline]
+    pub fn shared(&self) -> &Arc<SharedJSONGetText> {
+        &self.ctx
+    }
+}
+
+/// An extractor for the `JSONGetText` instance and the locale negotiated for a request, from its query string, cookie and `Accept-Language` header.
+#[derive(Debug, Clone)]
+pub struct Text {
+    ctx: Arc<JSONGetText<'static>>,
+    key: Key,
+}
+
+impl Text {
+    /// Get the key negotiated for the request.
+    #[inline]
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Get the `JSONGetText` instance which was current when the request was extracted.
+    #[inline]
+    pub fn context(&self) -> &JSONGetText<'static> {
+        &self.ctx
+    }
+
+    /// Get text with the negotiated key.
+    #[inline]
+    pub fn get_text<T: AsRef<str>>(&self, text: T) -> Option<JSONGetTextValue<'_>> {
+        self.ctx.lookup_text(self.ctx.find_map_or_default(&self.key), text.as_ref())
+    }
+}
+
+impl<S> FromRequestParts<S> for Text
+where
+    JSONGetTextState: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let state = JSONGetTextState::from_ref(state);
+
+        let ctx = state.ctx.load_full();
+
+        let request = LocaleRequest {
+            query: parts.uri.query(),
+            cookie: parts.headers.get(COOKIE).and_then(|value| value.to_str().ok()),
+            accept_language: parts
+                .headers
+                .get(ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok()),
+            ..LocaleRequest::default()
+        };
+
+        let key = state.resolvers.resolve(&ctx, &request);
+
+        Ok(Text {
+            ctx,
+            key,
+        })
+    }
+}
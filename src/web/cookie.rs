@@ -0,0 +1,142 @@
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+use crate::locale::key_from_tag;
+use crate::Key;
+
+/// The `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl Display for SameSite {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SameSite::Strict => f.write_str("Strict"),
+            SameSite::Lax => f.write_str("Lax"),
+            SameSite::None => f.write_str("None"),
+        }
+    }
+}
+
+/// Reads and writes the cookie which remembers the locale chosen by a user. All web integrations of this crate use it, so the cookie behaves the same across frameworks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocaleCookie {
+    name: String,
+    max_age: Option<Duration>,
+    same_site: SameSite,
+    path: String,
+    secure: bool,
+}
+
+impl Default for LocaleCookie {
+    /// A cookie named `lang` which lasts a year, with `SameSite=Lax` and `Path=/`.
+    #[inline]
+    fn default() -> Self {
+        LocaleCookie {
+            name: String::from("lang"),
+            max_age: Some(Duration::from_secs(365 * 24 * 60 * 60)),
+            same_site: SameSite::Lax,
+            path: String::from("/"),
+            secure: false,
+        }
+    }
+}
+
+impl LocaleCookie {
+    /// Create a `LocaleCookie` instance with a cookie name and the default attributes.
+    #[inline]
+    pub fn new<S: Into<String>>(name: S) -> LocaleCookie {
+        LocaleCookie {
+            name: name.into(),
+            ..LocaleCookie::default()
+        }
+    }
+
+    /// Set the `Max-Age` attribute. `None` makes it a session cookie.
+    #[inline]
+    pub fn max_age(&mut self, max_age: Option<Duration>) -> &mut Self {
+        self.max_age = max_age;
+
+        self
+    }
+
+    /// Set the `SameSite` attribute.
+    #[inline]
+    pub fn same_site(&mut self, same_site: SameSite) -> &mut Self {
+        self.same_site = same_site;
+
+        self
+    }
+
+    /// Set the `Path` attribute.
+    #[inline]
+    pub fn path<S: Into<String>>(&mut self, path: S) -> &mut Self {
+        self.path = path.into();
+
+        self
+    }
+
+    /// Set the `Secure` attribute. It is always set if `SameSite` is `None`.
+    #[inline]
+    pub fn secure(&mut self, secure: bool) -> &mut Self {
+        self.secure = secure;
+
+        self
+    }
+
+    #[inline]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Create the value of a `Set-Cookie` header which stores a key.
+    #[inline]
+    pub fn to_set_cookie(&self, key: &Key) -> String {
+        self.format(key, self.max_age)
+    }
+
+    /// Create the value of a `Set-Cookie` header which removes the cookie.
+    #[inline]
+    pub fn to_removal_set_cookie(&self) -> String {
+        self.format("", Some(Duration::ZERO))
+    }
+
+    /// Find the raw value of the cookie in a `Cookie` header.
+    pub fn read_value<'h>(&self, cookie_header: &'h str) -> Option<&'h str> {
+        cookie_header.split(';').find_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+
+            if name.trim() == self.name {
+                Some(value.trim().trim_matches('"'))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Read the key stored in the cookie from a `Cookie` header.
+    #[inline]
+    pub fn read(&self, cookie_header: &str) -> Option<Key> {
+        self.read_value(cookie_header).and_then(key_from_tag)
+    }
+
+    fn format<V: Display>(&self, value: V, max_age: Option<Duration>) -> String {
+        let mut s =
+            format!("{}={}; Path={}; SameSite={}", self.name, value, self.path, self.same_site);
+
+        if let Some(max_age) = max_age {
+            s.push_str(&format!("; Max-Age={}", max_age.as_secs()));
+        }
+
+        if self.secure || self.same_site == SameSite::None {
+            s.push_str("; Secure");
+        }
+
+        s
+    }
+}
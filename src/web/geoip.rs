@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use super::resolver::available;
+use crate::locale::region_of;
+use crate::{JSONGetText, Key, LocaleRequest, LocaleResolver};
+
+/// Looks up the country of an IP address. Implement this for the GeoIP database you use.
+pub trait GeoIpBackend: Send + Sync {
+    /// Get the ISO 3166-1 alpha-2 code of the country (e.g. `TW`), if known.
+    fn country(&self, ip: IpAddr) -> Option<String>;
+}
+
+impl<F: Fn(IpAddr) -> Option<String> + Send + Sync> GeoIpBackend for F {
+    #[inline]
+    fn country(&self, ip: IpAddr) -> Option<String> {
+        self(ip)
+    }
+}
+
+/// Resolves the locale from the country of the client IP. Since a country is only a weak hint, this resolver should be pushed after the resolvers of explicit signals, such as `AcceptLanguageResolver`.
+///
+/// A country is mapped to a key by `map_country`, or otherwise to a key in the context whose region matches it. When several keys match, the default key is preferred, then the first one in sorted order.
+#[derive(Clone)]
+pub struct GeoIpResolver {
+    backend: Arc<dyn GeoIpBackend>,
+    countries: HashMap<String, Key>,
+}
+
+impl GeoIpResolver {
+    #[inline]
+    pub fn new<B: GeoIpBackend + 'static>(backend: B) -> GeoIpResolver {
+        GeoIpResolver {
+            backend: Arc::new(backend),
+            countries: HashMap::new(),
+        }
+    }
+
+    /// Map a country to a specific key.
+    #[inline]
+    pub fn map_country<S: AsRef<str>, K: Into<Key>>(&mut self, country: S, key: K) -> &mut Self {
+        self.countries.insert(country.as_ref().to_ascii_uppercase(), key.into());
+
+        self
+    }
+}
+
+impl LocaleResolver for GeoIpResolver {
+    fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Option<Key> {
+        let country = self.backend.country(request.client_ip?)?.to_ascii_uppercase();
+
+        if let Some(key) = self.countries.get(&country) {
+            return available(ctx, Some(key.to_owned()));
+        }
+
+        let mut candidates: Vec<&Key> = ctx
+            .context
+            .keys()
+            .filter(|key| region_of(key).as_deref() == Some(country.as_str()))
+            .collect();
+
+        if let Some(key) = candidates.iter().find(|key| ***key == ctx.default_key) {
+            return Some((*key).to_owned());
+        }
+
+        candidates.sort_by_cached_key(|key| key.to_string());
+
+        candidates.first().map(|key| (*key).to_owned())
+    }
+}
+
+impl Debug for GeoIpResolver {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeoIpResolver").field("countries", &self.countries).finish()
+    }
+}
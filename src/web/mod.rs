@@ -0,0 +1,22 @@
+mod accept_language;
+#[cfg(feature = "actix-web")]
+mod actix_web_feature;
+mod async_resolver;
+#[cfg(feature = "axum")]
+mod axum_feature;
+mod cookie;
+mod geoip;
+mod negotiate;
+mod problem;
+mod resolver;
+
+pub use accept_language::*;
+#[cfg(feature = "actix-web")]
+pub use actix_web_feature::*;
+pub use async_resolver::*;
+#[cfg(feature = "axum")]
+pub use axum_feature::*;
+pub use cookie::*;
+pub use geoip::*;
+pub use problem::*;
+pub use resolver::*;
@@ -0,0 +1,149 @@
+#[cfg(feature = "likely_subtags")]
+use unic_langid::LanguageIdentifier;
+
+use crate::{AcceptLanguage, JSONGetText, Key};
+
+/// How well an available language matches a requested one. Smaller is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchLevel {
+    /// Both are the same after adding likely subtags, e.g. `zh-TW` and `zh-Hant`, or `en` and `en-US`. Without the `likely_subtags` feature, both are the same as they are.
+    Maximized,
+    /// Both have the same language and script, e.g. `en-GB` and `en-US`.
+    Script,
+    /// Both have the same language, e.g. `zh-TW` and `zh-CN`.
+    Language,
+}
+
+/// The subtags of a language tag which languages are matched by, with the language and the script in lowercase and the region in uppercase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Subtags {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+}
+
+/// Parse a language tag and add its likely subtags, e.g. `zh-TW` becomes `zh-Hant-TW`.
+#[cfg(feature = "likely_subtags")]
+#[inline]
+fn maximized(tag: &str) -> Option<Subtags> {
+    let mut langid: LanguageIdentifier = tag.replace('_', "-").parse().ok()?;
+
+    langid.maximize();
+
+    Some(Subtags {
+        language: langid.language.as_str().to_string(),
+        script: langid.script.map(|script| script.as_str().to_ascii_lowercase()),
+        region: langid.region.map(|region| region.as_str().to_string()),
+    })
+}
+
+/// Parse a language tag. Likely subtags are not added without the `likely_subtags` feature, so e.g. `zh-TW` only matches `zh-CN` by its language.
+#[cfg(not(feature = "likely_subtags"))]
+fn maximized(tag: &str) -> Option<Subtags> {
+    let mut subtags = tag.split(['_', '-']);
+
+    let language = subtags.next()?;
+
+    if !(2..=8).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut script = None;
+    let mut region = None;
+
+    for subtag in subtags {
+        if script.is_none()
+            && region.is_none()
+            && subtag.len() == 4
+            && subtag.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            script = Some(subtag.to_ascii_lowercase());
+        } else if region.is_none()
+            && ((subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit())))
+        {
+            region = Some(subtag.to_ascii_uppercase());
+        }
+    }
+
+    Some(Subtags {
+        language: language.to_ascii_lowercase(),
+        script,
+        region,
+    })
+}
+
+/// Get the maximized language identifier of a key. A key of a region stands for the most likely language of the region.
+#[inline]
+fn maximized_key(key: &Key) -> Option<Subtags> {
+    #[cfg(feature = "region")]
+    let tag = format!("und-{}", key);
+
+    #[cfg(not(feature = "region"))]
+    let tag = key.to_string();
+
+    maximized(&tag)
+}
+
+fn match_level(requested: &Subtags, available: &Subtags) -> Option<MatchLevel> {
+    if requested.language != available.language {
+        None
+    } else if requested == available {
+        Some(MatchLevel::Maximized)
+    } else if requested.script == available.script {
+        Some(MatchLevel::Script)
+    } else {
+        Some(MatchLevel::Language)
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Find the key in context which best matches the language ranges of an `Accept-Language` header, in order of preference. For each range, a key equal to it is preferred, then keys matching it by likely subtags (e.g. `zh-TW` matches `zh_Hant`, and `en-GB` matches `en_US`). Returns `None` if no range matches or a wildcard is preferred to the remaining ranges.
+    pub(crate) fn negotiate_key(&self, accept_language: &AcceptLanguage) -> Option<Key> {
+        let mut available: Vec<(&Key, Subtags)> = self
+            .context
+            .keys()
+            .filter_map(|key| maximized_key(key).map(|langid| (key, langid)))
+            .collect();
+
+        // ties are broken in the same way every time
+        available.sort_by_cached_key(|(key, _)| key.to_string());
+
+        for range in accept_language.iter().filter(|range| range.is_acceptable()) {
+            if range.is_wildcard() {
+                return None;
+            }
+
+            if let Some(key) = range.to_key() {
+                if self.context.contains_key(&key) {
+                    return Some(key);
+                }
+            }
+
+            let requested = match maximized(&range.tag) {
+                Some(requested) => requested,
+                None => continue,
+            };
+
+            let best = available
+                .iter()
+                .filter_map(|(key, langid)| {
+                    match_level(&requested, langid).map(|level| (level, *key))
+                })
+                .min_by_key(|(level, _)| *level);
+
+            if let Some((_, key)) = best {
+                return Some(key.to_owned());
+            }
+        }
+
+        None
+    }
+
+    /// Find the key in context which best matches an HTTP `Accept-Language` header, taking quality values into account. Keys equal to a language range are preferred, then keys matching it by likely subtags (e.g. `zh-TW` matches `zh_Hant`, and `en-GB` matches `en_US`). Likely subtags need the `likely_subtags` feature. If nothing matches, the default key is returned.
+    #[inline]
+    pub fn negotiate<S: AsRef<str>>(&self, accept_language: S) -> Key {
+        self.negotiate_key(&AcceptLanguage::parse(accept_language))
+            .unwrap_or_else(|| self.default_entry().0.to_owned())
+    }
+}
@@ -0,0 +1,108 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::{
+    JSONGetText, JSONGetTextValue, Key, LocaleRequest, LocaleResolverChain, ERROR_TEXT_PREFIX,
+};
+
+/// The media type of problem details.
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// A localized problem details object (RFC 7807), created by `JSONGetText::problem_details`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// The error code which the texts come from.
+    pub code: String,
+    /// The language of the texts, for the `Content-Language` header.
+    #[serde(skip)]
+    pub content_language: String,
+}
+
+impl ProblemDetails {
+    /// Set the URI reference which identifies the problem type. The default one is `about:blank`.
+    #[inline]
+    pub fn type_uri<S: Into<String>>(&mut self, type_uri: S) -> &mut Self {
+        self.type_uri = type_uri.into();
+
+        self
+    }
+
+    /// Set the URI reference which identifies the occurrence of the problem.
+    #[inline]
+    pub fn instance<S: Into<String>>(&mut self, instance: S) -> &mut Self {
+        self.instance = Some(instance.into());
+
+        self
+    }
+
+    /// Convert to a string for JSON format.
+    #[inline]
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl<'a> JSONGetText<'a> {
+    /// Create problem details for an error code with a specific key. The title is the message of `localize_error`, and the detail comes from the optional `errors.{code}.detail` text. `{name}` placeholders in both are replaced with `args`. If the key is not in context, the default key is used.
+    pub fn problem_details<Q: ?Sized + Hash + Eq, C: AsRef<str>>(
+        &self,
+        key: &Q,
+        code: C,
+        status: u16,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> ProblemDetails
+    where
+        Key: Borrow<Q>, {
+        let code = code.as_ref();
+
+        let (key, map) = self.find_entry_or_default(key);
+
+        let error = self.localize_error::<Key, _>(key, code, args);
+
+        let detail = if error.generic {
+            None
+        } else {
+            self.lookup_text(map, &format!("{}{}.detail", ERROR_TEXT_PREFIX, code)).map(|value| {
+                crate::format::interpolate_with(&value.to_string(), |name| {
+                    args.get(name).map(|value| value.to_string())
+                })
+            })
+        };
+
+        ProblemDetails {
+            type_uri: String::from("about:blank"),
+            title: error.message,
+            status,
+            detail,
+            instance: None,
+            code: error.code,
+            content_language: key.to_string().replace('_', "-"),
+        }
+    }
+
+    /// Create problem details for an error code in the locale which a resolver chain negotiates for the request.
+    #[inline]
+    pub fn problem_details_for_request<C: AsRef<str>>(
+        &self,
+        chain: &LocaleResolverChain,
+        request: &LocaleRequest,
+        code: C,
+        status: u16,
+        args: &HashMap<&str, JSONGetTextValue>,
+    ) -> ProblemDetails {
+        let key = chain.resolve(self, request);
+
+        self.problem_details(&key, code, status, args)
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::{self, Debug, Formatter};
+use std::net::IpAddr;
+
+#[cfg(not(feature = "langid"))]
+use crate::locale::is_same_tag;
+use crate::locale::key_from_tag;
+use crate::{AcceptLanguage, JSONGetText, Key, LocaleCookie};
+
+/// The parts of an HTTP request which can be used to resolve the locale. Web integrations fill in what they have.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocaleRequest<'r> {
+    /// The query string, without `?`.
+    pub query: Option<&'r str>,
+    /// A `application/x-www-form-urlencoded` body.
+    pub form: Option<&'r str>,
+    /// The value of the `Cookie` header.
+    pub cookie: Option<&'r str>,
+    /// The value of the `Accept-Language` header.
+    pub accept_language: Option<&'r str>,
+    /// The IP address of the client.
+    pub client_ip: Option<IpAddr>,
+    /// The identifier of the signed-in user, for resolvers which look up a stored preference.
+    pub user_id: Option<&'r str>,
+}
+
+/// Finds a key for a request. Resolvers should only return keys which are in the context.
+pub trait LocaleResolver: Send + Sync {
+    fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Option<Key>;
+}
+
+/// Get the key in context which a resolved key stands for. String keys which are the same language tag (e.g. a catalog added as `zh-TW` and the `zh_TW` which tags are converted into) are treated as the same key.
+pub(crate) fn available(ctx: &JSONGetText, key: Option<Key>) -> Option<Key> {
+    let key = key?;
+
+    if let Some((key, _)) = ctx.find_entry(&key) {
+        return Some(key.to_owned());
+    }
+
+    #[cfg(not(feature = "langid"))]
+    let found = ctx
+        .context
+        .keys()
+        .filter(|available| is_same_tag(&available.0, &key.0))
+        .min_by(|a, b| a.0.cmp(&b.0))
+        .cloned();
+
+    #[cfg(feature = "langid")]
+    let found = None;
+
+    found
+}
+
+/// Resolves the locale from a URL-encoded `name=value` pair.
+fn find_urlencoded(urlencoded: &str, name: &str) -> Option<Key> {
+    urlencoded.split('&').find_map(|pair| {
+        let (n, value) = pair.split_once('=')?;
+
+        if percent_decode(n) == name {
+            key_from_tag(&percent_decode(value))
+        } else {
+            None
+        }
+    })
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+
+    let mut output = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => output.push(b' '),
+            b'%' if i + 2 < bytes.len() && s.is_char_boundary(i + 3) => {
+                match u8::from_str_radix(&s[i + 1..i + 3], 16) {
+                    Ok(b) => {
+                        output.push(b);
+
+                        i += 2;
+                    }
+                    Err(_) => output.push(b'%'),
+                }
+            }
+            b => output.push(b),
+        }
+
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+/// Resolves the locale from a query parameter, e.g. `?lang=zh-TW`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryResolver {
+    name: String,
+}
+
+impl QueryResolver {
+    #[inline]
+    pub fn new<S: Into<String>>(name: S) -> QueryResolver {
+        QueryResolver {
+            name: name.into(),
+        }
+    }
+}
+
+impl Default for QueryResolver {
+    /// Use the `lang` parameter.
+    #[inline]
+    fn default() -> Self {
+        QueryResolver::new("lang")
+    }
+}
+
+impl LocaleResolver for QueryResolver {
+    #[inline]
+    fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Option<Key> {
+        available(ctx, find_urlencoded(request.query?, &self.name))
+    }
+}
+
+/// Resolves the locale from a field of a posted form, e.g. a language switcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormResolver {
+    name: String,
+}
+
+impl FormResolver {
+    #[inline]
+    pub fn new<S: Into<String>>(name: S) -> FormResolver {
+        FormResolver {
+            name: name.into(),
+        }
+    }
+}
+
+impl Default for FormResolver {
+    /// Use the `lang` field.
+    #[inline]
+    fn default() -> Self {
+        FormResolver::new("lang")
+    }
+}
+
+impl LocaleResolver for FormResolver {
+    #[inline]
+    fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Option<Key> {
+        available(ctx, find_urlencoded(request.form?, &self.name))
+    }
+}
+
+/// Resolves the locale from the cookie described by a `LocaleCookie` instance.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CookieResolver {
+    cookie: LocaleCookie,
+}
+
+impl CookieResolver {
+    #[inline]
+    pub fn new(cookie: LocaleCookie) -> CookieResolver {
+        CookieResolver {
+            cookie,
+        }
+    }
+}
+
+impl LocaleResolver for CookieResolver {
+    #[inline]
+    fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Option<Key> {
+        available(ctx, self.cookie.read(request.cookie?))
+    }
+}
+
+/// Resolves the locale from the `Accept-Language` header.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AcceptLanguageResolver;
+
+impl LocaleResolver for AcceptLanguageResolver {
+    #[inline]
+    fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Option<Key> {
+        ctx.negotiate_key(&AcceptLanguage::parse(request.accept_language?))
+    }
+}
+
+/// Runs resolvers in order and uses the first key found. If none is found, the default key is used.
+pub struct LocaleResolverChain {
+    resolvers: Vec<Box<dyn LocaleResolver>>,
+}
+
+impl LocaleResolverChain {
+    /// Create an empty chain.
+    #[inline]
+    pub fn new() -> LocaleResolverChain {
+        LocaleResolverChain {
+            resolvers: Vec::new(),
+        }
+    }
+
+    /// Append a resolver to the chain.
+    #[inline]
+    pub fn push<R: LocaleResolver + 'static>(&mut self, resolver: R) -> &mut Self {
+        self.resolvers.push(Box::new(resolver));
+
+        self
+    }
+
+    /// Resolve the key of a request.
+    pub fn resolve(&self, ctx: &JSONGetText, request: &LocaleRequest) -> Key {
+        self.resolvers
+            .iter()
+            .find_map(|resolver| available(ctx, resolver.resolve(ctx, request)))
+            .unwrap_or_else(|| ctx.default_entry().0.to_owned())
+    }
+}
+
+impl Default for LocaleResolverChain {
+    /// The query parameter `lang`, the form field `lang`, the default `LocaleCookie` and then the `Accept-Language` header.
+    #[inline]
+    fn default() -> Self {
+        let mut chain = LocaleResolverChain::new();
+
+        chain
+            .push(QueryResolver::default())
+            .push(FormResolver::default())
+            .push(CookieResolver::default())
+            .push(AcceptLanguageResolver);
+
+        chain
+    }
+}
+
+impl Debug for LocaleResolverChain {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocaleResolverChain").field("resolvers", &self.resolvers.len()).finish()
+    }
+}
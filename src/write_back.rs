@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::locale::key_from_tag;
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+/// A line of a write-back file.
+#[derive(Serialize, Deserialize)]
+struct WriteBackEntry {
+    key: String,
+    text: String,
+    value: String,
+}
+
+/// Read the entries of a write-back file, later ones replacing earlier ones. A missing file has no entries. Lines which cannot be parsed, e.g. partial lines from crashes while appending, are skipped, and logged with the `log` feature, since the file is only a cache.
+fn read_write_back_file(
+    path: &Path,
+) -> Result<HashMap<(Key, String), String>, JSONGetTextBuildError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut entries = HashMap::new();
+
+    for (i, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let entry: WriteBackEntry = match serde_json::from_str(line) {
+            Ok(entry) => entry,
+            Err(_error) => {
+                #[cfg(feature = "log")]
+                log::warn!(
+                    "skipped line {} of the write-back file {}: {}",
+                    i + 1,
+                    path.display(),
+                    _error
+                );
+
+                #[cfg(not(feature = "log"))]
+                let _ = i;
+
+                continue;
+            }
+        };
+
+        if let Some(key) = key_from_tag(&entry.key) {
+            entries.insert((key, entry.text), entry.value);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// An append-only JSON Lines file which persists the texts found in a secondary context.
+#[derive(Debug)]
+pub(crate) struct WriteBack {
+    file: Mutex<File>,
+    /// The latest value of every text in the file, so unchanged texts are not appended again.
+    written: Mutex<HashMap<(Key, String), String>>,
+}
+
+impl WriteBack {
+    fn open(path: &Path) -> Result<WriteBack, JSONGetTextBuildError> {
+        let written = read_write_back_file(path)?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        // end a partial last line, so that the next entry is not appended to it
+        if file.metadata()?.len() > 0 {
+            let mut last = [0];
+
+            let mut reader = File::open(path)?;
+
+            reader.seek(SeekFrom::End(-1))?;
+            reader.read_exact(&mut last)?;
+
+            if last[0] != b'\n' {
+                file.write_all(b"\n")?;
+            }
+        }
+
+        Ok(WriteBack {
+            file: Mutex::new(file),
+            written: Mutex::new(written),
+        })
+    }
+
+    /// Get the texts in the file.
+    #[inline]
+    pub(crate) fn entries(&self) -> HashMap<(Key, String), String> {
+        self.written.lock().unwrap().clone()
+    }
+
+    /// Append a text to the file if its value changed. Failing to write is ignored, since the file is only a cache.
+    pub(crate) fn record(&self, key: &Key, text: &str, value: &str) {
+        let mut written = self.written.lock().unwrap();
+
+        let id = (key.to_owned(), text.to_string());
+
+        if written.get(&id).is_some_and(|v| v == value) {
+            return;
+        }
+
+        let entry = WriteBackEntry {
+            key: key.to_string(),
+            text: text.to_string(),
+            value: value.to_string(),
+        };
+
+        let mut line = serde_json::to_string(&entry).unwrap();
+
+        line.push('\n');
+
+        if self.file.lock().unwrap().write_all(line.as_bytes()).is_ok() {
+            written.insert(id, value.to_string());
+        }
+    }
+}
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Persist the texts found in the secondary context (see `set_secondary_context`) to an append-only JSON Lines file, so they are served from the cache after a restart without consulting the secondary context until they expire. The file is created if it does not exist. Use `add_write_back_file` to compact it into the catalogs during the next build.
+    pub fn set_write_back_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let write_back = Arc::new(WriteBack::open(path.as_ref())?);
+
+        if let Some(read_through) = self.options.read_through.as_mut() {
+            read_through.attach_write_back(write_back.clone());
+        }
+
+        self.options.write_back = Some(write_back);
+
+        Ok(self)
+    }
+
+    /// Add the texts of a write-back file (see `set_write_back_file`) to the texts which have been added, replacing the ones which are already there, e.g. to release the fixes served by a secondary context with the catalogs. A key which has not been added is added with only these texts. A missing file adds nothing.
+    pub fn add_write_back_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        for ((key, text), value) in read_write_back_file(path.as_ref())? {
+            self.context.entry(key).or_default().insert(text, JSONGetTextValue::from_string(value));
+        }
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::io::{self, ErrorKind};
+#[cfg(feature = "fs")]
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::{JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key};
+
+impl<'a> JSONGetTextBuilder<'a> {
+    /// Add a YAML document to the context for a specify key. The document must be a mapping of texts, which is checked in the same way as a JSON object added by `add_json`, including the consistency with the default key when building.
+    pub fn add_yaml<K: Into<Key>, S: AsRef<str>>(
+        &mut self,
+        key: K,
+        yaml: S,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let value: Map<String, Value> = serde_yaml::from_str(yaml.as_ref())?;
+
+        let map: HashMap<String, JSONGetTextValue<'static>> =
+            value.into_iter().map(|(k, v)| (k, JSONGetTextValue::from_json_value(v))).collect();
+
+        self.add_map(key.into(), map)
+    }
+
+    /// Add a YAML file to the context for a specify key. See `add_yaml`.
+    #[cfg(feature = "fs")]
+    pub fn add_yaml_file<K: Into<Key>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Result<&mut Self, JSONGetTextBuildError> {
+        let key = key.into();
+
+        let yaml = self.options.ingest_limits.read_file(&key, path.as_ref())?;
+
+        let yaml =
+            String::from_utf8(yaml).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        self.add_yaml(key, yaml)
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(all(feature = "actix-web", not(feature = "langid")))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use actix_web::http::StatusCode;
+use actix_web::test::{self, TestRequest};
+use actix_web::{web, App};
+
+use json_gettext::{I18n, JSONGetTextMiddleware};
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+async fn hello(i18n: I18n) -> String {
+    format!("{}: {}", i18n.key(), i18n.t("hello").unwrap())
+}
+
+#[test]
+fn middleware() {
+    block_on(async {
+        let ctx = static_json_gettext_build!(
+            "en_US";
+            "en_US" => "langs/en_US.json",
+            "zh_TW" => "langs/zh_TW.json",
+        )
+        .unwrap();
+
+        let app = test::init_service(
+            App::new().wrap(JSONGetTextMiddleware::new(ctx)).route("/", web::get().to(hello)),
+        )
+        .await;
+
+        let body = test::call_and_read_body(&app, TestRequest::get().uri("/").to_request()).await;
+
+        assert_eq!("en_US: Hello, world!", body);
+
+        let request = TestRequest::get().uri("/").insert_header(("Accept-Language", "zh-TW"));
+
+        let body = test::call_and_read_body(&app, request.to_request()).await;
+
+        assert_eq!("zh_TW: 哈囉，世界！", body);
+
+        let request = TestRequest::get()
+            .uri("/?lang=en-US")
+            .insert_header(("Accept-Language", "zh-TW"))
+            .to_request();
+
+        let body = test::call_and_read_body(&app, request).await;
+
+        assert_eq!("en_US: Hello, world!", body);
+    });
+}
+
+#[test]
+fn without_middleware() {
+    block_on(async {
+        let app = test::init_service(App::new().route("/", web::get().to(hello))).await;
+
+        let response = test::call_service(&app, TestRequest::get().uri("/").to_request()).await;
+
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+    });
+}
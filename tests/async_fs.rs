@@ -0,0 +1,68 @@
+#![cfg(all(feature = "tokio", not(feature = "langid")))]
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+#[test]
+fn add_json_file_async() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+    runtime.block_on(async {
+        let mut builder = JSONGetText::build("en_US");
+
+        builder
+            .add_json_file_async("en_US", "langs/en_US.json")
+            .await
+            .unwrap()
+            .add_json_file_async("zh_TW", "langs/zh_TW.json")
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            builder.add_json_file_async("zh_TW", "langs/zh_TW.json").await,
+            Err(JSONGetTextBuildError::DuplicatedKey(_))
+        ));
+
+        assert!(matches!(
+            builder.add_json_file_async("fr", "langs/fr.json").await,
+            Err(JSONGetTextBuildError::IOError(_))
+        ));
+
+        let ctx = builder.build().unwrap();
+
+        assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+        assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+        assert!(ctx.last_modified("zh_TW").is_some());
+    });
+}
+
+#[test]
+fn add_json_file_ns_async() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+    runtime.block_on(async {
+        let mut builder = JSONGetText::build("en_US");
+
+        builder
+            .add_json_file_async("en_US", "langs/en_US.json")
+            .await
+            .unwrap()
+            .add_json_file_ns_async("en_US", "home", "langs/en_US.json")
+            .await
+            .unwrap()
+            .add_json_file_ns_async("zh_TW", "home", "langs/zh_TW.json")
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            builder.add_json_file_ns_async("zh_TW", "about", "langs/fr.json").await,
+            Err(JSONGetTextBuildError::IOError(_))
+        ));
+
+        let ctx = builder.build().unwrap();
+
+        assert_eq!("Hello, world!", ctx.get_text_ns("home", "hello").unwrap());
+        assert_eq!("哈囉，世界！", ctx.get_text_ns_with_key("zh_TW", "home", "hello").unwrap());
+        assert!(ctx.last_modified_ns("zh_TW", "home").is_some());
+        assert!(ctx.last_modified_ns("zh_TW", "about").is_none());
+    });
+}
@@ -0,0 +1,116 @@
+#![cfg(all(feature = "axum", not(feature = "langid")))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::Request;
+
+use json_gettext::{JSONGetTextState, Key, LocaleResolverChain, QueryResolver, Text};
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn extract<S: Send + Sync>(state: &S, request: Request<()>) -> Text
+where
+    JSONGetTextState: FromRef<S>, {
+    let (mut parts, _) = request.into_parts();
+
+    block_on(Text::from_request_parts(&mut parts, state)).unwrap()
+}
+
+fn state() -> JSONGetTextState {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    JSONGetTextState::new(ctx)
+}
+
+#[test]
+fn text_extractor() {
+    let state = state();
+
+    let text = extract(&state, Request::builder().body(()).unwrap());
+
+    assert_eq!(&Key::from("en_US"), text.key());
+    assert_eq!("Hello, world!", get_text!(text, "hello").unwrap());
+
+    let text = extract(
+        &state,
+        Request::builder().header("Accept-Language", "zh-TW, en;q=0.5").body(()).unwrap(),
+    );
+
+    assert_eq!(&Key::from("zh_TW"), text.key());
+    assert_eq!("哈囉，世界！", get_text!(text, "hello").unwrap());
+
+    let text = extract(
+        &state,
+        Request::builder()
+            .header("Cookie", "lang=en-US")
+            .header("Accept-Language", "zh-TW")
+            .body(())
+            .unwrap(),
+    );
+
+    assert_eq!(&Key::from("en_US"), text.key());
+
+    let text = extract(&state, Request::builder().uri("/?lang=zh-TW").body(()).unwrap());
+
+    assert_eq!(&Key::from("zh_TW"), text.key());
+}
+
+#[test]
+fn text_extractor_custom_state() {
+    #[derive(Clone)]
+    struct AppState {
+        text: JSONGetTextState,
+    }
+
+    impl FromRef<AppState> for JSONGetTextState {
+        fn from_ref(state: &AppState) -> Self {
+            state.text.clone()
+        }
+    }
+
+    let mut resolvers = LocaleResolverChain::new();
+
+    resolvers.push(QueryResolver::new("locale"));
+
+    let state = AppState {
+        text: state().with_resolvers(resolvers),
+    };
+
+    let text = extract(&state, Request::builder().uri("/?lang=zh-TW").body(()).unwrap());
+
+    assert_eq!(&Key::from("en_US"), text.key());
+
+    let text = extract(&state, Request::builder().uri("/?locale=zh-TW").body(()).unwrap());
+
+    assert_eq!(&Key::from("zh_TW"), text.key());
+}
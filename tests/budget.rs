@@ -0,0 +1,58 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{sms_length, JSONGetText, SmsEncoding, TextBudget};
+
+#[test]
+fn sms_length_of_text() {
+    let length = sms_length(&"a".repeat(160));
+
+    assert_eq!(SmsEncoding::Gsm7, length.encoding);
+    assert_eq!(1, length.segments);
+
+    assert_eq!(2, sms_length(&"a".repeat(161)).segments);
+    assert_eq!(2, sms_length("€").units);
+
+    let length = sms_length(&"字".repeat(71));
+
+    assert_eq!(SmsEncoding::Ucs2, length.encoding);
+    assert_eq!(71, length.units);
+    assert_eq!(2, length.segments);
+
+    assert_eq!(0, sms_length("").segments);
+}
+
+#[test]
+fn get_text_within_budget() {
+    let long = "a".repeat(100);
+    let long_zh = "字".repeat(100);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json_owned(
+            "en_US",
+            format!(r#"{{"body": "{}", "body@short": "Short", "title": "Title"}}"#, long),
+        )
+        .unwrap();
+
+    builder
+        .add_json_owned("zh_TW", format!(r#"{{"body": "{}", "body@short": "短"}}"#, long_zh))
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let budget = TextBudget::SmsSegments(1);
+
+    assert_eq!(long.as_str(), ctx.get_text_within_budget("en_US", "body", budget).unwrap());
+    assert_eq!("短", ctx.get_text_within_budget("zh_TW", "body", budget).unwrap());
+
+    assert_eq!(
+        "Short",
+        ctx.get_text_within_budget("en_US", "body", TextBudget::Bytes(10)).unwrap()
+    );
+    assert_eq!(
+        "Title",
+        ctx.get_text_within_budget("en_US", "title", TextBudget::Bytes(1)).unwrap()
+    );
+    assert!(ctx.get_text_within_budget("en_US", "missing", budget).is_none());
+}
@@ -0,0 +1,156 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{ConsistencyCheck, ConsistencyIssue, Glossary, GlossaryIssue, JSONGetText, Key};
+#[cfg(feature = "regex")]
+use json_gettext::{IdLint, IdLintIssue};
+
+#[test]
+fn consistency_check() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "hello": "Hello, world!",
+                "rust": "Rust!",
+                "save": "Save",
+                "space": "A  space"
+            }"#,
+        )
+        .unwrap();
+    builder.add_json("zh_TW", r#"{"hello": "哈囉，世界", "rust": "Rust!"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let report = ConsistencyCheck::new().check(&ctx);
+
+    let en = &report[&Key::from("en_US")];
+
+    assert_eq!(1, en.len());
+    assert_eq!(ConsistencyIssue::DoubleSpace, en[0].issue);
+
+    let zh = &report[&Key::from("zh_TW")];
+
+    assert_eq!(2, zh.len());
+    assert_eq!("hello", zh[0].text);
+    assert_eq!(
+        ConsistencyIssue::TerminalPunctuationMismatch {
+            expected: Some('!'),
+            found: None,
+        },
+        zh[0].issue
+    );
+    assert_eq!("rust", zh[1].text);
+    assert_eq!(ConsistencyIssue::Untranslated, zh[1].issue);
+
+    let report = ConsistencyCheck::new().add_word("rust").check(&ctx);
+
+    assert_eq!(1, report[&Key::from("zh_TW")].len());
+}
+
+#[test]
+fn glossary() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "open": "Open the file",
+                "save": "Save the file",
+                "close": "Close the file"
+            }"#,
+        )
+        .unwrap();
+    builder
+        .add_json(
+            "zh_TW",
+            r#"{
+                "open": "開啟檔案",
+                "save": "儲存文件",
+                "close": "關閉文檔"
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut glossary = Glossary::new();
+
+    glossary.add_term("zh_TW", "file", "檔案").add_unapproved_variant("zh_TW", "file", "文檔");
+
+    let report = glossary.check(&ctx);
+
+    let zh = &report[&Key::from("zh_TW")];
+
+    assert_eq!(2, zh.len());
+    assert_eq!("close", zh[0].text);
+    assert!(matches!(zh[0].issue, GlossaryIssue::UnapprovedVariant { .. }));
+    assert_eq!("save", zh[1].text);
+    assert!(matches!(zh[1].issue, GlossaryIssue::MissingApprovedTerm { .. }));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn id_lint() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "checkout.pay": "Pay",
+                "checkout.cart.items.count": "Items",
+                "Checkout.Title": "Checkout",
+                "menu.file open": "Open",
+                "@checkout.pay": {"description": "A button"}
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert!(IdLint::new().check(&ctx).is_empty());
+
+    let mut lint = IdLint::new();
+
+    lint.set_naming_convention(r"[a-z_]+(\.[a-z_]+)*")
+        .unwrap()
+        .set_max_depth(3)
+        .add_forbidden_characters([' ', '/'])
+        .add_namespace("checkout");
+
+    let findings = lint.check(&ctx);
+
+    assert_eq!(
+        vec![
+            ("Checkout.Title", IdLintIssue::NamingConvention {
+                pattern: r"[a-z_]+(\.[a-z_]+)*".to_string()
+            }),
+            ("Checkout.Title", IdLintIssue::MissingNamespace {
+                namespaces: vec!["checkout".to_string()]
+            }),
+            ("checkout.cart.items.count", IdLintIssue::TooDeep {
+                depth: 4,
+                max_depth: 3
+            }),
+            ("menu.file open", IdLintIssue::NamingConvention {
+                pattern: r"[a-z_]+(\.[a-z_]+)*".to_string()
+            }),
+            ("menu.file open", IdLintIssue::ForbiddenCharacter {
+                character: ' '
+            }),
+            ("menu.file open", IdLintIssue::MissingNamespace {
+                namespaces: vec!["checkout".to_string()]
+            }),
+        ],
+        findings
+            .iter()
+            .map(|finding| (finding.text.as_str(), finding.issue.clone()))
+            .collect::<Vec<_>>()
+    );
+
+    assert!(lint.lint_id("checkout.pay").is_empty());
+    assert!(lint.set_naming_convention("(").is_err());
+}
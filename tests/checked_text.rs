@@ -0,0 +1,37 @@
+#![cfg(all(feature = "checked_text", not(feature = "langid")))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::t;
+
+#[test]
+fn checked_text() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert_eq!("hello", t!(catalog = "langs/en_US.json", "hello"));
+    assert_eq!("Rust!", get_text!(ctx, t!(catalog = "langs/en_US.json", "rust")).unwrap());
+    assert_eq!(
+        "哈囉，世界！",
+        get_text!(ctx, "zh_TW", t!(catalog = "langs/en_US.json", "hello")).unwrap()
+    );
+}
+
+#[test]
+fn checked_text_in_const() {
+    const RUST: &str = t!(catalog = "langs/en_US.json", "rust",);
+
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+    )
+    .unwrap();
+
+    assert_eq!("Rust!", ctx.get_text(RUST).unwrap());
+    assert_eq!(["hello", "rust"], [t!(catalog = "langs/en_US.json", "hello"), RUST]);
+}
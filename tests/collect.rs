@@ -0,0 +1,94 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{
+    IngestLimits, JSONGetText, JSONGetTextBuildError, JSONGetTextBuilder, JSONGetTextValue, Key,
+};
+
+fn map(texts: &[(&str, &'static str)]) -> HashMap<String, JSONGetTextValue<'static>> {
+    texts.iter().map(|&(text, value)| (String::from(text), JSONGetTextValue::from(value))).collect()
+}
+
+#[test]
+fn from_iter() {
+    let builder: JSONGetTextBuilder = vec![
+        (Key::from("en_US"), map(&[("hello", "Hello!"), ("bye", "Bye!")])),
+        (Key::from("zh_TW"), map(&[("hello", "哈囉！")])),
+    ]
+    .into_iter()
+    .collect();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("en_US", ctx.get_default_key());
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Bye!", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+
+    let builder: JSONGetTextBuilder = Vec::new().into_iter().collect();
+
+    assert!(matches!(builder.build(), Err(JSONGetTextBuildError::DefaultKeyNotFound)));
+}
+
+#[test]
+fn extend() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+    builder.extend([(Key::from("zh_TW"), map(&[("hello", "哈囉！")]))]);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+    builder.extend([(Key::from("en_US"), map(&[("hello", "Hi!")]))]);
+
+    assert!(
+        matches!(builder.build(), Err(JSONGetTextBuildError::DuplicatedKey(key)) if key == String::from("en_US"))
+    );
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.set_ingest_limits(IngestLimits {
+        max_texts: 1,
+        ..IngestLimits::default()
+    });
+    builder.extend([(Key::from("en_US"), map(&[("hello", "Hello!"), ("bye", "Bye!")]))]);
+
+    assert!(matches!(builder.build(), Err(JSONGetTextBuildError::IngestLimitExceeded { .. })));
+}
+
+#[test]
+fn try_from() {
+    let mut context = HashMap::new();
+
+    context.insert(String::from("zh_TW"), map(&[("hello", "哈囉！")]));
+    context.insert(String::from("en_US"), map(&[("hello", "Hello!"), ("bye", "Bye!")]));
+
+    let ctx = JSONGetText::try_from(context).unwrap();
+
+    assert_eq!("en_US", ctx.get_default_key());
+    assert_eq!("Bye!", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+
+    let mut context = HashMap::new();
+
+    context.insert(Key::from("zh_TW"), map(&[("hello", "哈囉！")]));
+    context.insert(Key::from("en_US"), map(&[("hello", "Hello!")]));
+
+    let ctx = JSONGetText::try_from(context).unwrap();
+
+    assert_eq!("en_US", ctx.get_default_key());
+
+    let mut context = HashMap::new();
+
+    context.insert(String::from("zh_TW"), map(&[("hello", "哈囉！")]));
+    context.insert(String::from("en_US"), map(&[("bye", "Bye!")]));
+
+    assert!(matches!(
+        JSONGetText::try_from(context),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { .. })
+    ));
+}
@@ -0,0 +1,45 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+fn build(zh_tw: &str, owned: bool) -> JSONGetText<'_> {
+    let mut builder = JSONGetText::build("en_US");
+
+    let en_us = r#"{"hello": "Hello!", "rust": "Rust!", "count": 3}"#;
+
+    if owned {
+        builder.add_json_owned("zh_TW", zh_tw).unwrap().add_json_owned("en_US", en_us).unwrap();
+    } else {
+        builder.add_json("en_US", en_us).unwrap().add_json("zh_TW", zh_tw).unwrap();
+    }
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn partial_eq() {
+    let zh_tw = r#"{"hello": "哈囉！"}"#;
+
+    assert_eq!(build(zh_tw, false), build(zh_tw, true));
+    assert_ne!(build(zh_tw, false), build(r#"{"hello": "你好！"}"#, false));
+    assert_ne!(build(zh_tw, false), build(r#"{"hello": "哈囉！", "rust": "Rust!"}"#, false));
+
+    let mut ctx = build(zh_tw, false);
+
+    ctx.insert_text("zh_TW", "count", JSONGetTextValue::from_u8(3)).unwrap();
+
+    assert_ne!(build(zh_tw, false), ctx);
+}
+
+#[test]
+fn debug() {
+    let zh_tw = r#"{"hello": "哈囉！"}"#;
+
+    let debug = format!("{:?}", build(zh_tw, false));
+
+    assert_eq!(debug, format!("{:?}", build(zh_tw, true)));
+
+    assert!(debug.starts_with(
+        r#"JSONGetText { default_key: "en_US", context: {Key("en_US"): {"count": 3, "hello": "Hello!", "rust": "Rust!"}, Key("zh_TW"): {"count": 3, "hello": "哈囉！", "rust": "Rust!"}}, filled: {Key("zh_TW"): {"count", "rust"}}"#
+    ));
+}
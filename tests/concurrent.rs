@@ -0,0 +1,53 @@
+#![cfg(not(feature = "langid"))]
+
+use std::thread;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError, SharedJSONGetTextBuilder};
+
+#[test]
+fn add_from_threads() {
+    let builder = SharedJSONGetTextBuilder::new(JSONGetText::build("en"));
+
+    let keys = ["en", "de", "fr", "es", "it", "nl", "pt", "sv"];
+
+    thread::scope(|scope| {
+        for key in keys {
+            let builder = &builder;
+
+            scope.spawn(move || {
+                builder.add_json_owned(key, format!(r#"{{"hello": "hello in {}"}}"#, key)).unwrap();
+            });
+        }
+    });
+
+    assert!(matches!(
+        builder.add_json_owned("de", r#"{"hello": "Hallo!"}"#),
+        Err(JSONGetTextBuildError::DuplicatedKey(_))
+    ));
+
+    let ctx = builder.build().unwrap();
+
+    for key in keys {
+        assert_eq!(
+            format!("hello in {}", key).as_str(),
+            ctx.get_text_with_key(key, "hello").unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn add_json_file() {
+    let builder = SharedJSONGetTextBuilder::from(JSONGetText::build("en_US"));
+
+    thread::scope(|scope| {
+        scope.spawn(|| builder.add_json_file("en_US", "langs/en_US.json").unwrap());
+        scope.spawn(|| builder.add_json_file("zh_TW", "langs/zh_TW.json").unwrap());
+    });
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert!(ctx.last_modified("zh_TW").is_some());
+}
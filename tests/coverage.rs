@@ -0,0 +1,87 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+#[test]
+fn coverage() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "rust": "Rust!", "ok": "OK", "bye": "Bye!", "@hello": {}}"#,
+        )
+        .unwrap()
+        .add_json("de", r#"{"hello": "Hallo!", "rust": "Rust!", "ok": "OK"}"#)
+        .unwrap()
+        .add_json("fr", r#"{"hello": "Bonjour !", "@hello": {}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let coverage = ctx.coverage();
+
+    assert_eq!(vec!["de", "en_US", "fr"], coverage.keys().collect::<Vec<_>>());
+
+    let de = &coverage["de"];
+
+    assert_eq!(3, de.translated_count);
+    assert_eq!(1, de.missing_count);
+    assert_eq!(vec!["hello", "ok", "rust"], de.translated);
+    assert_eq!(vec!["bye"], de.missing);
+    assert_eq!(75.0, de.percentage);
+
+    let fr = &coverage["fr"];
+
+    assert_eq!(vec!["hello"], fr.translated);
+    assert_eq!(vec!["bye", "ok", "rust"], fr.missing);
+    assert_eq!(25.0, fr.percentage);
+
+    assert_eq!(100.0, coverage["en_US"].percentage);
+    assert!(coverage["en_US"].missing.is_empty());
+}
+
+#[test]
+fn coverage_of_empty_catalogs() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"@hello": {}}"#).unwrap().add_json("de", "{}").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let coverage = ctx.coverage();
+
+    // there is nothing to translate, so every key is complete
+    assert_eq!(100.0, coverage["de"].percentage);
+    assert_eq!(0, coverage["de"].translated_count);
+    assert_eq!(0, coverage["de"].missing_count);
+}
+
+#[test]
+fn coverage_follows_mutations() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("es", r#"{"hello": "¡Hola!"}"#)
+        .unwrap()
+        .add_json("es_MX", "{}")
+        .unwrap()
+        .set_fallbacks("es_MX", ["es"]);
+
+    let mut ctx = builder.build().unwrap();
+
+    // texts filled from a fallback key are not translated by the key
+    assert_eq!(vec!["hello", "rust"], ctx.coverage()["es_MX"].missing);
+    assert_eq!(50.0, ctx.coverage()["es"].percentage);
+
+    ctx.insert_text("es", "rust", JSONGetTextValue::from_str("¡Rust!")).unwrap();
+
+    assert_eq!(100.0, ctx.coverage()["es"].percentage);
+
+    ctx.remove_text("es", "hello").unwrap();
+
+    assert_eq!(vec!["hello"], ctx.coverage()["es"].missing);
+    assert_eq!(0.0, ctx.coverage()["es_MX"].percentage);
+}
@@ -0,0 +1,145 @@
+#![cfg(not(feature = "langid"))]
+
+use std::sync::{Arc, Mutex};
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError, LookupObserver};
+
+type Usages = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+struct Recorder(Usages);
+
+impl LookupObserver for Recorder {
+    fn deprecated_text_used(&self, text: &str, replacement: Option<&str>) {
+        self.0.lock().unwrap().push((text.to_string(), replacement.map(String::from)));
+    }
+}
+
+#[test]
+fn deprecated_texts() {
+    let used: Usages = Arc::new(Mutex::new(Vec::new()));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "hi": "Hi!",
+                "@hi": {"deprecated": "hello"},
+                "hello": "Hello, world!"
+            }"#,
+        )
+        .unwrap();
+    builder.add_json("zh_TW", r#"{"hello": "哈囉，世界！"}"#).unwrap();
+    builder.set_observer(Recorder(used.clone()));
+
+    let ctx = builder.build().unwrap();
+
+    assert!(ctx.is_deprecated("hi"));
+    assert!(!ctx.is_deprecated("hello"));
+
+    assert_eq!("Hi!", ctx.get_text("hi").unwrap());
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+
+    assert_eq!(vec![(String::from("hi"), Some(String::from("hello")))], *used.lock().unwrap());
+
+    let deprecated = ctx.get_deprecated_texts();
+
+    assert_eq!(1, deprecated.len());
+    assert_eq!("hi", deprecated[0].text);
+    assert_eq!(Some("hello"), deprecated[0].replacement.as_deref());
+
+    let exported = ctx.export_chunks("zh_TW", 1024).unwrap();
+
+    assert_eq!(deprecated, exported.manifest.deprecated);
+}
+
+#[test]
+fn metadata_in_other_keys() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "@bye": {"deprecated": true}}"#)
+        .unwrap()
+        .add_json(
+            "zh_TW",
+            r#"{"hello": "哈囉！", "@hello": {"description": "A greeting"}, "@bye": {}}"#,
+        )
+        .unwrap();
+
+    assert!(builder.build().is_ok());
+
+    // metadata of a text which is not in the default key is rejected like the text
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！", "@stray": {"description": "Not in en_US"}}"#)
+        .unwrap();
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { text, .. }) if text == "@stray"
+    ));
+}
+
+#[test]
+fn deprecated_without_replacement() {
+    let used: Usages = Arc::new(Mutex::new(Vec::new()));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "old": "Old",
+                "@old": {"deprecated": true},
+                "kept": "Kept",
+                "@kept": {"deprecated": false, "description": "Still used"},
+                "plain": "Plain",
+                "@plain": "not an object"
+            }"#,
+        )
+        .unwrap();
+    builder.set_observer(Recorder(used.clone()));
+
+    let ctx = builder.build().unwrap();
+
+    assert!(ctx.is_deprecated("old"));
+    assert!(!ctx.is_deprecated("kept"));
+    assert!(!ctx.is_deprecated("plain"));
+    assert!(!ctx.is_deprecated("missing"));
+
+    assert_eq!("Old", ctx.get_text("old").unwrap());
+    assert_eq!("Kept", ctx.get_text("kept").unwrap());
+
+    assert_eq!(vec![(String::from("old"), None)], *used.lock().unwrap());
+
+    let deprecated = ctx.get_deprecated_texts();
+
+    assert_eq!(1, deprecated.len());
+    assert_eq!(None, deprecated[0].replacement);
+}
+
+#[test]
+fn deprecated_in_other_keys() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"b": "B", "a": "A", "@a": {"deprecated": "b"}}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"b": "乙", "@b": {"deprecated": "a"}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    // only the metadata of the default key counts
+    assert!(ctx.is_deprecated("a"));
+    assert!(!ctx.is_deprecated("b"));
+    assert_eq!(
+        vec!["a"],
+        ctx.get_deprecated_texts().iter().map(|d| d.text.as_str()).collect::<Vec<_>>()
+    );
+}
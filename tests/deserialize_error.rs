@@ -0,0 +1,85 @@
+#![cfg(all(feature = "regex", not(feature = "langid")))]
+
+use json_gettext::{DeserializeErrorInfo, DeserializeErrorKind, JSONGetText};
+use serde::Deserialize;
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SignUp {
+    email: String,
+    age: u8,
+}
+
+#[test]
+fn parse() {
+    let error = serde_json::from_str::<SignUp>(r#"{"email": "a@b.c"}"#).unwrap_err();
+
+    let info = DeserializeErrorInfo::parse(error.to_string());
+
+    assert_eq!(DeserializeErrorKind::MissingField, info.kind);
+    assert_eq!(Some("age"), info.field.as_deref());
+
+    let error = serde_json::from_str::<SignUp>(r#"{"email": "a@b.c", "age": "x"}"#).unwrap_err();
+
+    let info = DeserializeErrorInfo::parse(error.to_string());
+
+    assert_eq!(DeserializeErrorKind::InvalidType, info.kind);
+    assert_eq!(Some("string \"x\""), info.found.as_deref());
+    assert_eq!(Some("u8"), info.expected.as_deref());
+
+    let error =
+        serde_json::from_str::<SignUp>(r#"{"email": "a@b.c", "age": 1, "x": 1}"#).unwrap_err();
+
+    let info = DeserializeErrorInfo::parse(error.to_string());
+
+    assert_eq!(DeserializeErrorKind::UnknownField, info.kind);
+    assert_eq!(Some("x"), info.field.as_deref());
+
+    let error = serde_json::from_str::<SignUp>("{").unwrap_err();
+
+    assert_eq!(DeserializeErrorKind::Other, DeserializeErrorInfo::parse(error.to_string()).kind);
+}
+
+#[test]
+fn localize_deserialize_error() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "fields.age": "Age",
+                "errors.generic": "Something went wrong.",
+                "errors.deserialize.missing_field": "{field} is required."
+            }"#,
+        )
+        .unwrap();
+
+    builder
+        .add_json(
+            "zh_TW",
+            r#"{
+                "fields.age": "年齡",
+                "errors.deserialize.missing_field": "請填寫{field}。"
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let error = serde_json::from_str::<SignUp>(r#"{"email": "a@b.c"}"#).unwrap_err();
+
+    let localized = ctx.localize_deserialize_error("zh_TW", &error);
+
+    assert_eq!(Some("age"), localized.field.as_deref());
+    assert_eq!("deserialize.missing_field", localized.error.code);
+    assert_eq!("請填寫年齡。", localized.error.message);
+
+    let error = serde_json::from_str::<SignUp>("{").unwrap_err();
+
+    let localized = ctx.localize_deserialize_error("zh_TW", &error);
+
+    assert_eq!(None, localized.field);
+    assert_eq!("Something went wrong.", localized.error.message);
+}
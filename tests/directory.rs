@@ -0,0 +1,126 @@
+#![cfg(all(feature = "fs", not(feature = "langid")))]
+
+use std::fs;
+
+use json_gettext::{
+    DirectoryScheme, FileStemScheme, JSONGetText, JSONGetTextBuildError, SuffixScheme,
+};
+
+#[test]
+fn add_directory() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_directory("langs").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "zh_TW"], keys);
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
+
+#[test]
+fn add_directory_names() {
+    let dir = std::env::temp_dir().join("json-gettext-add-directory");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(dir.join("fr.json")).unwrap();
+    fs::write(dir.join("en-us.json"), r#"{"hello": "Hello!"}"#).unwrap();
+    fs::write(dir.join("zh-Hant-TW.json"), r#"{"hello": "哈囉！"}"#).unwrap();
+    fs::write(dir.join("README.md"), "").unwrap();
+    fs::write(dir.join("not a tag.json"), "").unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_directory(&dir).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "zh_Hant_TW"], keys);
+
+    fs::write(dir.join("en_US.json"), "{}").unwrap();
+    fs::write(dir.join("ja.json"), "{").unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    match builder.add_directory(&dir) {
+        Err(JSONGetTextBuildError::FilesFailed(errors)) => {
+            assert_eq!(2, errors.len());
+
+            assert_eq!(dir.join("en_US.json"), errors[0].0);
+            assert!(matches!(errors[0].1, JSONGetTextBuildError::DuplicatedKey(_)));
+            assert_eq!(dir.join("ja.json"), errors[1].0);
+            assert!(matches!(errors[1].1, JSONGetTextBuildError::SerdeJSONError(_)));
+        }
+        _ => panic!("expected the errors of the files"),
+    }
+
+    // the other files are loaded
+    let ctx = builder.build().unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "zh_Hant_TW"], keys);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn add_directory_with_scheme() {
+    let dir = std::env::temp_dir().join("json-gettext-add-directory-with-scheme");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(dir.join("en-US")).unwrap();
+    fs::create_dir_all(dir.join("zh-TW")).unwrap();
+    fs::write(dir.join("en-US").join("translation.json"), r#"{"hello": "Hello!"}"#).unwrap();
+    fs::write(dir.join("en-US").join("common.json"), r#"{"ok": "OK"}"#).unwrap();
+    fs::write(dir.join("zh-TW").join("translation.json"), r#"{"hello": "哈囉！"}"#).unwrap();
+    fs::write(dir.join("messages.en.json"), r#"{"hello": "Hello!"}"#).unwrap();
+    fs::write(dir.join("messages.zh-TW.json"), r#"{"hello": "哈囉！"}"#).unwrap();
+    fs::write(dir.join("errors.en.json"), "{}").unwrap();
+    fs::write(dir.join("en_US.i18n"), r#"{"hello": "Hello!"}"#).unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_directory_with_scheme(&dir, &DirectoryScheme::new("translation.json")).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "zh_TW"], keys);
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+
+    let mut builder = JSONGetText::build("en");
+
+    builder.add_directory_with_scheme(&dir, &SuffixScheme::new("messages", "json")).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en", "zh_TW"], keys);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_directory_with_scheme(&dir, &FileStemScheme::new("i18n")).unwrap();
+
+    assert_eq!(vec!["en_US"], builder.build().unwrap().get_keys());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,135 @@
+#![cfg(all(feature = "fs", not(feature = "langid")))]
+
+use std::fs;
+
+use json_gettext::{ImportProblem, JSONGetText, TextChange};
+use serde_json::json;
+
+#[test]
+fn dry_run_add_json_file() {
+    let dir = std::env::temp_dir().join("json-gettext-dry-run");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("zh_TW.json");
+
+    fs::write(
+        &path,
+        r#"{
+            "hello": "哈囉，{user}！",
+            "rust": "Rust！",
+            "cart": "購物車",
+            "@cart": "A cart",
+            "unknown": "?"
+        }"#,
+    )
+    .unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello, {name}!", "rust": "Rust!", "cart": "Cart", "bye": "Bye!"}"#,
+        )
+        .unwrap();
+
+    let preview = builder.dry_run_add_json_file("zh_TW", &path).unwrap();
+
+    assert_eq!(vec!["@cart", "cart", "hello", "rust", "unknown"], preview.new_texts);
+    assert!(preview.changed_texts.is_empty());
+    assert!(preview.removed_texts.is_empty());
+    assert_eq!(
+        vec![
+            ImportProblem::PlaceholderMismatch {
+                text: "hello".into(),
+                missing: vec!["name".into()],
+                unknown: vec!["user".into()],
+            },
+            ImportProblem::TextNotInDefaultKey {
+                text: "unknown".into()
+            },
+        ],
+        preview.problems
+    );
+    assert!(!preview.is_ok());
+
+    // the builder is not changed
+    assert_eq!(vec!["en_US"], builder.clone().build().unwrap().get_keys());
+
+    builder.add_json("zh_TW", r#"{"rust": "Rust!", "bye": "再見！"}"#).unwrap();
+
+    fs::write(&path, r#"{"hello": "哈囉，{name}！", "rust": "Rust！"}"#).unwrap();
+
+    let preview = builder.dry_run_add_json_file("zh_TW", &path).unwrap();
+
+    assert_eq!(vec!["hello"], preview.new_texts);
+    assert_eq!(
+        vec![TextChange {
+            text: "rust".into(),
+            old: json!("Rust!"),
+            new: json!("Rust！"),
+        }],
+        preview.changed_texts
+    );
+    assert_eq!(vec!["bye"], preview.removed_texts);
+    assert_eq!(vec![ImportProblem::DuplicatedKey], preview.problems);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn dry_run_default_key() {
+    let dir = std::env::temp_dir().join("json-gettext-dry-run-default");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("en_US.json");
+
+    fs::write(&path, r#"{"hello": "Hello, {name}!", "new": "New!", "count": 1}"#).unwrap();
+
+    let builder = JSONGetText::build("en_US");
+
+    // there is nothing to check the texts of the default key against
+    let preview = builder.dry_run_add_json_file("en_US", &path).unwrap();
+
+    assert_eq!(vec!["count", "hello", "new"], preview.new_texts);
+    assert!(preview.is_ok());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!", "count": 2}"#).unwrap();
+
+    let preview = builder.dry_run_add_json_file("en_US", &path).unwrap();
+
+    assert_eq!(vec!["new"], preview.new_texts);
+    assert_eq!(
+        vec![
+            TextChange {
+                text: "count".into(),
+                old: json!(2),
+                new: json!(1),
+            },
+            TextChange {
+                text: "hello".into(),
+                old: json!("Hello!"),
+                new: json!("Hello, {name}!"),
+            },
+        ],
+        preview.changed_texts
+    );
+    assert_eq!(vec![ImportProblem::DuplicatedKey], preview.problems);
+
+    // errors of reading and parsing are returned
+    assert!(builder.dry_run_add_json_file("zh_TW", dir.join("zh_TW.json")).is_err());
+
+    fs::write(&path, "[]").unwrap();
+
+    assert!(builder.dry_run_add_json_file("zh_TW", &path).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
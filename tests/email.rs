@@ -0,0 +1,97 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+fn ctx() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "emails.welcome.subject": "Welcome, {name}!",
+                "emails.welcome.body": "Hi **{name}**, you have {count} new messages. Use {{code}} literally."
+            }"#,
+        )
+        .unwrap();
+
+    builder
+        .add_json(
+            "zh_TW",
+            r#"{
+                "emails.welcome.subject": "歡迎，{name}！"
+            }"#,
+        )
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn render_email() {
+    let ctx = ctx();
+
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("<Ron>"));
+    args.insert("count", JSONGetTextValue::from_u32(3));
+
+    let email = ctx.render_email("zh_TW", "emails.welcome", &args).unwrap();
+
+    assert_eq!("歡迎，<Ron>！", email.subject);
+    assert_eq!("Hi **<Ron>**, you have 3 new messages. Use {code} literally.", email.body);
+
+    #[cfg(feature = "markdown")]
+    assert_eq!(
+        Some(
+            "<p>Hi <strong>&lt;Ron&gt;</strong>, you have 3 new messages. Use {code} \
+              literally.</p>\n"
+        ),
+        email.html.as_deref()
+    );
+
+    #[cfg(not(feature = "markdown"))]
+    assert_eq!(None, email.html);
+
+    let email = ctx.render_email("fr", "emails.welcome", &HashMap::new()).unwrap();
+
+    assert_eq!("Welcome, {name}!", email.subject);
+
+    assert!(ctx.render_email("en_US", "emails.goodbye", &args).is_none());
+}
+
+#[test]
+fn render_email_without_body() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "emails.reset.subject": "Reset your password",
+                "emails.reset.body": "Use <{link}> within {hours} hours.",
+                "emails.notice.subject": "Notice"
+            }"#,
+        )
+        .unwrap()
+        .add_json("de", r#"{"emails.reset.body": "Nutze <{link}> innerhalb von {hours} Stunden."}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    // an email needs both a subject and a body
+    assert!(ctx.render_email("en_US", "emails.notice", &HashMap::new()).is_none());
+
+    let mut args = HashMap::new();
+    args.insert("link", JSONGetTextValue::from_str("https://example.com/?a=1&b=2"));
+
+    let email = ctx.render_email("de", "emails.reset", &args).unwrap();
+
+    // the subject is filled from the default key, and a missing argument is kept as a placeholder
+    assert_eq!("Reset your password", email.subject);
+    assert_eq!("Nutze <https://example.com/?a=1&b=2> innerhalb von {hours} Stunden.", email.body);
+
+    #[cfg(feature = "markdown")]
+    assert!(email.html.unwrap().contains("https://example.com/?a=1&amp;b=2"));
+}
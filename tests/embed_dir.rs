@@ -0,0 +1,49 @@
+#![cfg(all(feature = "embed_dir", not(feature = "langid")))]
+
+#[macro_use]
+extern crate json_gettext;
+
+#[test]
+fn build_dir() {
+    let ctx = static_json_gettext_build_dir!("en_US"; "langs").unwrap();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "zh_TW"], keys);
+    assert_eq!("Hello, world!", get_text!(ctx, "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text!(ctx, "zh_TW", "hello").unwrap());
+}
+
+json_gettext_namespace!(APP, "app", "langs");
+json_gettext_namespace!(OTHER, "app", "langs");
+
+#[test]
+fn crate_namespace() {
+    use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+    assert_eq!("app", APP.namespace);
+    assert_eq!("json-gettext", APP.crate_name);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json_file("en_US", "langs/en_US.json").unwrap();
+    builder.add_crate_namespace(&APP).unwrap();
+
+    match builder.add_crate_namespace(&OTHER) {
+        Err(JSONGetTextBuildError::NamespaceCollision {
+            namespace,
+            crates,
+        }) => {
+            assert_eq!("app", namespace);
+            assert_eq!(["json-gettext", "json-gettext"], crates);
+        }
+        _ => panic!("expected a namespace collision"),
+    }
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello, world!", get_text_ns!(ctx, "app", "hello").unwrap());
+    assert_eq!("哈囉，世界！", get_text_ns!(ctx, "zh_TW", "app", "hello").unwrap());
+}
@@ -0,0 +1,69 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{EmptyKind, EmptyText, EmptyTextPolicy, JSONGetText};
+
+fn build(null: EmptyTextPolicy, empty_string: EmptyTextPolicy) -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!", "bye": "", "@hello": null}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": null, "rust": "", "bye": ""}"#)
+        .unwrap()
+        .set_empty_text_policy(EmptyKind::Null, null)
+        .set_empty_text_policy(EmptyKind::EmptyString, empty_string);
+
+    builder.build().unwrap()
+}
+
+fn empty_text(key: &str, text: &str, kind: EmptyKind, filled: bool) -> EmptyText {
+    EmptyText {
+        key: key.to_string(),
+        text: text.to_string(),
+        kind,
+        filled,
+    }
+}
+
+#[test]
+fn literal() {
+    let ctx = build(EmptyTextPolicy::Literal, EmptyTextPolicy::Literal);
+
+    assert!(ctx.get_text_with_key("zh_TW", "hello").unwrap().as_str().is_none());
+    assert_eq!("", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+    assert_eq!(
+        vec![
+            empty_text("en_US", "bye", EmptyKind::EmptyString, false),
+            empty_text("zh_TW", "bye", EmptyKind::EmptyString, false),
+            empty_text("zh_TW", "hello", EmptyKind::Null, false),
+            empty_text("zh_TW", "rust", EmptyKind::EmptyString, false),
+        ],
+        ctx.get_empty_texts()
+    );
+}
+
+#[test]
+fn missing() {
+    let ctx = build(EmptyTextPolicy::Missing, EmptyTextPolicy::Missing);
+
+    assert_eq!("Hello!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("", ctx.get_text("bye").unwrap());
+    assert_eq!(3, ctx.coverage()["zh_TW"].missing_count);
+
+    let ctx = build(EmptyTextPolicy::Missing, EmptyTextPolicy::Literal);
+
+    assert_eq!("Hello!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+    assert_eq!(
+        vec![
+            empty_text("en_US", "bye", EmptyKind::EmptyString, false),
+            empty_text("zh_TW", "bye", EmptyKind::EmptyString, false),
+            empty_text("zh_TW", "hello", EmptyKind::Null, true),
+            empty_text("zh_TW", "rust", EmptyKind::EmptyString, false),
+        ],
+        ctx.get_empty_texts()
+    );
+}
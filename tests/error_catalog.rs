@@ -0,0 +1,107 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{
+    JSONGetText, JSONGetTextValue, LocaleRequest, LocaleResolverChain, FALLBACK_ERROR_MESSAGE,
+};
+
+#[test]
+fn localize_error() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "errors.generic": "Something went wrong.",
+                "errors.not_found": "{resource} was not found."
+            }"#,
+        )
+        .unwrap();
+
+    builder.add_json("zh_TW", r#"{"errors.not_found": "找不到{resource}。"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("resource", JSONGetTextValue::from_str("User 7"));
+
+    let error = ctx.localize_error("zh_TW", "not_found", &args);
+
+    assert_eq!("not_found", error.code);
+    assert_eq!("找不到User 7。", error.message);
+    assert!(!error.generic);
+
+    let error = ctx.localize_error("zh_TW", "conflict", &args);
+
+    assert_eq!("conflict", error.code);
+    assert_eq!("Something went wrong.", error.message);
+    assert!(error.generic);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(FALLBACK_ERROR_MESSAGE, ctx.localize_error("en_US", "conflict", &args).message);
+}
+
+#[test]
+fn problem_details() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "errors.generic": "Something went wrong.",
+                "errors.out_of_credit": "You do not have enough credit.",
+                "errors.out_of_credit.detail": "Your balance is {balance}."
+            }"#,
+        )
+        .unwrap();
+
+    builder
+        .add_json(
+            "zh_TW",
+            r#"{
+                "errors.out_of_credit": "您的點數不足。",
+                "errors.out_of_credit.detail": "您的餘額為{balance}。"
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("balance", JSONGetTextValue::from_u32(30));
+
+    let request = LocaleRequest {
+        accept_language: Some("zh-TW, en;q=0.5"),
+        ..LocaleRequest::default()
+    };
+
+    let mut problem = ctx.problem_details_for_request(
+        &LocaleResolverChain::default(),
+        &request,
+        "out_of_credit",
+        403,
+        &args,
+    );
+
+    problem.instance("/account/12345");
+
+    assert_eq!("zh-TW", problem.content_language);
+    assert_eq!(
+        r#"{"type":"about:blank","title":"您的點數不足。","status":403,"detail":"您的餘額為30。","instance":"/account/12345","code":"out_of_credit"}"#,
+        problem.to_json_string()
+    );
+
+    let problem = ctx.problem_details("fr", "conflict", 409, &args);
+
+    assert_eq!("en-US", problem.content_language);
+    assert_eq!("Something went wrong.", problem.title);
+    assert_eq!(None, problem.detail);
+}
@@ -0,0 +1,49 @@
+#![cfg(not(feature = "langid"))]
+
+#[cfg(feature = "fs")]
+use std::fs;
+
+#[cfg(feature = "fs")]
+use json_gettext::scaffold_locales;
+use json_gettext::JSONGetText;
+
+#[test]
+fn example() {
+    let ctx = JSONGetText::example();
+
+    let mut keys = ctx.get_keys();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "fr_FR", "zh_TW"], keys);
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+    assert_eq!("哈囉，{name}！", ctx.get_text_with_key("zh_TW", "greeting").unwrap());
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn scaffold() {
+    let dir = std::env::temp_dir().join("json-gettext-scaffold").join("locales");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    let created = scaffold_locales(&dir, &["en-US", "zh-tw", "de"]).unwrap();
+
+    assert_eq!(vec![dir.join("en_US.json"), dir.join("zh_TW.json"), dir.join("de.json")], created);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_directory(&dir).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Hello, world!", ctx.get_text_with_key("de", "hello").unwrap());
+
+    fs::write(dir.join("de.json"), r#"{"hello": "Hallo, Welt!"}"#).unwrap();
+
+    assert_eq!(vec![dir.join("fr_FR.json")], scaffold_locales(&dir, &["de", "fr-FR"]).unwrap());
+    assert_eq!(r#"{"hello": "Hallo, Welt!"}"#, fs::read_to_string(dir.join("de.json")).unwrap());
+
+    assert!(scaffold_locales(&dir, &["not a tag"]).is_err());
+}
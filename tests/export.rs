@@ -0,0 +1,206 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+#[cfg(feature = "content_hash")]
+use json_gettext::{source_hash, JSONGetText, JSONGetTextValue};
+#[cfg(feature = "content_hash")]
+use serde_json::json;
+
+#[test]
+fn export_chunks() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let exported = ctx.export_chunks("en_US", 24).unwrap();
+
+    assert_eq!(2, exported.chunks.len());
+    assert_eq!("{\"hello\":\"Hello, world!\"}", exported.chunks[0].json);
+    assert_eq!("{\"rust\":\"Rust!\"}", exported.chunks[1].json);
+    assert_eq!(Some("en_US.1.json"), exported.manifest.chunk_for("rust"));
+
+    let exported = ctx.export_chunks("en_US", 1024).unwrap();
+
+    assert_eq!(1, exported.chunks.len());
+    assert_eq!(exported.chunks[0].json.len(), exported.manifest.chunks[0].size);
+
+    assert!(ctx.export_chunks("de", 1024).is_none());
+}
+
+#[test]
+fn export_minified() {
+    let mut builder = json_gettext::JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "rust": "Rust!",
+                "@hello": {"description": "Greeting"},
+                "hello": "Hello, world!",
+                "numbers": {"two": 2, "one": 1}
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(
+        r#"{"hello":"Hello, world!","numbers":{"one":1,"two":2},"rust":"Rust!"}"#,
+        ctx.export_minified("en_US").unwrap()
+    );
+}
+
+#[cfg(feature = "content_hash")]
+#[test]
+fn content_hash() {
+    let build = |en_us: &'static str, zh_tw: &'static str| {
+        let mut builder = json_gettext::JSONGetText::build("en_US");
+
+        builder.add_json("en_US", en_us).unwrap().add_json("zh_TW", zh_tw).unwrap();
+
+        builder.build().unwrap()
+    };
+
+    let ctx = build(
+        r#"{"hello": "Hello, world!", "numbers": {"one": 1, "two": 2}}"#,
+        r#"{"hello": "哈囉，世界！"}"#,
+    );
+
+    let reordered = build(
+        r#"{
+            "numbers": {"two": 2, "one": 1},
+            "@hello": {"description": "Greeting"},
+            "hello": "Hello, world!"
+        }"#,
+        r#"{"hello":"哈囉，世界！"}"#,
+    );
+
+    let changed = build(
+        r#"{"hello": "Hello, world!", "numbers": {"one": 1, "two": 2}}"#,
+        r#"{"hello": "哈囉！"}"#,
+    );
+
+    assert_eq!(ctx.content_hash(), reordered.content_hash());
+    assert_ne!(ctx.content_hash(), changed.content_hash());
+
+    assert_eq!(ctx.key_content_hash("en_US"), changed.key_content_hash("en_US"));
+    assert_ne!(ctx.key_content_hash("zh_TW"), changed.key_content_hash("zh_TW"));
+    assert_ne!(ctx.key_content_hash("en_US"), ctx.key_content_hash("zh_TW"));
+    assert!(ctx.key_content_hash("de").is_none());
+
+    let hex = ctx.content_hash().to_string();
+
+    assert_eq!(64, hex.len());
+    assert!(hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+
+    let exported = ctx.export_chunks("zh_TW", 1024).unwrap();
+
+    assert_eq!(ctx.key_content_hash("zh_TW").unwrap(), exported.manifest.content_hash);
+    assert!(exported
+        .manifest
+        .to_json_string()
+        .contains(&format!("\"content_hash\":\"{}\"", exported.manifest.content_hash)));
+}
+
+#[cfg(feature = "content_hash")]
+#[test]
+fn stale_translations() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "rust": "Rust!", "bye": "Bye!", "@bye": "A farewell"}"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！", "rust": "Rust！", "@rust": {"gender": "f"}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let exported = ctx.export_for_translation("zh_TW").unwrap();
+
+    let hello_hash = source_hash(&JSONGetTextValue::from_str("Hello!"));
+
+    assert_eq!(16, hello_hash.len());
+    assert_eq!(
+        json!({
+            "hello": "哈囉！",
+            "@hello": {"source_hash": hello_hash},
+            "rust": "Rust！",
+            "@rust": {"gender": "f", "source_hash": source_hash(&JSONGetTextValue::from_str("Rust!"))},
+        }),
+        serde_json::from_str::<serde_json::Value>(&exported).unwrap()
+    );
+
+    assert!(ctx.stale_texts("zh_TW").is_empty());
+
+    // the source of `hello` is changed after exporting
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello, world!", "rust": "Rust!", "bye": "Bye!"}"#)
+        .unwrap()
+        .add_json_owned("zh_TW", exported)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(vec!["hello"], ctx.stale_texts("zh_TW"));
+    assert!(ctx.is_stale_text("zh_TW", "hello"));
+    assert!(!ctx.is_stale_text("zh_TW", "rust"));
+    assert!(!ctx.is_stale_text("en_US", "hello"));
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
+
+#[cfg(feature = "content_hash")]
+#[test]
+fn export_search_documents() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "menu": {"open": "Open", "size": 3}, "@hello": {}}"#,
+        )
+        .unwrap()
+        .add_json_ns("en_US", "help", r#"{"reset": "How to reset your password"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let documents = ctx.export_search_documents("zh_TW").unwrap();
+
+    let ids: Vec<&str> = documents.iter().map(|document| document.id.as_str()).collect();
+
+    assert_eq!(vec!["hello", "help.reset", "menu.open"], ids);
+    assert_eq!("哈囉！", documents[0].text);
+    assert_eq!(None, documents[0].namespace);
+    assert_eq!(Some("help"), documents[1].namespace.as_deref());
+    assert_eq!("How to reset your password", documents[1].text);
+    assert_eq!(16, documents[0].document_id.len());
+    assert!(documents[0].document_id.bytes().all(|b| b.is_ascii_hexdigit()));
+
+    let index = ctx.export_search_index("zh_TW").unwrap();
+
+    assert_eq!(3, index.lines().count());
+    assert_eq!(
+        json!({
+            "document_id": documents[1].document_id,
+            "id": "help.reset",
+            "text": "How to reset your password",
+            "namespace": "help",
+        }),
+        serde_json::from_str::<serde_json::Value>(index.lines().nth(1).unwrap()).unwrap()
+    );
+
+    assert!(ctx.export_search_documents("fr").is_none());
+}
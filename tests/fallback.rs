@@ -0,0 +1,68 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError, Key};
+
+#[test]
+fn fallbacks() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "color": "Color", "computer": "Computer", "rust": "Rust!"}"#,
+        )
+        .unwrap()
+        .add_json("zh", r#"{"hello": "你好！", "color": "颜色", "computer": "计算机"}"#)
+        .unwrap()
+        .add_json("zh_Hant", r#"{"computer": "電腦"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .set_fallbacks("zh_TW", ["zh_Hant", "zh"]);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("電腦", ctx.get_text_with_key("zh_TW", "computer").unwrap());
+    assert_eq!("颜色", ctx.get_text_with_key("zh_TW", "color").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+    assert_eq!("電腦", ctx.get_text_with_key("zh_Hant", "computer").unwrap());
+    assert_eq!("Color", ctx.get_text_with_key("zh_Hant", "color").unwrap());
+}
+
+#[test]
+fn fallbacks_end_at_default_key() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("zh", r#"{"hello": "你好！"}"#)
+        .unwrap()
+        .add_json("zh_TW", "{}")
+        .unwrap()
+        .set_fallbacks("zh_TW", ["en_US", "zh"]);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
+
+#[test]
+fn fallback_key_not_found() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("zh_TW", "{}")
+        .unwrap()
+        .set_fallbacks("zh_TW", ["zh"]);
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::FallbackKeyNotFound { key, fallback })
+            if key == Key::from("zh_TW") && fallback == Key::from("zh")
+    ));
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "fluent", not(feature = "langid")))]
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+const EN_FTL: &str = r#"
+-brand = Rust
+hello = Hello, { $name }!
+welcome = Welcome to { -brand }.
+login-input = Predefined value
+    .placeholder = email@example.com
+    .aria-label = Login input value
+apples =
+    { $count ->
+        [one] { $count } apple
+       *[other] { $count } apples
+    }
+cart = You have { NUMBER($count) ->
+        [one] one item
+       *[other] { $count } items
+    } in your cart.
+braces = Use { "{" }name{ "}" } for placeholders.
+date = Today is { DATETIME($date) }.
+"#;
+
+#[test]
+fn add_fluent() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_fluent("en_US", EN_FTL)
+        .unwrap()
+        .add_fluent(
+            "zh_TW",
+            "hello = 哈囉，{ $name }！\nlogin-input =\n    .placeholder = 電子郵件\n",
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello, {name}!", ctx.get_text("hello").unwrap());
+    assert_eq!("Welcome to Rust.", ctx.get_text("welcome").unwrap());
+    assert_eq!("Predefined value", ctx.get_text("login-input").unwrap());
+    assert_eq!("email@example.com", ctx.get_text("login-input.placeholder").unwrap());
+    assert_eq!("Login input value", ctx.get_text("login-input.aria-label").unwrap());
+    assert_eq!("{count} apple", ctx.get_plural_text("en_US", "apples", 1).unwrap());
+    assert_eq!("{count} apples", ctx.get_plural_text("en_US", "apples", 2).unwrap());
+    assert_eq!("You have one item in your cart.", ctx.get_plural_text("en_US", "cart", 1).unwrap());
+    assert_eq!(
+        "You have {count} items in your cart.",
+        ctx.get_plural_text("en_US", "cart", 3).unwrap()
+    );
+    assert_eq!("Use {{name}} for placeholders.", ctx.get_text("braces").unwrap());
+    assert!(ctx.get_text("date").is_none());
+    assert!(ctx.get_text("-brand").is_none());
+
+    assert_eq!("哈囉，{name}！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("電子郵件", ctx.get_text_with_key("zh_TW", "login-input.placeholder").unwrap());
+    assert_eq!("Predefined value", ctx.get_text_with_key("zh_TW", "login-input").unwrap());
+}
+
+#[test]
+fn invalid_fluent() {
+    let mut builder = JSONGetText::build("en_US");
+
+    assert!(matches!(
+        builder.add_fluent("en_US", "hello = Hello!\n\nbye = { $name\n"),
+        Err(JSONGetTextBuildError::InvalidFluent {
+            line: 3,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,330 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use std::collections::HashMap;
+
+use json_gettext::{interpolate, JSONGetText, JSONGetTextValue, NumberSymbols};
+
+#[test]
+fn interpolate_placeholders() {
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Ron"));
+    args.insert("count", JSONGetTextValue::from_i32(-2));
+
+    assert_eq!("Hello, Ron! (-2)", interpolate("Hello, {name}! ({count})", &args));
+    assert_eq!("{name} is {missing}", interpolate("{{name}} is {missing}", &args));
+    assert_eq!("{ not a placeholder }", interpolate("{ not a placeholder }", &args));
+    assert_eq!("unclosed {name", interpolate("unclosed {name", &args));
+}
+
+#[test]
+fn interpolate_padding() {
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Ron"));
+    args.insert("city", JSONGetTextValue::from_str("東京"));
+    args.insert("accented", JSONGetTextValue::from_str("e\u{301}te\u{301}"));
+    args.insert("count", JSONGetTextValue::from_i32(42));
+
+    assert_eq!("|Ron       |", interpolate("|{name:10}|", &args));
+    assert_eq!("|Ron       |", interpolate("|{name:<10}|", &args));
+    assert_eq!("|       Ron|", interpolate("|{name:>10}|", &args));
+    assert_eq!("|   Ron    |", interpolate("|{name:^10}|", &args));
+    assert_eq!("|*******Ron|", interpolate("|{name:*>10}|", &args));
+    assert_eq!("|000042|", interpolate("|{count:0>6}|", &args));
+    assert_eq!("|Ron|", interpolate("|{name:>2}|", &args));
+
+    // wide characters take two columns and combining marks none
+    #[cfg(feature = "ansi")]
+    assert_eq!("|      東京|", interpolate("|{city:>10}|", &args));
+    #[cfg(feature = "ansi")]
+    assert_eq!("|été  |", interpolate("|{accented:5}|", &args).replace("e\u{301}", "é"));
+
+    // invalid specs are not placeholders, and unknown placeholders are kept
+    assert_eq!("{name:>}", interpolate("{name:>}", &args));
+    assert_eq!("{name:>1000}", interpolate("{name:>1000}", &args));
+    assert_eq!("{name:>x}", interpolate("{name:>x}", &args));
+    assert_eq!("{missing:>10}", interpolate("{missing:>10}", &args));
+
+    assert_eq!(vec!["name", "count"], json_gettext::placeholders_of("{name:>10} {count:3} {name}"));
+}
+
+#[test]
+fn format_text() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello, {name}!", "cart": "{count} items for {name}", "answer": 42}"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉，{name}！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Ron"));
+
+    assert_eq!("Hello, Ron!", ctx.format_text("hello", &args).unwrap());
+    assert_eq!("哈囉，Ron！", ctx.format_text_with_key("zh_TW", "hello", &args).unwrap());
+    assert_eq!("42", ctx.format_text("answer", &args).unwrap());
+    assert!(ctx.format_text("missing", &args).is_none());
+
+    assert_eq!("Hello, Alice!", get_text_fmt!(ctx, "hello", name = "Alice").unwrap());
+    assert_eq!("哈囉，Alice！", get_text_fmt!(ctx, "zh_TW", "hello", name = "Alice").unwrap());
+    assert_eq!(
+        "3 items for Alice",
+        get_text_fmt!(ctx, "zh_TW", "cart", count = 3, name = String::from("Alice"),).unwrap()
+    );
+    assert_eq!("Hello, {name}!", get_text_fmt!(ctx, "hello").unwrap());
+    assert_eq!("哈囉，{name}！", get_text_fmt!(ctx, "zh_TW", "hello").unwrap());
+}
+
+#[test]
+fn format_numbers() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"files": "{count} files ({count:raw}, {count:>10})", "small": "{count}"}"#,
+        )
+        .unwrap()
+        .add_json("de_DE", r#"{"files": "{count} Dateien ({count:raw})"}"#)
+        .unwrap()
+        .add_json("hi", r#"{"files": "{count} फ़ाइलें"}"#)
+        .unwrap()
+        .add_json("es", r#"{"small": "{count} {count > 1000 ? \"muchos\" : \"pocos\"}"}"#)
+        .unwrap()
+        .add_json("fr", r#"{}"#)
+        .unwrap()
+        .set_number_symbols("fr", NumberSymbols::new(',', ' '));
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(
+        "1,000,000 files (1000000,  1,000,000)",
+        get_text_fmt!(ctx, "files", count = 1000000).unwrap()
+    );
+    assert_eq!(
+        "1.000.000 Dateien (1000000)",
+        get_text_fmt!(ctx, "de_DE", "files", count = 1000000).unwrap()
+    );
+    assert_eq!(
+        "-1.234,5 Dateien (-1234.5)",
+        get_text_fmt!(ctx, "de_DE", "files", count = -1234.5).unwrap()
+    );
+    assert_eq!("12,34,567 फ़ाइलें", get_text_fmt!(ctx, "hi", "files", count = 1234567).unwrap());
+    // conditionals compare the raw value
+    assert_eq!("1000 pocos", get_text_fmt!(ctx, "es", "small", count = 1000).unwrap());
+    assert_eq!("10.000 muchos", get_text_fmt!(ctx, "es", "small", count = 10000).unwrap());
+    // overridden symbols, and numbers in strings are not formatted
+    assert_eq!(
+        "1 000 000 files (1000000,  1 000 000)",
+        get_text_fmt!(ctx, "fr", "files", count = 1000000).unwrap()
+    );
+    assert_eq!(
+        "1000000 files (1000000,    1000000)",
+        get_text_fmt!(ctx, "files", count = "1000000").unwrap()
+    );
+
+    // `interpolate` writes numbers as they are
+    let mut args = HashMap::new();
+    args.insert("count", JSONGetTextValue::from_u64(1000000));
+
+    assert_eq!("1000000", interpolate("{count}", &args));
+
+    assert_eq!(NumberSymbols::new(',', ' '), ctx.number_symbols("fr"));
+    assert_eq!(NumberSymbols::new('.', ','), ctx.number_symbols("fr_CA"));
+    assert_eq!(NumberSymbols::new(',', '\u{202f}'), NumberSymbols::of(&"fr_CA".into()));
+    assert_eq!(NumberSymbols::new('.', '’'), NumberSymbols::of(&"de_CH".into()));
+    assert_eq!("1’234.5", NumberSymbols::of(&"de_CH".into()).format_number("1234.5"));
+}
+
+#[test]
+fn placeholder_defaults() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"welcome": "Welcome, {name|guest}!", "guest": "guest", "roles": {"viewer": "viewer"}, "role": "Role: {role|roles.viewer}", "raw": "-", "row": "|{name:>6|guest}|{count:raw|raw}|"}"#,
+        )
+        .unwrap()
+        .add_json("de_DE", r#"{"welcome": "Willkommen, {name|guest}!", "guest": "Gast"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Welcome, guest!", get_text_fmt!(ctx, "welcome").unwrap());
+    assert_eq!("Welcome, Ron!", get_text_fmt!(ctx, "welcome", name = "Ron").unwrap());
+    assert_eq!("Willkommen, Gast!", get_text_fmt!(ctx, "de_DE", "welcome").unwrap());
+    assert_eq!("Role: viewer", get_text_fmt!(ctx, "de_DE", "role").unwrap());
+
+    // defaults can follow a spec, and a text id can be named like a spec
+    assert_eq!("| guest|-|", get_text_fmt!(ctx, "row").unwrap());
+    assert_eq!("|   Ron|1000|", get_text_fmt!(ctx, "row", name = "Ron", count = 1000).unwrap());
+
+    // a text id after `:` is not a default
+    assert_eq!("{name:guest}", interpolate("{name:guest}", &HashMap::new()));
+
+    // `interpolate` has no catalog to resolve defaults
+    assert_eq!("Welcome, {name|guest}!", interpolate("Welcome, {name|guest}!", &HashMap::new()));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"welcome": "Welcome, {name|guest}!"}"#).unwrap();
+
+    assert!(matches!(
+        builder.build(),
+        Err(json_gettext::JSONGetTextBuildError::PlaceholderDefaultNotFound { ref default, .. }) if default == "guest"
+    ));
+}
+
+#[test]
+fn interpolate_conditionals() {
+    let mut args = HashMap::new();
+    args.insert("isAdmin", JSONGetTextValue::from_bool(true));
+    args.insert("isGuest", JSONGetTextValue::from_bool(false));
+    args.insert("count", JSONGetTextValue::from_i32(3));
+    args.insert("role", JSONGetTextValue::from_str("editor"));
+    args.insert("name", JSONGetTextValue::from_str(""));
+
+    assert_eq!("Manage", interpolate(r#"{isAdmin ? "Manage" : "View"}"#, &args));
+    assert_eq!("View", interpolate(r#"{isGuest ? "Manage" : "View"}"#, &args));
+    assert_eq!("Anonymous", interpolate(r#"{name ? "{name}" : "Anonymous"}"#, &args));
+    assert_eq!(
+        "Delete 3 files?",
+        interpolate(r#"Delete {count > 1 ? "{count} files" : "the file"}?"#, &args)
+    );
+    assert_eq!("yes", interpolate(r#"{count>=3?"yes":"no"}"#, &args));
+    assert_eq!("no", interpolate(r#"{count == 1 ? "yes" : "no"}"#, &args));
+    assert_eq!("no", interpolate(r#"{isAdmin != true ? "yes" : "no"}"#, &args));
+    assert_eq!("Edit", interpolate(r#"{role == "editor" ? "Edit" : "Read"}"#, &args));
+    assert_eq!(r#"say "hi""#, interpolate(r#"{isAdmin ? "say \"hi\"" : ""}"#, &args));
+
+    // conditionals without an argument are kept as they are
+    assert_eq!(r#"{missing ? "a" : "b"}"#, interpolate(r#"{missing ? "a" : "b"}"#, &args));
+}
+
+#[test]
+fn conditionals_checked_when_building() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"files": "{count > 1 ? \"{count} files\" : \"a file\"}", "braces": "{{a ? b}}"}"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("2 files", get_text_fmt!(ctx, "files", count = 2).unwrap());
+    assert_eq!("a file", get_text_fmt!(ctx, "files", count = 1).unwrap());
+
+    for template in [
+        r#"{count > 1 ? \"files\"}"#,
+        r#"{count > many ? \"files\" : \"a file\"}"#,
+        r#"{role < \"b\" ? \"files\" : \"a file\"}"#,
+        r#"{count ? \"files : \"a file\"}"#,
+    ] {
+        let mut builder = JSONGetText::build("en_US");
+
+        builder.add_json_owned("en_US", format!(r#"{{"files": "{}"}}"#, template)).unwrap();
+
+        assert!(matches!(
+            builder.build(),
+            Err(json_gettext::JSONGetTextBuildError::InvalidConditional { .. })
+        ));
+    }
+
+    // braces which are not shaped like conditionals are literals
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"loop": "for {i<n}", "question": "{a?b}", "empty": "{x ? }"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("for {i<n}", get_text_fmt!(ctx, "loop", i = 1, n = 2).unwrap());
+    assert_eq!("{a?b}", get_text_fmt!(ctx, "question", a = true).unwrap());
+    assert_eq!("{x ? }", get_text_fmt!(ctx, "empty", x = true).unwrap());
+}
+
+#[cfg(feature = "bumpalo")]
+#[test]
+fn interpolate_in_arena() {
+    use bumpalo::Bump;
+    use json_gettext::interpolate_in;
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello, {name}!", "answer": 42}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Ron"));
+
+    let mut bump = Bump::new();
+
+    {
+        let hello = ctx.get_text_fmt_in(&bump, "en_US", "hello", &args).unwrap();
+        let answer = ctx.get_text_fmt_in(&bump, "en_US", "answer", &args).unwrap();
+
+        assert_eq!("Hello, Ron!", hello);
+        assert_eq!("42", answer);
+        assert_eq!("{{name}}", interpolate_in(&bump, "{{{{name}}}}", &args));
+        assert!(ctx.get_text_fmt_in(&bump, "en_US", "missing", &args).is_none());
+    }
+
+    bump.reset();
+}
+
+#[test]
+fn placeholders() {
+    use json_gettext::placeholders_of;
+
+    assert_eq!(
+        vec!["name", "count"],
+        placeholders_of("{name} has {count} messages, {name}. {{literal}} { x }")
+    );
+    assert!(placeholders_of("no placeholders").is_empty());
+    assert_eq!(
+        vec!["count", "name", "total"],
+        placeholders_of(r#"{count > 1 ? "{count} items for {name}" : "{total}"}"#)
+    );
+
+    let long = "Lorem ipsum dolor sit amet. ".repeat(1000) + "{name}";
+
+    assert_eq!(vec!["name"], placeholders_of(&long));
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn ansi() {
+    use json_gettext::{ansi_width, strip_ansi, truncate_ansi};
+
+    let red = "\x1b[31mError\x1b[0m: 檔案不存在";
+
+    assert_eq!("Error: 檔案不存在", strip_ansi(red));
+    assert_eq!(17, ansi_width(red));
+    assert_eq!("a link", strip_ansi("a \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"));
+
+    assert_eq!(red, truncate_ansi(red, 17, "…"));
+    assert_eq!("\x1b[31mErr…\x1b[0m", truncate_ansi(red, 4, "…"));
+    assert_eq!("\x1b[31mError\x1b[0m: 檔…", truncate_ansi(red, 10, "…"));
+    // a wide character which does not fit is dropped
+    assert_eq!("\x1b[31mError\x1b[0m: 檔…", truncate_ansi(red, 11, "…"));
+    assert_eq!("\x1b[31m\x1b[0m", truncate_ansi(red, 0, "…"));
+
+    let mut args = HashMap::new();
+    args.insert("status", JSONGetTextValue::from_str("\x1b[32mOK\x1b[0m"));
+
+    assert_eq!("[    \x1b[32mOK\x1b[0m]", interpolate("[{status:>6}]", &args));
+}
@@ -0,0 +1,54 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use std::thread;
+
+use json_gettext::JSONGetText;
+
+#[test]
+fn freeze() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let frozen = ctx.freeze();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            thread::spawn(move || frozen.get_text_with_key("zh_TW", "hello").unwrap().to_string())
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!("哈囉，世界！", handle.join().unwrap());
+    }
+
+    assert_eq!("Hello, world!", frozen.get_text("hello").unwrap());
+}
+
+#[test]
+fn freeze_borrowed() {
+    let en_us = String::from(
+        r#"{"hello": "Hello!", "apples": {"one": "an apple", "other": "{count} apples"}}"#,
+    );
+    let zh_tw = String::from(r#"{"hello": "哈囉！"}"#);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", &en_us).unwrap().add_json("zh_TW", &zh_tw).unwrap();
+
+    let frozen = builder.build().unwrap().freeze();
+
+    drop(en_us);
+    drop(zh_tw);
+
+    assert_eq!("哈囉！", frozen.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Hello!", frozen.get_text_with_key("de", "hello").unwrap());
+    assert_eq!("an apple", frozen.get_plural_text("en_US", "apples", 1).unwrap());
+    assert_eq!("{count} apples", frozen.get_plural_text("zh_TW", "apples", 2).unwrap());
+}
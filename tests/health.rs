@@ -0,0 +1,81 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+#[test]
+fn health_summary() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "rust": "Rust!", "ok": "OK", "bye": "Bye!", "@hello": {}}"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！", "rust": "Rust！", "ok": "好"}"#)
+        .unwrap()
+        .add_json("fr", r#"{"hello": "Bonjour !"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let summary = ctx.health_summary();
+
+    assert_eq!("en_US", summary.default_key);
+    assert_eq!(3, summary.locale_count);
+    assert_eq!(1.0, summary.coverage["en_US"]);
+    assert_eq!(0.75, summary.coverage["zh_TW"]);
+    assert_eq!(0.25, summary.coverage["fr"]);
+    #[cfg(feature = "content_hash")]
+    assert_eq!(ctx.content_hash(), summary.fingerprint);
+    assert!(summary.last_reload.is_none());
+
+    let json = serde_json::to_value(&summary).unwrap();
+
+    assert_eq!(3, json["locale_count"]);
+    assert_eq!(0.75, json["coverage"]["zh_TW"]);
+    #[cfg(feature = "content_hash")]
+    assert_eq!(ctx.content_hash().to_string(), json["fingerprint"]);
+    assert!(json.get("last_reload").is_none());
+}
+
+#[test]
+fn health_summary_of_fallbacks() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!", "@rust": {}}"#)
+        .unwrap()
+        .add_json("pt", r#"{"hello": "Olá!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("pt_BR", r#"{"@hello": {}}"#)
+        .unwrap()
+        .set_fallbacks("pt_BR", ["pt"]);
+
+    let mut ctx = builder.build().unwrap();
+
+    let summary = ctx.health_summary();
+
+    // texts filled from a fallback key are not translated by the key, and metadata is not counted
+    assert_eq!(1.0, summary.coverage["pt"]);
+    assert_eq!(0.0, summary.coverage["pt_BR"]);
+
+    ctx.insert_text("pt_BR", "hello", JSONGetTextValue::from_str("Oi!")).unwrap();
+
+    let summary = ctx.health_summary();
+
+    assert_eq!(0.5, summary.coverage["pt_BR"]);
+    assert_eq!(3, summary.locale_count);
+}
+
+#[test]
+fn health_summary_of_empty_catalogs() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", "{}").unwrap().add_json("de", "{}").unwrap();
+
+    let summary = builder.build().unwrap().health_summary();
+
+    assert_eq!(1.0, summary.coverage["en_US"]);
+    assert_eq!(1.0, summary.coverage["de"]);
+}
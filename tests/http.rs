@@ -0,0 +1,153 @@
+#![cfg(all(feature = "http", not(feature = "langid")))]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError, JSONGetTextValue, Key, ScanVerdict};
+
+/// Serve the current `(etag, body)` of a catalog, answering `304 Not Modified` to a request with a matching `If-None-Match` header.
+fn serve(catalog: Arc<Mutex<(&'static str, &'static str)>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+    let url = format!("http://{}/zh_TW.json", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+
+            let mut if_none_match = None;
+
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("if-none-match") {
+                        if_none_match = Some(value.trim().to_string());
+                    }
+                }
+            }
+
+            let (etag, body) = *catalog.lock().unwrap();
+
+            let response = if if_none_match.as_deref() == Some(etag) {
+                format!("HTTP/1.1 304 Not Modified\r\nETag: {}\r\nConnection: close\r\n\r\n", etag)
+            } else {
+                format!(
+                    "HTTP/1.1 200 OK\r\nETag: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    etag,
+                    body.len(),
+                    body
+                )
+            };
+
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    url
+}
+
+#[test]
+fn add_json_url_and_refresh() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+    runtime.block_on(async {
+        let catalog = Arc::new(Mutex::new(("\"v1\"", r#"{"hello": "哈囉！"}"#)));
+
+        let url = serve(catalog.clone());
+
+        let mut builder = JSONGetText::build("en_US");
+
+        builder
+            .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+            .unwrap()
+            .add_json_url("zh_TW", url)
+            .await
+            .unwrap()
+            .set_content_scanner(|_: &Key, _: &str, value: &JSONGetTextValue| {
+                if value.as_str().is_some_and(|s| s.contains("damn")) {
+                    ScanVerdict::Quarantine(String::from("profanity"))
+                } else {
+                    ScanVerdict::Accept
+                }
+            });
+
+        let mut ctx = builder.build().unwrap();
+
+        assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+
+        assert!(ctx.refresh().await.unwrap().is_empty());
+
+        *catalog.lock().unwrap() = ("\"v2\"", r#"{"rust": "Rust！"}"#);
+
+        assert_eq!(vec![Key::from("zh_TW")], ctx.refresh().await.unwrap());
+
+        assert_eq!("Hello!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+        assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+        *catalog.lock().unwrap() = ("\"v3\"", r#"{"bye": "再見！"}"#);
+
+        assert!(ctx.refresh().await.is_err());
+        assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+        // refreshed texts are scanned as when building
+        *catalog.lock().unwrap() = ("\"v4\"", r#"{"hello": "哈囉！", "rust": "damn"}"#);
+
+        assert_eq!(vec![Key::from("zh_TW")], ctx.refresh().await.unwrap());
+
+        assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+        assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+        assert_eq!("rust", ctx.get_quarantined_texts()[0].text);
+    });
+}
+
+#[test]
+fn add_json_url_errors() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+    runtime.block_on(async {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+
+                for line in BufReader::new(&stream).lines() {
+                    if line.unwrap().is_empty() {
+                        break;
+                    }
+                }
+
+                stream
+                    .write_all(
+                        b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    )
+                    .unwrap();
+            }
+        });
+
+        let mut builder = JSONGetText::build("en_US");
+
+        builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+
+        // an error status is an error
+        assert!(matches!(
+            builder.add_json_url("zh_TW", format!("http://{}/zh_TW.json", addr)).await,
+            Err(JSONGetTextBuildError::HTTPError(_))
+        ));
+
+        // a catalog which cannot be downloaded is not added
+        let mut ctx = builder.build().unwrap();
+
+        assert_eq!(vec!["en_US"], ctx.get_keys());
+        assert!(ctx.refresh().await.unwrap().is_empty());
+    });
+}
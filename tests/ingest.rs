@@ -0,0 +1,112 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::fs;
+
+use json_gettext::{
+    IngestLimit, IngestLimits, JSONGetText, JSONGetTextBuildError, JSONGetTextValue,
+};
+use serde_json::json;
+
+fn exceeded(result: Result<impl Sized, JSONGetTextBuildError>) -> Option<IngestLimit> {
+    match result {
+        Err(JSONGetTextBuildError::IngestLimitExceeded {
+            limit,
+            ..
+        }) => Some(limit),
+        _ => None,
+    }
+}
+
+#[test]
+fn ingest_limits() {
+    let limits = IngestLimits {
+        max_bytes: 64,
+        max_depth: 3,
+        max_texts: 2,
+    };
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.set_ingest_limits(limits);
+
+    assert!(builder.add_json("en_US", r#"{"a": {"b": ["c"]}, "d": "{[\"]}"}"#).is_ok());
+
+    assert_eq!(
+        Some(IngestLimit::Depth),
+        exceeded(builder.add_json("zh_TW", r#"{"a": {"b": [[]]}}"#))
+    );
+    assert_eq!(
+        Some(IngestLimit::Texts),
+        exceeded(builder.add_json("zh_TW", r#"{"a": 1, "b": 2, "c": 3}"#))
+    );
+    assert_eq!(
+        Some(IngestLimit::Bytes),
+        exceeded(builder.add_json_owned("zh_TW", format!(r#"{{"a": "{}"}}"#, "x".repeat(64))))
+    );
+
+    assert_eq!(
+        Some(IngestLimit::Depth),
+        exceeded(builder.add_serialize("zh_TW", json!({"a": [{"b": []}]})))
+    );
+
+    let mut map = HashMap::new();
+
+    map.insert(String::from("a"), JSONGetTextValue::JSONValue(json!([[[]]])));
+
+    assert_eq!(Some(IngestLimit::Depth), exceeded(builder.add_map("zh_TW", map)));
+
+    #[cfg(feature = "fs")]
+    {
+        let path = std::env::temp_dir().join("json-gettext-ingest-limits.json");
+
+        fs::write(&path, format!(r#"{{"a": "{}"}}"#, "x".repeat(64))).unwrap();
+
+        assert_eq!(Some(IngestLimit::Bytes), exceeded(builder.add_json_file("zh_TW", &path)));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    assert!(builder.add_json("zh_TW", r#"{"a": {"b": ["甲"]}}"#).is_ok());
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn pathological_nesting() {
+    let json = format!(r#"{{"a": {}{}}}"#, "[".repeat(1_000_000), "]".repeat(1_000_000));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    // without limits, still rejected by the recursion limit of `serde_json` instead of overflowing the stack
+    assert!(matches!(
+        builder.add_json_owned("en_US", &json),
+        Err(JSONGetTextBuildError::SerdeJSONError(_))
+    ));
+
+    builder.set_ingest_limits(IngestLimits::UNTRUSTED);
+
+    assert_eq!(Some(IngestLimit::Depth), exceeded(builder.add_json_owned("en_US", &json)));
+}
+
+#[test]
+fn unlimited_by_default() {
+    let json = serde_json::to_string(
+        &(0..IngestLimits::UNTRUSTED.max_texts + 1)
+            .map(|i| (i.to_string(), "x"))
+            .collect::<HashMap<_, _>>(),
+    )
+    .unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json_owned("en_US", &json).unwrap();
+
+    assert_eq!(IngestLimits::UNTRUSTED.max_texts + 1, builder.build().unwrap().get("en_US").len());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.set_ingest_limits(IngestLimits::UNTRUSTED);
+
+    assert_eq!(Some(IngestLimit::Texts), exceeded(builder.add_json_owned("en_US", &json)));
+}
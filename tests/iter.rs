@@ -0,0 +1,69 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::JSONGetText;
+
+fn build() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json_owned("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json_owned("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn keys() {
+    let ctx = build();
+
+    let mut keys: Vec<&str> = ctx.keys().map(|key| key.as_str()).collect();
+
+    keys.sort_unstable();
+
+    assert_eq!(vec!["en_US", "zh_TW"], keys);
+}
+
+#[test]
+fn iter() {
+    let ctx = build();
+
+    let mut texts: Vec<(&str, &str, &str)> = ctx
+        .iter()
+        .map(|(key, text, value)| (key.as_str(), text, value.as_str().unwrap()))
+        .collect();
+
+    texts.sort_unstable();
+
+    assert_eq!(
+        vec![
+            ("en_US", "hello", "Hello!"),
+            ("en_US", "rust", "Rust!"),
+            ("zh_TW", "hello", "哈囉！"),
+            ("zh_TW", "rust", "Rust!"),
+        ],
+        texts
+    );
+}
+
+#[test]
+fn iter_language() {
+    let ctx = build();
+
+    let mut texts: Vec<(&str, &str)> = ctx
+        .iter_language("zh_TW")
+        .unwrap()
+        .map(|(key, text, value)| {
+            assert_eq!("zh_TW", key.as_str());
+
+            (text, value.as_str().unwrap())
+        })
+        .collect();
+
+    texts.sort_unstable();
+
+    assert_eq!(vec![("hello", "哈囉！"), ("rust", "Rust!")], texts);
+
+    assert!(ctx.iter_language("fr").is_none());
+}
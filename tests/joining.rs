@@ -0,0 +1,63 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{german_article, FragmentJoiner, FrenchJoiner, Gender, GermanCase, JSONGetText};
+
+#[test]
+fn french_joiner() {
+    let joiner = FrenchJoiner;
+
+    assert_eq!("l'arbre", joiner.join("le", "arbre"));
+    assert_eq!("L'école", joiner.join("La", "école"));
+    assert_eq!("l'homme", joiner.join("le", "homme"));
+    assert_eq!("la maison", joiner.join("la", "maison"));
+    assert_eq!("qu'elle", joiner.join("que", "elle"));
+    assert_eq!("s'il vous plaît", joiner.join("si", "il vous plaît"));
+    assert_eq!("si elle", joiner.join("si", "elle"));
+
+    assert_eq!("du livre", joiner.join("de", "le livre"));
+    assert_eq!("des amis", joiner.join("de", "les amis"));
+    assert_eq!("au marché", joiner.join("à", "le marché"));
+    assert_eq!("Aux armes", joiner.join("À", "les armes"));
+    assert_eq!("de l'eau", joiner.join("de", "l'eau"));
+    assert_eq!("de la mer", joiner.join("de", "la mer"));
+}
+
+#[test]
+fn join_fragments() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"lamp": "lamp", "@lamp": {"gender": "n"}}"#)
+        .unwrap()
+        .add_json("fr_FR", r#"{"lamp": "lampe", "@lamp": {"gender": "f"}}"#)
+        .unwrap()
+        .add_json("de_DE", r#"{"lamp": "Lampe", "@lamp": {"gender": "feminine"}}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"lamp": "燈"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("l'abricot", ctx.join_fragments("fr_FR", "le", "abricot"));
+    assert_eq!("這個燈", ctx.join_fragments("zh_TW", "這個", "燈"));
+    assert_eq!("the lamp", ctx.join_fragments("de", "the", "lamp"));
+
+    assert_eq!(Some(Gender::Feminine), ctx.gender_of("fr_FR", "lamp"));
+    assert_eq!(Some(Gender::Neuter), ctx.gender_of("en_US", "lamp"));
+    assert_eq!(None, ctx.gender_of("zh_TW", "lamp"));
+    assert_eq!(None, ctx.gender_of("de_DE", "missing"));
+
+    let gender = ctx.gender_of("de_DE", "lamp").unwrap();
+
+    assert_eq!(
+        "mit der Lampe",
+        format!(
+            "mit {} {}",
+            german_article(gender, GermanCase::Dative, true),
+            ctx.get_text_with_key("de_DE", "lamp").unwrap()
+        )
+    );
+
+    assert_eq!("einen", german_article(Gender::Masculine, GermanCase::Accusative, false));
+    assert_eq!("des", german_article(Gender::Neuter, GermanCase::Genitive, true));
+}
@@ -0,0 +1,250 @@
+#![cfg(feature = "language")]
+
+#[macro_use]
+extern crate json_gettext;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use json_gettext::{
+    ConflictPolicy, EmptyKind, EmptyTextPolicy, ExtraText, JSONGetText, JSONGetTextBuildError,
+    JSONGetTextValue, Key, ReadThroughOptions, SharedJSONGetText, Strictness,
+};
+
+fn build() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build(key!("en"));
+
+    builder
+        .add_json(key!("en"), r#"{"hello": "Hello!", "rust": "Rust!", "color": "Color"}"#)
+        .unwrap()
+        .add_json(key!("zh"), r#"{"hello": "你好！", "color": "颜色"}"#)
+        .unwrap()
+        .add_json(key!("yue"), r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .set_fallbacks(key!("yue"), [key!("zh")]);
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn build_steps() {
+    let ctx = build();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key(key!("yue"), "hello").unwrap());
+    assert_eq!("颜色", ctx.get_text_with_key(key!("yue"), "color").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key(key!("yue"), "rust").unwrap());
+    assert_eq!(vec!["color", "rust"], ctx.coverage()["yue"].missing);
+
+    let mut builder = JSONGetText::build(key!("en"));
+
+    builder
+        .add_json(key!("en"), r#"{"hello": "Hello!", "quote": "Say \"hi\"."}"#)
+        .unwrap()
+        .add_json(key!("de"), r#"{"hello": "", "quote": "Sag \"hallo\".", "bye": "Tschüss!"}"#)
+        .unwrap()
+        .set_strictness(Strictness::Warn)
+        .set_empty_text_policy(EmptyKind::EmptyString, EmptyTextPolicy::Missing)
+        .normalize_quotation(true);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello!", ctx.get_text_with_key(key!("de"), "hello").unwrap());
+    assert_eq!("Sag „hallo“.", ctx.get_text_with_key(key!("de"), "quote").unwrap());
+    assert_eq!("Tschüss!", ctx.get_text_with_key(key!("de"), "bye").unwrap());
+    assert_eq!(
+        vec![ExtraText {
+            key: "de".to_string(),
+            text: "bye".to_string(),
+        }],
+        ctx.get_extra_texts()
+    );
+    assert_eq!(1, ctx.get_empty_texts().len());
+
+    let mut builder = JSONGetText::build(key!("en"));
+
+    builder
+        .add_json(key!("en"), r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json(key!("de"), r#"{"bye": "Tschüss!"}"#)
+        .unwrap();
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { key, .. }) if key == key!("de")
+    ));
+}
+
+#[test]
+fn merge() {
+    let mut ctx = build();
+
+    let mut builder = JSONGetText::build(key!("en"));
+
+    builder
+        .add_json(key!("en"), r#"{"plugin.title": "Plugin", "rust": "Rust"}"#)
+        .unwrap()
+        .add_json(key!("zh"), r#"{"plugin.title": "插件"}"#)
+        .unwrap();
+
+    let plugin = builder.build().unwrap();
+
+    assert!(matches!(
+        ctx.merge(plugin.clone(), ConflictPolicy::Error),
+        Err(JSONGetTextBuildError::DuplicatedText { ref text, .. }) if text == "rust"
+    ));
+    assert!(ctx.get_text("plugin.title").is_none());
+
+    ctx.merge(plugin, ConflictPolicy::Overwrite).unwrap();
+
+    assert_eq!("Rust", ctx.get_text("rust").unwrap());
+    assert_eq!("Rust", ctx.get_text_with_key(key!("zh"), "rust").unwrap());
+    assert_eq!("插件", ctx.get_text_with_key(key!("zh"), "plugin.title").unwrap());
+    // the key which takes texts from a merged key is filled again
+    assert_eq!("插件", ctx.get_text_with_key(key!("yue"), "plugin.title").unwrap());
+    assert_eq!("哈囉！", ctx.get_text_with_key(key!("yue"), "hello").unwrap());
+}
+
+#[test]
+fn mutation() {
+    let mut ctx = build();
+
+    ctx.insert_text(key!("zh"), "rust", JSONGetTextValue::from_str("锈！")).unwrap();
+
+    assert_eq!("锈！", ctx.get_text_with_key(key!("zh"), "rust").unwrap());
+    assert_eq!("锈！", ctx.get_text_with_key(key!("yue"), "rust").unwrap());
+
+    assert!(matches!(
+        ctx.insert_text(key!("zh"), "thanks", JSONGetTextValue::from_str("谢谢！")),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { .. })
+    ));
+
+    assert_eq!("哈囉！", ctx.remove_text(&key!("yue"), "hello").unwrap().unwrap());
+    assert_eq!("你好！", ctx.get_text_with_key(key!("yue"), "hello").unwrap());
+    assert!(ctx.remove_text(&key!("yue"), "hello").unwrap().is_none());
+    assert!(ctx.remove_text(&key!("fr"), "hello").unwrap().is_none());
+
+    assert_eq!("Color", ctx.remove_text(&key!("en"), "color").unwrap().unwrap());
+    assert!(ctx.get_text("color").is_none());
+    assert!(ctx.get_text_with_key(key!("yue"), "color").is_none());
+
+    let mut map = HashMap::new();
+
+    map.insert(String::from("hello"), JSONGetTextValue::from_str("Bonjour !"));
+
+    ctx.add_language(key!("fr"), map.clone()).unwrap();
+
+    assert_eq!("Bonjour !", ctx.get_text_with_key(key!("fr"), "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key(key!("fr"), "rust").unwrap());
+
+    assert!(matches!(
+        ctx.add_language(key!("fr"), map),
+        Err(JSONGetTextBuildError::DuplicatedKey(key)) if key == key!("fr")
+    ));
+}
+
+#[test]
+fn remove_text_pointed_at() {
+    let mut builder = JSONGetText::build(key!("en"));
+
+    builder
+        .add_json(
+            key!("en"),
+            r#"{"greeting": "Hello!", "guest": "guest", "welcome": "Hi, {name|guest}!"}"#,
+        )
+        .unwrap()
+        .add_migration("hello", "greeting");
+
+    let mut ctx = builder.build().unwrap();
+
+    assert!(matches!(
+        ctx.remove_text(&key!("en"), "greeting"),
+        Err(JSONGetTextBuildError::MigrationTargetNotFound { .. })
+    ));
+    assert!(matches!(
+        ctx.remove_text(&key!("en"), "guest"),
+        Err(JSONGetTextBuildError::PlaceholderDefaultNotFound { .. })
+    ));
+    assert_eq!("Hello!", ctx.get_text("hello").unwrap());
+}
+
+#[test]
+fn secondary_context() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = JSONGetText::build(key!("en"));
+
+    builder
+        .add_json(key!("en"), r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json(key!("zh"), r#"{"hello": "你好！"}"#)
+        .unwrap();
+
+    {
+        let calls = calls.clone();
+
+        builder.set_secondary_context(
+            move |key: &Key, text: &str| {
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                (*key == key!("zh") && text == "rust").then(|| "锈！".to_string())
+            },
+            ReadThroughOptions::default(),
+        );
+    }
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("你好！", ctx.get_text_with_key(key!("zh"), "hello").unwrap());
+    assert_eq!("锈！", ctx.get_text_with_key(key!("zh"), "rust").unwrap());
+    assert_eq!("锈！", ctx.get_text_with_key(key!("zh"), "rust").unwrap());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn shared() {
+    let shared = SharedJSONGetText::new(build());
+
+    let mut texts = HashMap::new();
+
+    texts.insert(String::from("color"), JSONGetTextValue::from_string("顏色".to_string()));
+
+    shared.patch_language(key!("yue"), texts).unwrap();
+
+    assert_eq!("顏色", shared.read().get_text_with_key(key!("yue"), "color").unwrap());
+    assert_eq!("颜色", shared.read().get_text_with_key(key!("zh"), "color").unwrap());
+
+    shared.insert_text(key!("zh"), "rust", JSONGetTextValue::from_str("锈！")).unwrap();
+
+    assert_eq!("锈！", shared.read().get_text_with_key(key!("yue"), "rust").unwrap());
+}
+
+#[test]
+fn compare_and_iterate() {
+    let ctx = build();
+
+    assert_eq!(build(), ctx);
+
+    let mut texts: Vec<(String, &str)> = ctx
+        .iter_language(&key!("yue"))
+        .unwrap()
+        .map(|(key, text, _)| (key.to_string(), text))
+        .collect();
+
+    texts.sort_unstable();
+
+    assert_eq!(
+        vec![
+            ("yue".to_string(), "color"),
+            ("yue".to_string(), "hello"),
+            ("yue".to_string(), "rust")
+        ],
+        texts
+    );
+    assert_eq!(9, ctx.iter().count());
+
+    let frozen = build().freeze();
+
+    assert_eq!(&ctx, frozen);
+    assert_eq!("颜色", frozen.get_text_with_key(key!("yue"), "color").unwrap());
+}
@@ -31,3 +31,30 @@ fn multiple_get() {
     assert_eq!(&"哈囉，世界！", map_zh.get("hello").unwrap());
     assert_eq!(&"Rust!", map_zh.get("rust").unwrap());
 }
+
+#[test]
+fn pair_get() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let pair = ctx.get_text_pair("zh_TW", "hello").unwrap();
+
+    assert_eq!("Hello, world!", pair.default);
+    assert_eq!("哈囉，世界！", pair.localized);
+
+    let pair = ctx.get_text_pair("de", "rust").unwrap();
+
+    assert_eq!("Rust!", pair.default);
+    assert_eq!("Rust!", pair.localized);
+
+    assert_eq!(
+        r#"{"default":"Hello, world!","localized":"哈囉，世界！"}"#,
+        serde_json::to_string(&ctx.get_text_pair("zh_TW", "hello").unwrap()).unwrap()
+    );
+
+    assert!(ctx.get_text_pair("zh_TW", "missing").is_none());
+}
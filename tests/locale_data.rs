@@ -0,0 +1,264 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, Month, NameStyle, Weekday};
+
+fn ctx() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"calendar.weekdays.wide.monday": "Monday (catalog)"}"#).unwrap();
+    builder.add_json("de", "{}").unwrap();
+    builder.add_json("zh_TW", "{}").unwrap();
+    builder.add_json("zh_CN", "{}").unwrap();
+    builder.add_json("tlh", "{}").unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn calendar_names() {
+    let ctx = ctx();
+
+    assert_eq!("Montag", ctx.weekday_name("de", Weekday::Monday, NameStyle::Wide));
+    assert_eq!("So.", ctx.weekday_name("de", Weekday::Sunday, NameStyle::Abbreviated));
+    assert_eq!("週三", ctx.weekday_name("zh_TW", Weekday::Wednesday, NameStyle::Abbreviated));
+    assert_eq!("周三", ctx.weekday_name("zh_CN", Weekday::Wednesday, NameStyle::Abbreviated));
+    assert_eq!("Monday (catalog)", ctx.weekday_name("en_US", Weekday::Monday, NameStyle::Wide));
+    assert_eq!("Monday", ctx.weekday_name("tlh", Weekday::Monday, NameStyle::Wide));
+    assert_eq!("Tue", ctx.weekday_name("tlh", Weekday::Tuesday, NameStyle::Abbreviated));
+
+    assert_eq!("März", ctx.month_name("de", Month::March, NameStyle::Wide));
+    assert_eq!("十二月", ctx.month_name("zh_TW", Month::December, NameStyle::Wide));
+    assert_eq!("May", ctx.month_name("fr_FR", Month::May, NameStyle::Wide));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_calendar_names() {
+    let ctx = ctx();
+
+    assert_eq!("Freitag", ctx.weekday_name("de", chrono::Weekday::Fri, NameStyle::Wide));
+    assert_eq!("Okt", ctx.month_name("de", chrono::Month::October, NameStyle::Abbreviated));
+}
+
+#[test]
+fn display_names() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"names.countries.TW": "Taiwan (catalog)"}"#).unwrap();
+    builder.add_json("fr", "{}").unwrap();
+    builder.add_json("zh_TW", r#"{"names.countries.TW": "臺灣"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(Some("臺灣"), ctx.country_name("zh_TW", "tw").as_deref());
+    assert_eq!(Some("Taiwan (catalog)"), ctx.country_name("en_US", "TW").as_deref());
+
+    #[cfg(feature = "display_names")]
+    {
+        assert_eq!(Some("Allemagne"), ctx.country_name("fr", "DE").as_deref());
+        assert_eq!(Some("Taïwan"), ctx.country_name("fr", "TW").as_deref());
+        assert_eq!(Some("德文"), ctx.language_name("zh_TW", "de").as_deref());
+        assert_eq!(Some("German"), ctx.language_name("en_US", "DE").as_deref());
+        assert_eq!(None, ctx.country_name("fr", "ZZ"));
+    }
+
+    #[cfg(not(feature = "display_names"))]
+    assert_eq!(None, ctx.country_name("fr", "DE"));
+}
+
+#[test]
+fn timezone_display() {
+    use json_gettext::{TimeZoneNameError, TimeZoneNameStyle};
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"metazones.Taipei.generic": "Taiwan Time"}"#).unwrap();
+    builder.add_json("fr", "{}").unwrap();
+    builder.add_json("ja", "{}").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(
+        Ok("Taiwan Time"),
+        ctx.timezone_display("en_US", "Asia/Taipei", TimeZoneNameStyle::Generic).as_deref()
+    );
+    assert_eq!(
+        Err(TimeZoneNameError::UnknownTimeZone {
+            tz_id: "Mars/Olympus_Mons".to_string()
+        }),
+        ctx.timezone_display("fr", "Mars/Olympus_Mons", TimeZoneNameStyle::Generic)
+    );
+
+    // Japanese names are not embedded, and English is not used instead
+    assert_eq!(
+        Err(TimeZoneNameError::UnsupportedLocale {
+            key: "ja".to_string(),
+            metazone: "America_Pacific",
+        }),
+        ctx.timezone_display("ja", "America/Los_Angeles", TimeZoneNameStyle::Daylight)
+    );
+
+    #[cfg(feature = "timezone_names")]
+    {
+        assert_eq!(
+            Ok("heure normale d’Europe centrale"),
+            ctx.timezone_display("fr", "Europe/Paris", TimeZoneNameStyle::Standard).as_deref()
+        );
+        assert_eq!(
+            Ok("Pacific Daylight Time"),
+            ctx.timezone_display("en_US", "America/Los_Angeles", TimeZoneNameStyle::Daylight)
+                .as_deref()
+        );
+    }
+
+    #[cfg(not(feature = "timezone_names"))]
+    assert_eq!(
+        Err(TimeZoneNameError::UnsupportedLocale {
+            key: "fr".to_string(),
+            metazone: "Europe_Central",
+        }),
+        ctx.timezone_display("fr", "Europe/Paris", TimeZoneNameStyle::Standard)
+    );
+}
+
+#[test]
+fn calendar_preferences() {
+    use json_gettext::{CalendarPreferences, HourCycle, Key};
+
+    let us = CalendarPreferences::of(&Key::from("en_US"));
+
+    assert_eq!(Weekday::Sunday, us.first_day_of_week);
+    assert_eq!(HourCycle::H12, us.hour_cycle);
+    assert_eq!(Weekday::Sunday, us.week()[0]);
+    assert_eq!(Weekday::Saturday, us.week()[6]);
+
+    let de = CalendarPreferences::of(&Key::from("de"));
+
+    assert_eq!(Weekday::Monday, de.first_day_of_week);
+    assert_eq!(HourCycle::H23, de.hour_cycle);
+    assert!(de.is_weekend(Weekday::Sunday));
+    assert!(!de.is_weekend(Weekday::Friday));
+
+    let eg = CalendarPreferences::of(&Key::from("ar_EG"));
+
+    assert_eq!(Weekday::Saturday, eg.first_day_of_week);
+    assert!(eg.is_weekend(Weekday::Friday));
+    assert!(!eg.is_weekend(Weekday::Sunday));
+
+    #[cfg(feature = "likely_subtags")]
+    assert_eq!(HourCycle::H12, CalendarPreferences::of(&Key::from("zh_Hant")).hour_cycle);
+    assert!(CalendarPreferences::of(&Key::from("hi_IN")).is_weekend(Weekday::Sunday));
+    assert!(!CalendarPreferences::of(&Key::from("hi_IN")).is_weekend(Weekday::Saturday));
+}
+
+#[test]
+fn name_and_address_order() {
+    use json_gettext::{AddressField, PersonName, PostalAddress};
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"names.countries.US": "United States"}"#).unwrap();
+    builder.add_json("ja_JP", "{}").unwrap();
+    builder.add_json("de", "{}").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let name = PersonName {
+        given: String::from("太郎"),
+        family: String::from("山田"),
+    };
+
+    assert_eq!("山田太郎", ctx.format_person_name("ja_JP", &name));
+    assert_eq!("太郎 山田", ctx.format_person_name("en_US", &name));
+
+    let name = PersonName {
+        given: String::from("John"),
+        family: String::from("Smith"),
+    };
+
+    assert_eq!("Smith John", ctx.format_person_name("ja_JP", &name));
+
+    let address = PostalAddress {
+        name: String::from("John Smith"),
+        street: String::from("1600 Amphitheatre Parkway"),
+        city: String::from("Mountain View"),
+        region: String::from("CA"),
+        postal_code: String::from("94043"),
+        country_code: String::from("US"),
+        ..PostalAddress::default()
+    };
+
+    assert_eq!(
+        "John Smith\n1600 Amphitheatre Parkway\nMountain View, CA 94043\nUnited States",
+        ctx.format_postal_address("en_US", &address)
+    );
+
+    let address = PostalAddress {
+        name: String::from("Max Mustermann"),
+        street: String::from("Musterstraße 1"),
+        city: String::from("Berlin"),
+        postal_code: String::from("10115"),
+        ..PostalAddress::default()
+    };
+
+    assert_eq!(
+        "Max Mustermann\nMusterstraße 1\n10115 Berlin",
+        ctx.format_postal_address("de", &address)
+    );
+
+    assert_eq!(
+        vec![
+            AddressField::Country,
+            AddressField::PostalCode,
+            AddressField::Region,
+            AddressField::City,
+            AddressField::Street,
+            AddressField::Organization,
+            AddressField::Name,
+        ],
+        ctx.address_field_order("ja_JP", None)
+    );
+}
+
+#[test]
+fn phone_example() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"formats.phone_example.TW": "0912 345 678"}"#).unwrap();
+    builder.add_json("de", "{}").unwrap();
+    builder.add_json("zh_TW", r#"{"formats.phone_example.TW": "0912-345-678"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    #[cfg(feature = "likely_subtags")]
+    {
+        let example = ctx.phone_example("de", None).unwrap();
+
+        assert_eq!("DE", example.region);
+        assert_eq!("+49", example.calling_code);
+        assert_eq!("01512 3456789", example.national);
+    }
+
+    // the likely region of a language is not known without the likely subtags
+    #[cfg(not(feature = "likely_subtags"))]
+    assert_eq!(None, ctx.phone_example("de", None));
+
+    assert_eq!("+49", ctx.phone_example("de", Some("DE")).unwrap().calling_code);
+
+    assert_eq!("0912-345-678", ctx.phone_example("zh_TW", None).unwrap().national);
+    assert_eq!("+81 90-1234-5678", ctx.phone_example("de", Some("jp")).unwrap().international);
+    assert_eq!(None, ctx.phone_example("de", Some("ZZ")));
+
+    #[cfg(feature = "phonenumber")]
+    {
+        assert_eq!(
+            Some("(201) 555-0123"),
+            ctx.format_phone_number("en_US", "2015550123", None).as_deref()
+        );
+        assert_eq!(
+            Some("+1 201-555-0123"),
+            ctx.format_phone_number("de", "+1 201 555 0123", None).as_deref()
+        );
+        assert_eq!(None, ctx.format_phone_number("en_US", "12", None));
+    }
+}
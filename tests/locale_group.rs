@@ -0,0 +1,107 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{ConflictPolicy, JSONGetText, PreflightFailure};
+
+fn build() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json_owned("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json_owned("es_MX", r#"{"hello": "¡Hola!", "rust": "¡Rust!"}"#)
+        .unwrap()
+        .add_json_owned("pt_BR", r#"{"hello": "Olá!"}"#)
+        .unwrap()
+        .add_json_owned("de_DE", r#"{"hello": "Hallo!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_locale_group("LATAM", ["es_MX", "pt_BR", "es_MX"])
+        .add_locale_group("EU", ["de_DE", "fr_FR"]);
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn locale_group() {
+    let ctx = build();
+
+    assert_eq!(vec!["EU", "LATAM"], ctx.get_locale_group_names());
+    assert_eq!(2, ctx.locale_group("LATAM").unwrap().len());
+    assert!(ctx.locale_group("APAC").is_none());
+}
+
+#[test]
+fn group_coverage() {
+    let ctx = build();
+
+    let coverage = ctx.group_coverage("LATAM").unwrap();
+
+    assert_eq!(vec!["es_MX", "pt_BR"], coverage.keys().collect::<Vec<_>>());
+    assert_eq!(100.0, coverage["es_MX"].percentage);
+    assert_eq!(vec!["rust"], coverage["pt_BR"].missing);
+
+    let coverage = ctx.group_coverage("EU").unwrap();
+
+    assert_eq!(vec!["de_DE"], coverage.keys().collect::<Vec<_>>());
+
+    assert!(ctx.group_coverage("APAC").is_none());
+}
+
+#[test]
+fn preflight_group() {
+    let ctx = build();
+
+    assert_eq!(Ok(()), ctx.preflight_group("LATAM", &["hello"]));
+
+    assert_eq!(
+        Err(vec![PreflightFailure::UntranslatedText {
+            key: "pt_BR".to_string(),
+            text: "rust".to_string(),
+        }]),
+        ctx.preflight_group("LATAM", &["hello", "rust"])
+    );
+
+    assert_eq!(
+        Err(vec![PreflightFailure::MissingLocale {
+            key: "fr_FR".to_string(),
+        }]),
+        ctx.preflight_group("EU", &["hello"])
+    );
+
+    assert_eq!(
+        Err(vec![PreflightFailure::UnknownLocaleGroup {
+            group: "APAC".to_string(),
+        }]),
+        ctx.preflight_group("APAC", &["hello"])
+    );
+}
+
+#[test]
+fn export_group_json() {
+    let ctx = build();
+
+    let bundle: serde_json::Value =
+        serde_json::from_str(&ctx.export_group_json("LATAM").unwrap()).unwrap();
+
+    assert_eq!("¡Hola!", bundle["es_MX"]["hello"]);
+    assert_eq!("Olá!", bundle["pt_BR"]["hello"]);
+    assert_eq!("Rust!", bundle["pt_BR"]["rust"]);
+    assert!(bundle.get("de_DE").is_none());
+
+    assert!(ctx.export_group_json("APAC").is_none());
+}
+
+#[test]
+fn merge_locale_groups() {
+    let mut ctx = build();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json_owned("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_locale_group("APAC", ["zh_TW"]);
+
+    ctx.merge(builder.build().unwrap(), ConflictPolicy::KeepFirst).unwrap();
+
+    assert_eq!(vec!["APAC", "EU", "LATAM"], ctx.get_locale_group_names());
+}
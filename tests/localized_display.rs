@@ -0,0 +1,118 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, LocalizedDisplay};
+
+#[allow(dead_code)]
+#[derive(json_gettext_macros::LocalizedDisplay)]
+#[loc(prefix = "status")]
+enum Status {
+    Active,
+    #[loc("status.away")]
+    Idle {
+        since: u64,
+    },
+    PendingReview(u32),
+}
+
+#[test]
+fn localized_display() {
+    assert_eq!("status.active", Status::Active.text_id());
+    assert_eq!(
+        "status.away",
+        Status::Idle {
+            since: 0
+        }
+        .text_id()
+    );
+    assert_eq!("status.pending_review", Status::PendingReview(1).text_id());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "status.active": "Active",
+                "status.away": "Away",
+                "status.pending_review": "Pending review"
+            }"#,
+        )
+        .unwrap();
+
+    builder.add_json("zh_TW", r#"{"status.active": "使用中"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("使用中", Status::Active.localized(&ctx, "zh_TW").unwrap());
+    assert_eq!(
+        "Away",
+        Status::Idle {
+            since: 0
+        }
+        .localized(&ctx, "zh_TW")
+        .unwrap()
+    );
+    assert_eq!("Pending review", Status::PendingReview(1).localized(&ctx, "fr").unwrap());
+}
+
+#[allow(dead_code)]
+#[derive(json_gettext_macros::LocalizedDisplay)]
+enum Plan {
+    #[loc("free")]
+    Free,
+    #[loc("billing.plan.pro")]
+    ProMonthly,
+}
+
+#[test]
+fn localized_display_with_ids() {
+    assert_eq!("free", Plan::Free.text_id());
+    assert_eq!("billing.plan.pro", Plan::ProMonthly.text_id());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"free": "Free", "billing.plan.pro": "Pro ({price}/month)"}"#)
+        .unwrap()
+        .add_json("de", r#"{"billing.plan.pro": "Pro ({price}/Monat)"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Free", Plan::Free.localized(&ctx, "de").unwrap());
+    assert_eq!("Pro ({price}/Monat)", Plan::ProMonthly.localized(&ctx, "de").unwrap());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"free": "Free"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    // a value without a text is `None`
+    assert!(Plan::ProMonthly.localized(&ctx, "en_US").is_none());
+}
+
+struct Weekday(usize);
+
+impl LocalizedDisplay for Weekday {
+    fn text_id(&self) -> &'static str {
+        ["mon", "tue", "wed", "thu", "fri", "sat", "sun"][self.0]
+    }
+}
+
+#[test]
+fn localized_display_by_hand() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"mon": "Monday", "tue": "Tuesday"}"#)
+        .unwrap()
+        .add_json("fr", r#"{"mon": "lundi"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("lundi", Weekday(0).localized(&ctx, "fr").unwrap());
+    assert_eq!("Tuesday", Weekday(1).localized(&ctx, "fr").unwrap());
+    assert!(Weekday(2).localized(&ctx, "fr").is_none());
+}
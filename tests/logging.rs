@@ -0,0 +1,103 @@
+#![cfg(all(feature = "log", not(feature = "langid")))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use json_gettext::log::kv::{Key as KvKey, Value, VisitSource};
+use json_gettext::log::{self, Level, LevelFilter, Log, Metadata, Record};
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+type CapturedRecord = (Level, String, Vec<(String, String)>);
+
+static RECORDS: Mutex<Vec<CapturedRecord>> = Mutex::new(Vec::new());
+
+struct CaptureLogger;
+
+struct CaptureFields(Vec<(String, String)>);
+
+impl<'kvs> VisitSource<'kvs> for CaptureFields {
+    fn visit_pair(&mut self, key: KvKey<'kvs>, value: Value<'kvs>) -> Result<(), log::kv::Error> {
+        self.0.push((key.to_string(), value.to_string()));
+
+        Ok(())
+    }
+}
+
+impl Log for CaptureLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let mut fields = CaptureFields(Vec::new());
+
+        record.key_values().visit(&mut fields).unwrap();
+
+        RECORDS.lock().unwrap().push((record.level(), record.args().to_string(), fields.0));
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn log_t() {
+    log::set_logger(&CaptureLogger).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello, {name}!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉，{name}！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Len"));
+
+    log_t!(Level::Info, ctx, "rust");
+    log_t!(Level::Warn, ctx, "zh_TW" => "hello", &args);
+    log_t!(Level::Error, ctx, "zh_TW" => "missing");
+
+    let records = RECORDS.lock().unwrap();
+
+    let field = |key: &str, value: &str| (key.to_string(), value.to_string());
+
+    assert_eq!(
+        vec![
+            (Level::Info, "Rust!".to_string(), vec![field("id", "rust")]),
+            (Level::Warn, "Hello, Len!".to_string(), vec![
+                field("id", "hello"),
+                field("localized", "哈囉，Len！")
+            ]),
+            (Level::Error, "missing".to_string(), vec![
+                field("id", "missing"),
+                field("localized", "missing")
+            ]),
+        ],
+        *records
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_t() {
+    use json_gettext::tracing::Level;
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello, {name}!"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Len"));
+
+    tracing_t!(Level::INFO, ctx, "hello");
+    tracing_t!(Level::WARN, ctx, "en_US" => "hello", &args);
+}
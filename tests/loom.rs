@@ -0,0 +1,68 @@
+#![cfg(all(loom, not(feature = "langid")))]
+
+use std::collections::HashMap;
+
+use json_gettext::{JSONGetText, JSONGetTextValue, SharedJSONGetText};
+
+use loom::sync::Arc;
+use loom::thread;
+
+fn build(hello: &'static str) -> JSONGetText<'static> {
+    let mut map = HashMap::new();
+
+    map.insert(String::from("hello"), JSONGetTextValue::Str(hello));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_map("en_US", map).unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn read_during_replace() {
+    loom::model(|| {
+        let shared = Arc::new(SharedJSONGetText::new(build("old")));
+
+        let reader = {
+            let shared = shared.clone();
+
+            thread::spawn(move || {
+                let text = shared.read().get_text("hello").unwrap().to_string();
+
+                assert!(text == "old" || text == "new");
+            })
+        };
+
+        drop(shared.replace(build("new")));
+
+        reader.join().unwrap();
+
+        assert_eq!("new", shared.read().get_text("hello").unwrap());
+    });
+}
+
+#[test]
+fn replace_from_two_threads() {
+    loom::model(|| {
+        let shared = Arc::new(SharedJSONGetText::new(build("a")));
+
+        let writer = {
+            let shared = shared.clone();
+
+            thread::spawn(move || {
+                drop(shared.replace(build("b")));
+            })
+        };
+
+        let guard = shared.read();
+
+        drop(shared.replace(build("c")));
+
+        writer.join().unwrap();
+
+        let text = guard.get_text("hello").unwrap().to_string();
+
+        assert!(text == "a" || text == "b" || text == "c");
+    });
+}
@@ -0,0 +1,131 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{ConflictPolicy, ExtraText, JSONGetText, JSONGetTextBuildError, Strictness};
+
+fn app() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "ok": "OK"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+fn plugin() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"plugin.title": "Plugin", "ok": "Okay"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"plugin.title": "外掛"}"#)
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn merge() {
+    let mut ctx = app();
+
+    assert!(matches!(
+        ctx.merge(plugin(), ConflictPolicy::Error),
+        Err(JSONGetTextBuildError::DuplicatedText { ref text, .. }) if text == "ok"
+    ));
+    assert!(ctx.get_text("plugin.title").is_none());
+
+    ctx.merge(plugin(), ConflictPolicy::KeepFirst).unwrap();
+
+    assert_eq!("Plugin", ctx.get_text("plugin.title").unwrap());
+    assert_eq!("外掛", ctx.get_text_with_key("zh_TW", "plugin.title").unwrap());
+    assert_eq!("OK", ctx.get_text("ok").unwrap());
+    // the text filled in the plugin is ignored
+    assert_eq!("OK", ctx.get_text_with_key("zh_TW", "ok").unwrap());
+
+    let mut ctx = app();
+
+    ctx.merge(plugin(), ConflictPolicy::Overwrite).unwrap();
+
+    assert_eq!("Okay", ctx.get_text("ok").unwrap());
+    // texts filled from the default key follow the new text
+    assert_eq!("Okay", ctx.get_text_with_key("zh_TW", "ok").unwrap());
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
+
+#[test]
+fn merge_text_not_in_default_key() {
+    let mut ctx = app();
+
+    let mut builder = JSONGetText::build("zh_TW");
+
+    builder.add_json("zh_TW", r#"{"bye": "再見"}"#).unwrap();
+
+    assert!(matches!(
+        ctx.merge(builder.build().unwrap(), ConflictPolicy::Overwrite),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { .. })
+    ));
+    assert!(ctx.get_text_with_key("zh_TW", "bye").is_none());
+}
+
+#[test]
+fn merge_build_steps() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "ok": "OK", "quote": "Say \"hi\"."}"#)
+        .unwrap()
+        .add_json("zh", r#"{"ok": "好"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .set_fallbacks("zh_TW", ["zh"])
+        .set_strictness(Strictness::Warn)
+        .normalize_quotation(true);
+
+    let mut ctx = builder.build().unwrap();
+
+    let mut builder = JSONGetText::build("zh");
+
+    builder.add_json("zh", r#"{"quote": "说\"嗨\"。", "bye": "再见"}"#).unwrap();
+
+    ctx.merge(builder.build().unwrap(), ConflictPolicy::Error).unwrap();
+
+    // the merged texts are normalized and filled into the keys which take texts from their key
+    assert_eq!("说“嗨”。", ctx.get_text_with_key("zh", "quote").unwrap());
+    assert_eq!("说“嗨”。", ctx.get_text_with_key("zh_TW", "quote").unwrap());
+    assert_eq!("好", ctx.get_text_with_key("zh_TW", "ok").unwrap());
+
+    // the extra text is kept by the strictness of this instance
+    assert_eq!("再见", ctx.get_text_with_key("zh", "bye").unwrap());
+    assert_eq!(
+        vec![ExtraText {
+            key: "zh".to_string(),
+            text: "bye".to_string(),
+        }],
+        ctx.get_extra_texts()
+    );
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn add_json_file_merge() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hi!", "bye": "Bye!"}"#).unwrap();
+
+    assert!(builder
+        .add_json_file_merge("en_US", "langs/en_US.json", ConflictPolicy::Error)
+        .is_err());
+
+    builder.add_json_file_merge("en_US", "langs/en_US.json", ConflictPolicy::KeepFirst).unwrap();
+    builder.add_json_file_merge("zh_TW", "langs/zh_TW.json", ConflictPolicy::Error).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hi!", ctx.get_text("hello").unwrap());
+    assert_eq!("Bye!", ctx.get_text("bye").unwrap());
+    assert_eq!("Rust!", ctx.get_text("rust").unwrap());
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
@@ -0,0 +1,28 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+#[test]
+fn migrated_texts() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"greeting": "Hello, world!"}"#).unwrap();
+    builder.add_json("zh_TW", r#"{"greeting": "哈囉，世界！"}"#).unwrap();
+    builder.add_migration("hello", "greeting");
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(Some("greeting"), ctx.get_migration("hello"));
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
+
+#[test]
+fn migration_target_not_found() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"greeting": "Hello, world!"}"#).unwrap();
+    builder.add_migration("hello", "greetings");
+
+    assert!(matches!(builder.build(), Err(JSONGetTextBuildError::MigrationTargetNotFound { .. })));
+}
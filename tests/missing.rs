@@ -0,0 +1,74 @@
+#![cfg(not(feature = "langid"))]
+
+use std::sync::{Arc, Mutex};
+
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+#[test]
+fn missing_handler() {
+    let misses = Arc::new(Mutex::new(Vec::new()));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    {
+        let misses = misses.clone();
+
+        builder.set_missing_handler(move |key, text| {
+            misses.lock().unwrap().push(format!("{}:{}", key, text));
+
+            Some(JSONGetTextValue::from_string(format!("⟦{}⟧", text)))
+        });
+    }
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello!", ctx.get_text("hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("⟦bye⟧", ctx.get_text("bye").unwrap());
+    assert_eq!("⟦bye⟧", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+    assert_eq!("⟦bye⟧", ctx.get_text_with_key("fr", "bye").unwrap());
+
+    let map = ctx.get_multiple_text_with_key("zh_TW", &["hello", "bye"]).unwrap();
+
+    assert_eq!("哈囉！", map["hello"]);
+    assert_eq!("⟦bye⟧", map["bye"]);
+
+    assert_eq!(vec!["en_US:bye", "zh_TW:bye", "en_US:bye", "zh_TW:bye"], *misses.lock().unwrap());
+}
+
+#[test]
+fn without_missing_handler() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(None, ctx.get_text("bye"));
+}
+
+#[test]
+fn get_text_or_default() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "menu": {"open": "Open", "save": "Save"}}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！", "menu": {"open": "開啟"}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello!", json_gettext::get_text_or_default!(ctx, "hello"));
+    assert_eq!("哈囉！", json_gettext::get_text_or_default!(ctx, "zh_TW", "hello"));
+    assert_eq!("Save", ctx.get_text_with_key_or_default("zh_TW", "menu.save"));
+    assert_eq!("Hello!", ctx.get_text_with_key_or_default("fr", "hello"));
+    assert_eq!("bye", ctx.get_text_or_default("bye"));
+    assert_eq!("menu.close", ctx.get_text_with_key_or_default("zh_TW", "menu.close"));
+}
@@ -0,0 +1,70 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::JSONGetText;
+use serde_json::json;
+
+#[test]
+fn to_model() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "@hello": {"description": "Greeting"}, "count": 2}"#,
+        )
+        .unwrap();
+    builder.add_json("zh_TW", r#"{"hello": "哈囉！"}"#).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let model = ctx.to_model();
+
+    assert_eq!("en_US", model.default_key.as_str());
+    assert_eq!(2, model.catalogs.len());
+    assert_eq!(
+        json!({"hello": "Hello!", "count": 2}),
+        serde_json::to_value(&model.catalogs["en_US"]).unwrap()
+    );
+    assert_eq!(
+        json!({"hello": "哈囉！", "count": 2}),
+        serde_json::to_value(&model.catalogs["zh_TW"]).unwrap()
+    );
+
+    assert_eq!(model, model.build().unwrap().to_model());
+}
+
+#[cfg(feature = "proptest")]
+mod round_trip {
+    use json_gettext::strategy::{catalog_model, check_round_trip};
+    use json_gettext::{JSONGetText, Key};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn build(model in catalog_model(Key::from("en_US"), vec![Key::from("zh_TW"), Key::from("fr")])) {
+            prop_assert_eq!(&model, &model.build().unwrap().to_model());
+        }
+
+        #[test]
+        fn export_minified(model in catalog_model(Key::from("en_US"), vec![Key::from("zh_TW"), Key::from("fr")])) {
+            check_round_trip(
+                &model,
+                |ctx| {
+                    ctx.get_keys()
+                        .into_iter()
+                        .map(|key| (key.to_string(), ctx.export_minified(key).unwrap()))
+                        .collect::<Vec<_>>()
+                },
+                |exported| {
+                    let mut builder = JSONGetText::build("en_US");
+
+                    for (key, json) in exported {
+                        builder.add_json_owned(key, json)?;
+                    }
+
+                    builder.build()
+                },
+            )?;
+        }
+    }
+}
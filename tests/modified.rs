@@ -0,0 +1,99 @@
+#![cfg(all(feature = "fs", not(feature = "langid")))]
+
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use json_gettext::{CatalogId, ConflictPolicy, JSONGetText};
+
+#[test]
+fn last_modified() {
+    let file_time = fs::metadata("langs/en_US.json").unwrap().modified().unwrap();
+    let ns_time = file_time + Duration::from_secs(60);
+    let zh_time = file_time + Duration::from_secs(120);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json_file("en_US", "langs/en_US.json")
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .add_json_ns("en_US", "errors", r#"{"oops": "Oops!"}"#)
+        .unwrap()
+        .set_last_modified("zh_TW", zh_time)
+        .set_last_modified_ns("en_US", "errors", ns_time);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(Some(file_time), ctx.last_modified("en_US"));
+    assert_eq!(Some(zh_time), ctx.last_modified("zh_TW"));
+    assert_eq!(Some(ns_time), ctx.last_modified_ns("en_US", "errors"));
+    assert_eq!(None, ctx.last_modified_ns("zh_TW", "errors"));
+    assert_eq!(None, ctx.last_modified("fr"));
+
+    let ids = |since: SystemTime| -> Vec<CatalogId> {
+        ctx.changed_since(since).into_iter().map(|(id, _)| id.clone()).collect()
+    };
+
+    assert_eq!(
+        vec![
+            CatalogId {
+                key: "en_US".into(),
+                namespace: None
+            },
+            CatalogId {
+                key: "en_US".into(),
+                namespace: Some("errors".to_string())
+            },
+            CatalogId {
+                key: "zh_TW".into(),
+                namespace: None
+            },
+        ],
+        ids(SystemTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        vec![CatalogId {
+            key: "zh_TW".into(),
+            namespace: None
+        }],
+        ids(ns_time)
+    );
+    assert!(ids(zh_time).is_empty());
+}
+
+#[test]
+fn last_modified_of_merged_contexts() {
+    let old_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+    let new_time = old_time + Duration::from_secs(60);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .set_last_modified("en_US", new_time)
+        .set_last_modified("zh_TW", old_time);
+
+    let mut ctx = builder.build().unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"plugin.title": "Plugin"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"plugin.title": "外掛"}"#)
+        .unwrap()
+        .set_last_modified("en_US", old_time)
+        .set_last_modified("zh_TW", new_time);
+
+    ctx.merge(builder.build().unwrap(), ConflictPolicy::Error).unwrap();
+
+    // the later time of both contexts is kept
+    assert_eq!(Some(new_time), ctx.last_modified("en_US"));
+    assert_eq!(Some(new_time), ctx.last_modified("zh_TW"));
+    assert_eq!(2, ctx.changed_since(old_time).len());
+    assert!(ctx.changed_since(new_time).is_empty());
+}
@@ -0,0 +1,217 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{
+    EmptyKind, EmptyTextPolicy, ExtraText, JSONGetText, JSONGetTextBuildError, JSONGetTextValue,
+    Strictness,
+};
+
+fn build() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn insert_text() {
+    let mut ctx = build();
+
+    ctx.insert_text("zh_TW", "rust", JSONGetTextValue::from_str("Rust！")).unwrap();
+    ctx.insert_text("en_US", "bye", JSONGetTextValue::from_str("Bye!")).unwrap();
+    ctx.insert_text("en_US", "greeting", JSONGetTextValue::from_str("{#hello} {#bye}")).unwrap();
+
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("Bye!", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+    assert_eq!("Hello! Bye!", ctx.get_text("greeting").unwrap());
+
+    assert!(matches!(
+        ctx.insert_text("zh_TW", "thanks", JSONGetTextValue::from_str("謝謝！")),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { .. })
+    ));
+
+    assert!(matches!(
+        ctx.insert_text("en_US", "broken", JSONGetTextValue::from_str("{#missing}")),
+        Err(JSONGetTextBuildError::MessageReferenceNotFound { .. })
+    ));
+
+    assert!(ctx.get_text("broken").is_none());
+}
+
+#[test]
+fn remove_text() {
+    let mut ctx = build();
+
+    assert_eq!("哈囉！", ctx.remove_text("zh_TW", "hello").unwrap().unwrap());
+    assert_eq!("Hello!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert!(ctx.remove_text("zh_TW", "hello").unwrap().is_none());
+    assert!(ctx.remove_text("fr", "hello").unwrap().is_none());
+
+    assert_eq!("Rust!", ctx.remove_text("en_US", "rust").unwrap().unwrap());
+    assert!(ctx.get_text("rust").is_none());
+    assert!(ctx.get_text_with_key("zh_TW", "rust").is_none());
+}
+
+#[test]
+fn add_language() {
+    let mut ctx = build();
+
+    let mut map = HashMap::new();
+
+    map.insert(String::from("hello"), JSONGetTextValue::from_str("Bonjour !"));
+
+    ctx.add_language("fr", map.clone()).unwrap();
+
+    assert_eq!("Bonjour !", ctx.get_text_with_key("fr", "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("fr", "rust").unwrap());
+
+    assert!(matches!(ctx.add_language("fr", map), Err(JSONGetTextBuildError::DuplicatedKey(_))));
+}
+
+#[test]
+fn remove_text_refills_from_fallbacks() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "color": "Color"}"#)
+        .unwrap()
+        .add_json("zh", r#"{"hello": "你好！", "color": "颜色"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .set_fallbacks("zh_TW", ["zh"]);
+
+    let mut ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉！", ctx.remove_text("zh_TW", "hello").unwrap().unwrap());
+    assert_eq!("你好！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+
+    // the keys which take texts from the key are filled again
+    assert_eq!("颜色", ctx.remove_text("zh", "color").unwrap().unwrap());
+    assert_eq!("Color", ctx.get_text_with_key("zh_TW", "color").unwrap());
+}
+
+#[test]
+fn remove_text_pointed_at() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"greeting": "Hello!", "guest": "guest", "welcome": "Welcome, {name|guest}!"}"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"greeting": "哈囉！"}"#)
+        .unwrap()
+        .add_migration("hello", "greeting");
+
+    let mut ctx = builder.build().unwrap();
+
+    assert!(matches!(
+        ctx.remove_text("en_US", "greeting"),
+        Err(JSONGetTextBuildError::MigrationTargetNotFound { .. })
+    ));
+
+    assert!(matches!(
+        ctx.remove_text("en_US", "guest"),
+        Err(JSONGetTextBuildError::PlaceholderDefaultNotFound { .. })
+    ));
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("guest", ctx.get_text("guest").unwrap());
+
+    assert_eq!("Welcome, {name|guest}!", ctx.remove_text("en_US", "welcome").unwrap().unwrap());
+    assert_eq!("guest", ctx.remove_text("en_US", "guest").unwrap().unwrap());
+    assert!(ctx.get_text_with_key("zh_TW", "guest").is_none());
+}
+
+#[test]
+fn insert_text_build_steps() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "quote": "Say \"hi\"."}"#)
+        .unwrap()
+        .add_json("zh", r#"{"hello": "你好！"}"#)
+        .unwrap()
+        .add_json("zh_TW", "{}")
+        .unwrap()
+        .add_json("de", "{}")
+        .unwrap()
+        .set_fallbacks("zh_TW", ["zh"])
+        .set_strictness(Strictness::Warn)
+        .set_empty_text_policy(EmptyKind::EmptyString, EmptyTextPolicy::Missing)
+        .normalize_quotation(true);
+
+    let mut ctx = builder.build().unwrap();
+
+    ctx.insert_text("de", "quote", JSONGetTextValue::from_str("Sag \"hallo\".")).unwrap();
+
+    assert_eq!("Sag „hallo“.", ctx.get_text_with_key("de", "quote").unwrap());
+
+    // an empty text is treated as missing
+    ctx.insert_text("de", "hello", JSONGetTextValue::from_str("")).unwrap();
+
+    assert_eq!("Hello!", ctx.get_text_with_key("de", "hello").unwrap());
+    assert!(ctx.get_empty_texts().iter().any(|empty| empty.key == "de" && empty.filled));
+
+    // a text inserted into a fallback key is filled into the keys which take texts from it
+    ctx.insert_text("zh", "quote", JSONGetTextValue::from_str("说\"嗨\"。")).unwrap();
+
+    assert_eq!("说“嗨”。", ctx.get_text_with_key("zh_TW", "quote").unwrap());
+
+    // an extra text is kept and reported by the strictness
+    ctx.insert_text("de", "thanks", JSONGetTextValue::from_str("Danke!")).unwrap();
+
+    assert_eq!("Danke!", ctx.get_text_with_key("de", "thanks").unwrap());
+    assert_eq!(
+        vec![ExtraText {
+            key: "de".to_string(),
+            text: "thanks".to_string(),
+        }],
+        ctx.get_extra_texts()
+    );
+
+    assert!(matches!(
+        ctx.insert_text("en_US", "files", JSONGetTextValue::from_str("{count ? \"files}")),
+        Err(JSONGetTextBuildError::InvalidConditional { .. })
+    ));
+    assert!(ctx.get_text("files").is_none());
+}
+
+#[test]
+fn add_language_build_steps() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "quote": "Say \"hi\"."}"#)
+        .unwrap()
+        .set_strictness(Strictness::Prune)
+        .normalize_quotation(true);
+
+    let mut ctx = builder.build().unwrap();
+
+    let mut map = HashMap::new();
+
+    map.insert(String::from("quote"), JSONGetTextValue::from_str("Dis \"salut\"."));
+    map.insert(String::from("bye"), JSONGetTextValue::from_str("Au revoir !"));
+
+    ctx.add_language("fr", map).unwrap();
+
+    assert_eq!("Dis «salut».", ctx.get_text_with_key("fr", "quote").unwrap());
+    assert_eq!("Hello!", ctx.get_text_with_key("fr", "hello").unwrap());
+    assert!(ctx.get_text_with_key("fr", "bye").is_none());
+    assert_eq!(
+        vec![ExtraText {
+            key: "fr".to_string(),
+            text: "bye".to_string(),
+        }],
+        ctx.get_extra_texts()
+    );
+}
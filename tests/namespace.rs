@@ -0,0 +1,49 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+#[test]
+fn namespaces() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello, world!"}"#)
+        .unwrap()
+        .add_json_ns("en_US", "errors", r#"{"not_found": "Not found", "@not_found": {}}"#)
+        .unwrap()
+        .add_json_ns("en_US", "errors", r#"{"forbidden": "Forbidden"}"#)
+        .unwrap()
+        .add_json_ns("en_US", "emails", r#"{"subject": "Welcome, {#hello}"}"#)
+        .unwrap()
+        .add_json_ns("zh_TW", "errors", r#"{"not_found": "找不到"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Not found", get_text_ns!(ctx, "errors", "not_found").unwrap());
+    assert_eq!("Forbidden", get_text_ns!(ctx, "errors", "forbidden").unwrap());
+    assert_eq!("Welcome, Hello, world!", get_text_ns!(ctx, "emails", "subject").unwrap());
+    assert_eq!("找不到", get_text_ns!(ctx, "zh_TW", "errors", "not_found").unwrap());
+    assert_eq!("Hello, world!", get_text!(ctx, "zh_TW", "hello").unwrap());
+    assert_eq!("找不到", get_text!(ctx, "zh_TW", "errors.not_found").unwrap());
+    assert!(get_text_ns!(ctx, "ui", "not_found").is_none());
+}
+
+#[test]
+fn duplicated_text() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"errors.not_found": "Not found"}"#)
+        .unwrap()
+        .add_json_ns("en_US", "errors", r#"{"not_found": "Not found"}"#)
+        .unwrap();
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::DuplicatedText { ref text, .. }) if text == "errors.not_found"
+    ));
+}
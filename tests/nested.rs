@@ -0,0 +1,69 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::JSONGetText;
+
+#[test]
+fn dot_path() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "menu": {"file": {"open": "Open", "save": "Save"}, "edit.undo": "Undo"},
+                "menu.help": "Help",
+                "count": 1
+            }"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"menu": {"file": {"open": "開啟"}}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Open", get_text!(ctx, "menu.file.open").unwrap());
+    assert_eq!("Undo", get_text!(ctx, "menu.edit.undo").unwrap());
+    assert_eq!("Help", get_text!(ctx, "menu.help").unwrap());
+    assert_eq!("開啟", get_text!(ctx, "zh_TW", "menu.file.open").unwrap());
+
+    // paths missing in a key are looked up in the default key
+    assert_eq!("Save", get_text!(ctx, "zh_TW", "menu.file.save").unwrap());
+
+    assert!(get_text!(ctx, "menu.file.close").is_none());
+    assert!(get_text!(ctx, "count.value").is_none());
+    assert!(get_text!(ctx, "menu.").is_none());
+}
+
+#[test]
+fn fill_missing_from_default() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "menu": {"file": {"open": "Open", "save": "Save"}, "edit": "Edit", "@file": {}},
+                "hello": "Hello!"
+            }"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"menu": {"file": {"open": "開啟"}}}"#)
+        .unwrap()
+        .fill_missing_from_default(true);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("開啟", get_text!(ctx, "zh_TW", "menu.file.open").unwrap());
+    assert_eq!("Save", get_text!(ctx, "zh_TW", "menu.file.save").unwrap());
+    assert_eq!("Hello!", get_text!(ctx, "zh_TW", "hello").unwrap());
+
+    let menu = get_text!(ctx, "zh_TW", "menu").unwrap().to_json_string();
+
+    assert_eq!(
+        serde_json::json!({"file": {"open": "開啟", "save": "Save"}, "edit": "Edit"}),
+        serde_json::from_str::<serde_json::Value>(&menu).unwrap()
+    );
+}
@@ -0,0 +1,144 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{extract_openapi_texts, JSONGetText};
+use serde_json::json;
+
+#[test]
+fn localize_openapi() {
+    let document = json!({
+        "openapi": "3.1.0",
+        "info": {"title": "Pet Store", "version": "1.0.0"},
+        "paths": {
+            "/pets/{id}": {
+                "get": {
+                    "operationId": "getPet",
+                    "summary": "Get a pet",
+                    "parameters": [{"name": "id", "in": "path", "description": "The ID of the pet"}],
+                    "responses": {"200": {"description": "A pet"}}
+                },
+                "delete": {"summary": "No operation ID"}
+            }
+        },
+        "components": {
+            "schemas": {
+                "Pet": {"description": "A pet", "properties": {"name": {"type": "string"}}}
+            }
+        }
+    });
+
+    let texts = extract_openapi_texts("api", &document);
+
+    assert_eq!(
+        vec![
+            "api.info.title",
+            "api.operations.getPet.parameters.id",
+            "api.operations.getPet.responses.200",
+            "api.operations.getPet.summary",
+            "api.schemas.Pet.description",
+        ],
+        texts.keys().collect::<Vec<_>>()
+    );
+    assert_eq!("Get a pet", texts["api.operations.getPet.summary"]);
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_serialize("en_US", &texts).unwrap();
+
+    builder
+        .add_json(
+            "zh_TW",
+            r#"{
+                "api.info.title": "寵物商店",
+                "api.operations.getPet.summary": "取得寵物",
+                "api.operations.getPet.parameters.id": "寵物的 ID"
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let localized = ctx.localize_openapi("zh_TW", "api", &document);
+
+    assert_eq!("寵物商店", localized["info"]["title"]);
+    assert_eq!("取得寵物", localized["paths"]["/pets/{id}"]["get"]["summary"]);
+    assert_eq!(
+        "寵物的 ID",
+        localized["paths"]["/pets/{id}"]["get"]["parameters"][0]["description"]
+    );
+    assert_eq!("A pet", localized["components"]["schemas"]["Pet"]["description"]);
+    assert_eq!("No operation ID", localized["paths"]["/pets/{id}"]["delete"]["summary"]);
+
+    assert_eq!(2, ctx.localize_openapi_all("api", &document).len());
+}
+
+#[test]
+fn extract_openapi_tags_and_escaped_names() {
+    let document = json!({
+        "info": {"title": "Shop", "description": 42},
+        "tags": [{"name": "orders", "description": "Order management"}, {"description": "No name"}],
+        "paths": {
+            "/orders/{id}": {
+                "post": {
+                    "operationId": "createOrder",
+                    "description": "Create an order",
+                    "responses": {"4XX": {"description": "A client error"}}
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "Order": {
+                    "title": "Order",
+                    "properties": {
+                        "total": {"description": "The total price"},
+                        "id": {"type": "integer"}
+                    }
+                }
+            }
+        }
+    });
+
+    let texts = extract_openapi_texts("shop", &document);
+
+    // a description which is not a string and a tag without a name are skipped
+    assert_eq!(
+        vec![
+            "shop.info.title",
+            "shop.operations.createOrder.description",
+            "shop.operations.createOrder.responses.4XX",
+            "shop.schemas.Order.properties.total",
+            "shop.schemas.Order.title",
+            "shop.tags.orders.description",
+        ],
+        texts.keys().collect::<Vec<_>>()
+    );
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_serialize("en_US", &texts).unwrap();
+
+    builder
+        .add_json(
+            "de",
+            r#"{
+                "shop.tags.orders.description": "Bestellverwaltung",
+                "shop.schemas.Order.properties.total": "Der Gesamtpreis"
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let localized = ctx.localize_openapi("de", "shop", &document);
+
+    assert_eq!("Bestellverwaltung", localized["tags"][0]["description"]);
+    assert_eq!("No name", localized["tags"][1]["description"]);
+    assert_eq!(
+        "Der Gesamtpreis",
+        localized["components"]["schemas"]["Order"]["properties"]["total"]["description"]
+    );
+    assert_eq!(42, localized["info"]["description"]);
+
+    // a key which is not in context uses the default key, so the document is unchanged
+    assert_eq!(document, ctx.localize_openapi("fr", "shop", &document));
+}
@@ -0,0 +1,135 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, PluralCategory};
+
+#[test]
+fn plural() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "apple": {"one": "{count} apple", "other": "{count} apples"},
+                "rust": "Rust!"
+            }"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"apple": {"other": "{count} 顆蘋果"}}"#)
+        .unwrap()
+        .add_json(
+            "pl",
+            r#"{"apple": {"one": "{count} jabłko", "few": "{count} jabłka", "many": "{count} jabłek"}}"#,
+        )
+        .unwrap()
+        .add_json("ar", r#"{"apple": {"zero": "لا تفاحات", "other": "{count} تفاحة"}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("{count} apple", ctx.get_plural_text("en_US", "apple", 1).unwrap());
+    assert_eq!("{count} apples", ctx.get_plural_text("en_US", "apple", 0).unwrap());
+    assert_eq!("{count} apples", ctx.get_plural_text("de", "apple", 2).unwrap());
+
+    assert_eq!("Rust!", ctx.get_plural_text("en_US", "rust", 2).unwrap());
+    assert!(ctx.get_plural_text("en_US", "missing", 2).is_none());
+
+    assert_eq!(PluralCategory::One, ctx.plural_category("en_US", 1));
+    assert_eq!("many", PluralCategory::Many.as_str());
+
+    #[cfg(feature = "plural_rules")]
+    {
+        assert_eq!("{count} 顆蘋果", ctx.get_plural_text("zh_TW", "apple", 1).unwrap());
+
+        assert_eq!("{count} jabłko", ctx.get_plural_text("pl", "apple", 1).unwrap());
+        assert_eq!("{count} jabłka", ctx.get_plural_text("pl", "apple", 22).unwrap());
+        assert_eq!("{count} jabłek", ctx.get_plural_text("pl", "apple", 5).unwrap());
+
+        assert_eq!("لا تفاحات", ctx.get_plural_text("ar", "apple", 0).unwrap());
+        assert_eq!("{count} تفاحة", ctx.get_plural_text("ar", "apple", 3).unwrap());
+
+        assert_eq!(PluralCategory::Few, ctx.plural_category("pl", 3));
+        assert_eq!(PluralCategory::Other, ctx.plural_category("zh_TW", 1));
+        assert_eq!(PluralCategory::Two, ctx.plural_category("ar", 2));
+    }
+
+    // without the CLDR rules, every language is pluralized like English
+    #[cfg(not(feature = "plural_rules"))]
+    assert_eq!(PluralCategory::One, ctx.plural_category("pl", 1));
+}
+
+#[cfg(feature = "plural_rules")]
+#[test]
+fn regional_plural_rules() {
+    use std::collections::HashMap;
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"apple": {"one": "{count} apple", "other": "{count} apples"}}"#)
+        .unwrap()
+        .add_json("pt", r#"{"apple": {"one": "{count} maçã", "other": "{count} maçãs"}}"#)
+        .unwrap()
+        .add_json("pt_PT", r#"{"apple": {"one": "{count} maçã", "other": "{count} maçãs"}}"#)
+        .unwrap();
+
+    let mut ctx = builder.build().unwrap();
+
+    // 0 is `one` in Portuguese, but `other` in European Portuguese
+    assert_eq!(PluralCategory::One, ctx.plural_category("pt", 0));
+    assert_eq!(PluralCategory::Other, ctx.plural_category("pt_PT", 0));
+    assert_eq!("{count} maçãs", ctx.get_plural_text("pt_PT", "apple", 0).unwrap());
+
+    // a region without rules of its own uses the rules of its language
+    assert_eq!(PluralCategory::One, ctx.plural_category("en_US", 1));
+
+    // the rules of a key added after building are cached too
+    ctx.add_language("pt-PT", HashMap::new()).unwrap();
+
+    assert_eq!(PluralCategory::Other, ctx.plural_category("pt-PT", 0));
+}
+
+#[test]
+fn format_plural_text() {
+    use std::collections::HashMap;
+
+    use json_gettext::JSONGetTextValue;
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"apple": {"one": "{count} apple for {name}", "other": "{count} apples for {name}"}}"#,
+        )
+        .unwrap()
+        .add_json("de", r#"{"apple": {"one": "{count} Apfel", "other": "{count} Äpfel"}}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let mut args = HashMap::new();
+    args.insert("name", JSONGetTextValue::from_str("Ron"));
+
+    assert_eq!(
+        Some("1 apple for Ron"),
+        ctx.format_plural_text_with_key("en_US", "apple", 1, &args).as_deref()
+    );
+    assert_eq!(
+        Some("1,000 apples for Ron"),
+        ctx.format_plural_text_with_key("en_US", "apple", 1000, &args).as_deref()
+    );
+    assert_eq!(
+        Some("1.000 Äpfel"),
+        ctx.format_plural_text_with_key("de", "apple", 1000, &args).as_deref()
+    );
+
+    // a `count` argument is written instead of the count
+    args.insert("count", JSONGetTextValue::from_str("many"));
+
+    assert_eq!(
+        Some("many apples for Ron"),
+        ctx.format_plural_text_with_key("en_US", "apple", 2, &args).as_deref()
+    );
+    assert_eq!(None, ctx.format_plural_text_with_key("en_US", "missing", 2, &args));
+}
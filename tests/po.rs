@@ -0,0 +1,127 @@
+#![cfg(all(feature = "po", not(feature = "langid")))]
+
+#[cfg(feature = "fs")]
+use std::fs;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+const PL_PO: &str = r#"# Polish translations
+msgid ""
+msgstr ""
+"Language: pl\n"
+"Plural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+#: src/main.rs:10
+msgid "Hello, {name}!"
+msgstr "Cześć, {name}!"
+
+msgid "A \"quoted\"\n"
+"multi-line text"
+msgstr "Tekst \"w cudzysłowie\"\n"
+"w wielu liniach"
+
+msgctxt "menu"
+msgid "Open"
+msgstr "Otwórz"
+
+msgid "{count} apple"
+msgid_plural "{count} apples"
+msgstr[0] "{count} jabłko"
+msgstr[1] "{count} jabłka"
+msgstr[2] "{count} jabłek"
+
+#, fuzzy
+msgid "Rust!"
+msgstr "Rdza!"
+
+msgid "Bye!"
+msgstr ""
+
+#~ msgid "Obsolete"
+#~ msgstr "Przestarzały"
+"#;
+
+fn builder() -> json_gettext::JSONGetTextBuilder<'static> {
+    let mut builder = JSONGetText::build("en");
+
+    builder
+        .add_json(
+            "en",
+            r#"{
+                "Hello, {name}!": "Hello, {name}!",
+                "A \"quoted\"\nmulti-line text": "A \"quoted\"\nmulti-line text",
+                "menu\u0004Open": "Open",
+                "{count} apple": {"one": "{count} apple", "other": "{count} apples"},
+                "Rust!": "Rust!",
+                "Bye!": "Bye!"
+            }"#,
+        )
+        .unwrap();
+
+    builder
+}
+
+#[test]
+fn add_po() {
+    let mut builder = builder();
+
+    builder.add_po("pl", PL_PO).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Cześć, {name}!", ctx.get_text_with_key("pl", "Hello, {name}!").unwrap());
+    assert_eq!(
+        "Tekst \"w cudzysłowie\"\nw wielu liniach",
+        ctx.get_text_with_key("pl", "A \"quoted\"\nmulti-line text").unwrap()
+    );
+    assert_eq!("Otwórz", ctx.get_text_with_key("pl", "menu\u{4}Open").unwrap());
+    assert_eq!("{count} jabłko", ctx.get_plural_text("pl", "{count} apple", 1).unwrap());
+    assert_eq!("{count} jabłka", ctx.get_plural_text("pl", "{count} apple", 3).unwrap());
+    assert_eq!("{count} jabłek", ctx.get_plural_text("pl", "{count} apple", 5).unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("pl", "Rust!").unwrap());
+    assert_eq!("Bye!", ctx.get_text_with_key("pl", "Bye!").unwrap());
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn add_po_file() {
+    let dir = std::env::temp_dir().join("json-gettext-add-po-file");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("fr.po"), "msgid \"{count} apple\"\nmsgid_plural \"{count} apples\"\nmsgstr[0] \"{count} pomme\"\nmsgstr[1] \"{count} pommes\"\n").unwrap();
+
+    let mut builder = builder();
+
+    builder.add_po_file("fr", dir.join("fr.po")).unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("{count} pomme", ctx.get_plural_text("fr", "{count} apple", 1).unwrap());
+    assert_eq!("{count} pommes", ctx.get_plural_text("fr", "{count} apple", 2).unwrap());
+    assert_eq!("{count} pommes", ctx.get_plural_text("fr", "{count} apple", 1_000_000).unwrap());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn invalid_po() {
+    let mut builder = builder();
+
+    assert!(matches!(
+        builder.add_po("pl", "msgid \"Hello\"\nmsgstr \"Cześć\\q\"\n"),
+        Err(JSONGetTextBuildError::InvalidPO {
+            line: 2,
+            ..
+        })
+    ));
+
+    assert!(matches!(
+        builder.add_po("pl", "msgid \"Hello\"\nmsgfoo \"Cześć\"\n"),
+        Err(JSONGetTextBuildError::InvalidPO {
+            line: 2,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,99 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextValue, PreflightFailure};
+
+#[test]
+fn preflight() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(Ok(()), ctx.preflight(&["en_US", "zh_TW"], &["hello"]));
+
+    let failures = ctx.preflight(&["en_US", "zh_TW", "fr"], &["hello", "rust", "bye"]).unwrap_err();
+
+    assert_eq!(
+        vec![
+            PreflightFailure::MissingText {
+                key: "en_US".to_string(),
+                text: "bye".to_string()
+            },
+            PreflightFailure::UntranslatedText {
+                key: "zh_TW".to_string(),
+                text: "rust".to_string()
+            },
+            PreflightFailure::MissingText {
+                key: "zh_TW".to_string(),
+                text: "bye".to_string()
+            },
+            PreflightFailure::MissingLocale {
+                key: "fr".to_string()
+            },
+        ],
+        failures
+    );
+
+    assert_eq!(
+        r#"{"kind":"untranslated_text","key":"zh_TW","text":"rust"}"#,
+        serde_json::to_string(&failures[1]).unwrap()
+    );
+    assert_eq!("The locale `fr` is missing.", failures[3].to_string());
+}
+
+#[test]
+fn preflight_fallbacks_and_mutations() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("es", r#"{"hello": "¡Hola!", "rust": "¡Rust!"}"#)
+        .unwrap()
+        .add_json("es_MX", r#"{"hello": "¡Qué onda!"}"#)
+        .unwrap()
+        .set_fallbacks("es_MX", ["es"]);
+
+    let mut ctx = builder.build().unwrap();
+
+    // a text filled from a fallback key is not translated by the key
+    assert_eq!(
+        Err(vec![PreflightFailure::UntranslatedText {
+            key: "es_MX".to_string(),
+            text: "rust".to_string(),
+        }]),
+        ctx.preflight(&["es", "es_MX"], &["hello", "rust"])
+    );
+
+    ctx.insert_text("es_MX", "rust", JSONGetTextValue::from_str("¡Rust!")).unwrap();
+
+    assert_eq!(Ok(()), ctx.preflight(&["es", "es_MX"], &["hello", "rust"]));
+
+    // nothing to check passes
+    assert_eq!(Ok(()), ctx.preflight::<str, &str>(&[], &[]));
+}
+
+#[test]
+fn preflight_failure_messages() {
+    let missing = PreflightFailure::MissingText {
+        key: "de".to_string(),
+        text: "bye".to_string(),
+    };
+
+    assert_eq!(
+        r#"{"kind":"missing_text","key":"de","text":"bye"}"#,
+        serde_json::to_string(&missing).unwrap()
+    );
+    assert!(missing.to_string().contains("bye"));
+
+    let group = PreflightFailure::UnknownLocaleGroup {
+        group: "APAC".to_string(),
+    };
+
+    assert!(group.to_string().contains("APAC"));
+}
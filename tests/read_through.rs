@@ -0,0 +1,382 @@
+#![cfg(not(feature = "langid"))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use json_gettext::{JSONGetText, Key, ReadThroughOptions};
+
+#[test]
+fn secondary_context() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    {
+        let calls = calls.clone();
+
+        builder.set_secondary_context(
+            move |key: &Key, text: &str| {
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                match (key.0.as_str(), text) {
+                    ("zh_TW", "rust") => Some("Rust！".to_string()),
+                    ("en_US", "slow") => {
+                        thread::sleep(Duration::from_millis(300));
+
+                        Some("Slow!".to_string())
+                    }
+                    _ => None,
+                }
+            },
+            ReadThroughOptions {
+                timeout: Duration::from_millis(100),
+                ..ReadThroughOptions::default()
+            },
+        );
+    }
+
+    let ctx = builder.build().unwrap();
+
+    // own texts are not looked up
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!(0, calls.load(Ordering::SeqCst));
+
+    // filled texts are
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    // misses are cached too
+    assert!(ctx.get_text("bye").is_none());
+    assert!(ctx.get_text("bye").is_none());
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+
+    // a slow lookup times out, and its late result is cached
+    assert!(ctx.get_text("slow").is_none());
+
+    thread::sleep(Duration::from_millis(400));
+
+    assert_eq!("Slow!", ctx.get_text("slow").unwrap());
+    assert_eq!(3, calls.load(Ordering::SeqCst));
+
+    ctx.clear_secondary_cache();
+
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!(4, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn negative_ttl() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+
+    {
+        let calls = calls.clone();
+
+        builder.set_secondary_context(
+            move |_: &Key, _: &str| {
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                None
+            },
+            ReadThroughOptions {
+                negative_ttl: Duration::ZERO,
+                ..ReadThroughOptions::default()
+            },
+        );
+    }
+
+    let ctx = builder.build().unwrap();
+
+    assert!(ctx.get_text("bye").is_none());
+    assert!(ctx.get_text("bye").is_none());
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn pending_expires() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+
+    {
+        let calls = calls.clone();
+
+        builder.set_secondary_context(
+            move |_: &Key, text: &str| {
+                // the first lookup hangs, and the second one panics
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => thread::sleep(Duration::from_millis(300)),
+                    1 => panic!("the secondary context is down"),
+                    _ => (),
+                }
+
+                Some(text.to_uppercase())
+            },
+            ReadThroughOptions {
+                timeout: Duration::from_millis(50),
+                ..ReadThroughOptions::default()
+            },
+        );
+    }
+
+    let ctx = Arc::new(builder.build().unwrap());
+
+    let hanging = {
+        let ctx = ctx.clone();
+
+        thread::spawn(move || ctx.get_text("bye").is_none())
+    };
+
+    thread::sleep(Duration::from_millis(10));
+
+    // the lookup is in progress, so this one misses without waiting
+    assert!(ctx.get_text("bye").is_none());
+    assert!(hanging.join().unwrap());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    // the lookup has timed out, so the text is looked up again
+    thread::sleep(Duration::from_millis(10));
+
+    assert!(ctx.get_text("bye").is_none());
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+
+    thread::sleep(Duration::from_millis(100));
+
+    assert_eq!("BYE", ctx.get_text("bye").unwrap());
+    assert_eq!(3, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn clear_pending() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+
+    {
+        let calls = calls.clone();
+
+        builder.set_secondary_context(
+            move |_: &Key, _: &str| {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+
+                if call == 0 {
+                    thread::sleep(Duration::from_millis(300));
+                }
+
+                Some(format!("Bye {}!", call))
+            },
+            ReadThroughOptions {
+                timeout: Duration::from_secs(1),
+                ..ReadThroughOptions::default()
+            },
+        );
+    }
+
+    let ctx = Arc::new(builder.build().unwrap());
+
+    let slow = {
+        let ctx = ctx.clone();
+
+        thread::spawn(move || ctx.get_text("bye").map(|value| value.to_string()))
+    };
+
+    thread::sleep(Duration::from_millis(100));
+
+    // the pending lookup is cleared, so the text is looked up again
+    ctx.clear_secondary_cache();
+
+    assert_eq!("Bye 1!", ctx.get_text("bye").unwrap());
+    assert_eq!(Some("Bye 0!".to_string()), slow.join().unwrap());
+
+    // the result of the cleared lookup is not cached
+    assert_eq!("Bye 1!", ctx.get_text("bye").unwrap());
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn bounded_workers() {
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello!"}"#).unwrap();
+
+    {
+        let running = running.clone();
+        let max_running = max_running.clone();
+
+        builder.set_secondary_context(
+            move |_: &Key, _: &str| {
+                let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+
+                max_running.fetch_max(n, Ordering::SeqCst);
+
+                thread::sleep(Duration::from_millis(20));
+
+                running.fetch_sub(1, Ordering::SeqCst);
+
+                None
+            },
+            ReadThroughOptions {
+                timeout: Duration::from_millis(10),
+                workers: 2,
+                ..ReadThroughOptions::default()
+            },
+        );
+    }
+
+    let ctx = builder.build().unwrap();
+
+    for i in 0..100 {
+        assert!(ctx.get_text(format!("text {}", i)).is_none());
+    }
+
+    thread::sleep(Duration::from_millis(100));
+
+    assert!(max_running.load(Ordering::SeqCst) <= 2);
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn write_back() {
+    use std::fs;
+
+    let path = std::env::temp_dir().join("json-gettext-write-back.jsonl");
+
+    let _ = fs::remove_file(&path);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let build = |remote: bool| {
+        let calls = calls.clone();
+
+        let mut builder = JSONGetText::build("en_US");
+
+        builder
+            .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+            .unwrap()
+            .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+            .unwrap()
+            .set_secondary_context(
+                move |key: &Key, text: &str| {
+                    calls.fetch_add(1, Ordering::SeqCst);
+
+                    match (key.0.as_str(), text, remote) {
+                        ("zh_TW", "rust", true) => Some("Rust！".to_string()),
+                        _ => None,
+                    }
+                },
+                ReadThroughOptions::default(),
+            )
+            .set_write_back_file(&path)
+            .unwrap();
+
+        builder.build().unwrap()
+    };
+
+    let ctx = build(true);
+
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+    // after a restart, the text is served from the write-back file
+    let ctx = build(false);
+
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .add_write_back_file(&path)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn write_back_broken_lines() {
+    use std::fs;
+
+    let path = std::env::temp_dir().join("json-gettext-write-back-broken.jsonl");
+
+    // a crash left a partial line, and a later run appended after it
+    fs::write(
+        &path,
+        concat!(
+            r#"{"key": "zh_TW", "text": "hello", "value": "哈囉！"}"#,
+            "\n",
+            r#"{"key": "zh_TW", "text": "rust", "val"#,
+            r#"{"key": "zh_TW", "text": "rust", "value": "Rust！"}"#,
+            "\n",
+            "not json\n",
+            r#"{"key": "zh_TW", "text": "bye", "val"#,
+        ),
+    )
+    .unwrap();
+
+    let build = || {
+        let mut builder = JSONGetText::build("en_US");
+
+        builder
+            .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!", "bye": "Bye!"}"#)
+            .unwrap()
+            .add_json("zh_TW", "{}")
+            .unwrap()
+            .set_secondary_context(
+                |_: &Key, text: &str| (text == "bye").then(|| "再見！".to_string()),
+                ReadThroughOptions::default(),
+            )
+            .set_write_back_file(&path)
+            .unwrap();
+
+        builder.build().unwrap()
+    };
+
+    let ctx = build();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("再見！", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+
+    // the new entry is not appended to the partial last line
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!", "bye": "Bye!"}"#)
+        .unwrap()
+        .add_write_back_file(&path)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("再見！", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,75 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+#[test]
+fn inline_references() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r##"{
+                "common.appName": "Ron's App",
+                "welcome": "Welcome to {#common.appName}, {name}!",
+                "about": "About {#welcome}",
+                "escaped": "{{#common.appName}}",
+                "count": 3,
+                "items": "{#count} items"
+            }"##,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"common.appName": "榮的應用程式", "about": "關於「{#welcome}」"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Welcome to Ron's App, {name}!", ctx.get_text("welcome").unwrap());
+    assert_eq!("About Welcome to Ron's App, {name}!", ctx.get_text("about").unwrap());
+    assert_eq!("{{#common.appName}}", ctx.get_text("escaped").unwrap());
+    assert_eq!("3 items", ctx.get_text("items").unwrap());
+
+    // a text filled from the default key refers to the texts of its own key
+    assert_eq!(
+        "Welcome to 榮的應用程式, {name}!",
+        ctx.get_text_with_key("zh_TW", "welcome").unwrap()
+    );
+    assert_eq!(
+        "關於「Welcome to 榮的應用程式, {name}!」",
+        ctx.get_text_with_key("zh_TW", "about").unwrap()
+    );
+}
+
+#[test]
+fn reference_errors() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r##"{"welcome": "Welcome to {#appName}!"}"##).unwrap();
+
+    match builder.build() {
+        Err(JSONGetTextBuildError::MessageReferenceNotFound {
+            text,
+            reference,
+            ..
+        }) => {
+            assert_eq!("welcome", text);
+            assert_eq!("appName", reference);
+        }
+        _ => unreachable!(),
+    }
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r##"{"a": "{#b}", "b": "{#c}", "c": "{#a}", "d": "{#a}"}"##).unwrap();
+
+    match builder.build() {
+        Err(JSONGetTextBuildError::MessageReferenceCycle {
+            texts,
+            ..
+        }) => {
+            assert_eq!(4, texts.len());
+            assert_eq!(texts[0], texts[3]);
+        }
+        _ => unreachable!(),
+    }
+}
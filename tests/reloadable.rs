@@ -0,0 +1,121 @@
+#![cfg(all(feature = "watch", not(feature = "langid")))]
+
+use std::fs;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use json_gettext::{JSONGetText, JSONGetTextReloadable, ReloadStats};
+
+fn wait_for<F: Fn() -> bool>(f: F) -> bool {
+    let start = Instant::now();
+
+    while start.elapsed() < Duration::from_secs(10) {
+        if f() {
+            return true;
+        }
+
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    false
+}
+
+#[test]
+fn reloadable() {
+    let dir = std::env::temp_dir().join("json-gettext-reloadable");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("en_US.json"), r#"{"hello": "Hello!", "rust": "Rust!"}"#).unwrap();
+    fs::write(dir.join("zh_TW.json"), r#"{"hello": "哈囉！"}"#).unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json_file("en_US", dir.join("en_US.json")).unwrap();
+    builder.add_json_file("zh_TW", dir.join("zh_TW.json")).unwrap();
+    builder.add_json("fr", r#"{"hello": "Bonjour !"}"#).unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+
+    let ctx = JSONGetTextReloadable::with_error_handler(builder, move |err| {
+        let _ = sender.send(err.to_string());
+    })
+    .unwrap();
+
+    assert_eq!("哈囉！", ctx.read().get_text_with_key("zh_TW", "hello").unwrap());
+
+    let old = ctx.read();
+
+    fs::write(dir.join("zh_TW.json"), r#"{"hello": "哈囉，世界！", "rust": "Rust！"}"#).unwrap();
+
+    assert!(wait_for(|| "Rust！" == ctx.read().get_text_with_key("zh_TW", "rust").unwrap()));
+    assert_eq!("哈囉，世界！", ctx.read().get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Bonjour !", ctx.read().get_text_with_key("fr", "hello").unwrap());
+
+    // a guard keeps the instance which it was taken from
+    assert_eq!("哈囉！", old.get_text_with_key("zh_TW", "hello").unwrap());
+
+    // an invalid file keeps the current instance
+    while receiver.try_recv().is_ok() {}
+
+    fs::write(dir.join("zh_TW.json"), r#"{"hello": "#).unwrap();
+
+    assert!(receiver.recv_timeout(Duration::from_secs(10)).is_ok());
+    assert_eq!("Rust！", ctx.read().get_text_with_key("zh_TW", "rust").unwrap());
+
+    assert!(ctx.reload().is_err());
+
+    fs::write(dir.join("zh_TW.json"), r#"{"hello": "嗨！"}"#).unwrap();
+
+    assert!(wait_for(|| "嗨！" == ctx.read().get_text_with_key("zh_TW", "hello").unwrap()));
+
+    drop(ctx);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn debounce() {
+    let dir = std::env::temp_dir().join("json-gettext-reloadable-debounce");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("en_US.json"), r#"{"hello": "Hello!"}"#).unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json_file("en_US", dir.join("en_US.json"))
+        .unwrap()
+        .set_reload_quiet_period(Duration::from_millis(300));
+
+    let ctx = JSONGetTextReloadable::new(builder).unwrap();
+
+    assert_eq!(ReloadStats::default(), ctx.stats());
+
+    // an editor writing a file in chunks
+    for hello in ["Hi", "Hi!", "Hi!!"] {
+        fs::write(dir.join("en_US.json"), format!(r#"{{"hello": "{}"}}"#, hello)).unwrap();
+    }
+
+    assert!(wait_for(|| ctx.stats().reloads > 0));
+    assert_eq!("Hi!!", ctx.read().get_text("hello").unwrap());
+
+    let stats = ctx.stats();
+
+    assert!(stats.coalesced_events > 0);
+    assert_eq!(0, stats.failures);
+    assert!(stats.last_duration.is_some());
+
+    let health = ctx.health_summary().last_reload.unwrap();
+
+    assert!(health.ok);
+    assert!(health.finished_at > 0);
+
+    drop(ctx);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,133 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{
+    ConflictPolicy, JSONGetText, JSONGetTextBuildError, JSONGetTextValue, Key, ScanVerdict,
+    SharedJSONGetText,
+};
+
+fn scanner(_key: &Key, _text: &str, value: &JSONGetTextValue) -> ScanVerdict {
+    match value.as_str() {
+        Some(s) if s.contains("damn") => ScanVerdict::Quarantine(String::from("profanity")),
+        Some(s) if s.contains('@') => ScanVerdict::Reject(String::from("email address")),
+        _ => ScanVerdict::Accept,
+    }
+}
+
+#[test]
+fn quarantine() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello, world!"}"#).unwrap();
+    builder.add_json("zh_TW", r#"{"hello": "damn"}"#).unwrap();
+    builder.set_content_scanner(scanner);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello, world!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+
+    let quarantined = ctx.get_quarantined_texts();
+
+    assert_eq!(1, quarantined.len());
+    assert_eq!("zh_TW", quarantined[0].key);
+    assert_eq!("profanity", quarantined[0].reason);
+}
+
+#[test]
+fn reject() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello, len@magiclen.org!"}"#).unwrap();
+    builder.set_content_scanner(scanner);
+
+    assert!(matches!(builder.build(), Err(JSONGetTextBuildError::ContentRejected { .. })));
+}
+
+fn build() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "Hello, world!", "rust": "Rust!"}"#).unwrap();
+    builder.add_json("zh_TW", r#"{"hello": "哈囉，世界！"}"#).unwrap();
+    builder.set_content_scanner(scanner);
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn scan_inserted_texts() {
+    let mut ctx = build();
+
+    ctx.insert_text("zh_TW", "rust", JSONGetTextValue::from_str("damn")).unwrap();
+
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!(1, ctx.get_quarantined_texts().len());
+    assert_eq!("rust", ctx.get_quarantined_texts()[0].text);
+
+    // a rejected text leaves the instance unchanged
+    let before = ctx.clone();
+
+    assert!(matches!(
+        ctx.insert_text("en_US", "hello", JSONGetTextValue::from_str("len@magiclen.org")),
+        Err(JSONGetTextBuildError::ContentRejected { .. })
+    ));
+    assert_eq!(before, ctx);
+
+    // a text quarantined in the default key is dropped from the other keys too
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"hello": "damn it"}"#).unwrap();
+    builder.add_json("de", r#"{"hello": "Hallo!"}"#).unwrap();
+
+    ctx.merge(builder.build().unwrap(), ConflictPolicy::Overwrite).unwrap();
+
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+    assert_eq!("Hello, world!", ctx.get_text_with_key("de", "hello").unwrap());
+    assert_eq!(2, ctx.get_quarantined_texts().len());
+}
+
+#[test]
+fn scan_added_languages() {
+    let mut ctx = build();
+
+    let mut map = HashMap::new();
+    map.insert(String::from("hello"), JSONGetTextValue::from_str("damn"));
+    map.insert(String::from("rust"), JSONGetTextValue::from_str("Rost!"));
+
+    ctx.add_language("de", map).unwrap();
+
+    assert_eq!("Hello, world!", ctx.get_text_with_key("de", "hello").unwrap());
+    assert_eq!("Rost!", ctx.get_text_with_key("de", "rust").unwrap());
+
+    let mut map = HashMap::new();
+    map.insert(String::from("hello"), JSONGetTextValue::from_str("len@magiclen.org"));
+
+    assert!(matches!(
+        ctx.add_language("fr", map),
+        Err(JSONGetTextBuildError::ContentRejected { .. })
+    ));
+    assert!(!ctx.contains_key("fr"));
+}
+
+#[test]
+fn scan_patched_languages() {
+    let shared = SharedJSONGetText::new(build());
+
+    let mut texts = HashMap::new();
+    texts.insert(String::from("hello"), JSONGetTextValue::from_str("damn"));
+    texts.insert(String::from("rust"), JSONGetTextValue::from_str("Rust！"));
+
+    shared.patch_language("zh_TW", texts).unwrap();
+
+    let ctx = shared.read();
+
+    // the quarantined text is dropped, so the current one is kept
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Rust！", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("hello", ctx.get_quarantined_texts()[0].text);
+
+    drop(ctx);
+
+    assert!(shared.insert_text("zh_TW", "rust", JSONGetTextValue::from_str("@")).is_err());
+    assert_eq!("Rust！", shared.read().get_text_with_key("zh_TW", "rust").unwrap());
+}
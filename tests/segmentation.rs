@@ -0,0 +1,27 @@
+#![cfg(feature = "segmentation")]
+
+use json_gettext::{split_sentences, JSONGetTextValue};
+
+#[test]
+fn sentences() {
+    assert_eq!(
+        vec!["Hello, world!", "How are you?", "Fine."],
+        split_sentences("Hello, world! How are you?  Fine.")
+    );
+
+    assert_eq!(
+        Some(vec!["哈囉，世界！", "你好嗎？"]),
+        JSONGetTextValue::from_str("哈囉，世界！你好嗎？").split_sentences()
+    );
+}
+
+#[test]
+fn sentences_of_blank_and_non_string_values() {
+    assert!(split_sentences("").is_empty());
+    assert!(split_sentences("  \n ").is_empty());
+    assert_eq!(vec!["No full stop"], split_sentences("No full stop"));
+    assert_eq!(vec!["First line", "Second line."], split_sentences("First line\n\nSecond line."));
+
+    assert_eq!(None, JSONGetTextValue::from_i64(1).split_sentences());
+    assert_eq!(None, JSONGetTextValue::from_bool(true).split_sentences());
+}
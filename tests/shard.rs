@@ -0,0 +1,48 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn shard() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let shard = ctx.shard("zh_TW").unwrap();
+
+    let hello = thread::spawn(move || shard.get("hello").unwrap().to_string()).join().unwrap();
+
+    assert_eq!("哈囉，世界！", hello);
+
+    assert!(ctx.shard("de").is_none());
+}
+
+#[test]
+fn evict_locale() {
+    let mut ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    assert!(ctx.evict_locale("en_US").is_none());
+
+    let shard = ctx.shard("zh_TW").unwrap();
+
+    let evicted = ctx.evict_locale("zh_TW").unwrap();
+
+    assert!(Arc::ptr_eq(&shard, &evicted));
+    assert!(!ctx.contains_key("zh_TW"));
+    assert_eq!("Hello, world!", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("哈囉，世界！", shard.get("hello").unwrap());
+
+    assert!(ctx.evict_locale("zh_TW").is_none());
+}
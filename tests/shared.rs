@@ -0,0 +1,102 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError, JSONGetTextValue, SharedJSONGetText};
+
+fn build(hello: &str) -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json_owned("en_US", format!(r#"{{"hello": "{}"}}"#, hello)).unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn read_and_replace() {
+    let shared = SharedJSONGetText::new(build("Hello!"));
+
+    let guard = shared.read();
+
+    let old = shared.replace(build("Hi!"));
+
+    assert_eq!("Hello!", guard.get_text("hello").unwrap());
+    assert_eq!("Hello!", old.get_text("hello").unwrap());
+    assert_eq!("Hi!", shared.read().get_text("hello").unwrap());
+
+    drop(guard);
+
+    assert_eq!(1, Arc::strong_count(&old));
+}
+
+#[test]
+fn concurrent_reload() {
+    let shared = Arc::new(SharedJSONGetText::new(build("0")));
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = shared.clone();
+
+            thread::spawn(move || {
+                let mut last = 0;
+
+                for _ in 0..1000 {
+                    let n: u32 =
+                        shared.read().get_text("hello").unwrap().as_str().unwrap().parse().unwrap();
+
+                    assert!(n >= last);
+
+                    last = n;
+                }
+            })
+        })
+        .collect();
+
+    for n in 1..=100 {
+        shared.replace(build(&n.to_string()));
+    }
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    assert_eq!("100", shared.read().get_text("hello").unwrap());
+}
+
+#[test]
+fn patch_and_insert() {
+    let shared = SharedJSONGetText::new(build("Hello!"));
+
+    let guard = shared.read();
+
+    shared.insert_text("en_US", "hello", JSONGetTextValue::from_str("Hi!")).unwrap();
+
+    assert_eq!("Hello!", guard.get_text("hello").unwrap());
+    assert_eq!("Hi!", shared.read().get_text("hello").unwrap());
+
+    let mut texts = HashMap::new();
+
+    texts.insert(String::from("hello"), JSONGetTextValue::from_str("哈囉！"));
+
+    shared.patch_language("zh_TW", texts).unwrap();
+
+    assert_eq!("哈囉！", shared.read().get_text_with_key("zh_TW", "hello").unwrap());
+
+    let mut texts = HashMap::new();
+
+    texts.insert(String::from("bye"), JSONGetTextValue::from_str("再見！"));
+
+    assert!(matches!(
+        shared.patch_language("zh_TW", texts),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { .. })
+    ));
+
+    shared.insert_text("en_US", "bye", JSONGetTextValue::from_str("Bye!")).unwrap();
+
+    let ctx = shared.read();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Bye!", ctx.get_text_with_key("zh_TW", "bye").unwrap());
+}
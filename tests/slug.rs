@@ -0,0 +1,55 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::JSONGetText;
+
+fn ctx() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"about": "About Us", "contact": "Contact", "slugs.about": "about", "slugs.contact": "contact", "@about": {}}"#,
+        )
+        .unwrap()
+        .add_json(
+            "de",
+            r#"{"about": "Über uns", "contact": "Kontakt", "slugs.contact": "kontakt-formular"}"#,
+        )
+        .unwrap()
+        .add_json("ru", r#"{"contact": "Контакты"}"#)
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn translated_slugs() {
+    let ctx = ctx();
+
+    assert_eq!(Some("about"), ctx.get_slug("en_US", "about").as_deref());
+    assert_eq!(Some("kontakt-formular"), ctx.get_slug("de", "contact").as_deref());
+    assert_eq!(Some("contact"), ctx.find_slug_id("de", "kontakt-formular"));
+    assert_eq!(None, ctx.find_slug_id("de", "nothing"));
+}
+
+#[cfg(feature = "slug")]
+#[test]
+fn slugify() {
+    use json_gettext::Key;
+
+    assert_eq!("ueber-uns", json_gettext::slugify(&Key::from("de"), "Über uns!"));
+    assert_eq!("uber-uns", json_gettext::slugify(&Key::from("fr"), "  Über  uns "));
+    assert_eq!("strasse-nicht", json_gettext::slugify(&Key::from("en"), "Straße, nicht"));
+    assert_eq!("kontakty", json_gettext::slugify(&Key::from("ru"), "Контакты"));
+    assert_eq!("heorhii", json_gettext::slugify(&Key::from("uk"), "Георгій"));
+    assert_eq!("hello-世界", json_gettext::slugify(&Key::from("en"), "Hello, 世界"));
+
+    let ctx = ctx();
+
+    assert_eq!(Some("ueber-uns"), ctx.get_slug("de", "about").as_deref());
+    assert_eq!(Some("kontakty"), ctx.get_slug("ru", "contact").as_deref());
+    assert_eq!(Some("about"), ctx.get_slug("ru", "about").as_deref());
+    assert_eq!(Some("contact"), ctx.get_slug("en_US", "contact").as_deref());
+    assert_eq!(Some("about"), ctx.find_slug_id("de", "ueber-uns"));
+    assert_eq!(Some("contact"), ctx.find_slug_id("ru", "kontakty"));
+}
@@ -0,0 +1,61 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{interpolate, spell_out, JSONGetText, JSONGetTextBuildError, Key};
+
+#[test]
+fn spell_out_text() {
+    assert_eq!("[hello]", spell_out("hello", None));
+    assert_eq!("[hello]", spell_out("hello", Some("Hello, world!")));
+    assert_eq!(
+        "[cart.summary {{count}} {{total}}]",
+        spell_out("cart.summary", Some("{count} items, {total} in total ({count})"))
+    );
+    assert_eq!("[odd{{id}}]", spell_out("odd{id}", None));
+}
+
+#[test]
+fn spell_out_key() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "hello": "Hello, {name}!",
+                "rust": "Rust!",
+                "limits": {"max": 3},
+                "@hello": "A greeting"
+            }"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉，{name}！"}"#)
+        .unwrap()
+        .set_spell_out_key("ids");
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("[hello {{name}}]", ctx.get_text_with_key("ids", "hello").unwrap().to_string());
+    assert_eq!("[rust]", ctx.get_text_with_key("ids", "rust").unwrap().to_string());
+    assert_eq!(r#"{"max":3}"#, ctx.get_text_with_key("ids", "limits").unwrap().to_json_string());
+    assert_eq!("A greeting", ctx.get("ids")["@hello"].to_string());
+
+    let args: HashMap<&str, _> = HashMap::new();
+
+    assert_eq!(
+        "[hello {name}]",
+        interpolate(&ctx.get_text_with_key("ids", "hello").unwrap().to_string(), &args)
+    );
+
+    assert_eq!("哈囉，{name}！", ctx.get_text_with_key("zh_TW", "hello").unwrap().to_string());
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", "{}").unwrap().set_spell_out_key("en_US");
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::DuplicatedKey(key)) if key == Key::from("en_US")
+    ));
+}
@@ -0,0 +1,82 @@
+#![cfg(all(feature = "sqlite", not(feature = "langid")))]
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+use rusqlite::Connection;
+
+fn connection() -> Connection {
+    let connection = Connection::open_in_memory().unwrap();
+
+    connection
+        .execute_batch(
+            r#"
+            CREATE TABLE translations (
+                locale TEXT NOT NULL,
+                text_id TEXT NOT NULL,
+                value,
+                PRIMARY KEY (locale, text_id)
+            );
+
+            INSERT INTO translations VALUES
+                ('en_US', 'hello', 'Hello!'),
+                ('en_US', 'rust', 'Rust!'),
+                ('en_US', 'max', 10),
+                ('zh_TW', 'hello', '哈囉！'),
+                ('fr', 'hello', NULL);
+            "#,
+        )
+        .unwrap();
+
+    connection
+}
+
+#[test]
+fn add_from_table() {
+    let connection = connection();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_from_table(&connection, "translations").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello!", ctx.get_text("hello").unwrap());
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("10", ctx.get_text("max").unwrap().to_json_string());
+    assert!(ctx.get_text_with_key("fr", "hello").unwrap().as_str().is_none());
+}
+
+#[test]
+fn add_from_query() {
+    let connection = connection();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_from_query(
+            &connection,
+            "SELECT locale, text_id, value FROM translations WHERE locale = ?1",
+            ["zh_TW"],
+        )
+        .unwrap();
+
+    assert!(matches!(
+        builder.add_from_query(
+            &connection,
+            "SELECT locale, text_id, value FROM translations WHERE locale = ?1",
+            ["en_US"],
+        ),
+        Err(JSONGetTextBuildError::DuplicatedKey(_))
+    ));
+
+    assert!(matches!(
+        builder.add_from_query(&connection, "SELECT 'fr', 'hello', x'00'", ()),
+        Err(JSONGetTextBuildError::SQLiteError(_))
+    ));
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+}
@@ -0,0 +1,113 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, LengthBucket};
+
+#[test]
+fn stats() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "hello": "Hello, {name}!",
+                "apple": {"one": "{count} apple", "other": "{count} apples"},
+                "long": "This sentence is long enough to be in the bucket of up to a hundred chars.",
+                "@hello": "A greeting"
+            }"#,
+        )
+        .unwrap()
+        .add_json_ns("en_US", "help", r#"{"reset": "Reset"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "{name}，哈囉！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let stats = ctx.stats();
+
+    assert_eq!(vec!["en_US", "zh_TW"], stats.keys().collect::<Vec<_>>());
+
+    let en = &stats["en_US"];
+
+    assert_eq!(4, en.text_count);
+    assert_eq!(0, en.filled_count);
+    assert_eq!(5, en.string_count);
+    assert_eq!(1, en.plural_count);
+    assert_eq!(3, en.placeholder_count);
+    assert_eq!(2 + 2 + 2 + 16 + 1, en.word_count);
+    assert_eq!(74, en.max_length);
+    assert_eq!(Some(&1), en.namespace_sizes.get("help"));
+    assert_eq!(
+        LengthBucket {
+            min: 0,
+            max: Some(10),
+            count: 1
+        },
+        en.length_histogram[0]
+    );
+    assert_eq!(3, en.length_histogram[1].count);
+    assert_eq!(1, en.length_histogram[3].count);
+    assert_eq!(None, en.length_histogram.last().unwrap().max);
+    assert_eq!(en.character_count as f64 / 5.0, en.mean_length);
+
+    let zh = &stats["zh_TW"];
+
+    assert_eq!(4, zh.text_count);
+    assert_eq!(3, zh.filled_count);
+    assert_eq!(5, zh.string_count);
+    assert_eq!(3, zh.placeholder_count);
+
+    let json = serde_json::to_value(&stats).unwrap();
+
+    assert_eq!(1, json["zh_TW"]["namespace_sizes"]["help"]);
+}
+
+#[test]
+fn stats_of_non_string_values() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "count": 3,
+                "enabled": true,
+                "empty": "",
+                "unicode": "日本語のテキスト",
+                "menu": {"file": "File", "@file": "The file menu", "edit": "Edit"}
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let en = &ctx.stats()["en_US"];
+
+    // numbers and booleans are texts without strings, and metadata in nested objects is skipped
+    assert_eq!(5, en.text_count);
+    assert_eq!(4, en.string_count);
+    assert_eq!(0, en.plural_count);
+    assert_eq!(0, en.placeholder_count);
+    // lengths are counted in characters
+    assert_eq!(8, en.max_length);
+    assert_eq!(8 + 4 + 4, en.character_count);
+    assert_eq!(3, en.word_count);
+    assert_eq!(4, en.length_histogram[0].count);
+    assert!(en.namespace_sizes.is_empty());
+}
+
+#[test]
+fn stats_of_empty_catalogs() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", "{}").unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let en = &ctx.stats()["en_US"];
+
+    assert_eq!(0, en.text_count);
+    assert_eq!(0.0, en.mean_length);
+    assert_eq!(0, en.length_histogram.iter().map(|bucket| bucket.count).sum::<usize>());
+}
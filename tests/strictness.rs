@@ -0,0 +1,111 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{ExtraText, JSONGetText, JSONGetTextBuildError, JSONGetTextValue, Strictness};
+
+fn build(strictness: Strictness) -> Result<JSONGetText<'static>, JSONGetTextBuildError> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!", "rust": "Rust!"}"#)
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！", "stray": "多餘", "@hello": "ok"}"#)
+        .unwrap()
+        .add_json("fr", r#"{"hello": "Bonjour !", "bye": "Au revoir !", "another": "Encore"}"#)
+        .unwrap()
+        .set_strictness(strictness);
+
+    builder.build()
+}
+
+#[test]
+fn strictness() {
+    assert!(matches!(
+        build(Strictness::Strict),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { .. })
+    ));
+
+    let extra_texts = vec![
+        ExtraText {
+            key: "fr".to_string(),
+            text: "another".to_string(),
+        },
+        ExtraText {
+            key: "fr".to_string(),
+            text: "bye".to_string(),
+        },
+        ExtraText {
+            key: "zh_TW".to_string(),
+            text: "stray".to_string(),
+        },
+    ];
+
+    let ctx = build(Strictness::Warn).unwrap();
+
+    assert_eq!(extra_texts, ctx.get_extra_texts());
+    assert_eq!("多餘", ctx.get_text_with_key("zh_TW", "stray").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+
+    let ctx = build(Strictness::Prune).unwrap();
+
+    assert_eq!(extra_texts, ctx.get_extra_texts());
+    assert_eq!(None, ctx.get_text_with_key("zh_TW", "stray"));
+    assert_eq!(None, ctx.get_text_with_key("fr", "bye"));
+    assert_eq!("Bonjour !", ctx.get_text_with_key("fr", "hello").unwrap());
+}
+
+#[test]
+fn strictness_of_metadata() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("de", r#"{"hello": "Hallo!", "@hello": {"note": "kept"}, "@bye": {}}"#)
+        .unwrap()
+        .set_strictness(Strictness::Warn);
+
+    // metadata of a text in the default key is not an extra text, but orphan metadata is
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(
+        vec![ExtraText {
+            key: "de".to_string(),
+            text: "@bye".to_string(),
+        }],
+        ctx.get_extra_texts()
+    );
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("de", r#"{"bye": "Tschüss!", "@bye": {"note": "dropped"}, "@hello": {}}"#)
+        .unwrap()
+        .set_strictness(Strictness::Prune);
+
+    let ctx = builder.build().unwrap();
+
+    // the metadata of a pruned text is pruned along with it
+    assert_eq!(None, ctx.get_text_with_key("de", "@bye"));
+    assert!(ctx.get_text_with_key("de", "@hello").is_some());
+}
+
+#[test]
+fn strictness_of_later_texts() {
+    let mut ctx = build(Strictness::Prune).unwrap();
+
+    ctx.insert_text("fr", "later", JSONGetTextValue::from_str("Plus tard")).unwrap();
+
+    assert_eq!(None, ctx.get_text_with_key("fr", "later"));
+    assert!(ctx.get_extra_texts().iter().any(|extra| extra.text == "later"));
+    // the extra texts found when building are kept
+    assert_eq!(4, ctx.get_extra_texts().len());
+
+    let mut ctx = build(Strictness::Warn).unwrap();
+
+    ctx.insert_text("zh_TW", "later", JSONGetTextValue::from_str("稍後")).unwrap();
+
+    assert_eq!("稍後", ctx.get_text_with_key("zh_TW", "later").unwrap());
+    assert_eq!(4, ctx.get_extra_texts().len());
+}
@@ -0,0 +1,80 @@
+#![cfg(not(feature = "langid"))]
+
+use json_gettext::{JSONGetText, TextNotFound};
+
+#[test]
+fn did_you_mean() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{"hello": "Hello!", "help": "Help", "settings": {"profile": "Profile"}, "@hello": {}}"#,
+        )
+        .unwrap()
+        .add_json("zh_TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("哈囉！", ctx.try_get_text_with_key("zh_TW", "hello").unwrap());
+
+    let error = ctx.try_get_text("helo").unwrap_err();
+
+    assert_eq!(
+        TextNotFound {
+            key: "en_US".to_string(),
+            text: "helo".to_string(),
+            suggestions: vec!["hello".to_string(), "help".to_string()],
+        },
+        error
+    );
+    assert_eq!(
+        "The text `helo` is not found in the key `en_US`. Did you mean `hello`, `help`?",
+        error.to_string()
+    );
+
+    let error = ctx.try_get_text_with_key("zh_TW", "settings.profil").unwrap_err();
+
+    assert_eq!(vec!["settings.profile"], error.suggestions);
+
+    let error = ctx.try_get_text("goodbye").unwrap_err();
+
+    assert!(error.suggestions.is_empty());
+    assert_eq!("The text `goodbye` is not found in the key `en_US`.", error.to_string());
+}
+
+#[test]
+fn did_you_mean_with_typos() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "account.delete": "Delete account",
+                "account.deleted": "Account deleted",
+                "account.details": "Account details",
+                "café": "Café",
+                "@account.delete": {}
+            }"#,
+        )
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let error = ctx.try_get_text("acount.delete").unwrap_err();
+
+    // the nearest text comes first
+    assert_eq!("account.delete", error.suggestions[0]);
+    assert!(!error.suggestions.iter().any(|id| id.starts_with('@')));
+
+    // distances are counted in characters
+    assert_eq!(vec!["café"], ctx.try_get_text("cafe").unwrap_err().suggestions);
+
+    // a key which is not in context is looked up in the default key
+    let error = ctx.try_get_text_with_key("fr", "cafe").unwrap_err();
+
+    assert_eq!("en_US", error.key);
+    assert_eq!(vec!["café"], error.suggestions);
+}
@@ -0,0 +1,84 @@
+#![cfg(all(feature = "toml", not(feature = "langid")))]
+
+#[cfg(feature = "fs")]
+use std::fs;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+const EN_US_TOML: &str = r#"
+hello = "Hello, world!"
+rust = "Rust!"
+
+[apples]
+one = "{count} apple"
+other = "{count} apples"
+"#;
+
+#[test]
+fn add_toml() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_toml("en_US", EN_US_TOML).unwrap();
+    builder
+        .add_toml("zh_TW", "hello = \"哈囉，世界！\"\n\n[apples]\nother = \"{count} 個蘋果\"\n")
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("{count} apples", ctx.get_plural_text("en_US", "apples", 2).unwrap());
+    assert_eq!("{count} 個蘋果", ctx.get_plural_text("zh_TW", "apples", 1).unwrap());
+}
+
+#[test]
+fn add_toml_errors() {
+    let mut builder = JSONGetText::build("en_US");
+
+    assert!(matches!(
+        builder.add_toml("en_US", "hello = "),
+        Err(JSONGetTextBuildError::TOMLError(_))
+    ));
+
+    builder.add_toml("en_US", EN_US_TOML).unwrap();
+
+    assert!(matches!(
+        builder.add_toml("en_US", EN_US_TOML),
+        Err(JSONGetTextBuildError::DuplicatedKey(_))
+    ));
+
+    builder.add_toml("zh_TW", "bye = \"再見！\"\n").unwrap();
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { text, .. }) if text == "bye"
+    ));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn add_toml_file() {
+    let dir = std::env::temp_dir().join("json-gettext-add-toml-file");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("en_US.toml"), EN_US_TOML).unwrap();
+    fs::write(dir.join("zh_TW.toml"), [0xFF, 0xFE]).unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_toml_file("en_US", dir.join("en_US.toml")).unwrap();
+
+    assert!(matches!(
+        builder.add_toml_file("zh_TW", dir.join("zh_TW.toml")),
+        Err(JSONGetTextBuildError::IOError(_))
+    ));
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Rust!", ctx.get_text("rust").unwrap());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
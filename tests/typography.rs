@@ -0,0 +1,39 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::JSONGetText;
+
+#[test]
+fn normalize_quotation() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_json("en_US", r#"{"quote": "Don't say \"it's 'fine'\"."}"#).unwrap();
+    builder.add_json("de", r#"{"quote": "Sag nicht \"alles 'gut'\"."}"#).unwrap();
+    builder.add_json("fr", r#"{"quote": "Ne dis pas \"c'est bon\"."}"#).unwrap();
+    builder.add_json("zh_TW", r#"{"quote": "別說\"沒事\"。"}"#).unwrap();
+    builder.normalize_quotation(true);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Don’t say “it’s ‘fine’”.", ctx.get_text("quote").unwrap());
+    assert_eq!("Sag nicht „alles ‚gut‘“.", ctx.get_text_with_key("de", "quote").unwrap());
+    assert_eq!("Ne dis pas «c’est bon».", ctx.get_text_with_key("fr", "quote").unwrap());
+    assert_eq!("別說「沒事」。", ctx.get_text_with_key("zh_TW", "quote").unwrap());
+}
+
+#[test]
+fn normalize_quotation_keeps_conditionals() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"quote": "{isAdmin ? \"Don't\" : \"Can't\"} say \"no\"."}"#)
+        .unwrap();
+    builder.normalize_quotation(true);
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Don’t say “no”.", get_text_fmt!(ctx, "quote", isAdmin = true).unwrap());
+    assert_eq!("Can’t say “no”.", get_text_fmt!(ctx, "quote", isAdmin = false).unwrap());
+}
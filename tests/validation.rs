@@ -0,0 +1,95 @@
+#![cfg(not(feature = "langid"))]
+
+use std::collections::HashMap;
+
+use json_gettext::{JSONGetText, JSONGetTextValue};
+
+fn ctx() -> JSONGetText<'static> {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json(
+            "en_US",
+            r#"{
+                "fields.email": "Email",
+                "fields.name": "Name",
+                "validation.email": "{field} must be an email address.",
+                "validation.length": "{field} must have {min} to {max} characters.",
+                "validation.name.required": "Please tell us your name.",
+                "validation.invalid": "{field} is not valid."
+            }"#,
+        )
+        .unwrap();
+
+    builder
+        .add_json(
+            "zh_TW",
+            r#"{
+                "fields.email": "電子郵件",
+                "validation.email": "{field}必須是電子郵件地址。"
+            }"#,
+        )
+        .unwrap();
+
+    builder.build().unwrap()
+}
+
+#[test]
+fn localize_field_error() {
+    let ctx = ctx();
+
+    let mut params = HashMap::new();
+    params.insert("min", JSONGetTextValue::from_u8(2));
+    params.insert("max", JSONGetTextValue::from_u8(20));
+
+    assert_eq!(
+        "電子郵件必須是電子郵件地址。",
+        ctx.localize_field_error("zh_TW", "email", "email", None, &params)
+    );
+    assert_eq!(
+        "Name must have 2 to 20 characters.",
+        ctx.localize_field_error("en_US", "name", "length", None, &params)
+    );
+    assert_eq!(
+        "Please tell us your name.",
+        ctx.localize_field_error("en_US", "name", "required", None, &params)
+    );
+    assert_eq!(
+        "Use digits only.",
+        ctx.localize_field_error("en_US", "phone", "digits", Some("Use digits only."), &params)
+    );
+    assert_eq!(
+        "phone is not valid.",
+        ctx.localize_field_error("en_US", "phone", "digits", None, &params)
+    );
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn localize_validation_errors() {
+    use std::borrow::Cow;
+
+    use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+    let ctx = ctx();
+
+    let mut length = ValidationError::new("length");
+    length.add_param(Cow::from("min"), &2);
+    length.add_param(Cow::from("max"), &20);
+
+    let mut address = ValidationErrors::new();
+    address.add("city", ValidationError::new("required"));
+
+    let mut errors = ValidationErrors::new();
+    errors.add("name", length);
+    errors.add("email", ValidationError::new("email"));
+    errors
+        .errors_mut()
+        .insert(Cow::from("address"), ValidationErrorsKind::Struct(Box::new(address)));
+
+    let messages = ctx.localize_validation_errors("zh_TW", &errors);
+
+    assert_eq!(vec!["address.city is not valid."], messages["address.city"]);
+    assert_eq!(vec!["電子郵件必須是電子郵件地址。"], messages["email"]);
+    assert_eq!(vec!["Name must have 2 to 20 characters."], messages["name"]);
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "watch", not(feature = "langid")))]
+
+use std::fs;
+use std::process::Command;
+
+fn watch_once(directory: &str) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_json-gettext-watch"))
+        .args(["--once", "en_US", directory])
+        .output()
+        .unwrap();
+
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn watch_once_valid() {
+    let (success, output) = watch_once(concat!(env!("CARGO_MANIFEST_DIR"), "/langs"));
+
+    assert!(success, "{}", output);
+    assert!(output.ends_with("ok: 2 key(s), 4 text(s)\n"), "{}", output);
+}
+
+#[test]
+fn watch_once_problems() {
+    let directory = std::env::temp_dir().join(format!("json-gettext-watch-{}", std::process::id()));
+
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("en_US.json"), r#"{"hello": "Hello!", "rust": "Rust!"}"#).unwrap();
+    fs::write(directory.join("de.json"), r#"{"hello": "Hallo!"}"#).unwrap();
+
+    let (success, output) = watch_once(directory.to_str().unwrap());
+
+    assert!(success, "{}", output);
+    assert!(output.contains("warning: de is 50% translated, missing rust\n"), "{}", output);
+
+    fs::write(directory.join("de.json"), r#"{"bye": "Tschüss!"}"#).unwrap();
+
+    let (success, output) = watch_once(directory.to_str().unwrap());
+
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(!success);
+    assert!(output.starts_with("error: "), "{}", output);
+    assert!(output.contains("bye"), "{}", output);
+}
@@ -0,0 +1,291 @@
+#![cfg(not(feature = "langid"))]
+
+#[macro_use]
+extern crate json_gettext;
+
+use json_gettext::{
+    AcceptLanguage, AsyncLocaleResolverChain, GeoIpResolver, JSONGetText, Key, LocaleRequest,
+    LocaleResolver, LocaleResolverChain, QueryResolver, ResolveFuture,
+};
+
+#[test]
+fn parse() {
+    let accept_language = AcceptLanguage::parse("fr-CH, fr;q=0.9, en;q=0.8, de;q=0, *;q=0.5");
+
+    let tags: Vec<&str> = accept_language.iter().map(|range| range.tag.as_str()).collect();
+
+    assert_eq!(vec!["fr-CH", "fr", "en", "*", "de"], tags);
+    assert!(accept_language.accepts_any());
+
+    assert_eq!(
+        vec![Key::from("fr_CH"), Key::from("fr"), Key::from("en")],
+        accept_language.to_keys()
+    );
+
+    assert_eq!(vec![Key::from("zh_Hant_TW")], AcceptLanguage::parse("zh-hant-tw").to_keys());
+    assert!(AcceptLanguage::parse("").is_empty());
+}
+
+#[test]
+fn locale_cookie() {
+    let mut cookie = json_gettext::LocaleCookie::new("locale");
+
+    cookie.same_site(json_gettext::SameSite::Strict).max_age(None);
+
+    assert_eq!("locale=zh_TW; Path=/; SameSite=Strict", cookie.to_set_cookie(&Key::from("zh_TW")));
+    assert_eq!("locale=; Path=/; SameSite=Strict; Max-Age=0", cookie.to_removal_set_cookie());
+    assert_eq!(Some(Key::from("zh_TW")), cookie.read("theme=dark; locale=zh_TW"));
+    assert_eq!(None, cookie.read("theme=dark"));
+}
+
+#[test]
+fn resolver_chain() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let chain = LocaleResolverChain::default();
+
+    let request = LocaleRequest {
+        query: Some("page=2&lang=zh-TW"),
+        accept_language: Some("en-US"),
+        ..LocaleRequest::default()
+    };
+
+    assert_eq!(Key::from("zh_TW"), chain.resolve(&ctx, &request));
+    assert_eq!(Some(Key::from("zh_TW")), QueryResolver::default().resolve(&ctx, &request));
+
+    let request = LocaleRequest {
+        query: Some("lang=fr"),
+        form: Some("name=Magic+Len&lang=zh%2Dtw"),
+        ..LocaleRequest::default()
+    };
+
+    assert_eq!(Key::from("zh_TW"), chain.resolve(&ctx, &request));
+
+    let request = LocaleRequest {
+        cookie: Some("theme=dark; lang=zh_TW"),
+        accept_language: Some("en-US"),
+        ..LocaleRequest::default()
+    };
+
+    assert_eq!(Key::from("zh_TW"), chain.resolve(&ctx, &request));
+
+    let request = LocaleRequest {
+        accept_language: Some("fr, zh-TW;q=0.5"),
+        ..LocaleRequest::default()
+    };
+
+    assert_eq!(Key::from("zh_TW"), chain.resolve(&ctx, &request));
+    assert_eq!(Key::from("en_US"), chain.resolve(&ctx, &LocaleRequest::default()));
+}
+
+#[test]
+fn resolver_chain_with_hyphenated_keys() {
+    let mut builder = JSONGetText::build("en-US");
+
+    builder
+        .add_json("en-US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("zh-TW", r#"{"hello": "哈囉！"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    let chain = LocaleResolverChain::default();
+
+    // tags are converted into `zh_TW`, which stands for the `zh-TW` catalog
+    for request in [
+        LocaleRequest {
+            query: Some("lang=zh-TW"),
+            ..LocaleRequest::default()
+        },
+        LocaleRequest {
+            form: Some("lang=zh_tw"),
+            ..LocaleRequest::default()
+        },
+        LocaleRequest {
+            cookie: Some("lang=zh_TW"),
+            ..LocaleRequest::default()
+        },
+    ] {
+        assert_eq!(Key::from("zh-TW"), chain.resolve(&ctx, &request));
+    }
+
+    assert_eq!(
+        Some(Key::from("zh-TW")),
+        QueryResolver::default().resolve(&ctx, &LocaleRequest {
+            query: Some("lang=zh-TW"),
+            ..LocaleRequest::default()
+        })
+    );
+    assert_eq!(Key::from("en-US"), chain.resolve(&ctx, &LocaleRequest::default()));
+}
+
+#[test]
+fn negotiate() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder
+        .add_json("en_US", r#"{"hello": "Hello!"}"#)
+        .unwrap()
+        .add_json("zh_Hant", r#"{"hello": "哈囉！"}"#)
+        .unwrap()
+        .add_json("zh_CN", r#"{"hello": "你好！"}"#)
+        .unwrap()
+        .add_json("fr", r#"{"hello": "Bonjour !"}"#)
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!(Key::from("fr"), ctx.negotiate("fr"));
+    assert_eq!(Key::from("fr"), ctx.negotiate("fr-CA, en;q=0.8"));
+    #[cfg(feature = "likely_subtags")]
+    assert_eq!(Key::from("zh_Hant"), ctx.negotiate("zh-TW"));
+    #[cfg(feature = "likely_subtags")]
+    assert_eq!(Key::from("zh_Hant"), ctx.negotiate("zh-HK"));
+    #[cfg(feature = "likely_subtags")]
+    assert_eq!(Key::from("zh_CN"), ctx.negotiate("zh-SG"));
+    #[cfg(feature = "likely_subtags")]
+    assert_eq!(Key::from("zh_CN"), ctx.negotiate("zh"));
+    assert_eq!(Key::from("en_US"), ctx.negotiate("en-GB"));
+    #[cfg(feature = "likely_subtags")]
+    assert_eq!(Key::from("zh_Hant"), ctx.negotiate("de, zh-TW;q=0.5, fr;q=0.4"));
+    assert_eq!(Key::from("en_US"), ctx.negotiate("de, *;q=0.5, fr;q=0.4"));
+    assert_eq!(Key::from("en_US"), ctx.negotiate("fr;q=0, de"));
+    assert_eq!(Key::from("en_US"), ctx.negotiate(""));
+
+    // explicit scripts are matched without the likely subtags
+    assert_eq!(Key::from("zh_Hant"), ctx.negotiate("zh-Hant-TW"));
+
+    #[cfg(feature = "likely_subtags")]
+    {
+        let request = LocaleRequest {
+            accept_language: Some("de, zh-TW;q=0.5"),
+            ..LocaleRequest::default()
+        };
+
+        assert_eq!(Key::from("zh_Hant"), LocaleResolverChain::default().resolve(&ctx, &request));
+    }
+}
+
+#[test]
+fn geoip_resolver() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let mut chain = LocaleResolverChain::default();
+
+    chain.push(GeoIpResolver::new(|ip: std::net::IpAddr| {
+        if ip.is_loopback() {
+            Some(String::from("tw"))
+        } else {
+            None
+        }
+    }));
+
+    let mut request = LocaleRequest {
+        client_ip: Some("127.0.0.1".parse().unwrap()),
+        ..LocaleRequest::default()
+    };
+
+    assert_eq!(Key::from("zh_TW"), chain.resolve(&ctx, &request));
+
+    request.accept_language = Some("en-US");
+
+    assert_eq!(Key::from("en_US"), chain.resolve(&ctx, &request));
+
+    request.accept_language = None;
+    request.client_ip = Some("8.8.8.8".parse().unwrap());
+
+    assert_eq!(Key::from("en_US"), chain.resolve(&ctx, &request));
+
+    let mut resolver = GeoIpResolver::new(|_| Some(String::from("HK")));
+
+    resolver.map_country("hk", "zh_TW");
+
+    request.client_ip = Some("127.0.0.1".parse().unwrap());
+
+    assert_eq!(Some(Key::from("zh_TW")), resolver.resolve(&ctx, &request));
+}
+
+struct ProfileResolver;
+
+// not imported, so that `resolve` of sync resolvers is not ambiguous
+impl json_gettext::AsyncLocaleResolver for ProfileResolver {
+    fn resolve<'f>(
+        &'f self,
+        _ctx: &'f JSONGetText<'_>,
+        request: &'f LocaleRequest<'_>,
+    ) -> ResolveFuture<'f> {
+        Box::pin(async move {
+            match request.user_id {
+                Some("magiclen") => Some(Key::from("zh_TW")),
+                Some(_) => Some(Key::from("fr")),
+                None => None,
+            }
+        })
+    }
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn async_resolver_chain() {
+    let ctx = static_json_gettext_build!(
+        "en_US";
+        "en_US" => "langs/en_US.json",
+        "zh_TW" => "langs/zh_TW.json",
+    )
+    .unwrap();
+
+    let mut chain = AsyncLocaleResolverChain::new();
+
+    chain.push(QueryResolver::default()).push(ProfileResolver);
+
+    let mut request = LocaleRequest {
+        user_id: Some("magiclen"),
+        ..LocaleRequest::default()
+    };
+
+    assert_eq!(Key::from("zh_TW"), block_on(chain.resolve(&ctx, &request)));
+
+    request.query = Some("lang=en-US");
+
+    assert_eq!(Key::from("en_US"), block_on(chain.resolve(&ctx, &request)));
+
+    request.query = None;
+    request.user_id = Some("someone");
+
+    assert_eq!(Key::from("en_US"), block_on(chain.resolve(&ctx, &request)));
+}
@@ -0,0 +1,82 @@
+#![cfg(all(feature = "yaml", not(feature = "langid")))]
+
+#[cfg(feature = "fs")]
+use std::fs;
+
+use json_gettext::{JSONGetText, JSONGetTextBuildError};
+
+const EN_US_YAML: &str = r#"
+hello: Hello, world!
+rust: Rust!
+apples:
+  one: "{count} apple"
+  other: "{count} apples"
+"#;
+
+#[test]
+fn add_yaml() {
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_yaml("en_US", EN_US_YAML).unwrap();
+    builder
+        .add_yaml("zh_TW", "hello: 哈囉，世界！\napples:\n  other: \"{count} 個蘋果\"\n")
+        .unwrap();
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Hello, world!", ctx.get_text("hello").unwrap());
+    assert_eq!("哈囉，世界！", ctx.get_text_with_key("zh_TW", "hello").unwrap());
+    assert_eq!("Rust!", ctx.get_text_with_key("zh_TW", "rust").unwrap());
+    assert_eq!("{count} apple", ctx.get_plural_text("en_US", "apples", 1).unwrap());
+    assert_eq!("{count} 個蘋果", ctx.get_plural_text("zh_TW", "apples", 1).unwrap());
+}
+
+#[test]
+fn add_yaml_errors() {
+    let mut builder = JSONGetText::build("en_US");
+
+    assert!(matches!(
+        builder.add_yaml("en_US", "- hello\n- rust\n"),
+        Err(JSONGetTextBuildError::SerdeYAMLError(_))
+    ));
+
+    builder.add_yaml("en_US", EN_US_YAML).unwrap();
+
+    assert!(matches!(
+        builder.add_yaml("en_US", EN_US_YAML),
+        Err(JSONGetTextBuildError::DuplicatedKey(_))
+    ));
+
+    builder.add_yaml("zh_TW", "bye: 再見！\n").unwrap();
+
+    assert!(matches!(
+        builder.build(),
+        Err(JSONGetTextBuildError::TextInKeyNotInDefaultKey { text, .. }) if text == "bye"
+    ));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn add_yaml_file() {
+    let dir = std::env::temp_dir().join("json-gettext-add-yaml-file");
+
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("en_US.yaml"), EN_US_YAML).unwrap();
+
+    let mut builder = JSONGetText::build("en_US");
+
+    builder.add_yaml_file("en_US", dir.join("en_US.yaml")).unwrap();
+
+    assert!(matches!(
+        builder.add_yaml_file("zh_TW", dir.join("zh_TW.yaml")),
+        Err(JSONGetTextBuildError::IOError(_))
+    ));
+
+    let ctx = builder.build().unwrap();
+
+    assert_eq!("Rust!", ctx.get_text("rust").unwrap());
+
+    fs::remove_dir_all(&dir).unwrap();
+}